@@ -0,0 +1,83 @@
+//! Benchmarks of calendar conversions
+
+use calendars::{
+    gregorian::{absolute_from_gregorian, gregorian_from_absolute, Gregorian},
+    hebrew::{hebrew_from_absolute, HebrewYearTable},
+    hindu::{absolute_from_old_hindu_lunar, old_hindu_lunar_from_absolute, OldHinduLunar},
+    islamic::islamic_from_absolute,
+    julian::julian_from_absolute,
+};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Range of absolute dates (about 30 years from 1900) used in benchmarks
+static DATES: std::ops::Range<i64> = 693596..704554;
+
+fn gregorian(c: &mut Criterion) {
+    c.bench_function("gregorian_from_absolute", |b| {
+        b.iter(|| {
+            for d in DATES.clone() {
+                black_box(gregorian_from_absolute(black_box(d)));
+            }
+        })
+    });
+    let dates: Vec<Gregorian> = DATES.clone().map(gregorian_from_absolute).collect();
+    c.bench_function("absolute_from_gregorian", |b| {
+        b.iter(|| {
+            for d in &dates {
+                black_box(absolute_from_gregorian(black_box(*d)));
+            }
+        })
+    });
+}
+
+fn islamic(c: &mut Criterion) {
+    c.bench_function("islamic_from_absolute", |b| {
+        b.iter(|| {
+            for d in DATES.clone() {
+                black_box(islamic_from_absolute(black_box(d)));
+            }
+        })
+    });
+}
+
+fn julian(c: &mut Criterion) {
+    c.bench_function("julian_from_absolute", |b| {
+        b.iter(|| {
+            for d in DATES.clone() {
+                black_box(julian_from_absolute(black_box(d)));
+            }
+        })
+    });
+}
+
+fn hebrew(c: &mut Criterion) {
+    c.bench_function("hebrew_from_absolute", |b| {
+        b.iter(|| {
+            for d in DATES.clone() {
+                black_box(hebrew_from_absolute(black_box(d)));
+            }
+        })
+    });
+    let table = HebrewYearTable::new(5650, 5700);
+    c.bench_function("HebrewYearTable::hebrew_from_absolute", |b| {
+        b.iter(|| {
+            for d in DATES.clone() {
+                black_box(table.hebrew_from_absolute(black_box(d)));
+            }
+        })
+    });
+}
+
+fn old_hindu_lunar(c: &mut Criterion) {
+    let dates: Vec<OldHinduLunar> = DATES.clone().map(old_hindu_lunar_from_absolute).collect();
+    c.bench_function("absolute_from_old_hindu_lunar", |b| {
+        b.iter(|| {
+            for d in &dates {
+                black_box(absolute_from_old_hindu_lunar(black_box(*d)));
+            }
+        })
+    });
+}
+
+criterion_group!(benches, gregorian, islamic, julian, hebrew, old_hindu_lunar);
+criterion_main!(benches);
@@ -0,0 +1,165 @@
+//! R bindings of the calendars crate
+//!
+//! Dates are passed as lists of numeric vectors, one per date component, so
+//! that whole data frame columns are converted in one call. Missing or
+//! invalid dates are converted to `NA`.
+
+#![allow(clippy::needless_return)]
+
+use calendars::{
+    error::CalendarError,
+    french::{checked_absolute_from_french, French},
+    gregorian::{checked_absolute_from_gregorian, Gregorian},
+    hebrew::{checked_absolute_from_hebrew, Hebrew},
+    hindu::{
+        checked_absolute_from_old_hindu_lunar, checked_absolute_from_old_hindu_solar,
+        OldHinduLunar, OldHinduSolar,
+    },
+    islamic::{checked_absolute_from_islamic, Islamic},
+    iso::{checked_absolute_from_iso, Iso},
+    julian::{checked_absolute_from_julian, Julian},
+    mayan::{checked_absolute_from_mayan_long_count, MayanLongCount},
+    ordinal::{checked_absolute_from_ordinal, Ordinal},
+    utility::{try_date_from_absolute, Date},
+};
+use extendr_api::prelude::*;
+
+/// Names of the date components of each calendar
+static COMPONENT_NAMES: [(&str, &[&str]); 12] = [
+    ("gregorian", &["year", "month", "day"]),
+    ("iso", &["year", "week", "day"]),
+    ("ordinal", &["year", "day"]),
+    ("julian", &["year", "month", "day"]),
+    ("islamic", &["year", "month", "day"]),
+    ("hebrew", &["year", "month", "day"]),
+    (
+        "mayanLongCount",
+        &["baktun", "katun", "tun", "uinal", "kin"],
+    ),
+    ("mayanHaab", &["day", "month"]),
+    ("mayanTzolkin", &["number", "name"]),
+    ("french", &["year", "month", "day"]),
+    ("oldHinduSolar", &["year", "month", "day"]),
+    ("oldHinduLunar", &["year", "month", "leap_month", "day"]),
+];
+
+/// Returns the names of the date components of a given calendar, or an error
+/// if the calendar is unknown.
+fn component_names(calendar: &str) -> std::result::Result<&'static [&'static str], CalendarError> {
+    return COMPONENT_NAMES
+        .iter()
+        .find(|(name, _)| *name == calendar)
+        .map(|(_, components)| *components)
+        .ok_or_else(|| CalendarError::UnknownCalendar(calendar.to_string()));
+}
+
+/// Converts an R number to an integer, or returns `None` if it is missing or
+/// not an integer.
+fn integer(x: Rfloat) -> Option<i64> {
+    if x.is_na() || x.inner().fract() != 0.0 {
+        return None;
+    }
+    return Some(x.inner() as i64);
+}
+
+/// Computes the absolute (fixed) date of a date of a given calendar, given
+/// its components.
+fn absolute_from_components(
+    calendar: &str,
+    components: Vec<i64>,
+) -> std::result::Result<i64, CalendarError> {
+    let d = Date {
+        calendar: calendar.to_string(),
+        components,
+        component_names: Vec::new(),
+        month_names: Vec::new(),
+    };
+    return match calendar {
+        "gregorian" => checked_absolute_from_gregorian(Gregorian::try_from(d)?),
+        "iso" => checked_absolute_from_iso(Iso::try_from(d)?),
+        "ordinal" => checked_absolute_from_ordinal(Ordinal::try_from(d)?),
+        "julian" => checked_absolute_from_julian(Julian::try_from(d)?),
+        "islamic" => checked_absolute_from_islamic(Islamic::try_from(d)?),
+        "hebrew" => checked_absolute_from_hebrew(Hebrew::try_from(d)?),
+        "mayanLongCount" => checked_absolute_from_mayan_long_count(MayanLongCount::try_from(d)?),
+        "french" => checked_absolute_from_french(French::try_from(d)?),
+        "oldHinduSolar" => checked_absolute_from_old_hindu_solar(OldHinduSolar::try_from(d)?),
+        "oldHinduLunar" => checked_absolute_from_old_hindu_lunar(OldHinduLunar::try_from(d)?),
+        "mayanHaab" | "mayanTzolkin" => Err(CalendarError::NotConvertible {
+            calendar: calendar.to_string(),
+        }),
+        _ => Err(CalendarError::UnknownCalendar(calendar.to_string())),
+    };
+}
+
+/// Returns the names of the date components of a given calendar.
+#[extendr]
+fn calendar_components(calendar: &str) -> Result<Strings> {
+    let names = component_names(calendar).map_err(|e| Error::Other(e.to_string()))?;
+    return Ok(names.iter().copied().collect());
+}
+
+/// Converts absolute (fixed) dates to dates of a given calendar, returned as
+/// a list of numeric vectors, one per date component.
+#[extendr]
+fn absolute_to_components(absolute_date: Doubles, calendar: &str) -> Result<List> {
+    let names = component_names(calendar).map_err(|e| Error::Other(e.to_string()))?;
+    let mut columns = vec![Vec::with_capacity(absolute_date.len()); names.len()];
+    for x in absolute_date.iter() {
+        let date = integer(x).and_then(|a| try_date_from_absolute(a, calendar).ok());
+        for (i, column) in columns.iter_mut().enumerate() {
+            column.push(match &date {
+                Some(d) => Rfloat::from(d.components[i] as f64),
+                None => Rfloat::na(),
+            });
+        }
+    }
+    return List::from_names_and_values(names, columns.into_iter().map(Doubles::from_values));
+}
+
+/// Converts dates of a given calendar, given as a list of numeric vectors
+/// (one per date component), to absolute (fixed) dates.
+#[extendr]
+fn components_to_absolute(components: List, calendar: &str) -> Result<Doubles> {
+    let names = component_names(calendar).map_err(|e| Error::Other(e.to_string()))?;
+    if components.len() != names.len() {
+        return Err(Error::Other(format!(
+            "a {} date has {} components, found {}",
+            calendar,
+            names.len(),
+            components.len()
+        )));
+    }
+    let columns = components
+        .values()
+        .map(Doubles::try_from)
+        .collect::<Result<Vec<Doubles>>>()?;
+    let rows = columns.first().map_or(0, |column| column.len());
+    if columns.iter().any(|column| column.len() != rows) {
+        return Err(Error::Other(
+            "all date components must have the same length".to_string(),
+        ));
+    }
+    let absolute_dates = (0..rows).map(|row| {
+        let date = columns
+            .iter()
+            .map(|column| integer(column.elt(row)))
+            .collect::<Option<Vec<i64>>>()
+            .and_then(|components| absolute_from_components(calendar, components).ok());
+        return match date {
+            Some(absolute_date) => Rfloat::from(absolute_date as f64),
+            None => Rfloat::na(),
+        };
+    });
+    return Ok(Doubles::from_values(absolute_dates));
+}
+
+// Macro to generate exports.
+// This ensures exported functions are registered with R.
+// See corresponding C code in `entrypoint.c`.
+extendr_module! {
+    mod calendars;
+    fn calendar_components;
+    fn absolute_to_components;
+    fn components_to_absolute;
+}
@@ -9,6 +9,10 @@ struct ReferenceDates {
     gregorian: Vec<Vec<i64>>,
     julian: Vec<[i64; 3]>,
     iso: Vec<[i64; 3]>,
+    ordinal: Vec<[i64; 2]>,
+    unix: Vec<i64>,
+    excel1900: Vec<i64>,
+    excel1904: Vec<i64>,
     islamic: Vec<[i64; 3]>,
     hebrew: Vec<[i64; 3]>,
     mayanlongcount: Vec<[i64; 5]>,
@@ -29,6 +33,13 @@ struct ReferenceHolidays {
     memorial_day: Vec<i64>,
     daylight_savings_start: Vec<i64>,
     daylight_savings_end: Vec<i64>,
+    new_years_day: Vec<i64>,
+    martin_luther_king_day: Vec<i64>,
+    presidents_day: Vec<i64>,
+    juneteenth: Vec<i64>,
+    columbus_day: Vec<i64>,
+    veterans_day: Vec<i64>,
+    thanksgiving: Vec<i64>,
     christmas: Vec<i64>,
     advent: Vec<i64>,
     epiphany: Vec<i64>,
@@ -42,6 +53,59 @@ struct ReferenceHolidays {
     purim: Vec<i64>,
     ta_anit_esther: Vec<i64>,
     tisha_b_av: Vec<i64>,
+    islamic_new_year: Vec<Vec<i64>>,
+    ashura: Vec<Vec<i64>>,
+    ramadan_start: Vec<Vec<i64>>,
+    laylat_al_qadr: Vec<Vec<i64>>,
+    eid_al_fitr: Vec<Vec<i64>>,
+    eid_al_adha: Vec<Vec<i64>>,
+    orthodox_easter: Vec<i64>,
+    great_lent_start: Vec<i64>,
+    orthodox_pentecost: Vec<i64>,
+    apostles_fast_start: Vec<i64>,
+    apostles_fast_end: Vec<i64>,
+    septuagesima: Vec<i64>,
+    mardi_gras: Vec<i64>,
+    ash_wednesday: Vec<i64>,
+    palm_sunday: Vec<i64>,
+    good_friday: Vec<i64>,
+    ascension: Vec<i64>,
+    trinity_sunday: Vec<i64>,
+    corpus_christi: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ReferenceDst {
+    //note: String,
+    year: Vec<i64>,
+    daylight_savings_start: Vec<i64>,
+    daylight_savings_end: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ReferenceParashah {
+    //note: String,
+    rd: Vec<i64>,
+    israel: Vec<bool>,
+    reading: Vec<Option<String>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+struct ReferenceSwedish {
+    //note: String,
+    //event: Vec<String>,
+    rd: Vec<i64>,
+    civil: Vec<[i64; 3]>,
+    style: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct ReferenceUmmAlQura {
+    //note: String,
+    rd: Vec<i64>,
+    umm_al_qura: Vec<[i64; 3]>,
 }
 
 macro_rules! test_from_absolute {
@@ -49,6 +113,7 @@ macro_rules! test_from_absolute {
         let calendar = match $calendar {
             "gregorian" =>  ["gregorian", "calendars::gregorian::*"],
             "iso" => ["iso", "calendars::iso::*"],
+            "ordinal" => ["ordinal", "calendars::ordinal::*"],
             "julian" => ["julian", "calendars::julian::*"],
             "islamic" => ["islamic", "calendars::islamic::*"],
             "hebrew" => ["hebrew", "calendars::hebrew::*"],
@@ -71,7 +136,7 @@ macro_rules! test_from_absolute {
         for (absolute_date, date_components) in ($rd).iter().zip(($components).iter()) {
             writeln!(
                 $file,
-                "#[test]\nfn {name}() {{\nassert_eq!({function}({rd}), {ref_date});\n}}",
+                "#[test]\nfn {name}() {{\nassert_eq!({function}({rd}), {ref_date});\n{checked}\n}}",
                 name = if *absolute_date < 0 {
                     "neg_".to_owned() + &absolute_date.abs().to_string()
                 } else {
@@ -79,7 +144,22 @@ macro_rules! test_from_absolute {
                 },
                 function = function,
                 rd = absolute_date,
-                ref_date = format!("{}({:?})", date_from_slice, date_components),
+                // dates before the epochs of the Islamic and French calendars
+                // are represented by all-zero components, and are clamped to
+                // the first day of the calendar
+                ref_date = if date_components.iter().all(|c| *c == 0) {
+                    format!("{}([1, 1, 1])", date_from_slice)
+                } else {
+                    format!("{}({:?})", date_from_slice, date_components)
+                },
+                checked = if date_components.iter().all(|c| *c == 0) {
+                    format!("assert!(checked_{}({}).is_err());", function, absolute_date)
+                } else {
+                    format!(
+                        "assert_eq!(checked_{}({}), Ok({}({:?})));",
+                        function, absolute_date, date_from_slice, date_components
+                    )
+                },
             )
             .unwrap();
         }
@@ -92,6 +172,7 @@ macro_rules! test_from_calendar {
         let calendar = match $calendar {
             "gregorian" =>  ["gregorian", "calendars::gregorian::*"],
             "iso" => ["iso", "calendars::iso::*"],
+            "ordinal" => ["ordinal", "calendars::ordinal::*"],
             "julian" => ["julian", "calendars::julian::*"],
             "islamic" => ["islamic", "calendars::islamic::*"],
             "hebrew" => ["hebrew", "calendars::hebrew::*"],
@@ -112,9 +193,13 @@ macro_rules! test_from_calendar {
         let date_from_slice = format!("{}_from_slice", calendar[0]);
         writeln!($file, "{}", s).unwrap();
         for (absolute_date, date_components) in ($rd).iter().zip(($components).iter()) {
+            // skip placeholders of dates preceding the calendar's epoch
+            if date_components.iter().all(|c| *c == 0) {
+                continue;
+            }
             writeln!(
                 $file,
-                "#[test]\nfn {name}() {{\nassert_eq!({function}({date}), {rd});\n}}",
+                "#[test]\nfn {name}() {{\nassert_eq!({function}({date}), {rd});\nassert_eq!(checked_{function}({date}), Ok({absolute_date}));\n}}",
                 name = if *absolute_date < 0 {
                     "neg_".to_owned() + &absolute_date.abs().to_string()
                 } else {
@@ -134,6 +219,140 @@ macro_rules! test_from_calendar {
     };
 }
 
+macro_rules! test_date_round_trip {
+    ($file:ident, $calendar:literal, $rd:expr, $components:expr) => {
+        let calendar = match $calendar {
+            "gregorian" =>  ["gregorian", "gregorian::Gregorian"],
+            "iso" => ["iso", "iso::Iso"],
+            "ordinal" => ["ordinal", "ordinal::Ordinal"],
+            "julian" => ["julian", "julian::Julian"],
+            "islamic" => ["islamic", "islamic::Islamic"],
+            "hebrew" => ["hebrew", "hebrew::Hebrew"],
+            "mayanLongCount" => ["mayan_long_count", "mayan::MayanLongCount"],
+            "mayanHaab" => ["mayan_haab", "mayan::MayanHaab"],
+            "mayanTzolkin" => ["mayan_tzolkin", "mayan::MayanTzolkin"],
+            "french" =>  ["french", "french::French"],
+            "oldHinduSolar" =>  ["old_hindu_solar", "hindu::OldHinduSolar"],
+            "oldHinduLunar" =>  ["old_hindu_lunar", "hindu::OldHinduLunar"],
+            _ => ["", ""],
+        };
+        let mod_name = format!("test_{}_date_round_trip", calendar[0]);
+        let s = format!(
+            "#[cfg(test)]\nmod {} {{\nextern crate calendars;\nuse calendars::utility::*;\nuse calendars::helper::*;",
+            mod_name
+        );
+        let date_from_slice = format!("{}_from_slice", calendar[0]);
+        writeln!($file, "{}", s).unwrap();
+        for (absolute_date, date_components) in ($rd).iter().zip(($components).iter()) {
+            writeln!(
+                $file,
+                "#[test]\nfn {name}() {{\nlet d = {ref_date};\nassert_eq!(calendars::{date_type}::try_from(d.to_date()).unwrap(), d);\nassert_eq!(d.to_date().to_calendar_date().to_date(), d.to_date());\nassert_eq!(d.to_date().to_string().parse::<Date>().unwrap(), d.to_date());\n#[cfg(feature = \"serde\")]\nassert_eq!(serde_json::from_str::<calendars::{date_type}>(&serde_json::to_string(&d).unwrap()).unwrap(), d);\n#[cfg(feature = \"serde\")]\nassert_eq!(serde_json::from_str::<Date>(&serde_json::to_string(&d.to_date()).unwrap()).unwrap(), d.to_date());{interop}\n}}",
+                name = if *absolute_date < 0 {
+                    "neg_".to_owned() + &absolute_date.abs().to_string()
+                } else {
+                    "pos_".to_owned() + &absolute_date.to_string()
+                },
+                date_type = calendar[1],
+                ref_date = format!("{}({:?})", date_from_slice, date_components),
+                interop = if ["gregorian", "iso", "ordinal"].contains(&calendar[0]) {
+                    format!(
+                        "\n#[cfg(feature = \"time\")]\nassert_eq!(calendars::{0}::from(time::Date::try_from(d).unwrap()), d);\n#[cfg(feature = \"jiff\")]\nassert_eq!(calendars::{0}::from(jiff::civil::Date::try_from(d).unwrap()), d);",
+                        calendar[1]
+                    )
+                } else {
+                    String::new()
+                },
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+/// Returns the numeric string representation of a date, as accepted by the
+/// date types' FromStr implementations.
+fn numeric_date_string(calendar: &str, c: &[i64]) -> String {
+    match calendar {
+        "iso" => format!("{}-W{:02}-{}", c[0], c[1], c[2]),
+        "ordinal" => format!("{}-{:03}", c[0], c[1]),
+        "mayanLongCount" => format!("{}.{}.{}.{}.{}", c[0], c[1], c[2], c[3], c[4]),
+        // reference Haab dates are given as [month, day]
+        "mayanHaab" => format!("{} {}", c[1], c[0]),
+        "mayanTzolkin" => format!("{} {}", c[0], c[1]),
+        "oldHinduLunar" => format!("{}-{:02}-{}-{:02}", c[0], c[1], c[2], c[3]),
+        _ => format!("{}-{:02}-{:02}", c[0], c[1], c[2]),
+    }
+}
+
+/// Returns a formatting pattern for dates of a given calendar, whose output
+/// is accepted by the date types' FromStr implementations.
+fn pattern_of_calendar(calendar: &str) -> &'static str {
+    match calendar {
+        "iso" => "%Y-W%{week}-%-d",
+        "ordinal" => "%Y-%-d",
+        "mayanLongCount" => "%{baktun}.%{katun}.%{tun}.%{uinal}.%{kin}",
+        "mayanHaab" => "%-d %B",
+        "mayanTzolkin" => "%{number} %{name}",
+        _ => "%-d %B %Y",
+    }
+}
+
+macro_rules! test_parse {
+    ($file:ident, $calendar:literal, $rd:expr, $components:expr) => {
+        let calendar = match $calendar {
+            "gregorian" =>  ["gregorian", "gregorian::Gregorian"],
+            "iso" => ["iso", "iso::Iso"],
+            "ordinal" => ["ordinal", "ordinal::Ordinal"],
+            "julian" => ["julian", "julian::Julian"],
+            "islamic" => ["islamic", "islamic::Islamic"],
+            "hebrew" => ["hebrew", "hebrew::Hebrew"],
+            "mayanLongCount" => ["mayan_long_count", "mayan::MayanLongCount"],
+            "mayanHaab" => ["mayan_haab", "mayan::MayanHaab"],
+            "mayanTzolkin" => ["mayan_tzolkin", "mayan::MayanTzolkin"],
+            "french" =>  ["french", "french::French"],
+            "oldHinduSolar" =>  ["old_hindu_solar", "hindu::OldHinduSolar"],
+            "oldHinduLunar" =>  ["old_hindu_lunar", "hindu::OldHinduLunar"],
+            _ => ["", ""],
+        };
+        let mod_name = format!("test_parse_{}", calendar[0]);
+        let s = format!(
+            "#[cfg(test)]\nmod {} {{\nextern crate calendars;\nuse calendars::utility::*;\nuse calendars::helper::*;",
+            mod_name
+        );
+        let date_from_slice = format!("{}_from_slice", calendar[0]);
+        let date_type = format!("calendars::{}", calendar[1]);
+        // formatted Old Hindu lunar dates do not indicate leap months
+        let format_check = if $calendar == "oldHinduLunar" {
+            "".to_string()
+        } else {
+            format!("\nassert_eq!(d.format().parse::<{}>().unwrap(), d);", date_type)
+        };
+        writeln!($file, "{}", s).unwrap();
+        for (absolute_date, date_components) in ($rd).iter().zip(($components).iter()) {
+            // skip placeholders of dates preceding the calendar's epoch
+            if date_components.iter().all(|c| *c == 0) {
+                continue;
+            }
+            writeln!(
+                $file,
+                "#[test]\nfn {name}() {{\nlet d = {ref_date};\nassert_eq!(\"{string}\".parse::<{date_type}>().unwrap(), d);{format_check}\nassert_eq!(d.to_string(), d.format());\nassert_eq!(d.format_with(\"{pattern}\").unwrap().parse::<{date_type}>().unwrap(), d);\n}}",
+                name = if *absolute_date < 0 {
+                    "neg_".to_owned() + &absolute_date.abs().to_string()
+                } else {
+                    "pos_".to_owned() + &absolute_date.to_string()
+                },
+                date_type = date_type,
+                string = numeric_date_string($calendar, &date_components[..]),
+                format_check = format_check,
+                pattern = pattern_of_calendar($calendar),
+                ref_date = format!("{}({:?})", date_from_slice, date_components),
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
 macro_rules! test_holidays {
     ($file:ident, $holiday:literal, $years:expr, $dates:expr) => {
         let holiday = $holiday;
@@ -157,7 +376,192 @@ macro_rules! test_holidays {
     };
 }
 
+macro_rules! test_dst {
+    ($file:ident, $transition:literal, $years:expr, $dates:expr) => {
+        writeln!(
+            $file,
+            "#[cfg(test)]\nmod test_us_dst_{} {{\nextern crate calendars;\nuse calendars::holidays::*;",
+            $transition
+        )
+        .unwrap();
+        for (year, date) in ($years).iter().zip(($dates).iter()) {
+            writeln!(
+                $file,
+                "#[test]\nfn y{year}() {{\nassert_eq!(DstRule::UnitedStates.{transition}({year}), Some({date}));\nassert_eq!(daylight_savings_{transition}({year}), {date});\n}}",
+                year = year,
+                transition = $transition,
+                date = date,
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+macro_rules! test_holiday_registry {
+    ($file:ident, $years:expr) => {
+        writeln!(
+            $file,
+            "#[cfg(test)]\nmod test_holiday_registry {{\nextern crate calendars;\nuse calendars::{{gregorian::*, holidays::*}};"
+        )
+        .unwrap();
+        for year in ($years).iter() {
+            writeln!(
+                $file,
+                "#[test]\nfn y{year}() {{\nfor holiday in HOLIDAYS {{\nfor date in holiday.dates_in_gregorian_year({year}) {{\nassert_eq!(gregorian_from_absolute(date).year, {year}, \"{{}}\", holiday.id());\n}}\n}}\n}}",
+                year = year,
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+macro_rules! test_cycle_difference {
+    ($file:ident, $calendar:literal, $cycle:literal, $rd:expr, $dates:expr) => {
+        writeln!(
+            $file,
+            "#[cfg(test)]\nmod test_{}_difference {{\nextern crate calendars;\nuse calendars::{{helper::*, mayan::*}};",
+            $calendar
+        )
+        .unwrap();
+        for (i, (rd, date)) in ($rd).iter().zip(($dates).iter()).enumerate().skip(1) {
+            writeln!(
+                $file,
+                "#[test]\nfn rd_{prev}_{rd}() {{\nlet d1 = {calendar}_from_slice({d1:?});\nlet d2 = {calendar}_from_slice({d2:?});\nassert_eq!({calendar}_difference(d1, d2), {difference});\n}}",
+                prev = ($rd)[i - 1].to_string().replace('-', "neg_"),
+                rd = rd.to_string().replace('-', "neg_"),
+                calendar = $calendar,
+                d1 = ($dates)[i - 1],
+                d2 = date,
+                difference = (rd - ($rd)[i - 1]).rem_euclid($cycle),
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+macro_rules! test_parashah {
+    ($file:ident, $rd:expr, $israel:expr, $readings:expr) => {
+        writeln!(
+            $file,
+            "#[cfg(test)]\nmod test_parashah {{\nextern crate calendars;\nuse calendars::parashah::*;"
+        )
+        .unwrap();
+        for ((rd, israel), reading) in ($rd).iter().zip(($israel).iter()).zip(($readings).iter()) {
+            writeln!(
+                $file,
+                "#[test]\nfn rd_{rd}_{location}() {{\nassert_eq!(parashah({rd}, {israel}).map(|r| r.to_string()).as_deref(), {reading:?});\n}}",
+                rd = rd,
+                location = if *israel { "israel" } else { "diaspora" },
+                israel = israel,
+                reading = reading.as_deref(),
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+macro_rules! test_swedish {
+    ($file:ident, $rd:expr, $civil:expr, $styles:expr) => {
+        writeln!(
+            $file,
+            "#[cfg(test)]\nmod test_swedish {{\nextern crate calendars;\nuse calendars::civil::*;"
+        )
+        .unwrap();
+        for ((rd, civil), style) in ($rd).iter().zip(($civil).iter()).zip(($styles).iter()) {
+            writeln!(
+                $file,
+                "#[test]\nfn rd_{rd}() {{\nlet d = CivilDate {{ year: {year}, month: {month}, day: {day}, style: CalendarStyle::{style} }};\nassert_eq!(CivilCalendar::SWEDEN.from_absolute({rd}), d);\nassert_eq!(CivilCalendar::SWEDEN.date({year}, {month}, {day}), Ok(d));\nassert_eq!(d.to_absolute(), {rd});\n}}",
+                rd = rd,
+                year = civil[0],
+                month = civil[1],
+                day = civil[2],
+                style = match style.as_str() {
+                    "julian" => "Julian",
+                    "swedish" => "Swedish",
+                    _ => "Gregorian",
+                },
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+macro_rules! test_umm_al_qura {
+    ($file:ident, $rd:expr, $dates:expr) => {
+        writeln!(
+            $file,
+            "#[cfg(test)]\nmod test_umm_al_qura {{\nextern crate calendars;\nuse calendars::islamic::*;"
+        )
+        .unwrap();
+        for (rd, date) in ($rd).iter().zip(($dates).iter()) {
+            writeln!(
+                $file,
+                "#[test]\nfn rd_{rd}() {{\nlet d = UmmAlQura::new({year}, {month}, {day});\nassert_eq!(umm_al_qura_from_absolute({rd}), d);\nassert_eq!(checked_absolute_from_umm_al_qura(d), Ok({rd}));\n}}",
+                rd = rd,
+                year = date[0],
+                month = date[1],
+                day = date[2],
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+macro_rules! test_function {
+    ($file:ident, $module:literal, $function:literal, $inputs:expr, $outputs:expr) => {
+        let mod_name = format!("test_{}", $function);
+        let s = format!(
+            "#[cfg(test)]\nmod {} {{\nextern crate calendars;\nuse calendars::{}::*;",
+            mod_name, $module
+        );
+        writeln!($file, "{}", s).unwrap();
+        for (input, output) in ($inputs).iter().zip(($outputs).iter()) {
+            writeln!(
+                $file,
+                "#[test]\nfn {name}() {{\nassert_eq!({function}({input}), {output:?});\n}}",
+                name = if *input < 0 {
+                    "neg_".to_owned() + &input.abs().to_string()
+                } else {
+                    "pos_".to_owned() + &input.to_string()
+                },
+                function = $function,
+                input = input,
+                output = output,
+            )
+            .unwrap();
+        }
+        writeln!($file, "}}").unwrap();
+    };
+}
+
+/// Generates the C header of the ffi module in the output directory, and
+/// updates include/calendars.h if the environment variable
+/// CALENDARS_UPDATE_HEADER is set.
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    let crate_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let config = cbindgen::Config::from_file("cbindgen.toml")
+        .expect("an error occurred while reading cbindgen.toml");
+    let bindings = cbindgen::generate_with_config(&crate_dir, config)
+        .expect("an error occurred while generating the C header");
+    bindings.write_to_file(Path::new(&out_dir).join("calendars.h"));
+    if std::env::var_os("CALENDARS_UPDATE_HEADER").is_some() {
+        bindings.write_to_file(Path::new("include").join("calendars.h"));
+    }
+}
+
 fn main() {
+    // generate the C header
+    #[cfg(feature = "ffi")]
+    generate_header();
+
     // check if tests-directory exists
     if !Path::new("tests").exists() {
         let r = fs::create_dir("./tests");
@@ -188,18 +592,38 @@ fn main() {
     );
     test_holidays!(file, "labor_day", holidays.year, holidays.labor_day);
     test_holidays!(file, "memorial_day", holidays.year, holidays.memorial_day);
+    // calendar.l applies the US rules of 1987-2006 to all years
+    let (start_years, start_dates): (Vec<i64>, Vec<i64>) = holidays
+        .year
+        .iter()
+        .zip(holidays.daylight_savings_start.iter())
+        .filter(|(year, _)| (1987..=2006).contains(*year))
+        .unzip();
+    let (end_years, end_dates): (Vec<i64>, Vec<i64>) = holidays
+        .year
+        .iter()
+        .zip(holidays.daylight_savings_end.iter())
+        .filter(|(year, _)| (1967..=2006).contains(*year))
+        .unzip();
+    test_holidays!(file, "daylight_savings_start", start_years, start_dates);
+    test_holidays!(file, "daylight_savings_end", end_years, end_dates);
+    test_holidays!(file, "new_years_day", holidays.year, holidays.new_years_day);
     test_holidays!(
         file,
-        "daylight_savings_start",
+        "martin_luther_king_day",
         holidays.year,
-        holidays.daylight_savings_start
+        holidays.martin_luther_king_day
     );
     test_holidays!(
         file,
-        "daylight_savings_end",
+        "presidents_day",
         holidays.year,
-        holidays.daylight_savings_end
+        holidays.presidents_day
     );
+    test_holidays!(file, "juneteenth", holidays.year, holidays.juneteenth);
+    test_holidays!(file, "columbus_day", holidays.year, holidays.columbus_day);
+    test_holidays!(file, "veterans_day", holidays.year, holidays.veterans_day);
+    test_holidays!(file, "thanksgiving", holidays.year, holidays.thanksgiving);
     test_holidays!(file, "christmas", holidays.year, holidays.christmas);
     test_holidays!(file, "advent", holidays.year, holidays.advent);
     test_holidays!(file, "epiphany", holidays.year, holidays.epiphany);
@@ -228,46 +652,181 @@ fn main() {
         holidays.ta_anit_esther
     );
     test_holidays!(file, "tisha_b_av", holidays.year, holidays.tisha_b_av);
+    test_holidays!(file, "septuagesima", holidays.year, holidays.septuagesima);
+    test_holidays!(file, "mardi_gras", holidays.year, holidays.mardi_gras);
+    test_holidays!(file, "ash_wednesday", holidays.year, holidays.ash_wednesday);
+    test_holidays!(file, "palm_sunday", holidays.year, holidays.palm_sunday);
+    test_holidays!(file, "good_friday", holidays.year, holidays.good_friday);
+    test_holidays!(file, "ascension", holidays.year, holidays.ascension);
+    test_holidays!(
+        file,
+        "trinity_sunday",
+        holidays.year,
+        holidays.trinity_sunday
+    );
+    test_holidays!(
+        file,
+        "corpus_christi",
+        holidays.year,
+        holidays.corpus_christi
+    );
+    test_holidays!(
+        file,
+        "orthodox_easter",
+        holidays.year,
+        holidays.orthodox_easter
+    );
+    test_holidays!(
+        file,
+        "great_lent_start",
+        holidays.year,
+        holidays.great_lent_start
+    );
+    test_holidays!(
+        file,
+        "orthodox_pentecost",
+        holidays.year,
+        holidays.orthodox_pentecost
+    );
+    test_holidays!(
+        file,
+        "apostles_fast_start",
+        holidays.year,
+        holidays.apostles_fast_start
+    );
+    test_holidays!(
+        file,
+        "apostles_fast_end",
+        holidays.year,
+        holidays.apostles_fast_end
+    );
+    test_holidays!(
+        file,
+        "islamic_new_year",
+        holidays.year,
+        holidays.islamic_new_year
+    );
+    test_holidays!(file, "ashura", holidays.year, holidays.ashura);
+    test_holidays!(file, "ramadan_start", holidays.year, holidays.ramadan_start);
+    test_holidays!(
+        file,
+        "laylat_al_qadr",
+        holidays.year,
+        holidays.laylat_al_qadr
+    );
+    test_holidays!(file, "eid_al_fitr", holidays.year, holidays.eid_al_fitr);
+    test_holidays!(file, "eid_al_adha", holidays.year, holidays.eid_al_adha);
+
+    // US daylight savings time (IANA time zone database)
+    let dst_json =
+        fs::read_to_string("./test-dates/reference_dst_test.json").expect("Unable to read file.");
+    let dst: ReferenceDst =
+        serde_json::from_str(&dst_json).expect("JSON does not have correct format.");
+    test_dst!(file, "start", dst.year, dst.daylight_savings_start);
+    test_dst!(file, "end", dst.year, dst.daylight_savings_end);
+
+    // weekly Torah portions (traditional rules, ICU Hebrew calendar)
+    let parashah_json = fs::read_to_string("./test-dates/reference_parashah_test.json")
+        .expect("Unable to read file.");
+    let parashah: ReferenceParashah =
+        serde_json::from_str(&parashah_json).expect("JSON does not have correct format.");
+    test_parashah!(file, parashah.rd, parashah.israel, parashah.reading);
+
+    // Swedish civil calendar (historical events)
+    let swedish_json = fs::read_to_string("./test-dates/reference_swedish_test.json")
+        .expect("Unable to read file.");
+    let swedish: ReferenceSwedish =
+        serde_json::from_str(&swedish_json).expect("JSON does not have correct format.");
+    test_swedish!(file, swedish.rd, swedish.civil, swedish.style);
+
+    // Umm al-Qura calendar (ICU)
+    let umm_al_qura_json = fs::read_to_string("./test-dates/reference_umm_al_qura_test.json")
+        .expect("Unable to read file.");
+    let umm_al_qura: ReferenceUmmAlQura =
+        serde_json::from_str(&umm_al_qura_json).expect("JSON does not have correct format.");
+    test_umm_al_qura!(file, umm_al_qura.rd, umm_al_qura.umm_al_qura);
+
+    // holiday registry
+    test_holiday_registry!(file, holidays.year);
 
     // Gregorian calendar
     test_from_absolute!(file, "gregorian", dates.rd, dates.gregorian);
     test_from_calendar!(file, "gregorian", dates.rd, dates.gregorian);
+    test_date_round_trip!(file, "gregorian", dates.rd, dates.gregorian);
+    test_parse!(file, "gregorian", dates.rd, dates.gregorian);
 
     // Iso calendar
     test_from_absolute!(file, "iso", dates.rd, dates.iso);
     test_from_calendar!(file, "iso", dates.rd, dates.iso);
+    test_date_round_trip!(file, "iso", dates.rd, dates.iso);
+    test_parse!(file, "iso", dates.rd, dates.iso);
+
+    // Ordinal dates
+    test_from_absolute!(file, "ordinal", dates.rd, dates.ordinal);
+    test_from_calendar!(file, "ordinal", dates.rd, dates.ordinal);
+    test_date_round_trip!(file, "ordinal", dates.rd, dates.ordinal);
+    test_parse!(file, "ordinal", dates.rd, dates.ordinal);
+
+    // Unix timestamps
+    test_function!(file, "interop", "unix_from_absolute", dates.rd, dates.unix);
+    test_function!(file, "interop", "absolute_from_unix", dates.unix, dates.rd);
+
+    // Excel serial day numbers
+    test_function!(file, "interop", "excel_1900_from_absolute", dates.rd, dates.excel1900);
+    test_function!(file, "interop", "excel_1904_from_absolute", dates.rd, dates.excel1904);
+    test_function!(file, "interop", "absolute_from_excel_1904", dates.excel1904, dates.rd);
 
     // Julian calendar
     test_from_absolute!(file, "julian", dates.rd, dates.julian);
     test_from_calendar!(file, "julian", dates.rd, dates.julian);
+    test_date_round_trip!(file, "julian", dates.rd, dates.julian);
+    test_parse!(file, "julian", dates.rd, dates.julian);
 
     // Islamic calendar
     test_from_absolute!(file, "islamic", dates.rd, dates.islamic);
     test_from_calendar!(file, "islamic", dates.rd, dates.islamic);
+    test_date_round_trip!(file, "islamic", dates.rd, dates.islamic);
+    test_parse!(file, "islamic", dates.rd, dates.islamic);
 
     // Hebrew calendar
     test_from_absolute!(file, "hebrew", dates.rd, dates.hebrew);
     test_from_calendar!(file, "hebrew", dates.rd, dates.hebrew);
+    test_date_round_trip!(file, "hebrew", dates.rd, dates.hebrew);
+    test_parse!(file, "hebrew", dates.rd, dates.hebrew);
 
     // Mayan Long Count
     test_from_absolute!(file, "mayanLongCount", dates.rd, dates.mayanlongcount);
     test_from_calendar!(file, "mayanLongCount", dates.rd, dates.mayanlongcount);
+    test_date_round_trip!(file, "mayanLongCount", dates.rd, dates.mayanlongcount);
+    test_parse!(file, "mayanLongCount", dates.rd, dates.mayanlongcount);
 
     // Mayan Haab calendar
     test_from_absolute!(file, "mayanHaab", dates.rd, dates.mayanhaab);
+    test_date_round_trip!(file, "mayanHaab", dates.rd, dates.mayanhaab);
+    test_parse!(file, "mayanHaab", dates.rd, dates.mayanhaab);
+    test_cycle_difference!(file, "mayan_haab", 365, dates.rd, dates.mayanhaab);
 
     // Mayan Tzolkin calendar
     test_from_absolute!(file, "mayanTzolkin", dates.rd, dates.mayantzolkin);
+    test_date_round_trip!(file, "mayanTzolkin", dates.rd, dates.mayantzolkin);
+    test_parse!(file, "mayanTzolkin", dates.rd, dates.mayantzolkin);
+    test_cycle_difference!(file, "mayan_tzolkin", 260, dates.rd, dates.mayantzolkin);
 
     // French Revolutionary calendar
     test_from_absolute!(file, "french", dates.rd, dates.french);
     test_from_calendar!(file, "french", dates.rd, dates.french);
+    test_date_round_trip!(file, "french", dates.rd, dates.french);
+    test_parse!(file, "french", dates.rd, dates.french);
 
     // Old Hindu Solar calendar
     test_from_absolute!(file, "oldHinduSolar", dates.rd, dates.oldhindusolar);
     test_from_calendar!(file, "oldHinduSolar", dates.rd, dates.oldhindusolar);
+    test_date_round_trip!(file, "oldHinduSolar", dates.rd, dates.oldhindusolar);
+    test_parse!(file, "oldHinduSolar", dates.rd, dates.oldhindusolar);
 
     // Old Hindu Lunar calendar
     test_from_absolute!(file, "oldHinduLunar", dates.rd, dates.oldhindulunar);
     test_from_calendar!(file, "oldHinduLunar", dates.rd, dates.oldhindulunar);
+    test_date_round_trip!(file, "oldHinduLunar", dates.rd, dates.oldhindulunar);
+    test_parse!(file, "oldHinduLunar", dates.rd, dates.oldhindulunar);
 }
@@ -0,0 +1,996 @@
+#ifndef CALENDARS_H
+#define CALENDARS_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Status returned by the functions of the C interface
+ */
+typedef enum CalendarsStatus {
+  /**
+   * The conversion succeeded.
+   */
+  CALENDARS_STATUS_OK = 0,
+  /**
+   * A date component lies outside the range permitted by its calendar.
+   */
+  CALENDARS_STATUS_INVALID_DATE = 1,
+  /**
+   * An absolute (fixed) date or a year lies outside the range supported by
+   * a calendar.
+   */
+  CALENDARS_STATUS_OUT_OF_RANGE = 2,
+  /**
+   * A date does not correspond to an absolute (fixed) date.
+   */
+  CALENDARS_STATUS_NOT_CONVERTIBLE = 3,
+  /**
+   * A null pointer was passed for the result.
+   */
+  CALENDARS_STATUS_NULL_POINTER = 4,
+} CalendarsStatus;
+
+/**
+ * Epoch (1 Muharram 1 AH) of the tabular Islamic calendar
+ */
+typedef struct CalendarsIslamicEpoch CalendarsIslamicEpoch;
+
+/**
+ * Leap years of the 30-year cycle of the tabular Islamic calendar. Each
+ * pattern has 11 leap years, in which Dhu al-Hijjah has 30 instead of 29
+ * days.
+ */
+typedef struct CalendarsIslamicLeapYears CalendarsIslamicLeapYears;
+
+/**
+ * Scheme of the tabular Islamic calendar, combining a leap year pattern with
+ * an epoch. Different communities use different schemes, which may disagree
+ * by a day or two.
+ *
+ * # Example
+ *
+ * ```
+ * use calendars::{
+ *     gregorian::{absolute_from_gregorian, Gregorian},
+ *     islamic::{absolute_from_islamic, islamic_from_absolute, Islamic, IslamicScheme},
+ * };
+ *
+ * // Ramadan 1445 began on March 10, 2024 in the Fatimid calendar
+ * let date = IslamicScheme::FATIMID.absolute_from_islamic(Islamic::new(1445, 9, 1));
+ * assert_eq!(date, absolute_from_gregorian(Gregorian::new(2024, 3, 10)));
+ * assert_eq!(IslamicScheme::FATIMID.islamic_from_absolute(date), Islamic::new(1445, 9, 1));
+ * assert_eq!(islamic_from_absolute(date), Islamic::new(1445, 8, 29));
+ *
+ * // the standard scheme agrees with the Islamic calendar functions
+ * for absolute_date in 730000..740000 {
+ *     let d = IslamicScheme::STANDARD.islamic_from_absolute(absolute_date);
+ *     assert_eq!(d, islamic_from_absolute(absolute_date));
+ *     assert_eq!(absolute_from_islamic(d), absolute_date);
+ * }
+ * ```
+ */
+typedef struct CalendarsIslamicScheme CalendarsIslamicScheme;
+
+/**
+ * Gregorian date
+ */
+typedef struct CalendarsGregorian {
+  int64_t year;
+  int64_t month;
+  int64_t day;
+} CalendarsGregorian;
+
+/**
+ * Julian date
+ */
+typedef struct CalendarsJulian {
+  int64_t year;
+  int64_t month;
+  int64_t day;
+} CalendarsJulian;
+
+/**
+ * Iso week date
+ */
+typedef struct CalendarsIso {
+  int64_t year;
+  int64_t week;
+  int64_t day;
+} CalendarsIso;
+
+/**
+ * Ordinal date (year and day of the Gregorian year)
+ */
+typedef struct CalendarsOrdinal {
+  int64_t year;
+  int64_t day;
+} CalendarsOrdinal;
+
+/**
+ * Islamic date
+ */
+typedef struct CalendarsIslamic {
+  int64_t year;
+  int64_t month;
+  int64_t day;
+} CalendarsIslamic;
+
+/**
+ * Hebrew date
+ */
+typedef struct CalendarsHebrew {
+  int64_t year;
+  int64_t month;
+  int64_t day;
+} CalendarsHebrew;
+
+/**
+ * French date
+ */
+typedef struct CalendarsFrench {
+  int64_t year;
+  int64_t month;
+  int64_t day;
+} CalendarsFrench;
+
+/**
+ * Mayan Long Count
+ */
+typedef struct CalendarsMayanLongCount {
+  int64_t baktun;
+  int64_t katun;
+  int64_t tun;
+  int64_t uinal;
+  int64_t kin;
+} CalendarsMayanLongCount;
+
+/**
+ * Mayan Haab date
+ */
+typedef struct CalendarsMayanHaab {
+  int64_t day;
+  int64_t month;
+} CalendarsMayanHaab;
+
+/**
+ * Mayan Tzolkin date
+ */
+typedef struct CalendarsMayanTzolkin {
+  int64_t number;
+  int64_t name;
+} CalendarsMayanTzolkin;
+
+/**
+ * Old Hindu Solar date
+ */
+typedef struct CalendarsOldHinduSolar {
+  int64_t year;
+  int64_t month;
+  int64_t day;
+} CalendarsOldHinduSolar;
+
+/**
+ * Old Hindu Lunar date
+ */
+typedef struct CalendarsOldHinduLunar {
+  int64_t year;
+  int64_t month;
+  bool leap_month;
+  int64_t day;
+} CalendarsOldHinduLunar;
+
+
+
+
+
+/**
+ * Computes the absolute (fixed) date of a Gregorian date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_gregorian(struct CalendarsGregorian date,
+                                                       int64_t *absolute_date);
+
+/**
+ * Computes the Gregorian date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_gregorian_from_absolute(int64_t absolute_date,
+                                                       struct CalendarsGregorian *date);
+
+/**
+ * Computes the absolute (fixed) date of a Julian date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_julian(struct CalendarsJulian date,
+                                                    int64_t *absolute_date);
+
+/**
+ * Computes the Julian date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_julian_from_absolute(int64_t absolute_date,
+                                                    struct CalendarsJulian *date);
+
+/**
+ * Computes the absolute (fixed) date of an ISO date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_iso(struct CalendarsIso date, int64_t *absolute_date);
+
+/**
+ * Computes the ISO date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_iso_from_absolute(int64_t absolute_date, struct CalendarsIso *date);
+
+/**
+ * Computes the absolute (fixed) date of an ordinal date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_ordinal(struct CalendarsOrdinal date,
+                                                     int64_t *absolute_date);
+
+/**
+ * Computes the ordinal date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_ordinal_from_absolute(int64_t absolute_date,
+                                                     struct CalendarsOrdinal *date);
+
+/**
+ * Computes the absolute (fixed) date of an Islamic date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_islamic(struct CalendarsIslamic date,
+                                                     int64_t *absolute_date);
+
+/**
+ * Computes the Islamic date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_islamic_from_absolute(int64_t absolute_date,
+                                                     struct CalendarsIslamic *date);
+
+/**
+ * Computes the absolute (fixed) date of a Hebrew date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_hebrew(struct CalendarsHebrew date,
+                                                    int64_t *absolute_date);
+
+/**
+ * Computes the Hebrew date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_hebrew_from_absolute(int64_t absolute_date,
+                                                    struct CalendarsHebrew *date);
+
+/**
+ * Computes the absolute (fixed) date of a French Revolutionary date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_french(struct CalendarsFrench date,
+                                                    int64_t *absolute_date);
+
+/**
+ * Computes the French Revolutionary date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_french_from_absolute(int64_t absolute_date,
+                                                    struct CalendarsFrench *date);
+
+/**
+ * Computes the absolute (fixed) date of a Mayan long count.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_mayan_long_count(struct CalendarsMayanLongCount date,
+                                                              int64_t *absolute_date);
+
+/**
+ * Computes the Mayan long count of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_mayan_long_count_from_absolute(int64_t absolute_date,
+                                                              struct CalendarsMayanLongCount *date);
+
+/**
+ * Computes the Mayan haab date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_mayan_haab_from_absolute(int64_t absolute_date,
+                                                        struct CalendarsMayanHaab *date);
+
+/**
+ * Computes the Mayan tzolkin date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_mayan_tzolkin_from_absolute(int64_t absolute_date,
+                                                           struct CalendarsMayanTzolkin *date);
+
+/**
+ * Computes the absolute (fixed) date of an Old Hindu solar date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_old_hindu_solar(struct CalendarsOldHinduSolar date,
+                                                             int64_t *absolute_date);
+
+/**
+ * Computes the Old Hindu solar date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_old_hindu_solar_from_absolute(int64_t absolute_date,
+                                                             struct CalendarsOldHinduSolar *date);
+
+/**
+ * Computes the absolute (fixed) date of an Old Hindu lunar date.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_absolute_from_old_hindu_lunar(struct CalendarsOldHinduLunar date,
+                                                             int64_t *absolute_date);
+
+/**
+ * Computes the Old Hindu lunar date of an absolute (fixed) date.
+ *
+ * # Safety
+ *
+ * `date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_old_hindu_lunar_from_absolute(int64_t absolute_date,
+                                                             struct CalendarsOldHinduLunar *date);
+
+/**
+ * Computes the absolute (fixed) date of New Year's Day in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_new_years_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Martin Luther King Jr. Day (US) in a
+ * given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_martin_luther_king_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Presidents' Day (US) in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_presidents_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Juneteenth (US) in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_juneteenth(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Independence Day (US) in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_independence_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Labor Day (US) in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_labor_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Memorial Day (US) in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_memorial_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Columbus Day (US) in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_columbus_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Veterans Day (US) in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_veterans_day(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Thanksgiving (US) in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_thanksgiving(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of the start of daylight saving time
+ * (US) in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_daylight_savings_start(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of the end of daylight saving time (US)
+ * in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_daylight_savings_end(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Christmas in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_christmas(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Advent Sunday in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_advent(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Epiphany in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_epiphany(int64_t year, int64_t *absolute_date);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of Eastern Orthodox
+ * Christmas in a given Gregorian year to `dates`, and writes the number of such dates to `count` (0 or 1).
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_eastern_orthodox_christmas(int64_t year,
+                                                          int64_t *dates,
+                                                          size_t capacity,
+                                                          size_t *count);
+
+/**
+ * Computes the absolute (fixed) date of Easter according to the Nicaean rule
+ * in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_nicaean_rule_easter(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Orthodox Easter in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_orthodox_easter(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of the start of Great Lent (Orthodox)
+ * in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_great_lent_start(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Orthodox Pentecost in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_orthodox_pentecost(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of the start of the Apostles' Fast
+ * (Orthodox) in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_apostles_fast_start(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of the end of the Apostles' Fast
+ * (Orthodox) in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_apostles_fast_end(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Easter in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_easter(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Pentecost in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_pentecost(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Septuagesima Sunday in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_septuagesima(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Mardi Gras (Shrove Tuesday) in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_mardi_gras(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Ash Wednesday in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_ash_wednesday(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Palm Sunday in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_palm_sunday(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Good Friday in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_good_friday(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Ascension Day in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_ascension(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Trinity Sunday in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_trinity_sunday(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Corpus Christi in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_corpus_christi(int64_t year, int64_t *absolute_date);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of the Islamic New Year in a
+ * given Gregorian year to `dates`, and writes the number of such dates to `count`.
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_islamic_new_year(int64_t year,
+                                                int64_t *dates,
+                                                size_t capacity,
+                                                size_t *count);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of Ashura in a given
+ * Gregorian year to `dates`, and writes the number of such dates to `count`.
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_ashura(int64_t year, int64_t *dates, size_t capacity, size_t *count);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of Mulad al-Nabi in a given
+ * Gregorian year to `dates`, and writes the number of such dates to `count` (0, 1, or
+ * 2).
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_mulad_al_nabi(int64_t year,
+                                             int64_t *dates,
+                                             size_t capacity,
+                                             size_t *count);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of the first day of Ramadan
+ * in a given Gregorian year to `dates`, and writes the number of such dates to `count`.
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_ramadan_start(int64_t year,
+                                             int64_t *dates,
+                                             size_t capacity,
+                                             size_t *count);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of Laylat al-Qadr in a given
+ * Gregorian year to `dates`, and writes the number of such dates to `count`.
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_laylat_al_qadr(int64_t year,
+                                              int64_t *dates,
+                                              size_t capacity,
+                                              size_t *count);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of Eid al-Fitr in a given
+ * Gregorian year to `dates`, and writes the number of such dates to `count`.
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_eid_al_fitr(int64_t year,
+                                           int64_t *dates,
+                                           size_t capacity,
+                                           size_t *count);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of Eid al-Adha in a given
+ * Gregorian year to `dates`, and writes the number of such dates to `count`.
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_eid_al_adha(int64_t year,
+                                           int64_t *dates,
+                                           size_t capacity,
+                                           size_t *count);
+
+/**
+ * Computes the absolute (fixed) date of Yom Kippur in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_yom_kippur(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Passover in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_passover(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Purim in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_purim(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Ta'anit Esther in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_ta_anit_esther(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Tisha B'Av in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_tisha_b_av(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Rosh Hashanah in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_rosh_hashanah(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Sukkot in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_sukkot(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Shemini Atzeret in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_shemini_atzeret(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Simchat Torah (outside of Israel) in a
+ * given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_simchat_torah(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Simchat Torah in Israel in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_simchat_torah_israel(int64_t year, int64_t *absolute_date);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of the eight days of
+ * Hanukkah beginning in a given Gregorian year to `dates`, and writes the number of such dates to `count` (8).
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_hanukkah(int64_t year,
+                                        int64_t *dates,
+                                        size_t capacity,
+                                        size_t *count);
+
+/**
+ * Computes the absolute (fixed) date of Tu BiShvat in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_tu_bishvat(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Lag BaOmer in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_lag_ba_omer(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Shavuot in a given Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_shavuot(int64_t year, int64_t *absolute_date);
+
+/**
+ * Computes the absolute (fixed) date of Tzom Gedaliah in a given Gregorian
+ * year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_tzom_gedaliah(int64_t year, int64_t *absolute_date);
+
+/**
+ * Writes up to `capacity` absolute (fixed) dates of the Tenth of Tevet in a
+ * given Gregorian year to `dates`, and writes the number of such dates to `count` (0,
+ * 1, or 2).
+ *
+ * # Safety
+ *
+ * `dates` must be null or valid for `capacity` writes, and `count` must be
+ * null or valid for writes.
+ */
+enum CalendarsStatus calendars_asarah_b_tevet(int64_t year,
+                                              int64_t *dates,
+                                              size_t capacity,
+                                              size_t *count);
+
+/**
+ * Computes the absolute (fixed) date of the Seventeenth of Tammuz in a given
+ * Gregorian year.
+ *
+ * # Safety
+ *
+ * `absolute_date` must be null or valid for writes.
+ */
+enum CalendarsStatus calendars_shiva_asar_b_tammuz(int64_t year, int64_t *absolute_date);
+
+#endif  /* CALENDARS_H */
@@ -0,0 +1,338 @@
+//! Provides calendar-aware month and year arithmetic
+
+use crate::{
+    component::{invalid_component, Year},
+    error::CalendarError,
+    utility::MonthLength,
+};
+
+/// Policy for resolving dates that do not exist after adding months or years
+/// (e.g. 31 January + 1 month, or Adar II in a common Hebrew year).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// Clamp to the last existing month of the year and the last existing day
+    /// of the month (31 January + 1 month = 28/29 February). This is the
+    /// default.
+    #[default]
+    Clamp,
+    /// Roll excess days (and non-existent months) over into the following
+    /// month(s) (31 January + 1 month = 2/3 March).
+    Roll,
+}
+
+/// Policy for anniversaries of dates that do not exist in every year (e.g. 29
+/// February, or 30 Adar I of a leap Hebrew year).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AnniversaryPolicy {
+    /// Observe the anniversary on the last existing day of the month (28
+    /// February, 29 Adar).
+    Before,
+    /// Observe the anniversary on the day after the last existing day of the
+    /// month (1 March, 1 Nisan), as [`hebrew_birthday`](crate::holidays::hebrew_birthday)
+    /// does.
+    After,
+    /// Skip years in which the date does not exist.
+    Skip,
+}
+
+/// Difference between two dates, both as a raw number of days and broken down
+/// into whole years, whole months, and remaining days. All fields are negative
+/// if the second date precedes the first.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Difference {
+    pub total_days: i64,
+    pub years: i64,
+    pub months: i64,
+    pub days: i64,
+}
+
+/// Month and year arithmetic for calendars with year, month, and day
+/// components.
+pub trait MonthArithmetic: MonthLength + Sized {
+    /// Returns the date a given number of months after (or, if negative,
+    /// before) the date, resolving non-existent days according to `overflow`.
+    /// Results outside the range supported by the calendar saturate at its
+    /// first or last date, and dates that do not exist are returned
+    /// unchanged; see
+    /// [`checked_add_months`](MonthArithmetic::checked_add_months) for a
+    /// fallible alternative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     arithmetic::{MonthArithmetic, Overflow},
+    ///     gregorian::Gregorian,
+    /// };
+    ///
+    /// let date = Gregorian::new(2024, 1, 31);
+    /// assert_eq!(date.add_months(1, Overflow::Clamp), Gregorian::new(2024, 2, 29));
+    /// assert_eq!(date.add_months(i64::MAX, Overflow::Clamp), Gregorian::new(1_000_000_000_000_000, 12, 31));
+    /// assert_eq!(date.add_years(i64::MIN, Overflow::Clamp), Gregorian::new(-1_000_000_000_000_000, 1, 1));
+    /// assert_eq!(Gregorian::new(2024, 13, 1).add_months(1, Overflow::Clamp), Gregorian::new(2024, 13, 1));
+    /// ```
+    fn add_months(&self, months: i64, overflow: Overflow) -> Self {
+        return self
+            .checked_add_months(months, overflow)
+            .unwrap_or_else(|_| self.saturate(months));
+    }
+
+    /// Returns the date a given number of months after (or, if negative,
+    /// before) the date, resolving non-existent days according to `overflow`,
+    /// or an error if the date does not exist, or the year of the date or of
+    /// the result lies outside the range supported by the calendar (see the
+    /// `*_YEAR_RANGE` statics of the calendar modules).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     arithmetic::{MonthArithmetic, Overflow},
+    ///     gregorian::Gregorian,
+    ///     hebrew::Hebrew,
+    /// };
+    ///
+    /// let date = Gregorian::new(2024, 1, 31);
+    /// assert_eq!(date.checked_add_months(1, Overflow::Clamp), Ok(Gregorian::new(2024, 2, 29)));
+    /// assert_eq!(date.checked_add_months(1, Overflow::Roll), Ok(Gregorian::new(2024, 3, 2)));
+    /// assert_eq!(
+    ///     date.checked_add_months(-1_200_000, Overflow::Clamp),
+    ///     Ok(Gregorian::new(-97976, 1, 31))
+    /// );
+    /// assert!(date.checked_add_months(i64::MAX, Overflow::Clamp).is_err());
+    /// assert!(date.checked_add_months(i64::MIN, Overflow::Clamp).is_err());
+    /// assert!(Gregorian::new(2024, 13, 1).checked_add_months(1, Overflow::Clamp).is_err());
+    ///
+    /// // every cycle of 19 Hebrew years has 235 months
+    /// let date = Hebrew::new(5784, 1, 15);
+    /// assert_eq!(date.checked_add_months(235_000, Overflow::Clamp), Ok(Hebrew::new(24784, 1, 15)));
+    /// assert_eq!(date.checked_add_months(-7, Overflow::Clamp), Ok(Hebrew::new(5784, 7, 15)));
+    /// ```
+    fn checked_add_months(&self, months: i64, overflow: Overflow) -> Result<Self, CalendarError> {
+        self.try_to_absolute()?;
+        let (year, month, day) = self.year_month_day();
+        let (year, month) = month_count::<Self>(year, month)
+            .and_then(|count| count.checked_add(months))
+            .and_then(month_of_count::<Self>)
+            .ok_or_else(|| invalid_component::<Self>("year", year.saturating_add(months / 12)))?;
+        Year::checked::<Self>(year)?;
+        return resolve_overflow(year, month, day, overflow)
+            .ok_or_else(|| invalid_component::<Self>("year", year));
+    }
+
+    /// Returns the date a given number of years after (or, if negative,
+    /// before) the date, resolving non-existent months and days according to
+    /// `overflow`. Results outside the range supported by the calendar
+    /// saturate at its first or last date, and dates that do not exist are
+    /// returned unchanged; see
+    /// [`checked_add_years`](MonthArithmetic::checked_add_years) for a
+    /// fallible alternative.
+    fn add_years(&self, years: i64, overflow: Overflow) -> Self {
+        return self
+            .checked_add_years(years, overflow)
+            .unwrap_or_else(|_| self.saturate(years));
+    }
+
+    /// Returns the date a given number of years after (or, if negative,
+    /// before) the date, resolving non-existent months and days according to
+    /// `overflow`, or an error if the date does not exist, or the resulting
+    /// year lies outside the range supported by the calendar.
+    fn checked_add_years(&self, years: i64, overflow: Overflow) -> Result<Self, CalendarError> {
+        self.try_to_absolute()?;
+        let (year, month, day) = self.year_month_day();
+        let year = year.saturating_add(years);
+        Year::checked::<Self>(year)?;
+        return resolve_overflow(year, month, day, overflow)
+            .ok_or_else(|| invalid_component::<Self>("year", year));
+    }
+
+    /// Returns the first (if `direction` is negative) or last date supported
+    /// by the calendar, or the date itself if it does not exist.
+    #[doc(hidden)]
+    fn saturate(&self, direction: i64) -> Self {
+        let (year, month, day) = self.year_month_day();
+        return match self.try_to_absolute() {
+            Ok(_) if direction < 0 => Self::from_absolute(i64::MIN),
+            Ok(_) => Self::from_absolute(i64::MAX),
+            Err(_) => Self::from_year_month_day(year, month, day),
+        };
+    }
+
+    /// Returns the anniversary of the date in a given year, or `None` if the
+    /// date does not exist in that year and `policy` is
+    /// [`AnniversaryPolicy::Skip`]. Dates in the last month of a year with a
+    /// leap month have their anniversaries in the last month of every year
+    /// (e.g. Adar II and Adar of the Hebrew calendar).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     arithmetic::{AnniversaryPolicy, MonthArithmetic},
+    ///     gregorian::Gregorian,
+    ///     hebrew::Hebrew,
+    /// };
+    ///
+    /// let leap_day = Gregorian::new(2000, 2, 29);
+    /// let birthday = |year, policy| leap_day.anniversary(year, policy);
+    /// assert_eq!(birthday(2024, AnniversaryPolicy::Skip), Some(Gregorian::new(2024, 2, 29)));
+    /// assert_eq!(birthday(2025, AnniversaryPolicy::Before), Some(Gregorian::new(2025, 2, 28)));
+    /// assert_eq!(birthday(2025, AnniversaryPolicy::After), Some(Gregorian::new(2025, 3, 1)));
+    /// assert_eq!(birthday(2025, AnniversaryPolicy::Skip), None);
+    ///
+    /// // 30 Adar I 5784 (leap year), and 15 Adar II 5784
+    /// let adar_i = Hebrew::new(5784, 12, 30);
+    /// let adar_ii = Hebrew::new(5784, 13, 15);
+    /// assert_eq!(adar_i.anniversary(5785, AnniversaryPolicy::Before), Some(Hebrew::new(5785, 12, 29)));
+    /// assert_eq!(adar_i.anniversary(5785, AnniversaryPolicy::After), Some(Hebrew::new(5785, 1, 1)));
+    /// assert_eq!(adar_ii.anniversary(5785, AnniversaryPolicy::Skip), Some(Hebrew::new(5785, 12, 15)));
+    /// assert_eq!(adar_ii.anniversary(5787, AnniversaryPolicy::Skip), Some(Hebrew::new(5787, 13, 15)));
+    /// ```
+    fn anniversary(&self, year: i64, policy: AnniversaryPolicy) -> Option<Self> {
+        let (original_year, month, day) = self.year_month_day();
+        let month = if month == Self::last_month_of_year(original_year) {
+            Self::last_month_of_year(year)
+        } else {
+            month
+        };
+        if day <= Self::last_day_of_month(month, year) {
+            return Some(Self::from_year_month_day(year, month, day));
+        }
+        return match policy {
+            AnniversaryPolicy::Before => resolve_overflow(year, month, day, Overflow::Clamp),
+            AnniversaryPolicy::After => resolve_overflow(year, month, day, Overflow::Roll),
+            AnniversaryPolicy::Skip => None,
+        };
+    }
+
+    /// Computes the difference from the date to another date of the same
+    /// calendar (e.g. for age calculations), or returns an error if either
+    /// date does not exist or lies outside the range supported by the
+    /// calendar (see [`Calendar::try_to_absolute`](crate::utility::Calendar::try_to_absolute)).
+    /// Whole years and months are counted with [`Overflow::Clamp`], so the
+    /// difference between 31 January and 28 February of a common year is one
+    /// month.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{arithmetic::MonthArithmetic, gregorian::Gregorian};
+    ///
+    /// let born = Gregorian::new(1996, 2, 29);
+    /// let d = born.difference(&Gregorian::new(2024, 3, 15)).unwrap();
+    /// assert_eq!((d.years, d.months, d.days), (28, 0, 15));
+    ///
+    /// assert!(Gregorian::new(2024, 13, 1).difference(&Gregorian::new(2025, 1, 1)).is_err());
+    /// ```
+    fn difference(&self, other: &Self) -> Result<Difference, CalendarError> {
+        let start = self.try_to_absolute()?;
+        let end = other.try_to_absolute()?;
+        if end < start {
+            let d = other.difference(self)?;
+            return Ok(Difference {
+                total_days: -d.total_days,
+                years: -d.years,
+                months: -d.months,
+                days: -d.days,
+            });
+        }
+        // the anniversary in the year of the other date may follow it
+        let (start_year, _, _) = self.year_month_day();
+        let (end_year, end_month, _) = other.year_month_day();
+        let mut years = end_year - start_year;
+        if self
+            .checked_add_years(years, Overflow::Clamp)?
+            .try_to_absolute()?
+            > end
+        {
+            years -= 1;
+        }
+        let anniversary = self.checked_add_years(years, Overflow::Clamp)?;
+        // likewise, the monthly anniversary in the month of the other date
+        let (year, month, _) = anniversary.year_month_day();
+        let mut months = month_count::<Self>(end_year, end_month)
+            .zip(month_count::<Self>(year, month))
+            .map(|(end, start)| end - start)
+            .ok_or_else(|| invalid_component::<Self>("year", end_year))?;
+        if anniversary
+            .checked_add_months(months, Overflow::Clamp)?
+            .try_to_absolute()?
+            > end
+        {
+            months -= 1;
+        }
+        let days = end
+            - anniversary
+                .checked_add_months(months, Overflow::Clamp)?
+                .try_to_absolute()?;
+        return Ok(Difference {
+            total_days: end - start,
+            years,
+            months,
+            days,
+        });
+    }
+}
+
+impl<T: MonthLength> MonthArithmetic for T {}
+
+/// Returns the number of months from the beginning of year 1 to a given
+/// month of a given year (see [`MonthLength::months_before_year`]), or
+/// `None` if the year has no such month or the number overflows.
+fn month_count<C: MonthLength>(year: i64, month: i64) -> Option<i64> {
+    let before = C::months_before_year(year)?;
+    let index = C::months_of_year(year).position(|m| m == month)?;
+    return before.checked_add(index as i64);
+}
+
+/// Returns the year and month with a given number of months from the
+/// beginning of year 1 (the inverse of [`month_count`]), or `None` if the
+/// year overflows.
+fn month_of_count<C: MonthLength>(count: i64) -> Option<(i64, i64)> {
+    // years have 12 or 13 months, so that each step (at least) divides the
+    // distance to the year by 12
+    let mut year = count.div_euclid(13).checked_add(1)?;
+    loop {
+        let offset = count.checked_sub(C::months_before_year(year)?)?;
+        if offset < 0 {
+            year = year.checked_sub(offset.checked_neg()?.checked_add(11)? / 12)?;
+            continue;
+        }
+        let months = C::months_of_year(year).count() as i64;
+        if offset < months {
+            return Some((year, C::months_of_year(year).nth(offset as usize)?));
+        }
+        year = year.checked_add((offset / 13).max(1))?;
+    }
+}
+
+/// Returns the date corresponding to the given (possibly non-existent) year,
+/// month, and day components, according to a given overflow policy, or
+/// `None` if a rolled-over date overflows.
+fn resolve_overflow<C: MonthLength>(
+    year: i64,
+    month: i64,
+    day: i64,
+    overflow: Overflow,
+) -> Option<C> {
+    let last_month = C::last_month_of_year(year);
+    return match overflow {
+        Overflow::Clamp => {
+            let month = month.min(last_month);
+            Some(C::from_year_month_day(
+                year,
+                month,
+                day.min(C::last_day_of_month(month, year)),
+            ))
+        }
+        Overflow::Roll => {
+            let (year, month) = if month > last_month {
+                C::next_month(year, last_month)
+            } else {
+                (year, month)
+            };
+            let first = C::from_year_month_day(year, month, 1).to_absolute();
+            Some(C::from_absolute(first.checked_add(day.checked_sub(1)?)?))
+        }
+    };
+}
@@ -0,0 +1,755 @@
+//! Provides astronomical functions: the positions of the sun and the moon, the
+//! moments of equinoxes and solstices, of new and full moons, and of sunrise
+//! and sunset
+//!
+//! Moments are given as [`Moment`]s (absolute dates with a fraction of day)
+//! in universal time. The algorithms follow Reingold and Dershowitz, "Calendrical Calculations"
+//! (2018), and are accurate to a few minutes for dates within a few
+//! millennia of the present.
+
+use core::fmt;
+
+use crate::{
+    gregorian::{absolute_from_gregorian, Gregorian, GREGORIAN_YEAR_RANGE},
+    math::clamp_to_range,
+    moment::Moment,
+};
+
+/// Mean length of a tropical year (in days)
+pub static MEAN_TROPICAL_YEAR: f64 = 365.242189;
+
+/// Returns the sine of an angle given in degrees.
+pub(crate) fn sin_degrees(x: f64) -> f64 {
+    return x.to_radians().sin();
+}
+
+/// Returns the cosine of an angle given in degrees.
+pub(crate) fn cos_degrees(x: f64) -> f64 {
+    return x.to_radians().cos();
+}
+
+/// Approximates the difference between dynamical and universal time (in
+/// days) at a given moment, using the parabola of Morrison and Stephenson.
+pub fn delta_t(moment: Moment) -> f64 {
+    let u = (2000.0 + (moment.0 - 730120.5) / 365.25 - 1820.0) / 100.0;
+    return (-20.0 + 32.0 * u * u) / 86400.0;
+}
+
+/// Returns the number of Julian centuries (in dynamical time) since noon of
+/// January 1, 2000, for a given moment (in universal time).
+fn julian_centuries(moment: Moment) -> f64 {
+    return (moment.0 + delta_t(moment) - 730120.5) / 36525.0;
+}
+
+/// Approximates the nutation in longitude (in degrees) for a given number of
+/// Julian centuries.
+fn nutation(c: f64) -> f64 {
+    let a = 124.90 - 1934.134 * c + 0.002063 * c * c;
+    let b = 201.11 + 72001.5377 * c + 0.00057 * c * c;
+    return -0.004778 * sin_degrees(a) - 0.0003667 * sin_degrees(b);
+}
+
+/// Computes the apparent longitude of the sun (in degrees) at a given moment
+/// (absolute date and fraction of day, in universal time), following
+/// Reingold and Dershowitz, "Calendrical Calculations" (2018), section 14.4.
+pub fn solar_longitude(moment: Moment) -> f64 {
+    static COEFFICIENTS: [f64; 49] = [
+        403406.0, 195207.0, 119433.0, 112392.0, 3891.0, 2819.0, 1721.0, 660.0, 350.0, 334.0, 314.0,
+        268.0, 242.0, 234.0, 158.0, 132.0, 129.0, 114.0, 99.0, 93.0, 86.0, 78.0, 72.0, 68.0, 64.0,
+        46.0, 38.0, 37.0, 32.0, 29.0, 28.0, 27.0, 27.0, 25.0, 24.0, 21.0, 21.0, 20.0, 18.0, 17.0,
+        14.0, 13.0, 13.0, 13.0, 12.0, 10.0, 10.0, 10.0, 10.0,
+    ];
+    static MULTIPLIERS: [f64; 49] = [
+        0.9287892,
+        35999.1376958,
+        35999.4089666,
+        35998.7287385,
+        71998.20261,
+        71998.4403,
+        36000.35726,
+        71997.4812,
+        32964.4678,
+        -19.4410,
+        445267.1117,
+        45036.8840,
+        3.1008,
+        22518.4434,
+        -19.9739,
+        65928.9345,
+        9038.0293,
+        3034.7684,
+        33718.148,
+        3034.448,
+        -2280.773,
+        29929.992,
+        31556.493,
+        149.588,
+        9037.750,
+        107997.405,
+        -4444.176,
+        151.771,
+        67555.316,
+        31556.080,
+        -4561.540,
+        107996.706,
+        1221.655,
+        62894.167,
+        31437.369,
+        14578.298,
+        -31931.757,
+        34777.243,
+        1221.999,
+        62894.511,
+        -4442.039,
+        107997.909,
+        119.066,
+        16859.071,
+        -4.578,
+        26895.292,
+        -39.127,
+        12297.536,
+        90073.778,
+    ];
+    static ADDENDS: [f64; 49] = [
+        270.54861, 340.19128, 63.91854, 331.26220, 317.843, 86.631, 240.052, 310.26, 247.23,
+        260.87, 297.82, 343.14, 166.79, 81.53, 3.50, 132.75, 182.95, 162.03, 29.8, 266.4, 249.2,
+        157.6, 257.8, 185.1, 69.9, 8.0, 197.1, 250.4, 65.3, 162.7, 341.5, 291.6, 98.5, 146.7,
+        110.0, 5.2, 342.6, 230.9, 256.1, 45.3, 242.9, 115.2, 151.8, 285.3, 53.3, 126.6, 205.7,
+        85.9, 146.1,
+    ];
+    let c = julian_centuries(moment);
+    let mut sum = 0.0;
+    for i in 0..49 {
+        sum += COEFFICIENTS[i] * (MULTIPLIERS[i] * c + ADDENDS[i]).to_radians().sin();
+    }
+    let longitude = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * sum;
+    let aberration = 0.0000974 * (177.63 + 35999.01848 * c).to_radians().cos() - 0.005575;
+    return (longitude + aberration + nutation(c)).rem_euclid(360.0);
+}
+
+/// Computes the moment (in universal time) of the first time on or after a
+/// given moment at which the apparent longitude of the sun reaches a given
+/// value (in degrees).
+pub fn solar_longitude_after(longitude: f64, moment: Moment) -> Moment {
+    // the sun moves by about one degree per day
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    let estimate = moment.0 + rate * (longitude - solar_longitude(moment)).rem_euclid(360.0);
+    let mut low = moment.0.max(estimate - 5.0);
+    let mut high = estimate + 5.0;
+    // bisection to a precision of about a tenth of a second, or of the
+    // floating point numbers far from the present
+    while high - low > 1e-6 {
+        let middle = (low + high) / 2.0;
+        if middle <= low || high <= middle {
+            break;
+        }
+        if (solar_longitude(Moment(middle)) - longitude).rem_euclid(360.0) < 180.0 {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    return Moment((low + high) / 2.0);
+}
+
+/// Computes the apparent (geocentric) longitude of the moon (in degrees) at a
+/// given moment (absolute date and fraction of day, in universal time),
+/// following Reingold and Dershowitz, "Calendrical Calculations" (2018),
+/// section 14.5, which is based on Meeus, "Astronomical Algorithms" (1998),
+/// chapter 47.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     astro::{lunar_longitude, lunar_phase, nth_new_moon_after},
+///     moment::Moment,
+/// };
+///
+/// // the moon is at about 133.17 degrees at 0h dynamical time on April 12, 1992
+/// assert!((lunar_longitude(Moment(727300.0 - 59.0 / 86400.0)) - 133.1673).abs() < 0.01);
+/// // sun and moon are in conjunction at a new moon
+/// let new_moon = nth_new_moon_after(1, 739000);
+/// let phase = lunar_phase(new_moon);
+/// assert!(phase < 0.01 || phase > 359.99);
+/// ```
+pub fn lunar_longitude(moment: Moment) -> f64 {
+    // multiples of the elongation, the solar anomaly, the lunar anomaly, and
+    // the argument of latitude, with the coefficient of the sine
+    static TERMS: [(f64, f64, f64, f64, f64); 59] = [
+        (0.0, 0.0, 1.0, 0.0, 6288774.0),
+        (2.0, 0.0, -1.0, 0.0, 1274027.0),
+        (2.0, 0.0, 0.0, 0.0, 658314.0),
+        (0.0, 0.0, 2.0, 0.0, 213618.0),
+        (0.0, 1.0, 0.0, 0.0, -185116.0),
+        (0.0, 0.0, 0.0, 2.0, -114332.0),
+        (2.0, 0.0, -2.0, 0.0, 58793.0),
+        (2.0, -1.0, -1.0, 0.0, 57066.0),
+        (2.0, 0.0, 1.0, 0.0, 53322.0),
+        (2.0, -1.0, 0.0, 0.0, 45758.0),
+        (0.0, 1.0, -1.0, 0.0, -40923.0),
+        (1.0, 0.0, 0.0, 0.0, -34720.0),
+        (0.0, 1.0, 1.0, 0.0, -30383.0),
+        (2.0, 0.0, 0.0, -2.0, 15327.0),
+        (0.0, 0.0, 1.0, 2.0, -12528.0),
+        (0.0, 0.0, 1.0, -2.0, 10980.0),
+        (4.0, 0.0, -1.0, 0.0, 10675.0),
+        (0.0, 0.0, 3.0, 0.0, 10034.0),
+        (4.0, 0.0, -2.0, 0.0, 8548.0),
+        (2.0, 1.0, -1.0, 0.0, -7888.0),
+        (2.0, 1.0, 0.0, 0.0, -6766.0),
+        (1.0, 0.0, -1.0, 0.0, -5163.0),
+        (1.0, 1.0, 0.0, 0.0, 4987.0),
+        (2.0, -1.0, 1.0, 0.0, 4036.0),
+        (2.0, 0.0, 2.0, 0.0, 3994.0),
+        (4.0, 0.0, 0.0, 0.0, 3861.0),
+        (2.0, 0.0, -3.0, 0.0, 3665.0),
+        (0.0, 1.0, -2.0, 0.0, -2689.0),
+        (2.0, 0.0, -1.0, 2.0, -2602.0),
+        (2.0, -1.0, -2.0, 0.0, 2390.0),
+        (1.0, 0.0, 1.0, 0.0, -2348.0),
+        (2.0, -2.0, 0.0, 0.0, 2236.0),
+        (0.0, 1.0, 2.0, 0.0, -2120.0),
+        (0.0, 2.0, 0.0, 0.0, -2069.0),
+        (2.0, -2.0, -1.0, 0.0, 2048.0),
+        (2.0, 0.0, 1.0, -2.0, -1773.0),
+        (2.0, 0.0, 0.0, 2.0, -1595.0),
+        (4.0, -1.0, -1.0, 0.0, 1215.0),
+        (0.0, 0.0, 2.0, 2.0, -1110.0),
+        (3.0, 0.0, -1.0, 0.0, -892.0),
+        (2.0, 1.0, 1.0, 0.0, -810.0),
+        (4.0, -1.0, -2.0, 0.0, 759.0),
+        (0.0, 2.0, -1.0, 0.0, -713.0),
+        (2.0, 2.0, -1.0, 0.0, -700.0),
+        (2.0, 1.0, -2.0, 0.0, 691.0),
+        (2.0, -1.0, 0.0, -2.0, 596.0),
+        (4.0, 0.0, 1.0, 0.0, 549.0),
+        (0.0, 0.0, 4.0, 0.0, 537.0),
+        (4.0, -1.0, 0.0, 0.0, 520.0),
+        (1.0, 0.0, -2.0, 0.0, -487.0),
+        (2.0, 1.0, 0.0, -2.0, -399.0),
+        (0.0, 0.0, 2.0, -2.0, -381.0),
+        (1.0, 1.0, 1.0, 0.0, 351.0),
+        (3.0, 0.0, -2.0, 0.0, -340.0),
+        (4.0, 0.0, -3.0, 0.0, 330.0),
+        (2.0, -1.0, 2.0, 0.0, 327.0),
+        (0.0, 2.0, 1.0, 0.0, -323.0),
+        (1.0, 1.0, -1.0, 0.0, 299.0),
+        (2.0, 0.0, 3.0, 0.0, 294.0),
+    ];
+    let c = julian_centuries(moment);
+    let mean_longitude = 218.3164477 + 481267.88123421 * c - 0.0015786 * c * c
+        + c * c * c / 538841.0
+        - c * c * c * c / 65194000.0;
+    let elongation = 297.8501921 + 445267.1114034 * c - 0.0018819 * c * c + c * c * c / 545868.0
+        - c * c * c * c / 113065000.0;
+    let solar_anomaly =
+        357.5291092 + 35999.0502909 * c - 0.0001536 * c * c + c * c * c / 24490000.0;
+    let lunar_anomaly = 134.9633964 + 477198.8675055 * c + 0.0087414 * c * c + c * c * c / 69699.0
+        - c * c * c * c / 14712000.0;
+    let node = 93.2720950 + 483202.0175233 * c - 0.0036539 * c * c - c * c * c / 3526000.0
+        + c * c * c * c / 863310000.0;
+    // eccentricity of the earth's orbit
+    let e = 1.0 - 0.002516 * c - 0.0000074 * c * c;
+    let mut sum = 0.0;
+    for (w, x, y, z, v) in TERMS {
+        sum += v
+            * e.powi(x.abs() as i32)
+            * sin_degrees(w * elongation + x * solar_anomaly + y * lunar_anomaly + z * node);
+    }
+    let correction = sum / 1000000.0;
+    let venus = 0.003958 * sin_degrees(119.75 + 131.849 * c);
+    let jupiter = 0.000318 * sin_degrees(53.09 + 479264.29 * c);
+    let flat_earth = 0.001962 * sin_degrees(mean_longitude - node);
+    return (mean_longitude + correction + venus + jupiter + flat_earth + nutation(c))
+        .rem_euclid(360.0);
+}
+
+/// Computes the lunar phase (in degrees) at a given moment (in universal
+/// time): the difference between the longitudes of the moon and the sun,
+/// which is 0 at a new moon, 90 at the first quarter, 180 at a full moon, and
+/// 270 at the last quarter.
+pub fn lunar_phase(moment: Moment) -> f64 {
+    return (lunar_longitude(moment) - solar_longitude(moment)).rem_euclid(360.0);
+}
+
+/// Named phases of the moon: the four principal phases (new moon, first
+/// quarter, full moon, last quarter) and the intermediate phases between them
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Returns the name of the phase (e.g. "waxing crescent").
+    pub fn name(&self) -> &'static str {
+        return match self {
+            MoonPhase::NewMoon => "new moon",
+            MoonPhase::WaxingCrescent => "waxing crescent",
+            MoonPhase::FirstQuarter => "first quarter",
+            MoonPhase::WaxingGibbous => "waxing gibbous",
+            MoonPhase::FullMoon => "full moon",
+            MoonPhase::WaningGibbous => "waning gibbous",
+            MoonPhase::LastQuarter => "last quarter",
+            MoonPhase::WaningCrescent => "waning crescent",
+        };
+    }
+}
+
+impl fmt::Display for MoonPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Lunar phase of a day (see [`lunar_phase_on`])
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LunarPhase {
+    /// Lunar phase (in degrees) at noon (universal time), see [`lunar_phase`]
+    pub angle: f64,
+    /// Named phase of the day
+    pub phase: MoonPhase,
+}
+
+impl LunarPhase {
+    /// Returns the illuminated fraction of the moon's disk (0 at a new moon,
+    /// 1 at a full moon), approximated from the phase angle.
+    pub fn illumination(&self) -> f64 {
+        return (1.0 - cos_degrees(self.angle)) / 2.0;
+    }
+}
+
+/// Computes the lunar phase of a given absolute date (in universal time), as
+/// in almanacs: a principal phase is given for the day on which it occurs,
+/// and an intermediate phase for the days between them.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     astro::{lunar_phase_on, MoonPhase},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+/// };
+///
+/// // the moon was full at 23:49 UTC on April 23, 2024
+/// let phase = lunar_phase_on(absolute_from_gregorian(Gregorian::new(2024, 4, 23)));
+/// assert_eq!(phase.phase, MoonPhase::FullMoon);
+/// let phase = lunar_phase_on(absolute_from_gregorian(Gregorian::new(2024, 4, 20)));
+/// assert_eq!(phase.phase.name(), "waxing gibbous");
+/// assert!(phase.angle > 90.0 && phase.angle < 180.0);
+/// assert!(phase.illumination() > 0.5 && phase.illumination() < 1.0);
+/// let phase = lunar_phase_on(absolute_from_gregorian(Gregorian::new(2024, 5, 1)));
+/// assert_eq!(phase.phase.to_string(), "last quarter");
+/// ```
+pub fn lunar_phase_on(date: i64) -> LunarPhase {
+    static PRINCIPAL: [MoonPhase; 4] = [
+        MoonPhase::NewMoon,
+        MoonPhase::FirstQuarter,
+        MoonPhase::FullMoon,
+        MoonPhase::LastQuarter,
+    ];
+    static INTERMEDIATE: [MoonPhase; 4] = [
+        MoonPhase::WaxingCrescent,
+        MoonPhase::WaxingGibbous,
+        MoonPhase::WaningGibbous,
+        MoonPhase::WaningCrescent,
+    ];
+    let start = lunar_phase(Moment::from(date));
+    let end = lunar_phase(Moment::from(date.saturating_add(1)));
+    let angle = lunar_phase(Moment(date as f64 + 0.5));
+    let (first, last) = (
+        (start / 90.0).floor() as usize,
+        (end / 90.0).floor() as usize,
+    );
+    // the phase increases by about 12 degrees per day, and wraps at a new moon
+    let phase = if end < start {
+        MoonPhase::NewMoon
+    } else if last != first {
+        PRINCIPAL[last % 4]
+    } else {
+        INTERMEDIATE[first % 4]
+    };
+    return LunarPhase { angle, phase };
+}
+
+/// Equinoxes and solstices, at which the seasons of the northern hemisphere
+/// begin
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Season {
+    MarchEquinox,
+    JuneSolstice,
+    SeptemberEquinox,
+    DecemberSolstice,
+}
+
+impl Season {
+    /// Returns the apparent longitude of the sun (in degrees) at the
+    /// equinox or solstice.
+    pub fn solar_longitude(&self) -> f64 {
+        return match self {
+            Season::MarchEquinox => 0.0,
+            Season::JuneSolstice => 90.0,
+            Season::SeptemberEquinox => 180.0,
+            Season::DecemberSolstice => 270.0,
+        };
+    }
+}
+
+/// Computes the moment (in universal time) of an equinox or solstice in a
+/// given Gregorian year. Years outside [`GREGORIAN_YEAR_RANGE`] are clamped
+/// to the range.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     astro::{season_date, season_moment, Season},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     moment::{Moment, TimeOfDay},
+/// };
+///
+/// // the September equinox of 2024 occurred at 12:44 UTC on September 22
+/// let moment = season_moment(2024, Season::SeptemberEquinox);
+/// let date = absolute_from_gregorian(Gregorian::new(2024, 9, 22));
+/// assert_eq!(moment.absolute_date(), date);
+/// assert!((moment - Moment::new(date, TimeOfDay::new(12, 44, 0))).abs() < 5.0 / 1440.0);
+///
+/// assert_eq!(
+///     season_date(2024, Season::MarchEquinox),
+///     absolute_from_gregorian(Gregorian::new(2024, 3, 20))
+/// );
+/// assert_eq!(
+///     season_date(2024, Season::JuneSolstice),
+///     absolute_from_gregorian(Gregorian::new(2024, 6, 20))
+/// );
+/// assert_eq!(
+///     season_date(2024, Season::DecemberSolstice),
+///     absolute_from_gregorian(Gregorian::new(2024, 12, 21))
+/// );
+/// assert_eq!(
+///     season_date(i64::MAX, Season::MarchEquinox),
+///     season_date(1_000_000_000_000_000, Season::MarchEquinox)
+/// );
+/// ```
+pub fn season_moment(g_year: i64, season: Season) -> Moment {
+    let g_year = clamp_to_range(g_year, &GREGORIAN_YEAR_RANGE);
+    // the March equinox falls around March 20, the other seasons follow in
+    // intervals of about three months
+    let jan_1 = Moment::from(absolute_from_gregorian(Gregorian::new(g_year, 1, 1)));
+    return solar_longitude_after(season.solar_longitude(), jan_1 + 60.0);
+}
+
+/// Returns the absolute (fixed) date (in universal time) of an equinox or
+/// solstice in a given Gregorian year.
+pub fn season_date(g_year: i64, season: Season) -> i64 {
+    return season_moment(g_year, season).absolute_date();
+}
+
+// Lunar phases
+
+/// Mean length of a synodic month (in days)
+pub static MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+/// Moment (absolute date and fraction of day, in dynamical time) of the mean
+/// new moon of January 6, 2000
+pub static MEAN_NEW_MOON_2000: f64 = 730125.59766;
+
+/// Computes the moment (in universal time) of the lunar phase k (new moons
+/// for whole k, full moons for k + 1/2) counted from the new moon of January
+/// 6, 2000, using the given sine coefficients of the periodic terms,
+/// following Meeus, "Astronomical Algorithms" (1998), chapter 49.
+fn lunar_phase_moment(k: f64, sine_coeff: &[f64; 24]) -> Moment {
+    static E_FACTOR: [i32; 24] = [
+        0, 1, 0, 0, 1, 1, 2, 0, 0, 1, 0, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    static SOLAR_COEFF: [f64; 24] = [
+        0., 1., 0., 0., -1., 1., 2., 0., 0., 1., 0., 1., 1., -1., 2., 0., 3., 1., 0., 1., -1., -1.,
+        1., 0.,
+    ];
+    static LUNAR_COEFF: [f64; 24] = [
+        1., 0., 2., 0., 1., 1., 0., 1., 1., 2., 3., 0., 0., 2., 1., 2., 0., 1., 2., 1., 1., 1., 3.,
+        4.,
+    ];
+    static MOON_COEFF: [f64; 24] = [
+        0., 0., 0., 2., 0., 0., 0., -2., 2., 0., 0., 2., -2., 0., 0., -2., 0., -2., 2., 2., 2.,
+        -2., 0., 0.,
+    ];
+    static ADD_CONST: [f64; 13] = [
+        251.88, 251.83, 349.42, 84.66, 141.74, 207.14, 154.84, 34.52, 207.19, 291.34, 161.72,
+        239.56, 331.55,
+    ];
+    static ADD_COEFF: [f64; 13] = [
+        0.016321, 26.651886, 36.412478, 18.206239, 53.303771, 2.453732, 7.306860, 27.261239,
+        0.121824, 1.844379, 24.198154, 25.513099, 3.592518,
+    ];
+    static ADD_FACTOR: [f64; 13] = [
+        0.000165, 0.000164, 0.000126, 0.000110, 0.000062, 0.000060, 0.000056, 0.000047, 0.000042,
+        0.000040, 0.000037, 0.000035, 0.000023,
+    ];
+    let c = k / 1236.85;
+    let approx = MEAN_NEW_MOON_2000 + MEAN_SYNODIC_MONTH * k + 0.00015437 * c.powi(2)
+        - 0.00000015 * c.powi(3)
+        + 0.00000000073 * c.powi(4);
+    let e = 1.0 - 0.002516 * c - 0.0000074 * c.powi(2);
+    let solar_anomaly = 2.5534 + 29.10535670 * k - 0.0000014 * c.powi(2) - 0.00000011 * c.powi(3);
+    let lunar_anomaly =
+        201.5643 + 385.81693528 * k + 0.0107582 * c.powi(2) + 0.00001238 * c.powi(3)
+            - 0.000000058 * c.powi(4);
+    let moon_argument =
+        160.7108 + 390.67050284 * k - 0.0016118 * c.powi(2) - 0.00000227 * c.powi(3)
+            + 0.000000011 * c.powi(4);
+    let omega = 124.7746 - 1.56375588 * k + 0.0020672 * c.powi(2) + 0.00000215 * c.powi(3);
+    let mut correction = -0.00017 * sin_degrees(omega);
+    for i in 0..24 {
+        correction += sine_coeff[i]
+            * e.powi(E_FACTOR[i])
+            * sin_degrees(
+                SOLAR_COEFF[i] * solar_anomaly
+                    + LUNAR_COEFF[i] * lunar_anomaly
+                    + MOON_COEFF[i] * moon_argument,
+            );
+    }
+    let extra = 0.000325 * sin_degrees(299.77 + 0.107408 * k - 0.009173 * c.powi(2));
+    let mut additional = 0.0;
+    for i in 0..13 {
+        additional += ADD_FACTOR[i] * sin_degrees(ADD_CONST[i] + ADD_COEFF[i] * k);
+    }
+    let moment = Moment(approx + correction + extra + additional);
+    return moment - delta_t(moment);
+}
+
+/// Computes the moment (in universal time) of the n-th new moon after (or, if
+/// negative, before) the new moon of January 6, 2000.
+pub fn nth_new_moon(n: i64) -> Moment {
+    static SINE_COEFF: [f64; 24] = [
+        -0.40720, 0.17241, 0.01608, 0.01039, 0.00739, -0.00514, 0.00208, -0.00111, -0.00057,
+        0.00056, -0.00042, 0.00042, 0.00038, -0.00024, -0.00007, 0.00004, 0.00004, 0.00003,
+        0.00003, -0.00003, 0.00003, -0.00002, -0.00002, 0.00002,
+    ];
+    return lunar_phase_moment(n as f64, &SINE_COEFF);
+}
+
+/// Computes the moment (in universal time) of the n-th full moon after (or, if
+/// negative, before) the full moon of January 21, 2000.
+pub fn nth_full_moon(n: i64) -> Moment {
+    static SINE_COEFF: [f64; 24] = [
+        -0.40614, 0.17302, 0.01614, 0.01043, 0.00734, -0.00515, 0.00209, -0.00111, -0.00057,
+        0.00056, -0.00042, 0.00042, 0.00038, -0.00024, -0.00007, 0.00004, 0.00004, 0.00003,
+        0.00003, -0.00003, 0.00003, -0.00002, -0.00002, 0.00002,
+    ];
+    return lunar_phase_moment(n as f64 + 0.5, &SINE_COEFF);
+}
+
+/// Returns the index (see [`nth_new_moon`] and [`nth_full_moon`]) of the first
+/// lunar phase at or after a given moment, given the moment of each phase and
+/// its offset from the mean new moon (in synodic months).
+fn first_phase_at_or_after<F: Fn(i64) -> Moment>(moment: Moment, offset: f64, phase: F) -> i64 {
+    // true phases deviate from the mean ones by less than a day
+    let mut n = ((moment.0 - MEAN_NEW_MOON_2000) / MEAN_SYNODIC_MONTH - offset).floor() as i64;
+    while phase(n) < moment {
+        n += 1;
+    }
+    return n;
+}
+
+/// Computes the moment (in universal time) of the n-th new moon (n >= 1) from
+/// the beginning of a given absolute (fixed) date on. The absolute date of
+/// the new moon is the integral part of the moment.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     astro::{nth_full_moon_after, nth_new_moon_after},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     moment::{Moment, TimeOfDay},
+/// };
+///
+/// let date = |month, day| absolute_from_gregorian(Gregorian::new(2024, month, day));
+/// // new moons on September 3 and October 2, 2024 (18:49 UTC)
+/// let new_moon = nth_new_moon_after(2, date(9, 1));
+/// assert_eq!(new_moon.absolute_date(), date(10, 2));
+/// assert!((new_moon - Moment::new(date(10, 2), TimeOfDay::new(18, 49, 0))).abs() < 0.01);
+/// // full moons on September 18 and October 17, 2024 (11:26 UTC)
+/// assert_eq!(nth_full_moon_after(1, date(9, 1)).absolute_date(), date(9, 18));
+/// let full_moon = nth_full_moon_after(1, date(10, 17));
+/// assert!((full_moon - Moment::new(date(10, 17), TimeOfDay::new(11, 26, 0))).abs() < 0.01);
+/// ```
+pub fn nth_new_moon_after(n: i64, absolute_date: i64) -> Moment {
+    let first = first_phase_at_or_after(Moment::from(absolute_date), 0.0, nth_new_moon);
+    return nth_new_moon(first + n - 1);
+}
+
+/// Computes the moment (in universal time) of the n-th full moon (n >= 1) from
+/// the beginning of a given absolute (fixed) date on (see
+/// [`nth_new_moon_after`]).
+pub fn nth_full_moon_after(n: i64, absolute_date: i64) -> Moment {
+    let first = first_phase_at_or_after(Moment::from(absolute_date), 0.5, nth_full_moon);
+    return nth_full_moon(first + n - 1);
+}
+
+/// Computes the moment (in universal time) of the last new moon before a
+/// given moment.
+pub fn new_moon_before(moment: Moment) -> Moment {
+    return nth_new_moon(first_phase_at_or_after(moment, 0.0, nth_new_moon) - 1);
+}
+
+// Sunrise and sunset
+
+/// Geographic location, with the offset of its time zone from universal time
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Location {
+    /// Latitude (in degrees north)
+    pub latitude: f64,
+    /// Longitude (in degrees east)
+    pub longitude: f64,
+    /// Elevation above sea level (in meters)
+    pub elevation: f64,
+    /// Offset of the time zone from universal time (in hours)
+    pub zone: f64,
+}
+
+impl Location {
+    pub fn new(latitude: f64, longitude: f64, elevation: f64, zone: f64) -> Self {
+        Self {
+            latitude,
+            longitude,
+            elevation,
+            zone,
+        }
+    }
+}
+
+/// Location of Jerusalem (in standard time)
+pub static JERUSALEM: Location = Location {
+    latitude: 31.78,
+    longitude: 35.24,
+    elevation: 740.0,
+    zone: 2.0,
+};
+
+/// Location of Mecca
+pub static MECCA: Location = Location {
+    latitude: 21.4225,
+    longitude: 39.8262,
+    elevation: 298.0,
+    zone: 3.0,
+};
+
+/// Computes the moment (in universal time) at which the upper limb of the
+/// sun crosses the horizon on a given local date, using a low-precision solar
+/// position. Returns `None` if the sun does not rise or set on that day.
+fn sun_crossing(absolute_date: i64, location: Location, rising: bool) -> Option<Moment> {
+    let day = absolute_date as f64;
+    // refraction and semidiameter of the sun, and dip of the horizon
+    let altitude = -0.833 - 0.0347 * location.elevation.max(0.0).sqrt();
+    // first approximation: 6:00 or 18:00 local mean time
+    let mut moment = day + if rising { 0.25 } else { 0.75 } - location.longitude / 360.0;
+    for _ in 0..3 {
+        let n = moment - 730120.5;
+        let mean_longitude = (280.460 + 0.9856474 * n).rem_euclid(360.0);
+        let mean_anomaly = 357.528 + 0.9856003 * n;
+        let longitude = mean_longitude
+            + 1.915 * sin_degrees(mean_anomaly)
+            + 0.020 * sin_degrees(2.0 * mean_anomaly);
+        let obliquity = 23.439 - 0.0000004 * n;
+        let right_ascension = (cos_degrees(obliquity) * sin_degrees(longitude))
+            .atan2(cos_degrees(longitude))
+            .to_degrees();
+        let declination = (sin_degrees(obliquity) * sin_degrees(longitude)).asin();
+        let equation_of_time = (mean_longitude - right_ascension + 180.0).rem_euclid(360.0) - 180.0;
+        let cos_hour_angle = (sin_degrees(altitude)
+            - sin_degrees(location.latitude) * declination.sin())
+            / (cos_degrees(location.latitude) * declination.cos());
+        if !(-1.0..=1.0).contains(&cos_hour_angle) {
+            return None;
+        }
+        let hour_angle = cos_hour_angle.acos().to_degrees();
+        let hour_angle = if rising { -hour_angle } else { hour_angle };
+        moment = day + (180.0 - location.longitude - equation_of_time + hour_angle) / 360.0;
+    }
+    return Some(Moment(moment));
+}
+
+/// Computes the moment (in universal time) of sunrise at a given location on
+/// a given absolute (fixed) date. Returns `None` if the sun does not rise on
+/// that day (polar night or midnight sun).
+///
+/// The result is accurate to about a minute; the elevation of the location
+/// lowers the visible horizon (use 0 for sea-level times).
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     astro::{sunrise, sunset, Location, JERUSALEM},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     moment::Moment,
+/// };
+///
+/// // sunrise in Jerusalem at about 6:33 and sunset at about 18:22 (local
+/// // daylight saving time, sea level) on October 2, 2024
+/// let date = absolute_from_gregorian(Gregorian::new(2024, 10, 2));
+/// let jerusalem = Location { elevation: 0.0, ..JERUSALEM };
+/// let local = |moment: Moment| (moment + 3.0 / 24.0).time_of_day();
+/// assert!((local(sunrise(date, jerusalem).unwrap()).seconds() - 6 * 3600 - 33 * 60).abs() < 180);
+/// assert!((local(sunset(date, jerusalem).unwrap()).seconds() - 18 * 3600 - 22 * 60).abs() < 180);
+///
+/// // midnight sun in Longyearbyen
+/// let longyearbyen = Location::new(78.22, 15.65, 0.0, 2.0);
+/// let june_21 = absolute_from_gregorian(Gregorian::new(2024, 6, 21));
+/// assert_eq!(sunset(june_21, longyearbyen), None);
+/// ```
+pub fn sunrise(absolute_date: i64, location: Location) -> Option<Moment> {
+    return sun_crossing(absolute_date, location, true);
+}
+
+/// Computes the moment (in universal time) of sunset at a given location on a
+/// given absolute (fixed) date (see [`sunrise`]).
+pub fn sunset(absolute_date: i64, location: Location) -> Option<Moment> {
+    return sun_crossing(absolute_date, location, false);
+}
+
+/// Returns the local (civil) absolute date of a given moment (in universal
+/// time) at a given location.
+pub fn local_date(moment: Moment, location: Location) -> i64 {
+    return (moment + location.zone / 24.0).absolute_date();
+}
+
+/// Returns the absolute (fixed) date of the day containing a given moment (in
+/// universal time) at a given location, for calendars whose days begin at
+/// sunset (e.g. the Hebrew and Islamic calendars): after sunset, this is the
+/// day after the local date. Where the sun does not set, days begin at
+/// midnight.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     astro::{day_beginning_at_sunset, JERUSALEM},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     hebrew::hebrew_from_absolute,
+///     moment::{Moment, TimeOfDay},
+/// };
+///
+/// // Rosh Hashanah 5785 began at sunset on October 2, 2024
+/// let date = absolute_from_gregorian(Gregorian::new(2024, 10, 2));
+/// let afternoon = Moment::new(date, TimeOfDay::new(12, 0, 0));
+/// let evening = Moment::new(date, TimeOfDay::new(17, 0, 0));
+/// assert_eq!(hebrew_from_absolute(day_beginning_at_sunset(afternoon, JERUSALEM)).year, 5784);
+/// assert_eq!(hebrew_from_absolute(day_beginning_at_sunset(evening, JERUSALEM)).year, 5785);
+/// ```
+pub fn day_beginning_at_sunset(moment: Moment, location: Location) -> i64 {
+    let date = local_date(moment, location);
+    return match sunset(date, location) {
+        Some(sunset) if moment >= sunset => date + 1,
+        _ => date,
+    };
+}
@@ -0,0 +1,437 @@
+//! Command line interface converting dates between calendars
+
+#![allow(clippy::needless_return)]
+
+#[cfg(feature = "csv")]
+use std::fs::File;
+use std::{
+    env,
+    io::{self, BufRead},
+    process::ExitCode,
+};
+
+#[cfg(feature = "csv")]
+use calendars::csv::{convert_csv, Column, CsvOptions};
+use calendars::{
+    error::CalendarError,
+    holidays::holidays_in_range,
+    locale::Locale,
+    parse::parse_date,
+    render::{render, rendered_range, RenderOptions},
+    utility::{try_date_from_absolute, Date},
+    weekday::Weekday,
+};
+
+static USAGE: &str = "\
+Usage: calendars [OPTIONS] [DATE...]
+       calendars cal [CAL_OPTIONS] [YEAR [MONTH]]
+       calendars csv [CSV_OPTIONS] [FILE]
+
+Converts dates between calendars. Dates are read from the arguments, or from
+standard input (one date per line) if no dates are given.
+
+Options:
+  -f, --from CALENDAR    calendar of the input dates (default: dates are
+                         tagged with their calendar, e.g. \"gregorian:2024-5-1\")
+  -t, --to CALENDAR      calendar of the output dates (default: gregorian)
+  -p, --pattern PATTERN  format output dates according to a strftime-style
+                         pattern (e.g. \"%A, %-d %B %Y\")
+  -l, --locale LOCALE    language of month and weekday names (default: en)
+      --json             print output dates as JSON objects
+  -h, --help             print this help
+
+Calendars: gregorian, iso, ordinal, julian, islamic, ummAlQura, hebrew,
+mayanLongCount, mayanHaab, mayanTzolkin, french, oldHinduSolar, oldHinduLunar
+
+The cal subcommand prints a month (or, without MONTH, a year) as a grid of
+weeks. Without YEAR, it prints the current month.
+
+Cal options:
+  -c, --calendar CALENDAR  calendar of the grid: gregorian (default), julian,
+                           islamic, ummAlQura, hebrew, or french
+  -l, --locale LOCALE      language of month and weekday names (default: en)
+  -m, --monday             start weeks on Monday
+      --holidays           mark holidays with *
+
+The csv subcommand (requires the csv feature) converts a column of dates in a
+CSV file (or standard input), appending one column per target calendar, and
+writes the result to standard output. Invalid dates are left empty.
+
+Csv options:
+  -f, --from CALENDAR    calendar of the input dates (default: gregorian)
+  -t, --to CALENDAR      calendar of an appended column (repeatable)
+  -c, --column COLUMN    name or number (starting at 1) of the date column
+                         (default: 1)
+  -p, --pattern PATTERN  format output dates according to a strftime-style
+                         pattern
+  -l, --locale LOCALE    language of month and weekday names (default: en)
+  -d, --delimiter CHAR   field delimiter (default: ,)
+      --no-header        the first row is not a header row";
+
+/// Command line options
+struct Options {
+    from: Option<String>,
+    to: String,
+    pattern: Option<String>,
+    locale: Locale,
+    json: bool,
+    dates: Vec<String>,
+}
+
+/// Parses the command line arguments, or returns an error message.
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options {
+        from: None,
+        to: "gregorian".to_string(),
+        pattern: None,
+        locale: Locale::default(),
+        json: false,
+        dates: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            return args
+                .next()
+                .ok_or_else(|| format!("option {} requires a value", name));
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--from" => options.from = Some(value(&arg)?),
+            "-t" | "--to" => options.to = value(&arg)?,
+            "-p" | "--pattern" => options.pattern = Some(value(&arg)?),
+            "-l" | "--locale" => {
+                options.locale = value(&arg)?.parse().map_err(|e| format!("{}", e))?;
+            }
+            "--json" => options.json = true,
+            "--" => options.dates.extend(args.by_ref()),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {}", arg)),
+            _ => options.dates.push(arg),
+        }
+    }
+    return Ok(Some(options));
+}
+
+/// Returns a JSON string literal of a given string.
+fn json_string(s: &str) -> String {
+    let mut literal = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            c if c.is_control() => literal.push_str(&format!("\\u{:04x}", c as u32)),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    return literal;
+}
+
+/// Returns a JSON object describing a converted date: its calendar, named
+/// components, and formatted form.
+fn json_date(input: &str, date: &Date, formatted: &str) -> String {
+    let components: Vec<String> = date
+        .component_names
+        .iter()
+        .zip(&date.components)
+        .map(|(name, value)| format!("{}:{}", json_string(name), value))
+        .collect();
+    return format!(
+        "{{\"input\":{},\"calendar\":{},\"components\":{{{}}},\"formatted\":{}}}",
+        json_string(input),
+        json_string(&date.calendar),
+        components.join(","),
+        json_string(formatted)
+    );
+}
+
+/// Converts a date string according to the options, and returns the line to
+/// be printed.
+fn convert(input: &str, options: &Options) -> Result<String, CalendarError> {
+    let absolute_date = match &options.from {
+        Some(calendar) => parse_date(input, calendar)?.try_to_absolute()?,
+        None => {
+            // tagged dates are not validated when parsed, but only existing
+            // dates survive the round trip through their absolute dates
+            let date = input.parse::<Date>()?;
+            let absolute_date = date.try_to_absolute()?;
+            if try_date_from_absolute(absolute_date, &date.calendar)?.components != date.components
+            {
+                return Err(CalendarError::ParseError(format!(
+                    "{} does not exist",
+                    input
+                )));
+            }
+            absolute_date
+        }
+    };
+    let date = try_date_from_absolute(absolute_date, &options.to)?;
+    let formatted = match &options.pattern {
+        Some(pattern) => date.format_with_locale(pattern, options.locale)?,
+        None => date.format_localized(options.locale),
+    };
+    if options.json {
+        return Ok(json_date(input, &date, &formatted));
+    }
+    return Ok(formatted);
+}
+
+/// Options of the cal subcommand
+struct CalOptions {
+    calendar: String,
+    year: Option<i64>,
+    month: Option<i64>,
+    render: RenderOptions,
+    holidays: bool,
+}
+
+/// Parses the arguments of the cal subcommand, or returns an error message.
+fn parse_cal_args(mut args: impl Iterator<Item = String>) -> Result<Option<CalOptions>, String> {
+    let mut options = CalOptions {
+        calendar: "gregorian".to_string(),
+        year: None,
+        month: None,
+        render: RenderOptions::default(),
+        holidays: false,
+    };
+    let mut numbers = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            return args
+                .next()
+                .ok_or_else(|| format!("option {} requires a value", name));
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-c" | "--calendar" => options.calendar = value(&arg)?,
+            "-l" | "--locale" => {
+                options.render.locale = value(&arg)?.parse().map_err(|e| format!("{}", e))?;
+            }
+            "-m" | "--monday" => options.render.first_weekday = Weekday::Monday,
+            "--holidays" => options.holidays = true,
+            _ => {
+                let number = arg
+                    .parse::<i64>()
+                    .map_err(|_| format!("invalid argument {}", arg))?;
+                numbers.push(number);
+            }
+        }
+    }
+    match numbers[..] {
+        [] => {}
+        [year] => options.year = Some(year),
+        [year, month] => (options.year, options.month) = (Some(year), Some(month)),
+        _ => return Err("too many arguments".to_string()),
+    }
+    return Ok(Some(options));
+}
+
+/// Returns the current year and month of a given calendar.
+#[cfg(feature = "clock")]
+fn current_month(calendar: &str) -> Result<(i64, i64), CalendarError> {
+    let today = Date::today(calendar);
+    return Ok((today.components[0], today.components[1]));
+}
+
+/// Returns the current year and month of a given calendar.
+#[cfg(not(feature = "clock"))]
+fn current_month(_calendar: &str) -> Result<(i64, i64), CalendarError> {
+    return Err(CalendarError::ParseError(
+        "YEAR is required without the system clock".to_string(),
+    ));
+}
+
+/// Renders a month or year according to the options of the cal subcommand.
+fn cal(mut options: CalOptions) -> Result<String, CalendarError> {
+    let (year, month) = match options.year {
+        Some(year) => (year, options.month),
+        None => {
+            let (year, month) = current_month(&options.calendar)?;
+            (year, Some(month))
+        }
+    };
+    if options.holidays {
+        let (first, last) = rendered_range(&options.calendar, year, month)?;
+        options.render.highlight = holidays_in_range(first, last, |_| true)
+            .into_iter()
+            .map(|(date, _)| date)
+            .collect();
+    }
+    return render(&options.calendar, year, month, &options.render);
+}
+
+/// Runs the cal subcommand.
+fn cal_main(args: impl Iterator<Item = String>) -> ExitCode {
+    let options = match parse_cal_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("calendars: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    return match cal(options) {
+        Ok(text) => {
+            print!("{}", text);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("calendars: {}", e);
+            ExitCode::FAILURE
+        }
+    };
+}
+
+/// Options of the csv subcommand
+#[cfg(feature = "csv")]
+struct CsvArgs {
+    csv: CsvOptions,
+    file: Option<String>,
+}
+
+/// Parses the arguments of the csv subcommand, or returns an error message.
+#[cfg(feature = "csv")]
+fn parse_csv_args(mut args: impl Iterator<Item = String>) -> Result<Option<CsvArgs>, String> {
+    let mut options = CsvArgs {
+        csv: CsvOptions::default(),
+        file: None,
+    };
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            return args
+                .next()
+                .ok_or_else(|| format!("option {} requires a value", name));
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "-f" | "--from" => options.csv.from = value(&arg)?,
+            "-t" | "--to" => options.csv.to.push(value(&arg)?),
+            "-c" | "--column" => {
+                let column = value(&arg)?;
+                options.csv.column = match column.parse::<usize>() {
+                    Ok(0) => return Err("column numbers start at 1".to_string()),
+                    Ok(number) => Column::Index(number - 1),
+                    Err(_) => Column::Name(column),
+                };
+            }
+            "-p" | "--pattern" => options.csv.pattern = Some(value(&arg)?),
+            "-l" | "--locale" => {
+                options.csv.locale = value(&arg)?.parse().map_err(|e| format!("{}", e))?;
+            }
+            "-d" | "--delimiter" => {
+                options.csv.delimiter = match value(&arg)?.as_bytes() {
+                    [delimiter] => *delimiter,
+                    _ => return Err("the delimiter must be a single byte".to_string()),
+                };
+            }
+            "--no-header" => options.csv.has_headers = false,
+            _ if arg.starts_with('-') && arg != "-" => {
+                return Err(format!("unknown option {}", arg));
+            }
+            _ if options.file.is_none() => options.file = Some(arg),
+            _ => return Err("too many arguments".to_string()),
+        }
+    }
+    if options.csv.to.is_empty() {
+        return Err("option --to is required".to_string());
+    }
+    return Ok(Some(options));
+}
+
+/// Runs the csv subcommand.
+#[cfg(feature = "csv")]
+fn csv_main(args: impl Iterator<Item = String>) -> ExitCode {
+    let options = match parse_csv_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("calendars: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let input: Box<dyn io::Read> = match options.file.as_deref() {
+        None | Some("-") => Box::new(io::stdin().lock()),
+        Some(file) => match File::open(file) {
+            Ok(file) => Box::new(file),
+            Err(e) => {
+                eprintln!("calendars: {}: {}", file, e);
+                return ExitCode::FAILURE;
+            }
+        },
+    };
+    return match convert_csv(input, io::stdout().lock(), &options.csv) {
+        Ok(summary) if summary.invalid_rows > 0 => {
+            eprintln!(
+                "calendars: {} of {} dates could not be converted",
+                summary.invalid_rows, summary.rows
+            );
+            ExitCode::FAILURE
+        }
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("calendars: {}", e);
+            ExitCode::FAILURE
+        }
+    };
+}
+
+/// Runs the csv subcommand, which is unavailable without the csv feature.
+#[cfg(not(feature = "csv"))]
+fn csv_main(_args: impl Iterator<Item = String>) -> ExitCode {
+    eprintln!("calendars: the csv subcommand requires the csv feature");
+    return ExitCode::from(2);
+}
+
+fn main() -> ExitCode {
+    let mut args = env::args().skip(1).peekable();
+    if args.peek().is_some_and(|arg| arg == "cal") {
+        return cal_main(args.skip(1));
+    }
+    if args.peek().is_some_and(|arg| arg == "csv") {
+        return csv_main(args.skip(1));
+    }
+    let options = match parse_args(args) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(message) => {
+            eprintln!("calendars: {}\n\n{}", message, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let inputs: Box<dyn Iterator<Item = String>> = if options.dates.is_empty() {
+        Box::new(io::stdin().lock().lines().map_while(Result::ok))
+    } else {
+        Box::new(options.dates.clone().into_iter())
+    };
+    let mut status = ExitCode::SUCCESS;
+    for input in inputs {
+        let input = input.trim();
+        if input.is_empty() {
+            continue;
+        }
+        match convert(input, &options) {
+            Ok(line) => println!("{}", line),
+            Err(e) if options.json => {
+                println!(
+                    "{{\"input\":{},\"error\":{}}}",
+                    json_string(input),
+                    json_string(&e.to_string())
+                );
+                status = ExitCode::FAILURE;
+            }
+            Err(e) => {
+                eprintln!("calendars: {}: {}", input, e);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+    return status;
+}
@@ -0,0 +1,127 @@
+//! Provides a builder for generic dates with named components
+
+use crate::{error::CalendarError, system::calendar_info, utility::Date};
+
+/// Builder of a [`Date`] from named components (see [`Date::builder`])
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DateBuilder {
+    calendar: String,
+    components: Vec<(String, i64)>,
+}
+
+impl Date {
+    /// Returns a builder of a [`Date`] of the calendar with a given name (see
+    /// [`Date::convert_to`] for the supported calendars). Components are set
+    /// by name, in any order, and checked against the calendar's component
+    /// names (see [`CalendarInfo`](crate::system::CalendarInfo)) when the
+    /// date is built.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{error::CalendarError, utility::Date};
+    ///
+    /// let date = Date::builder("hebrew").year(5784).month(1).day(15).build()?;
+    /// assert_eq!(date.to_string(), "hebrew:5784-1-15");
+    /// assert_eq!(date.format(), "15 Nisan 5784");
+    ///
+    /// let date = Date::builder("oldHinduLunar")
+    ///     .day(1)
+    ///     .month(1)
+    ///     .leap_month(false)
+    ///     .year(5125)
+    ///     .build()?;
+    /// assert_eq!(date.to_string(), "oldHinduLunar:5125-1-0-1");
+    ///
+    /// let date = Date::builder("mayanHaab").component("day", 8).component("month", 3).build()?;
+    /// assert_eq!(date.to_string(), "mayanHaab:8-3");
+    ///
+    /// assert!(Date::builder("gregorian").year(2024).month(5).build().is_err());
+    /// assert!(Date::builder("gregorian").year(2024).week(18).day(3).build().is_err());
+    /// assert!(Date::builder("gregorian").year(2023).month(2).day(29).build().is_err());
+    /// # Ok::<(), CalendarError>(())
+    /// ```
+    pub fn builder(calendar: &str) -> DateBuilder {
+        return DateBuilder {
+            calendar: calendar.to_string(),
+            components: Vec::new(),
+        };
+    }
+}
+
+impl DateBuilder {
+    /// Sets the component with a given name (e.g. "baktun"). Setting a
+    /// component again replaces its value.
+    pub fn component(mut self, name: &str, value: i64) -> Self {
+        self.components.retain(|(n, _)| n != name);
+        self.components.push((name.to_string(), value));
+        return self;
+    }
+
+    /// Sets the year.
+    pub fn year(self, year: i64) -> Self {
+        return self.component("year", year);
+    }
+
+    /// Sets the month.
+    pub fn month(self, month: i64) -> Self {
+        return self.component("month", month);
+    }
+
+    /// Sets the week (of ISO week dates).
+    pub fn week(self, week: i64) -> Self {
+        return self.component("week", week);
+    }
+
+    /// Sets the day (of the month, or of the week or year for ISO week and
+    /// ordinal dates).
+    pub fn day(self, day: i64) -> Self {
+        return self.component("day", day);
+    }
+
+    /// Sets whether the month is a leap month (of Old Hindu lunar dates).
+    pub fn leap_month(self, leap_month: bool) -> Self {
+        return self.component("leapMonth", i64::from(leap_month));
+    }
+
+    /// Builds the date, or returns an error if the calendar is unknown, a
+    /// component is missing or does not belong to the calendar, or the date
+    /// does not exist (see [`Date::try_to_absolute`]; cyclical Mayan Haab and
+    /// Tzolkin dates are not checked).
+    pub fn build(self) -> Result<Date, CalendarError> {
+        let info = calendar_info(&self.calendar)
+            .ok_or_else(|| CalendarError::UnknownCalendar(self.calendar.clone()))?;
+        if let Some((name, _)) = self
+            .components
+            .iter()
+            .find(|(name, _)| !info.component_names.contains(&name.as_str()))
+        {
+            return Err(CalendarError::UnknownComponent {
+                calendar: info.name.to_string(),
+                component: name.clone(),
+            });
+        }
+        let components = info
+            .component_names
+            .iter()
+            .map(|name| {
+                return self
+                    .components
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, value)| *value)
+                    .ok_or_else(|| CalendarError::MissingComponent {
+                        calendar: info.name.to_string(),
+                        component: name.to_string(),
+                    });
+            })
+            .collect::<Result<Vec<i64>, CalendarError>>()?;
+        let date = Date::new(info.name, components, Vec::new(), Vec::new())
+            .try_to_calendar_date()?
+            .to_date();
+        return match date.try_to_absolute() {
+            Ok(_) | Err(CalendarError::NotConvertible { .. }) => Ok(date),
+            Err(e) => Err(e),
+        };
+    }
+}
@@ -0,0 +1,191 @@
+//! Provides business-day arithmetic with configurable weekends and holidays
+//!
+//! A [`WorkCalendar`] combines the days of the week that are not worked (e.g.
+//! Saturday and Sunday, or Friday and Saturday in much of the Middle East)
+//! with built-in holidays (see [`HOLIDAYS`](crate::holidays::HOLIDAYS)),
+//! observed according to an [`ObservancePolicy`], and further non-working
+//! absolute dates.
+
+use std::collections::HashMap;
+
+use crate::{
+    gregorian::gregorian_from_absolute,
+    holidays::{Holiday, ObservancePolicy, Tag, HOLIDAYS},
+    weekday::{weekday_from_absolute, Weekday},
+};
+
+/// Weekend and holidays determining the business days
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     business::WorkCalendar,
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{ObservancePolicy, Tag},
+///     weekday::Weekday,
+/// };
+///
+/// let us = WorkCalendar::default()
+///     .holidays_with_tag(Tag::UsFederal)
+///     .observance(ObservancePolicy::UsFederal);
+/// // Independence Day 2026 falls on a Saturday and is observed on Friday
+/// let thursday = absolute_from_gregorian(Gregorian::new(2026, 7, 2));
+/// assert!(!us.is_business_day(thursday + 1));
+/// assert_eq!(
+///     us.add_business_days(thursday, 1),
+///     Some(absolute_from_gregorian(Gregorian::new(2026, 7, 6)))
+/// );
+/// assert_eq!(us.add_business_days(thursday + 4, -1), Some(thursday));
+///
+/// // 23 weekdays in May 2024, including Memorial Day
+/// let may = absolute_from_gregorian(Gregorian::new(2024, 5, 1));
+/// let june = absolute_from_gregorian(Gregorian::new(2024, 6, 1));
+/// assert_eq!(us.business_days_between(may, june), 22);
+/// assert_eq!(us.business_days_between(june, may), -22);
+///
+/// // Thursday 2 May 2024 is followed by Sunday 5 May with a Friday-Saturday weekend
+/// let middle_east = WorkCalendar::new(&[Weekday::Friday, Weekday::Saturday]);
+/// assert_eq!(middle_east.add_business_days(may + 1, 1), Some(may + 4));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WorkCalendar {
+    weekend: [bool; 7],
+    holidays: Vec<Holiday>,
+    policy: ObservancePolicy,
+    dates: Vec<i64>,
+}
+
+impl WorkCalendar {
+    /// Create a work calendar with a given weekend, without holidays.
+    pub fn new(weekend: &[Weekday]) -> Self {
+        let mut days = [false; 7];
+        for day in weekend {
+            days[day.number() as usize] = true;
+        }
+        return WorkCalendar {
+            weekend: days,
+            holidays: Vec::new(),
+            policy: ObservancePolicy::Actual,
+            dates: Vec::new(),
+        };
+    }
+
+    /// Adds a built-in holiday.
+    pub fn holiday(mut self, holiday: Holiday) -> Self {
+        if !self.holidays.contains(&holiday) {
+            self.holidays.push(holiday);
+        }
+        return self;
+    }
+
+    /// Adds the built-in holidays with a given tag (e.g. [`Tag::UsFederal`]).
+    pub fn holidays_with_tag(self, tag: Tag) -> Self {
+        return HOLIDAYS
+            .iter()
+            .filter(|holiday| holiday.has_tag(tag))
+            .fold(self, |calendar, holiday| calendar.holiday(*holiday));
+    }
+
+    /// Adds a non-working absolute (fixed) date (e.g. a company holiday),
+    /// which is not shifted by the observance policy.
+    pub fn date(mut self, absolute_date: i64) -> Self {
+        if !self.dates.contains(&absolute_date) {
+            self.dates.push(absolute_date);
+        }
+        return self;
+    }
+
+    /// Sets the policy by which the built-in holidays falling on a weekend
+    /// are observed (by default, holidays are observed on their actual date).
+    pub fn observance(mut self, policy: ObservancePolicy) -> Self {
+        self.policy = policy;
+        return self;
+    }
+
+    /// Returns whether a given absolute (fixed) date falls on the weekend.
+    pub fn is_weekend(&self, absolute_date: i64) -> bool {
+        return self.weekend[weekday_from_absolute(absolute_date).number() as usize];
+    }
+
+    /// Returns the observed absolute (fixed) dates of the holidays of a given
+    /// Gregorian year.
+    fn observed_holidays(&self, year: i64) -> Vec<i64> {
+        return self
+            .holidays
+            .iter()
+            .flat_map(|holiday| holiday.dates_in_gregorian_year(year))
+            .map(|date| self.policy.observed(date))
+            .collect();
+    }
+
+    /// Returns whether a given absolute (fixed) date is a holiday, caching the
+    /// observed holidays by Gregorian year.
+    fn is_holiday_cached(&self, absolute_date: i64, cache: &mut HashMap<i64, Vec<i64>>) -> bool {
+        if self.dates.contains(&absolute_date) {
+            return true;
+        }
+        // observed dates may fall in the adjacent year (e.g. 31 December)
+        let year = gregorian_from_absolute(absolute_date).year;
+        return (year - 1..=year + 1).any(|y| {
+            cache
+                .entry(y)
+                .or_insert_with(|| self.observed_holidays(y))
+                .contains(&absolute_date)
+        });
+    }
+
+    /// Returns whether a given absolute (fixed) date is an (observed) holiday.
+    pub fn is_holiday(&self, absolute_date: i64) -> bool {
+        return self.is_holiday_cached(absolute_date, &mut HashMap::new());
+    }
+
+    /// Returns whether a given absolute (fixed) date is a business day, i.e.
+    /// neither on the weekend nor a holiday.
+    pub fn is_business_day(&self, absolute_date: i64) -> bool {
+        return !self.is_weekend(absolute_date) && !self.is_holiday(absolute_date);
+    }
+
+    /// Returns the absolute (fixed) date a given number of business days
+    /// after (or, if negative, before) a given absolute date, which need not
+    /// be a business day itself, or `None` if the weekend spans the whole
+    /// week. Adding zero days returns the date itself.
+    pub fn add_business_days(&self, absolute_date: i64, days: i64) -> Option<i64> {
+        if self.weekend.iter().all(|day| *day) {
+            return None;
+        }
+        let step = days.signum();
+        let mut cache = HashMap::new();
+        let mut date = absolute_date;
+        let mut remaining: u64 = days.unsigned_abs();
+        while remaining > 0 {
+            date += step;
+            if !self.is_weekend(date) && !self.is_holiday_cached(date, &mut cache) {
+                remaining -= 1;
+            }
+        }
+        return Some(date);
+    }
+
+    /// Returns the number of business days from a given absolute (fixed)
+    /// date `start` (included) to `end` (excluded), or the negated number of
+    /// business days from `end` to `start` if `end` precedes `start`. For a
+    /// business day `start`, this is the inverse of
+    /// [`add_business_days`](WorkCalendar::add_business_days).
+    pub fn business_days_between(&self, start: i64, end: i64) -> i64 {
+        if end < start {
+            return -self.business_days_between(end, start);
+        }
+        let mut cache = HashMap::new();
+        return (start..end)
+            .filter(|date| !self.is_weekend(*date) && !self.is_holiday_cached(*date, &mut cache))
+            .count() as i64;
+    }
+}
+
+/// A work calendar with a Saturday-Sunday weekend, without holidays.
+impl Default for WorkCalendar {
+    fn default() -> Self {
+        return WorkCalendar::new(&[Weekday::Saturday, Weekday::Sunday]);
+    }
+}
@@ -0,0 +1,279 @@
+//! Provides the calendar in civil use in a country, which switched from the
+//! Julian to the Gregorian calendar at a given date
+
+use core::{fmt, ops::RangeInclusive};
+
+use crate::{
+    error::CalendarError,
+    gregorian::{absolute_from_gregorian, gregorian_from_absolute, Gregorian},
+    julian::{absolute_from_julian, julian_from_absolute, Julian},
+    utility::Calendar,
+};
+
+/// Calendar a civil date is reckoned in
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CalendarStyle {
+    /// Julian calendar ("Old Style")
+    Julian,
+    /// Gregorian calendar ("New Style")
+    Gregorian,
+    /// Swedish calendar of 1700 to 1712, one day ahead of the Julian calendar
+    /// (see [`SWEDISH_ABSOLUTE_RANGE`])
+    Swedish,
+}
+
+/// Range of absolute (fixed) dates of the Swedish calendar: Sweden omitted the
+/// leap day of 1700 to approach the Gregorian calendar gradually, but
+/// abandoned the plan and returned to the Julian calendar by adding 30
+/// February 1712.
+pub static SWEDISH_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_julian(Julian::new(1700, 2, 29))..=absolute_from_julian(Julian::new(1712, 2, 29));
+
+/// Date of the calendar in civil use, reckoned in the Julian or Gregorian
+/// calendar
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CivilDate {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+    pub style: CalendarStyle,
+}
+
+impl CivilDate {
+    /// Returns the absolute (fixed) date of the date, according to its style.
+    pub fn to_absolute(&self) -> i64 {
+        return match self.style {
+            CalendarStyle::Julian => {
+                absolute_from_julian(Julian::new(self.year, self.month, self.day))
+            }
+            CalendarStyle::Gregorian => {
+                absolute_from_gregorian(Gregorian::new(self.year, self.month, self.day))
+            }
+            CalendarStyle::Swedish => {
+                absolute_from_julian(Julian::new(self.year, self.month, self.day)) - 1
+            }
+        };
+    }
+}
+
+/// Implement fmt::Display trait for [`CivilDate`], writing the date like the
+/// Julian or Gregorian date it is reckoned as (e.g. "4 October 1582")
+impl fmt::Display for CivilDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = match self.style {
+            CalendarStyle::Julian | CalendarStyle::Swedish => {
+                Julian::new(self.year, self.month, self.day).format()
+            }
+            CalendarStyle::Gregorian => Gregorian::new(self.year, self.month, self.day).format(),
+        };
+        write!(f, "{}", date)
+    }
+}
+
+/// Calendar in civil use in a country: the Julian calendar until the day
+/// before a given cutover date, and the Gregorian calendar from the cutover
+/// date on. The days between the last Julian and the first Gregorian date
+/// were skipped.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     civil::{CalendarStyle, CivilCalendar},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+/// };
+///
+/// let britain = CivilCalendar::BRITAIN;
+/// assert_eq!(britain.skipped_days(), 11);
+///
+/// // 2 September 1752 was followed by 14 September 1752
+/// let cutover = absolute_from_gregorian(Gregorian::new(1752, 9, 14));
+/// assert_eq!(britain.from_absolute(cutover - 1).to_string(), "2 September 1752");
+/// assert_eq!(britain.from_absolute(cutover).to_string(), "14 September 1752");
+/// assert_eq!(britain.from_absolute(cutover - 1).style, CalendarStyle::Julian);
+/// assert!(britain.date(1752, 9, 10).is_err());
+///
+/// // the same day in Rome
+/// let rome = CivilCalendar::default();
+/// assert_eq!(rome.from_absolute(cutover - 1).to_string(), "13 September 1752");
+/// let last_julian = rome.date(1582, 10, 4).unwrap();
+/// assert_eq!(last_julian.to_absolute() + 1, rome.date(1582, 10, 15).unwrap().to_absolute());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CivilCalendar {
+    /// First day of the Gregorian calendar
+    pub first_gregorian_day: Gregorian,
+    /// Whether the Swedish calendar was in use from 1 March 1700 to 30
+    /// February 1712 (see [`SWEDISH_ABSOLUTE_RANGE`])
+    pub swedish: bool,
+}
+
+impl CivilCalendar {
+    /// Papal States, Spain, Portugal, and Poland-Lithuania (15 October 1582),
+    /// the date set by the papal bull Inter gravissimas
+    pub const ROME: CivilCalendar = CivilCalendar::new(Gregorian::new(1582, 10, 15));
+
+    /// France (20 December 1582)
+    pub const FRANCE: CivilCalendar = CivilCalendar::new(Gregorian::new(1582, 12, 20));
+
+    /// Duchy of Prussia (2 September 1610)
+    pub const PRUSSIA: CivilCalendar = CivilCalendar::new(Gregorian::new(1610, 9, 2));
+
+    /// Protestant states of the Holy Roman Empire, and Denmark-Norway (1 March
+    /// 1700)
+    pub const DENMARK: CivilCalendar = CivilCalendar::new(Gregorian::new(1700, 3, 1));
+
+    /// Great Britain and its colonies (14 September 1752)
+    pub const BRITAIN: CivilCalendar = CivilCalendar::new(Gregorian::new(1752, 9, 14));
+
+    /// Sweden and Finland (1 March 1753), following the Swedish calendar from
+    /// 1 March 1700 to 30 February 1712
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     civil::{CalendarStyle, CivilCalendar},
+    ///     julian::{absolute_from_julian, Julian},
+    /// };
+    ///
+    /// let sweden = CivilCalendar::SWEDEN;
+    /// let date = |year, month, day| absolute_from_julian(Julian::new(year, month, day));
+    ///
+    /// // 28 February 1700 was followed by 1 March 1700
+    /// assert_eq!(sweden.from_absolute(date(1700, 2, 28)).to_string(), "28 February 1700");
+    /// assert_eq!(sweden.from_absolute(date(1700, 2, 29)).to_string(), "1 March 1700");
+    /// assert!(sweden.date(1700, 2, 29).is_err());
+    ///
+    /// // 30 February 1712 restored the Julian calendar
+    /// let day = sweden.date(1712, 2, 30).unwrap();
+    /// assert_eq!(day.style, CalendarStyle::Swedish);
+    /// assert_eq!(day.to_absolute(), date(1712, 2, 29));
+    /// assert_eq!(sweden.from_absolute(date(1712, 2, 29)), day);
+    /// assert_eq!(sweden.from_absolute(date(1712, 3, 1)).to_string(), "1 March 1712");
+    /// assert_eq!(sweden.from_absolute(date(1712, 3, 1)).style, CalendarStyle::Julian);
+    ///
+    /// // the Gregorian calendar was introduced in 1753
+    /// assert_eq!(sweden.last_julian_day(), Julian::new(1753, 2, 17));
+    /// ```
+    pub const SWEDEN: CivilCalendar = CivilCalendar {
+        first_gregorian_day: Gregorian::new(1753, 3, 1),
+        swedish: true,
+    };
+
+    /// Bulgaria (14 April 1916)
+    pub const BULGARIA: CivilCalendar = CivilCalendar::new(Gregorian::new(1916, 4, 14));
+
+    /// Russia (14 February 1918)
+    pub const RUSSIA: CivilCalendar = CivilCalendar::new(Gregorian::new(1918, 2, 14));
+
+    /// Greece (1 March 1923)
+    pub const GREECE: CivilCalendar = CivilCalendar::new(Gregorian::new(1923, 3, 1));
+
+    /// Create a new civil calendar from the first day of the Gregorian
+    /// calendar.
+    pub const fn new(first_gregorian_day: Gregorian) -> Self {
+        Self {
+            first_gregorian_day,
+            swedish: false,
+        }
+    }
+
+    /// Returns the absolute (fixed) date of the first day of the Gregorian
+    /// calendar.
+    pub fn cutover(&self) -> i64 {
+        return absolute_from_gregorian(self.first_gregorian_day);
+    }
+
+    /// Returns the last day of the Julian calendar.
+    pub fn last_julian_day(&self) -> Julian {
+        return julian_from_absolute(self.cutover() - 1);
+    }
+
+    /// Returns the number of days skipped at the cutover.
+    pub fn skipped_days(&self) -> i64 {
+        let first = self.first_gregorian_day;
+        let last = self.last_julian_day();
+        return absolute_from_gregorian(first)
+            - absolute_from_gregorian(Gregorian::new(last.year, last.month, last.day))
+            - 1;
+    }
+
+    /// Returns the civil date of a given absolute (fixed) date.
+    pub fn from_absolute(&self, absolute_date: i64) -> CivilDate {
+        return if self.swedish && SWEDISH_ABSOLUTE_RANGE.contains(&absolute_date) {
+            let d = if absolute_date == *SWEDISH_ABSOLUTE_RANGE.end() {
+                Julian::new(1712, 2, 30)
+            } else {
+                julian_from_absolute(absolute_date + 1)
+            };
+            CivilDate {
+                year: d.year,
+                month: d.month,
+                day: d.day,
+                style: CalendarStyle::Swedish,
+            }
+        } else if absolute_date < self.cutover() {
+            let d = julian_from_absolute(absolute_date);
+            CivilDate {
+                year: d.year,
+                month: d.month,
+                day: d.day,
+                style: CalendarStyle::Julian,
+            }
+        } else {
+            let d = gregorian_from_absolute(absolute_date);
+            CivilDate {
+                year: d.year,
+                month: d.month,
+                day: d.day,
+                style: CalendarStyle::Gregorian,
+            }
+        };
+    }
+
+    /// Create the civil date with a given year, month, and day, reckoned in
+    /// the Julian calendar before the cutover and in the Gregorian calendar
+    /// from the cutover on, or return an error if the date does not exist
+    /// (including the days skipped at the cutover).
+    pub fn date(&self, year: i64, month: i64, day: i64) -> Result<CivilDate, CalendarError> {
+        let last = self.last_julian_day();
+        let first = self.first_gregorian_day;
+        let skipped = Err(CalendarError::InvalidComponent {
+            calendar: "civil".to_string(),
+            component: "day".to_string(),
+            value: day,
+        });
+        let swedish = self.swedish && (year, month, day) >= (1700, 2, 29);
+        let style = if swedish && (year, month, day) <= (1712, 2, 30) {
+            if (year, month, day) == (1700, 2, 29) {
+                return skipped;
+            } else if (year, month, day) != (1712, 2, 30) {
+                Julian::try_new(year, month, day)?;
+            }
+            CalendarStyle::Swedish
+        } else if (year, month, day) <= (last.year, last.month, last.day) {
+            Julian::try_new(year, month, day)?;
+            CalendarStyle::Julian
+        } else if (year, month, day) >= (first.year, first.month, first.day) {
+            Gregorian::try_new(year, month, day)?;
+            CalendarStyle::Gregorian
+        } else {
+            return skipped;
+        };
+        return Ok(CivilDate {
+            year,
+            month,
+            day,
+            style,
+        });
+    }
+}
+
+/// The default civil calendar switches to the Gregorian calendar on
+/// 15 October 1582 (see [`CivilCalendar::ROME`]).
+impl Default for CivilCalendar {
+    fn default() -> Self {
+        return CivilCalendar::ROME;
+    }
+}
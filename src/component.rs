@@ -0,0 +1,116 @@
+//! Provides strongly-typed year, month, and day components
+//!
+//! [`Year`], [`Month`], and [`Day`] wrap the components of dates of calendars
+//! with years, months, and days (see [`MonthLength`]), so that transposed
+//! arguments (e.g. a month passed as the year) are caught at compile time by
+//! [`MonthLength::from_components`] and
+//! [`MonthLength::try_from_components`]. Their `checked` constructors check
+//! the components against the ranges of a given calendar.
+
+use core::fmt;
+
+use crate::{error::CalendarError, utility::MonthLength};
+
+/// Year component of a date
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Year(pub i64);
+
+/// Month component of a date (the number of the month in its calendar)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Month(pub i64);
+
+/// Day component of a date (the day of the month)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Day(pub i64);
+
+/// Returns an invalid component error for a given calendar.
+pub(crate) fn invalid_component<C: MonthLength>(component: &str, value: i64) -> CalendarError {
+    return CalendarError::InvalidComponent {
+        calendar: C::INFO.name.to_string(),
+        component: component.to_string(),
+        value,
+    };
+}
+
+impl Year {
+    /// Create a year, or return an error if the year lies outside the range
+    /// supported by the calendar `C` (see the `*_YEAR_RANGE` statics of the
+    /// calendar modules).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     component::{Day, Month, Year},
+    ///     gregorian::Gregorian,
+    ///     hebrew::Hebrew,
+    /// };
+    ///
+    /// assert_eq!(Year::checked::<Gregorian>(2024), Ok(Year(2024)));
+    /// assert!(Year::checked::<Gregorian>(i64::MAX).is_err());
+    ///
+    /// assert_eq!(Month::checked::<Hebrew>(Year(5784), 13), Ok(Month(13)));
+    /// assert!(Month::checked::<Hebrew>(Year(5783), 13).is_err());
+    ///
+    /// assert_eq!(Day::checked::<Gregorian>(Year(2024), Month(2), 29), Ok(Day(29)));
+    /// assert!(Day::checked::<Gregorian>(Year(2023), Month(2), 29).is_err());
+    /// assert!(Day::checked::<Gregorian>(Year(2024), Month(13), 1).is_err());
+    /// ```
+    pub fn checked<C: MonthLength>(year: i64) -> Result<Self, CalendarError> {
+        let first = C::from_year_month_day(year, C::first_month_of_year(year), 1);
+        return match first.try_to_absolute() {
+            Ok(_) => Ok(Year(year)),
+            Err(_) => Err(invalid_component::<C>("year", year)),
+        };
+    }
+}
+
+impl Month {
+    /// Create a month, or return an error if the calendar `C` has no such
+    /// month in a given year (e.g. Adar II in a common Hebrew year), or the
+    /// year is out of range (see [`Year::checked`]).
+    pub fn checked<C: MonthLength>(year: Year, month: i64) -> Result<Self, CalendarError> {
+        Year::checked::<C>(year.0)?;
+        if !C::months_of_year(year.0).any(|m| m == month) {
+            return Err(invalid_component::<C>("month", month));
+        }
+        return Ok(Month(month));
+    }
+}
+
+impl Day {
+    /// Create a day, or return an error if a given month of a given year of
+    /// the calendar `C` has no such day (see also [`Month::checked`]).
+    pub fn checked<C: MonthLength>(
+        year: Year,
+        month: Month,
+        day: i64,
+    ) -> Result<Self, CalendarError> {
+        let month = Month::checked::<C>(year, month.0)?;
+        if !(1..=C::last_day_of_month(month.0, year.0)).contains(&day) {
+            return Err(invalid_component::<C>("day", day));
+        }
+        return Ok(Day(day));
+    }
+}
+
+/// Implement From<component> for i64, and fmt::Display trait for components.
+macro_rules! impl_component {
+    ($($t:ident),*) => {
+        $(
+            impl From<$t> for i64 {
+                fn from(component: $t) -> Self {
+                    return component.0;
+                }
+            }
+
+            impl fmt::Display for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.0)
+                }
+            }
+        )*
+    };
+}
+
+impl_component!(Year, Month, Day);
@@ -0,0 +1,138 @@
+//! Provides direct conversions between date types via their absolute (fixed)
+//! dates
+
+use crate::{
+    error::CalendarError,
+    french::French,
+    gregorian::Gregorian,
+    hebrew::Hebrew,
+    hindu::{absolute_from_old_hindu_lunar, OldHinduLunar, OldHinduSolar},
+    islamic::{Islamic, UmmAlQura},
+    iso::Iso,
+    julian::Julian,
+    mayan::{MayanHaab, MayanLongCount, MayanTzolkin},
+    ordinal::Ordinal,
+    utility::{Calendar, FixedConvertible},
+};
+
+/// Converts a date to the date of another calendar with the same absolute
+/// (fixed) date. The source date must correspond to an absolute date (i.e.
+/// it cannot be a cyclical Mayan Haab or Tzolkin date); the target date is
+/// clamped to the range of its calendar (see [`checked_convert`] for a
+/// fallible alternative).
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     conversion::{checked_convert, convert},
+///     gregorian::Gregorian,
+///     hebrew::Hebrew,
+///     islamic::Islamic,
+///     mayan::MayanHaab,
+/// };
+///
+/// let date: Hebrew = convert(Gregorian::new(2024, 5, 1));
+/// assert_eq!(date, Hebrew { year: 5784, month: 1, day: 23 });
+/// assert_eq!(convert::<Hebrew, Gregorian>(date), Gregorian::new(2024, 5, 1));
+/// assert_eq!(convert::<_, MayanHaab>(date).to_string(), "12 Uo");
+/// assert!(checked_convert::<_, Islamic>(Gregorian::new(600, 1, 1)).is_err());
+/// ```
+pub fn convert<A: FixedConvertible, B: Calendar>(date: A) -> B {
+    return date.convert();
+}
+
+/// Converts a date to the date of another calendar with the same absolute
+/// (fixed) date, or returns an error if the absolute date lies outside the
+/// range of the target calendar.
+pub fn checked_convert<A: FixedConvertible, B: Calendar>(date: A) -> Result<B, CalendarError> {
+    return date.checked_convert();
+}
+
+/// Implement From<source> for target types that can represent any absolute
+/// date.
+macro_rules! impl_from_calendar {
+    ($s:ty => $($t:ty),*) => {
+        $(
+            impl From<$s> for $t {
+                fn from(d: $s) -> Self {
+                    return <$t>::from_absolute(d.to_absolute());
+                }
+            }
+        )*
+    };
+}
+
+/// Implement TryFrom<source> for target types whose calendars only cover a
+/// limited range of absolute dates.
+macro_rules! impl_try_from_calendar {
+    ($s:ty => $($t:ty),*) => {
+        $(
+            impl TryFrom<$s> for $t {
+                type Error = CalendarError;
+
+                fn try_from(d: $s) -> Result<Self, Self::Error> {
+                    return <$t>::checked_from_absolute(d.to_absolute());
+                }
+            }
+        )*
+    };
+}
+
+/// Implement TryFrom<OldHinduLunar> for target types, as not every Old Hindu
+/// lunar date corresponds to an absolute date.
+macro_rules! impl_try_from_old_hindu_lunar {
+    ($($t:ty),*) => {
+        $(
+            impl TryFrom<OldHinduLunar> for $t {
+                type Error = CalendarError;
+
+                fn try_from(d: OldHinduLunar) -> Result<Self, Self::Error> {
+                    return match absolute_from_old_hindu_lunar(d) {
+                        Some(absolute_date) => <$t>::checked_from_absolute(absolute_date),
+                        None => Err(CalendarError::NotConvertible {
+                            calendar: "oldHinduLunar".to_string(),
+                        }),
+                    };
+                }
+            }
+        )*
+    };
+}
+
+impl_from_calendar!(Gregorian => Iso, Ordinal, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(Iso => Gregorian, Ordinal, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(Ordinal => Gregorian, Iso, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(Julian => Gregorian, Iso, Ordinal, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(Islamic => Gregorian, Iso, Ordinal, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(Hebrew => Gregorian, Iso, Ordinal, Julian, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(MayanLongCount => Gregorian, Iso, Ordinal, Julian, Hebrew, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(French => Gregorian, Iso, Ordinal, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+impl_from_calendar!(OldHinduSolar => Gregorian, Iso, Ordinal, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduLunar);
+impl_from_calendar!(UmmAlQura => Gregorian, Iso, Ordinal, Julian, Hebrew, MayanLongCount, MayanHaab, MayanTzolkin, OldHinduSolar, OldHinduLunar);
+
+impl_try_from_calendar!(Gregorian => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(Iso => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(Ordinal => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(Julian => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(Islamic => French, UmmAlQura);
+impl_try_from_calendar!(Hebrew => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(MayanLongCount => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(French => Islamic, UmmAlQura);
+impl_try_from_calendar!(OldHinduSolar => Islamic, French, UmmAlQura);
+impl_try_from_calendar!(UmmAlQura => Islamic, French);
+
+impl_try_from_old_hindu_lunar!(
+    Gregorian,
+    Iso,
+    Ordinal,
+    Julian,
+    Islamic,
+    Hebrew,
+    MayanLongCount,
+    MayanHaab,
+    MayanTzolkin,
+    French,
+    OldHinduSolar,
+    UmmAlQura
+);
@@ -0,0 +1,232 @@
+//! Provides conversions of CSV columns of dates between calendars (requires
+//! the `csv` feature)
+//!
+//! The CSV data is converted row by row, so that files of any size can be
+//! converted without reading them into memory.
+//!
+//! # Example
+//!
+//! ```
+//! use calendars::csv::{convert_csv, Column, CsvOptions};
+//!
+//! let input = "name,born\nAda,10 December 1815\nAlan,23 June 1912\n";
+//! let options = CsvOptions {
+//!     column: Column::Name("born".to_string()),
+//!     to: vec!["julian".to_string(), "hebrew".to_string()],
+//!     ..CsvOptions::default()
+//! };
+//! let mut output = Vec::new();
+//! let summary = convert_csv(input.as_bytes(), &mut output, &options).unwrap();
+//! assert_eq!(summary.rows, 2);
+//! assert_eq!(
+//!     String::from_utf8(output).unwrap(),
+//!     "name,born,julian,hebrew
+//! Ada,10 December 1815,28 November 1815,8 Kislev 5576
+//! Alan,23 June 1912,10 June 1912,8 Tammuz 5672
+//! "
+//! );
+//! ```
+
+use std::{fmt, io};
+
+use crate::{
+    error::CalendarError,
+    locale::Locale,
+    parse::parse_date,
+    utility::{try_date_from_absolute, Date},
+};
+
+/// Column of a CSV file
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Column {
+    /// Column at a given (zero-based) position
+    Index(usize),
+    /// Column with a given name in the header row
+    Name(String),
+}
+
+/// Options of the conversion of a CSV column of dates
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Column of the input dates
+    pub column: Column,
+    /// Calendar of the input dates (see [`parse_date`])
+    pub from: String,
+    /// Calendars of the appended columns, one column per calendar
+    pub to: Vec<String>,
+    /// strftime-style pattern of the output dates (see
+    /// [`Date::format_with_locale`]), or `None` to format them like
+    /// [`Date::format_localized`]
+    pub pattern: Option<String>,
+    /// Language of month and weekday names
+    pub locale: Locale,
+    /// Whether the first row is a header row. Appended columns are named
+    /// after their calendars.
+    pub has_headers: bool,
+    /// Field delimiter
+    pub delimiter: u8,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            column: Column::Index(0),
+            from: "gregorian".to_string(),
+            to: Vec::new(),
+            pattern: None,
+            locale: Locale::default(),
+            has_headers: true,
+            delimiter: b',',
+        }
+    }
+}
+
+/// Summary of a conversion of a CSV column of dates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CsvSummary {
+    /// Number of converted rows (excluding the header row)
+    pub rows: usize,
+    /// Number of rows whose dates could not be converted
+    pub invalid_rows: usize,
+}
+
+/// Error returned by [`convert_csv`]
+#[derive(Debug)]
+pub enum CsvError {
+    /// The CSV data could not be read or written.
+    Csv(::csv::Error),
+    /// A calendar of the options is not supported, or a date could not be
+    /// formatted according to the pattern of the options.
+    Calendar(CalendarError),
+    /// The column of the input dates does not exist.
+    MissingColumn(Column),
+}
+
+/// Implement fmt::Display trait for [`CsvError`]
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(e) => write!(f, "{}", e),
+            CsvError::Calendar(e) => write!(f, "{}", e),
+            CsvError::MissingColumn(Column::Index(index)) => {
+                write!(f, "missing column {}", index + 1)
+            }
+            CsvError::MissingColumn(Column::Name(name)) => {
+                write!(f, "missing column \"{}\"", name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// Implement From<csv::Error> trait for [`CsvError`]
+impl From<::csv::Error> for CsvError {
+    fn from(e: ::csv::Error) -> Self {
+        return CsvError::Csv(e);
+    }
+}
+
+/// Implement From<CalendarError> trait for [`CsvError`]
+impl From<CalendarError> for CsvError {
+    fn from(e: CalendarError) -> Self {
+        return CsvError::Calendar(e);
+    }
+}
+
+/// Returns an error if a given calendar is not supported.
+fn check_calendar(calendar: &str) -> Result<(), CalendarError> {
+    return match try_date_from_absolute(0, calendar) {
+        Err(e @ CalendarError::UnknownCalendar(_)) => Err(e),
+        _ => Ok(()),
+    };
+}
+
+/// Formats a date according to the options.
+fn format_date(date: &Date, options: &CsvOptions) -> Result<String, CalendarError> {
+    return match &options.pattern {
+        Some(pattern) => date.format_with_locale(pattern, options.locale),
+        None => Ok(date.format_localized(options.locale)),
+    };
+}
+
+/// Converts a date string according to the options, returning one field per
+/// target calendar, or `None` if the date is invalid or cannot be converted.
+fn convert_field(input: &str, options: &CsvOptions) -> Result<Option<Vec<String>>, CalendarError> {
+    let absolute_date =
+        match parse_date(input.trim(), &options.from).and_then(|date| date.try_to_absolute()) {
+            Ok(absolute_date) => absolute_date,
+            Err(_) => return Ok(None),
+        };
+    let mut fields = Vec::with_capacity(options.to.len());
+    for calendar in &options.to {
+        match try_date_from_absolute(absolute_date, calendar) {
+            Ok(date) => fields.push(format_date(&date, options)?),
+            Err(_) => return Ok(None),
+        }
+    }
+    return Ok(Some(fields));
+}
+
+/// Reads CSV data from `input` and writes it to `output`, appending one
+/// column per target calendar with the dates of the input column converted
+/// to that calendar.
+///
+/// The rows are converted one at a time. Fields of rows whose dates are
+/// invalid (or lie outside the range of a target calendar) are left empty
+/// and counted in the returned [`CsvSummary`].
+pub fn convert_csv<R: io::Read, W: io::Write>(
+    input: R,
+    output: W,
+    options: &CsvOptions,
+) -> Result<CsvSummary, CsvError> {
+    check_calendar(&options.from)?;
+    for calendar in &options.to {
+        check_calendar(calendar)?;
+    }
+    let mut reader = ::csv::ReaderBuilder::new()
+        .has_headers(options.has_headers)
+        .delimiter(options.delimiter)
+        .from_reader(input);
+    let mut writer = ::csv::WriterBuilder::new()
+        .delimiter(options.delimiter)
+        .from_writer(output);
+    let column = match &options.column {
+        Column::Index(index) => *index,
+        Column::Name(name) if options.has_headers => reader
+            .headers()?
+            .iter()
+            .position(|header| header == name)
+            .ok_or_else(|| CsvError::MissingColumn(options.column.clone()))?,
+        Column::Name(_) => return Err(CsvError::MissingColumn(options.column.clone())),
+    };
+    if options.has_headers {
+        let mut headers = reader.headers()?.clone();
+        if column >= headers.len() {
+            return Err(CsvError::MissingColumn(options.column.clone()));
+        }
+        headers.extend(&options.to);
+        writer.write_record(&headers)?;
+    }
+    let mut summary = CsvSummary {
+        rows: 0,
+        invalid_rows: 0,
+    };
+    let mut record = ::csv::StringRecord::new();
+    while reader.read_record(&mut record)? {
+        let input = record
+            .get(column)
+            .ok_or_else(|| CsvError::MissingColumn(options.column.clone()))?;
+        match convert_field(input, options)? {
+            Some(fields) => record.extend(fields),
+            None => {
+                record.extend(options.to.iter().map(|_| ""));
+                summary.invalid_rows += 1;
+            }
+        }
+        writer.write_record(&record)?;
+        summary.rows += 1;
+    }
+    writer.flush().map_err(::csv::Error::from)?;
+    return Ok(summary);
+}
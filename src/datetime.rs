@@ -0,0 +1,470 @@
+//! Provides civil date and time values: dates of any calendar with a time of
+//! day, at a fixed offset from universal time
+
+use core::fmt;
+
+use crate::{
+    error::CalendarError,
+    gregorian::{Gregorian, GREGORIAN_MONTH_NAMES},
+    interop::{absolute_from_unix, checked_unix_from_absolute, SECONDS_PER_DAY, UNIX_EPOCH},
+    moment::{Moment, TimeOfDay},
+    utility::{Calendar, FixedConvertible},
+    weekday::{weekday_from_absolute, WEEKDAY_NAMES},
+};
+
+/// Civil date and time: a date of a calendar with a time of day, in a time
+/// zone with a fixed offset from universal time (in minutes east of UTC; 0
+/// for universal time)
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     datetime::CivilDateTime,
+///     gregorian::Gregorian,
+///     hebrew::Hebrew,
+///     moment::TimeOfDay,
+/// };
+///
+/// // 18:00 on October 2, 2024 in Jerusalem (UTC+3)
+/// let meeting = CivilDateTime::new(Gregorian::new(2024, 10, 2), TimeOfDay::new(18, 0, 0))
+///     .with_offset(180);
+/// assert_eq!(meeting.to_string(), "2 October 2024 18:00:00 UTC+03:00");
+/// assert_eq!(meeting.to_unix(), 1727881200);
+///
+/// // the same instant in universal time, and in the Hebrew calendar
+/// let utc = meeting.to_offset(0);
+/// assert_eq!(utc.time, TimeOfDay::new(15, 0, 0));
+/// assert_eq!(utc.to_moment(), meeting.to_moment());
+/// let hebrew: CivilDateTime<Hebrew> = meeting.to_calendar();
+/// assert_eq!(hebrew.date, Hebrew { year: 5784, month: 6, day: 29 });
+///
+/// // two hours and a half later
+/// let later = meeting.add_seconds(9000);
+/// assert_eq!(later.date, Gregorian::new(2024, 10, 2));
+/// assert_eq!(later.time, TimeOfDay::new(20, 30, 0));
+/// assert_eq!(later.seconds_since(&meeting), 9000);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CivilDateTime<D> {
+    pub date: D,
+    pub time: TimeOfDay,
+    pub offset: i64,
+}
+
+impl<D: FixedConvertible> CivilDateTime<D> {
+    /// Create a new date and time in universal time
+    pub fn new(date: D, time: TimeOfDay) -> Self {
+        Self {
+            date,
+            time,
+            offset: 0,
+        }
+    }
+
+    /// Create a new date and time in universal time, or return an error if
+    /// the time of day does not exist.
+    pub fn try_new(date: D, time: TimeOfDay) -> Result<Self, CalendarError> {
+        TimeOfDay::try_new(time.hour, time.minute, time.second)?;
+        return Ok(Self::new(date, time));
+    }
+
+    /// Returns the same date and time of day in the time zone with a given
+    /// offset from universal time (in minutes east of UTC).
+    pub fn with_offset(self, offset: i64) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Returns the date and time at a given number of seconds (possibly
+    /// negative, or exceeding a day) after midnight of a given absolute date
+    /// in local time.
+    fn from_local(absolute_date: i64, seconds: i64, offset: i64) -> Self {
+        Self {
+            date: D::from_absolute(
+                absolute_date.saturating_add(seconds.div_euclid(SECONDS_PER_DAY)),
+            ),
+            time: TimeOfDay::from_seconds(seconds),
+            offset,
+        }
+    }
+
+    /// Returns the date and time of a given Unix timestamp in the time zone
+    /// with a given offset from universal time (in minutes east of UTC).
+    /// Dates are clamped to the range of the calendar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{datetime::CivilDateTime, gregorian::Gregorian, moment::TimeOfDay};
+    ///
+    /// let latest = CivilDateTime::<Gregorian>::from_unix(i64::MAX, 0);
+    /// assert_eq!(latest.date, Gregorian::new(292277026596, 12, 4));
+    /// assert_eq!(latest.time, TimeOfDay::new(15, 30, 7));
+    /// assert_eq!(latest.to_unix(), i64::MAX);
+    /// assert!(latest.add_seconds(1).checked_to_unix().is_err());
+    /// assert_eq!(latest.add_seconds(1).to_unix(), i64::MAX);
+    /// ```
+    pub fn from_unix(seconds: i64, offset: i64) -> Self {
+        let (offset_days, offset_seconds) = split_offset(offset);
+        return Self::from_local(
+            absolute_from_unix(seconds).saturating_add(offset_days),
+            seconds.rem_euclid(SECONDS_PER_DAY) + offset_seconds,
+            offset,
+        );
+    }
+
+    /// Returns the Unix timestamp of the date and time, saturating at the
+    /// bounds of `i64` (see [`CivilDateTime::checked_to_unix`]).
+    pub fn to_unix(&self) -> i64 {
+        return self
+            .checked_to_unix()
+            .unwrap_or(if self.date.to_absolute() < UNIX_EPOCH {
+                i64::MIN
+            } else {
+                i64::MAX
+            });
+    }
+
+    /// Returns the Unix timestamp of the date and time, or an error if the
+    /// timestamp does not fit into an `i64`.
+    pub fn checked_to_unix(&self) -> Result<i64, CalendarError> {
+        let (offset_days, offset_seconds) = split_offset(self.offset);
+        let absolute_date = self.date.to_absolute();
+        let out_of_range = || CalendarError::OutOfRange {
+            calendar: "unix".to_string(),
+            absolute_date,
+        };
+        return absolute_date
+            .checked_sub(offset_days)
+            .ok_or_else(out_of_range)
+            .and_then(|date| checked_unix_from_absolute(date).map_err(|_| out_of_range()))?
+            .checked_add(self.time.seconds() - offset_seconds)
+            .ok_or_else(out_of_range);
+    }
+
+    /// Returns the date and time of a given moment (in universal time),
+    /// rounded to the nearest second, in the time zone with a given offset
+    /// from universal time (in minutes east of UTC).
+    pub fn from_moment(moment: Moment, offset: i64) -> Self {
+        let seconds = (moment.0 * SECONDS_PER_DAY as f64).round() as i64;
+        let (offset_days, offset_seconds) = split_offset(offset);
+        return Self::from_local(
+            seconds
+                .div_euclid(SECONDS_PER_DAY)
+                .saturating_add(offset_days),
+            seconds.rem_euclid(SECONDS_PER_DAY) + offset_seconds,
+            offset,
+        );
+    }
+
+    /// Returns the moment (in universal time) of the date and time.
+    pub fn to_moment(&self) -> Moment {
+        return Moment::new(self.date.to_absolute(), self.time) - self.offset as f64 / 1440.0;
+    }
+
+    /// Returns the date and time of the same instant in the time zone with a
+    /// given offset from universal time (in minutes east of UTC).
+    pub fn to_offset(&self, offset: i64) -> Self {
+        let (old_days, old_seconds) = split_offset(self.offset);
+        let (new_days, new_seconds) = split_offset(offset);
+        return Self::from_local(
+            self.date
+                .to_absolute()
+                .saturating_sub(old_days)
+                .saturating_add(new_days),
+            self.time.seconds() - old_seconds + new_seconds,
+            offset,
+        );
+    }
+
+    /// Returns the date and time of the same instant with the date given in
+    /// another calendar.
+    pub fn to_calendar<E: FixedConvertible>(&self) -> CivilDateTime<E> {
+        return CivilDateTime {
+            date: E::from_absolute(self.date.to_absolute()),
+            time: self.time,
+            offset: self.offset,
+        };
+    }
+
+    /// Returns the date and time a given number of seconds after (or, if
+    /// negative, before) the date and time.
+    pub fn add_seconds(&self, seconds: i64) -> Self {
+        return Self::from_local(
+            self.date
+                .to_absolute()
+                .saturating_add(seconds.div_euclid(SECONDS_PER_DAY)),
+            self.time.seconds() + seconds.rem_euclid(SECONDS_PER_DAY),
+            self.offset,
+        );
+    }
+
+    /// Returns the number of seconds from another date and time to the date
+    /// and time, saturating at the bounds of `i64`.
+    pub fn seconds_since<E: FixedConvertible>(&self, other: &CivilDateTime<E>) -> i64 {
+        return self.to_unix().saturating_sub(other.to_unix());
+    }
+}
+
+/// Splits an offset from universal time (in minutes) into whole days and the
+/// remaining seconds.
+fn split_offset(offset: i64) -> (i64, i64) {
+    return (offset.div_euclid(1440), 60 * offset.rem_euclid(1440));
+}
+
+/// Implement fmt::Display trait for [`CivilDateTime`] (e.g. "2 October 2024
+/// 18:00:00 UTC+03:00")
+impl<D: Calendar> fmt::Display for CivilDateTime<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} UTC", self.date.format(), self.time)?;
+        if self.offset != 0 {
+            let sign = if self.offset < 0 { '-' } else { '+' };
+            let minutes = self.offset.abs();
+            write!(f, "{}{:02}:{:02}", sign, minutes / 60, minutes % 60)?;
+        }
+        return Ok(());
+    }
+}
+
+impl CivilDateTime<Gregorian> {
+    /// Returns the RFC 2822 representation of the date and time (e.g. "Wed,
+    /// 02 Oct 2024 18:00:00 +0300"), as used in email headers, or an error if
+    /// the date does not exist, its year lies outside 0 to 9999, or the
+    /// offset is not less than 100 hours.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{datetime::CivilDateTime, gregorian::Gregorian, moment::TimeOfDay};
+    ///
+    /// let meeting = CivilDateTime::new(Gregorian::new(2024, 10, 2), TimeOfDay::new(18, 0, 0))
+    ///     .with_offset(180);
+    /// assert_eq!(meeting.to_rfc2822().unwrap(), "Wed, 02 Oct 2024 18:00:00 +0300");
+    /// assert_eq!(meeting.to_http_date().unwrap(), "Wed, 02 Oct 2024 15:00:00 GMT");
+    /// let ides = CivilDateTime::new(Gregorian::new(-43, 3, 15), TimeOfDay::new(12, 0, 0));
+    /// assert!(ides.to_rfc2822().is_err());
+    ///
+    /// assert_eq!(CivilDateTime::parse_rfc2822(&meeting.to_rfc2822().unwrap()), Ok(meeting));
+    /// assert_eq!(
+    ///     CivilDateTime::parse_rfc2822("2 Oct 2024 10:00 EST"),
+    ///     Ok(CivilDateTime::new(Gregorian::new(2024, 10, 2), TimeOfDay::new(10, 0, 0))
+    ///         .with_offset(-300))
+    /// );
+    /// // the weekday must match the date
+    /// assert!(CivilDateTime::parse_rfc2822("Thu, 02 Oct 2024 18:00:00 +0300").is_err());
+    /// // comments are ignored, and a leap second is read as second 59
+    /// assert_eq!(
+    ///     CivilDateTime::parse_rfc2822("Wed, 02 Oct 2024 18:00:00 +0300 (EEST)"),
+    ///     Ok(meeting)
+    /// );
+    /// assert_eq!(
+    ///     CivilDateTime::parse_rfc2822("Sat, 31 Dec 2016 23:59:60 +0000"),
+    ///     Ok(CivilDateTime::new(Gregorian::new(2016, 12, 31), TimeOfDay::new(23, 59, 59)))
+    /// );
+    ///
+    /// // HTTP dates in the preferred and in the two obsolete formats
+    /// let utc = meeting.to_offset(0);
+    /// for s in [
+    ///     "Wed, 02 Oct 2024 15:00:00 GMT",
+    ///     "Wednesday, 02-Oct-24 15:00:00 GMT",
+    ///     "Wed Oct  2 15:00:00 2024",
+    /// ] {
+    ///     assert_eq!(CivilDateTime::parse_http_date(s), Ok(utc));
+    /// }
+    /// ```
+    pub fn to_rfc2822(&self) -> Result<String, CalendarError> {
+        if !(-5999..=5999).contains(&self.offset) {
+            return Err(CalendarError::FormatError(format!(
+                "offset of {} minutes cannot be written in an internet date",
+                self.offset
+            )));
+        }
+        let sign = if self.offset < 0 { '-' } else { '+' };
+        let minutes = self.offset.abs();
+        return Ok(format!(
+            "{} {}{:02}{:02}",
+            self.format_internet_date()?,
+            sign,
+            minutes / 60,
+            minutes % 60
+        ));
+    }
+
+    /// Returns the HTTP-date representation of the date and time, converted
+    /// to universal time (e.g. "Wed, 02 Oct 2024 15:00:00 GMT"), or an error
+    /// if the date does not exist or its year lies outside 0 to 9999.
+    pub fn to_http_date(&self) -> Result<String, CalendarError> {
+        return Ok(self.to_offset(0).format_internet_date()? + " GMT");
+    }
+
+    /// Formats the date and time without time zone (e.g. "Wed, 02 Oct 2024
+    /// 18:00:00"), or returns an error if the date does not exist or its year
+    /// cannot be written with four digits.
+    fn format_internet_date(&self) -> Result<String, CalendarError> {
+        if !self.date.is_valid() || !(0..=9999).contains(&self.date.year) {
+            return Err(CalendarError::FormatError(format!(
+                "{:?} cannot be written as an internet date",
+                self.date
+            )));
+        }
+        let weekday = weekday_from_absolute(self.date.to_absolute()).name();
+        let month = GREGORIAN_MONTH_NAMES[(self.date.month - 1) as usize];
+        return Ok(format!(
+            "{}, {:02} {} {:04} {}",
+            &weekday[..3],
+            self.date.day,
+            &month[..3],
+            self.date.year,
+            self.time
+        ));
+    }
+
+    /// Parses an RFC 2822 date and time (e.g. "Wed, 02 Oct 2024 18:00:00
+    /// +0300"), or returns an error if the string is malformed, the date or
+    /// time does not exist, or the weekday does not match the date. The
+    /// weekday and the seconds are optional, comments in parentheses (e.g.
+    /// "(EEST)") are ignored, and the obsolete two-digit years and time zone
+    /// names (e.g. "GMT" or "EST") are accepted. A leap second (second 60) is
+    /// read as second 59, as [`TimeOfDay`] has no leap seconds.
+    pub fn parse_rfc2822(s: &str) -> Result<Self, CalendarError> {
+        return parse_internet_date(s)
+            .ok_or_else(|| CalendarError::ParseError(format!("invalid RFC 2822 date: \"{}\"", s)));
+    }
+
+    /// Parses an HTTP date in the preferred format (e.g. "Wed, 02 Oct 2024
+    /// 15:00:00 GMT") or in one of the obsolete RFC 850 (e.g. "Wednesday,
+    /// 02-Oct-24 15:00:00 GMT") and asctime (e.g. "Wed Oct  2 15:00:00 2024")
+    /// formats, and returns it in universal time.
+    pub fn parse_http_date(s: &str) -> Result<Self, CalendarError> {
+        return parse_internet_date(s)
+            .map(|datetime| datetime.to_offset(0))
+            .ok_or_else(|| CalendarError::ParseError(format!("invalid HTTP date: \"{}\"", s)));
+    }
+}
+
+/// Returns the position of a name, given in full or by its first three
+/// letters (case-insensitively), in a list of names.
+fn position_of_abbreviation(s: &str, names: &[&str]) -> Option<usize> {
+    return names.iter().position(|name| {
+        name.eq_ignore_ascii_case(s) || (s.len() == 3 && name[..3].eq_ignore_ascii_case(s))
+    });
+}
+
+/// Parses an unsigned number consisting of ASCII digits only.
+fn parse_digits(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    return s.parse().ok();
+}
+
+/// Returns a given string with its (possibly nested) comments in parentheses
+/// replaced by spaces, or `None` if the parentheses are unbalanced.
+fn strip_comments(s: &str) -> Option<String> {
+    let mut result = String::new();
+    let mut depth = 0;
+    let mut escaped = false;
+    for c in s.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if depth > 0 => escaped = true,
+            '(' => depth += 1,
+            ')' if depth == 0 => return None,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    result.push(' ');
+                }
+            }
+            _ if depth == 0 => result.push(c),
+            _ => {}
+        }
+    }
+    return if depth == 0 { Some(result) } else { None };
+}
+
+/// Returns the offset from universal time (in minutes east of UTC) of an
+/// RFC 2822 time zone (e.g. "+0300", "GMT", or "EST").
+fn zone_offset(zone: &str) -> Option<i64> {
+    let hours = match zone.to_ascii_uppercase().as_str() {
+        "GMT" | "UT" | "UTC" | "Z" => 0,
+        "EDT" => -4,
+        "EST" | "CDT" => -5,
+        "CST" | "MDT" => -6,
+        "MST" | "PDT" => -7,
+        "PST" => -8,
+        _ => {
+            let sign = match zone.chars().next()? {
+                '+' => 1,
+                '-' => -1,
+                _ => return None,
+            };
+            let digits = &zone[1..];
+            if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            let (hours, minutes) = (parse_digits(&digits[..2])?, parse_digits(&digits[2..])?);
+            return if minutes < 60 {
+                Some(sign * (60 * hours + minutes))
+            } else {
+                None
+            };
+        }
+    };
+    return Some(60 * hours);
+}
+
+/// Parses an RFC 2822 date and time, or an HTTP date in one of the obsolete
+/// RFC 850 and asctime formats.
+fn parse_internet_date(s: &str) -> Option<CivilDateTime<Gregorian>> {
+    let s = strip_comments(s)?;
+    let (mut weekday, rest) = match s.split_once(',') {
+        Some((weekday, rest)) => (Some(weekday.trim()), rest),
+        None => (None, s.as_str()),
+    };
+    let mut tokens: Vec<&str> = rest.split_whitespace().collect();
+    if weekday.is_none() && tokens.len() == 5 && parse_digits(tokens[0]).is_none() {
+        // asctime: weekday, month, day, time, and year, in universal time
+        weekday = Some(tokens[0]);
+        tokens = vec![tokens[2], tokens[1], tokens[4], tokens[3], "GMT"];
+    }
+    if tokens.first().is_some_and(|token| token.contains('-')) {
+        // RFC 850: day-month-year
+        let date: Vec<&str> = tokens[0].split('-').collect();
+        tokens.splice(0..1, date);
+    }
+    if tokens.len() != 5 {
+        return None;
+    }
+    let day = parse_digits(tokens[0])?;
+    let month = position_of_abbreviation(tokens[1], &GREGORIAN_MONTH_NAMES)? as i64 + 1;
+    let year = match (tokens[2].len(), parse_digits(tokens[2])?) {
+        // obsolete two- and three-digit years
+        (2, year) if year < 50 => year + 2000,
+        (2 | 3, year) => year + 1900,
+        (_, year) => year,
+    };
+    let time = tokens[3]
+        .split(':')
+        .map(parse_digits)
+        .collect::<Option<Vec<i64>>>()?;
+    if !(2..=3).contains(&time.len()) {
+        return None;
+    }
+    let offset = zone_offset(tokens[4])?;
+    let date = Gregorian::try_new(year, month, day).ok()?;
+    // a leap second is represented by the last second of its minute
+    let second = match time.get(2) {
+        Some(60) => 59,
+        second => *second.unwrap_or(&0),
+    };
+    let time = TimeOfDay::try_new(time[0], time[1], second).ok()?;
+    if let Some(weekday) = weekday {
+        let number = position_of_abbreviation(weekday, &WEEKDAY_NAMES)? as i64;
+        if number != weekday_from_absolute(date.to_absolute()).number() {
+            return None;
+        }
+    }
+    return Some(CivilDateTime { date, time, offset });
+}
@@ -0,0 +1,105 @@
+//! Provides an object-safe counterpart of the [`Calendar`] trait, for boxed
+//! dates of any calendar (see [`Date::to_calendar_date`])
+//!
+//! [`DynCalendar`] is implemented for every date type implementing
+//! [`Calendar`]. Import it only where dates are used as trait objects: for
+//! concrete date types, its methods would be ambiguous with those of
+//! [`Calendar`].
+
+use crate::{
+    error::CalendarError,
+    locale::Locale,
+    system::CalendarInfo,
+    utility::{Calendar, Date},
+};
+
+/// Object-safe trait for dates of any calendar
+///
+/// # Example
+///
+/// ```
+/// use calendars::{dynamic::DynCalendar, gregorian::Gregorian, hebrew::Hebrew};
+///
+/// let dates: Vec<Box<dyn DynCalendar>> = vec![
+///     Box::new(Gregorian::new(2024, 5, 1)),
+///     Box::new(Hebrew { year: 5784, month: 1, day: 23 }),
+/// ];
+/// for date in &dates {
+///     assert_eq!(date.try_to_absolute(), Ok(739007));
+/// }
+/// assert_eq!(dates[1].info().name, "hebrew");
+/// assert_eq!(dates[1].format(), "23 Nisan 5784");
+/// ```
+pub trait DynCalendar {
+    /// Returns the metadata of the date's calendar.
+    fn info(&self) -> CalendarInfo;
+
+    /// See [`Calendar::to_date`].
+    fn to_date(&self) -> Date;
+
+    /// See [`Calendar::format`].
+    fn format(&self) -> String;
+
+    /// See [`Calendar::format_with`].
+    fn format_with(&self, pattern: &str) -> Result<String, CalendarError>;
+
+    /// See [`Calendar::format_with_locale`].
+    fn format_with_locale(&self, pattern: &str, locale: Locale) -> Result<String, CalendarError>;
+
+    /// See [`Calendar::format_localized`].
+    fn format_localized(&self, locale: Locale) -> String;
+
+    /// See [`Calendar::try_to_absolute`].
+    fn try_to_absolute(&self) -> Result<i64, CalendarError>;
+
+    #[doc(hidden)]
+    fn absolute_unchecked(&self) -> Option<i64>;
+
+    /// See [`Calendar::month_name`].
+    fn month_name(&self) -> Option<&'static str>;
+
+    /// See [`Calendar::weekday_name`].
+    fn weekday_name(&self) -> Option<&'static str>;
+}
+
+impl<T: Calendar> DynCalendar for T {
+    fn info(&self) -> CalendarInfo {
+        return T::INFO;
+    }
+
+    fn to_date(&self) -> Date {
+        return Calendar::to_date(self);
+    }
+
+    fn format(&self) -> String {
+        return Calendar::format(self);
+    }
+
+    fn format_with(&self, pattern: &str) -> Result<String, CalendarError> {
+        return Calendar::format_with(self, pattern);
+    }
+
+    fn format_with_locale(&self, pattern: &str, locale: Locale) -> Result<String, CalendarError> {
+        return Calendar::format_with_locale(self, pattern, locale);
+    }
+
+    fn format_localized(&self, locale: Locale) -> String {
+        return Calendar::format_localized(self, locale);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return Calendar::try_to_absolute(self);
+    }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Calendar::absolute_unchecked(self);
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return Calendar::month_name(self);
+    }
+
+    fn weekday_name(&self) -> Option<&'static str> {
+        return Calendar::weekday_name(self);
+    }
+}
@@ -0,0 +1,134 @@
+//! Provides the error type returned by fallible functions of this crate
+
+use core::{fmt, ops::RangeInclusive};
+
+/// Error returned by fallible calendar functions
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CalendarError {
+    /// A [`Date`](crate::utility::Date) of a given calendar was found where a
+    /// date of another calendar was expected.
+    WrongCalendar { expected: String, found: String },
+    /// A [`Date`](crate::utility::Date) has the wrong number of components
+    /// for its calendar.
+    ComponentCount {
+        calendar: String,
+        expected: usize,
+        found: usize,
+    },
+    /// A date component lies outside the range permitted by its calendar
+    /// (e.g. month 13 of a Gregorian date).
+    InvalidComponent {
+        calendar: String,
+        component: String,
+        value: i64,
+    },
+    /// A component name does not belong to a calendar (see
+    /// [`Date::builder`](crate::utility::Date::builder)).
+    UnknownComponent { calendar: String, component: String },
+    /// A component of a calendar was not given (see
+    /// [`Date::builder`](crate::utility::Date::builder)).
+    MissingComponent { calendar: String, component: String },
+    /// A calendar name is not supported.
+    UnknownCalendar(String),
+    /// An absolute (fixed) date lies outside the range supported by a
+    /// calendar (e.g. before its epoch).
+    OutOfRange {
+        calendar: String,
+        absolute_date: i64,
+    },
+    /// A date does not correspond to an absolute (fixed) date.
+    NotConvertible { calendar: String },
+    /// A string could not be parsed into a date.
+    ParseError(String),
+    /// A date could not be formatted according to a pattern.
+    FormatError(String),
+}
+
+/// Implement fmt::Display trait for [`CalendarError`]
+impl fmt::Display for CalendarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarError::WrongCalendar { expected, found } => {
+                write!(f, "expected a {} date, found a {} date", expected, found)
+            }
+            CalendarError::ComponentCount {
+                calendar,
+                expected,
+                found,
+            } => write!(
+                f,
+                "a {} date has {} components, found {}",
+                calendar, expected, found
+            ),
+            CalendarError::InvalidComponent {
+                calendar,
+                component,
+                value,
+            } => write!(f, "invalid {} {} of a {} date", component, value, calendar),
+            CalendarError::UnknownComponent {
+                calendar,
+                component,
+            } => write!(f, "a {} date has no {} component", calendar, component),
+            CalendarError::MissingComponent {
+                calendar,
+                component,
+            } => write!(f, "missing {} component of a {} date", component, calendar),
+            CalendarError::UnknownCalendar(calendar) => {
+                write!(f, "unknown calendar \"{}\"", calendar)
+            }
+            CalendarError::OutOfRange {
+                calendar,
+                absolute_date,
+            } => write!(
+                f,
+                "absolute date {} is outside the range of the {} calendar",
+                absolute_date, calendar
+            ),
+            CalendarError::NotConvertible { calendar } => {
+                write!(f, "{} date has no absolute date", calendar)
+            }
+            CalendarError::ParseError(message) => write!(f, "parse error: {}", message),
+            CalendarError::FormatError(message) => write!(f, "format error: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for CalendarError {}
+
+/// Checks that a given date component lies within `min..=max`, and returns
+/// a [`CalendarError::InvalidComponent`] otherwise.
+pub fn check_component(
+    calendar: &str,
+    component: &str,
+    value: i64,
+    min: i64,
+    max: i64,
+) -> Result<(), CalendarError> {
+    return if min <= value && value <= max {
+        Ok(())
+    } else {
+        Err(CalendarError::InvalidComponent {
+            calendar: calendar.to_string(),
+            component: component.to_string(),
+            value,
+        })
+    };
+}
+
+/// Checks that a given absolute (fixed) date lies within the range of
+/// absolute dates supported by a calendar, and returns a
+/// [`CalendarError::OutOfRange`] otherwise.
+pub fn check_range(
+    calendar: &str,
+    absolute_date: i64,
+    range: &RangeInclusive<i64>,
+) -> Result<(), CalendarError> {
+    return if range.contains(&absolute_date) {
+        Ok(())
+    } else {
+        Err(CalendarError::OutOfRange {
+            calendar: calendar.to_string(),
+            absolute_date,
+        })
+    };
+}
@@ -0,0 +1,1115 @@
+//! Provides a C interface (requires the `ffi` feature)
+//!
+//! Dates are passed as the `#[repr(C)]` date structs of this crate (e.g.
+//! [`Gregorian`]), absolute (fixed) dates as 64-bit integers. Conversions and
+//! holiday computations return a [`Status`] and write their result through a
+//! pointer; holidays are computed for the Gregorian years in
+//! [`HOLIDAY_YEAR_RANGE`], and [`Status::OutOfRange`] is returned for other
+//! years.
+//!
+//! The C header `include/calendars.h` is generated from this module by
+//! cbindgen: building the crate with the `ffi` feature writes the header to
+//! the build script's output directory (`OUT_DIR`), and setting the
+//! environment variable `CALENDARS_UPDATE_HEADER` also updates
+//! `include/calendars.h` (e.g.
+//! `CALENDARS_UPDATE_HEADER=1 cargo build --features ffi`).
+
+use crate::{
+    error::{check_component, CalendarError},
+    french::{checked_absolute_from_french, checked_french_from_absolute, French},
+    gregorian::{checked_absolute_from_gregorian, checked_gregorian_from_absolute, Gregorian},
+    hebrew::{checked_absolute_from_hebrew, checked_hebrew_from_absolute, Hebrew},
+    hindu::{
+        checked_absolute_from_old_hindu_lunar, checked_absolute_from_old_hindu_solar,
+        checked_old_hindu_lunar_from_absolute, checked_old_hindu_solar_from_absolute,
+        OldHinduLunar, OldHinduSolar,
+    },
+    holidays::{self, HOLIDAY_YEAR_RANGE},
+    islamic::{checked_absolute_from_islamic, checked_islamic_from_absolute, Islamic},
+    iso::{checked_absolute_from_iso, checked_iso_from_absolute, Iso},
+    julian::{checked_absolute_from_julian, checked_julian_from_absolute, Julian},
+    mayan::{
+        checked_absolute_from_mayan_long_count, checked_mayan_haab_from_absolute,
+        checked_mayan_long_count_from_absolute, checked_mayan_tzolkin_from_absolute, MayanHaab,
+        MayanLongCount, MayanTzolkin,
+    },
+    ordinal::{checked_absolute_from_ordinal, checked_ordinal_from_absolute, Ordinal},
+};
+
+/// Status returned by the functions of the C interface
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Status {
+    /// The conversion succeeded.
+    Ok = 0,
+    /// A date component lies outside the range permitted by its calendar.
+    InvalidDate = 1,
+    /// An absolute (fixed) date or a year lies outside the range supported by
+    /// a calendar.
+    OutOfRange = 2,
+    /// A date does not correspond to an absolute (fixed) date.
+    NotConvertible = 3,
+    /// A null pointer was passed for the result.
+    NullPointer = 4,
+}
+
+/// Implement From<&CalendarError> trait for [`Status`]
+impl From<&CalendarError> for Status {
+    fn from(e: &CalendarError) -> Self {
+        return match e {
+            CalendarError::InvalidComponent { component, .. } if component == "year" => {
+                Status::OutOfRange
+            }
+            CalendarError::OutOfRange { .. } => Status::OutOfRange,
+            CalendarError::NotConvertible { .. } => Status::NotConvertible,
+            _ => Status::InvalidDate,
+        };
+    }
+}
+
+/// Writes the value of a successful conversion to `out`, and returns the
+/// status of the conversion.
+///
+/// # Safety
+///
+/// `out` must be null or valid for writes.
+unsafe fn write_result<T>(result: Result<T, CalendarError>, out: *mut T) -> Status {
+    if out.is_null() {
+        return Status::NullPointer;
+    }
+    return match result {
+        Ok(value) => {
+            out.write(value);
+            Status::Ok
+        }
+        Err(e) => Status::from(&e),
+    };
+}
+
+/// Returns an error if a given Gregorian year lies outside
+/// [`HOLIDAY_YEAR_RANGE`].
+fn check_holiday_year(year: i64) -> Result<i64, CalendarError> {
+    check_component(
+        "gregorian",
+        "year",
+        year,
+        *HOLIDAY_YEAR_RANGE.start(),
+        *HOLIDAY_YEAR_RANGE.end(),
+    )?;
+    return Ok(year);
+}
+
+/// Writes the absolute (fixed) date of a holiday in a given Gregorian year to
+/// `absolute_date`, and returns the status of the computation.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+unsafe fn write_holiday(year: i64, holiday: fn(i64) -> i64, absolute_date: *mut i64) -> Status {
+    return write_result(check_holiday_year(year).map(holiday), absolute_date);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of a holiday in a given
+/// Gregorian year to `dates` and their total number to `count`, and returns
+/// the status of the computation.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+unsafe fn write_holidays(
+    year: i64,
+    holiday: fn(i64) -> Vec<i64>,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    if count.is_null() {
+        return Status::NullPointer;
+    }
+    let values = match check_holiday_year(year) {
+        Ok(year) => holiday(year),
+        Err(e) => return Status::from(&e),
+    };
+    if !dates.is_null() {
+        for (i, value) in values.iter().take(capacity).enumerate() {
+            dates.add(i).write(*value);
+        }
+    }
+    return write_result(Ok(values.len()), count);
+}
+
+/// Computes the absolute (fixed) date of a Gregorian date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_gregorian(
+    date: Gregorian,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_gregorian(date), absolute_date);
+}
+
+/// Computes the Gregorian date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_gregorian_from_absolute(
+    absolute_date: i64,
+    date: *mut Gregorian,
+) -> Status {
+    return write_result(checked_gregorian_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of a Julian date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_julian(
+    date: Julian,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_julian(date), absolute_date);
+}
+
+/// Computes the Julian date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_julian_from_absolute(
+    absolute_date: i64,
+    date: *mut Julian,
+) -> Status {
+    return write_result(checked_julian_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of an ISO date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_iso(date: Iso, absolute_date: *mut i64) -> Status {
+    return write_result(checked_absolute_from_iso(date), absolute_date);
+}
+
+/// Computes the ISO date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_iso_from_absolute(absolute_date: i64, date: *mut Iso) -> Status {
+    return write_result(checked_iso_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of an ordinal date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_ordinal(
+    date: Ordinal,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_ordinal(date), absolute_date);
+}
+
+/// Computes the ordinal date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_ordinal_from_absolute(
+    absolute_date: i64,
+    date: *mut Ordinal,
+) -> Status {
+    return write_result(checked_ordinal_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of an Islamic date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_islamic(
+    date: Islamic,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_islamic(date), absolute_date);
+}
+
+/// Computes the Islamic date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_islamic_from_absolute(
+    absolute_date: i64,
+    date: *mut Islamic,
+) -> Status {
+    return write_result(checked_islamic_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of a Hebrew date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_hebrew(
+    date: Hebrew,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_hebrew(date), absolute_date);
+}
+
+/// Computes the Hebrew date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_hebrew_from_absolute(
+    absolute_date: i64,
+    date: *mut Hebrew,
+) -> Status {
+    return write_result(checked_hebrew_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of a French Revolutionary date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_french(
+    date: French,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_french(date), absolute_date);
+}
+
+/// Computes the French Revolutionary date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_french_from_absolute(
+    absolute_date: i64,
+    date: *mut French,
+) -> Status {
+    return write_result(checked_french_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of a Mayan long count.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_mayan_long_count(
+    date: MayanLongCount,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_mayan_long_count(date), absolute_date);
+}
+
+/// Computes the Mayan long count of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_mayan_long_count_from_absolute(
+    absolute_date: i64,
+    date: *mut MayanLongCount,
+) -> Status {
+    return write_result(checked_mayan_long_count_from_absolute(absolute_date), date);
+}
+
+/// Computes the Mayan haab date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_mayan_haab_from_absolute(
+    absolute_date: i64,
+    date: *mut MayanHaab,
+) -> Status {
+    return write_result(checked_mayan_haab_from_absolute(absolute_date), date);
+}
+
+/// Computes the Mayan tzolkin date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_mayan_tzolkin_from_absolute(
+    absolute_date: i64,
+    date: *mut MayanTzolkin,
+) -> Status {
+    return write_result(checked_mayan_tzolkin_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of an Old Hindu solar date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_old_hindu_solar(
+    date: OldHinduSolar,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_old_hindu_solar(date), absolute_date);
+}
+
+/// Computes the Old Hindu solar date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_old_hindu_solar_from_absolute(
+    absolute_date: i64,
+    date: *mut OldHinduSolar,
+) -> Status {
+    return write_result(checked_old_hindu_solar_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of an Old Hindu lunar date.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_absolute_from_old_hindu_lunar(
+    date: OldHinduLunar,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_result(checked_absolute_from_old_hindu_lunar(date), absolute_date);
+}
+
+/// Computes the Old Hindu lunar date of an absolute (fixed) date.
+///
+/// # Safety
+///
+/// `date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_old_hindu_lunar_from_absolute(
+    absolute_date: i64,
+    date: *mut OldHinduLunar,
+) -> Status {
+    return write_result(checked_old_hindu_lunar_from_absolute(absolute_date), date);
+}
+
+/// Computes the absolute (fixed) date of New Year's Day in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_new_years_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::new_years_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Martin Luther King Jr. Day (US) in a
+/// given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_martin_luther_king_day(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::martin_luther_king_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Presidents' Day (US) in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_presidents_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::presidents_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Juneteenth (US) in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_juneteenth(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::juneteenth, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Independence Day (US) in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_independence_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::independence_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Labor Day (US) in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_labor_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::labor_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Memorial Day (US) in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_memorial_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::memorial_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Columbus Day (US) in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_columbus_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::columbus_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Veterans Day (US) in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_veterans_day(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::veterans_day, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Thanksgiving (US) in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_thanksgiving(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::thanksgiving, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of the start of daylight saving time
+/// (US) in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_daylight_savings_start(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::daylight_savings_start, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of the end of daylight saving time (US)
+/// in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_daylight_savings_end(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::daylight_savings_end, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Christmas in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_christmas(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::christmas, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Advent Sunday in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_advent(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::advent, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Epiphany in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_epiphany(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::epiphany, absolute_date);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of Eastern Orthodox
+/// Christmas in a given Gregorian year to `dates`, and writes the number of such dates to `count` (0 or 1).
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_eastern_orthodox_christmas(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(
+        year,
+        holidays::eastern_orthodox_christmas,
+        dates,
+        capacity,
+        count,
+    );
+}
+
+/// Computes the absolute (fixed) date of Easter according to the Nicaean rule
+/// in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_nicaean_rule_easter(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::nicaean_rule_easter, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Orthodox Easter in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_orthodox_easter(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::orthodox_easter, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of the start of Great Lent (Orthodox)
+/// in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_great_lent_start(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::great_lent_start, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Orthodox Pentecost in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_orthodox_pentecost(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::orthodox_pentecost, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of the start of the Apostles' Fast
+/// (Orthodox) in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_apostles_fast_start(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::apostles_fast_start, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of the end of the Apostles' Fast
+/// (Orthodox) in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_apostles_fast_end(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::apostles_fast_end, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Easter in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_easter(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::easter, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Pentecost in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_pentecost(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::pentecost, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Septuagesima Sunday in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_septuagesima(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::septuagesima, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Mardi Gras (Shrove Tuesday) in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_mardi_gras(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::mardi_gras, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Ash Wednesday in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_ash_wednesday(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::ash_wednesday, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Palm Sunday in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_palm_sunday(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::palm_sunday, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Good Friday in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_good_friday(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::good_friday, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Ascension Day in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_ascension(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::ascension, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Trinity Sunday in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_trinity_sunday(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::trinity_sunday, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Corpus Christi in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_corpus_christi(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::corpus_christi, absolute_date);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of the Islamic New Year in a
+/// given Gregorian year to `dates`, and writes the number of such dates to `count`.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_islamic_new_year(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::islamic_new_year, dates, capacity, count);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of Ashura in a given
+/// Gregorian year to `dates`, and writes the number of such dates to `count`.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_ashura(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::ashura, dates, capacity, count);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of Mulad al-Nabi in a given
+/// Gregorian year to `dates`, and writes the number of such dates to `count` (0, 1, or
+/// 2).
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_mulad_al_nabi(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::mulad_al_nabi, dates, capacity, count);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of the first day of Ramadan
+/// in a given Gregorian year to `dates`, and writes the number of such dates to `count`.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_ramadan_start(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::ramadan_start, dates, capacity, count);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of Laylat al-Qadr in a given
+/// Gregorian year to `dates`, and writes the number of such dates to `count`.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_laylat_al_qadr(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::laylat_al_qadr, dates, capacity, count);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of Eid al-Fitr in a given
+/// Gregorian year to `dates`, and writes the number of such dates to `count`.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_eid_al_fitr(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::eid_al_fitr, dates, capacity, count);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of Eid al-Adha in a given
+/// Gregorian year to `dates`, and writes the number of such dates to `count`.
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_eid_al_adha(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::eid_al_adha, dates, capacity, count);
+}
+
+/// Computes the absolute (fixed) date of Yom Kippur in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_yom_kippur(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::yom_kippur, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Passover in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_passover(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::passover, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Purim in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_purim(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::purim, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Ta'anit Esther in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_ta_anit_esther(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::ta_anit_esther, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Tisha B'Av in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_tisha_b_av(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::tisha_b_av, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Rosh Hashanah in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_rosh_hashanah(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::rosh_hashanah, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Sukkot in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_sukkot(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::sukkot, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Shemini Atzeret in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_shemini_atzeret(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::shemini_atzeret, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Simchat Torah (outside of Israel) in a
+/// given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_simchat_torah(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::simchat_torah, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Simchat Torah in Israel in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_simchat_torah_israel(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::simchat_torah_israel, absolute_date);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of the eight days of
+/// Hanukkah beginning in a given Gregorian year to `dates`, and writes the number of such dates to `count` (8).
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_hanukkah(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::hanukkah, dates, capacity, count);
+}
+
+/// Computes the absolute (fixed) date of Tu BiShvat in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_tu_bishvat(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::tu_bishvat, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Lag BaOmer in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_lag_ba_omer(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::lag_ba_omer, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Shavuot in a given Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_shavuot(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::shavuot, absolute_date);
+}
+
+/// Computes the absolute (fixed) date of Tzom Gedaliah in a given Gregorian
+/// year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_tzom_gedaliah(year: i64, absolute_date: *mut i64) -> Status {
+    return write_holiday(year, holidays::tzom_gedaliah, absolute_date);
+}
+
+/// Writes up to `capacity` absolute (fixed) dates of the Tenth of Tevet in a
+/// given Gregorian year to `dates`, and writes the number of such dates to `count` (0,
+/// 1, or 2).
+///
+/// # Safety
+///
+/// `dates` must be null or valid for `capacity` writes, and `count` must be
+/// null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_asarah_b_tevet(
+    year: i64,
+    dates: *mut i64,
+    capacity: usize,
+    count: *mut usize,
+) -> Status {
+    return write_holidays(year, holidays::asarah_b_tevet, dates, capacity, count);
+}
+
+/// Computes the absolute (fixed) date of the Seventeenth of Tammuz in a given
+/// Gregorian year.
+///
+/// # Safety
+///
+/// `absolute_date` must be null or valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn calendars_shiva_asar_b_tammuz(
+    year: i64,
+    absolute_date: *mut i64,
+) -> Status {
+    return write_holiday(year, holidays::shiva_asar_b_tammuz, absolute_date);
+}
@@ -0,0 +1,433 @@
+//! Provides pattern-based (strftime-style) formatting of dates
+//!
+//! Patterns consist of literal text and the following tokens, which are
+//! resolved via the component metadata of [`Date`], so that the same pattern
+//! can be applied to dates of any calendar having the respective components:
+//!
+//! | Token      | Output                                                     |
+//! |------------|------------------------------------------------------------|
+//! | `%Y`       | year                                                       |
+//! | `%m`       | month, zero-padded to two digits                           |
+//! | `%d`       | day, zero-padded to two digits                             |
+//! | `%B`       | month name                                                 |
+//! | `%b`       | abbreviated month name (first three letters)               |
+//! | `%A`       | weekday name                                               |
+//! | `%a`       | abbreviated weekday name (first three letters)             |
+//! | `%u`       | weekday number (1 = Monday, ..., 7 = Sunday)               |
+//! | `%w`       | weekday number (0 = Sunday, ..., 6 = Saturday)             |
+//! | `%E`       | era (e.g. "CE"/"BCE" for Gregorian dates, "AH", "AM", "an")|
+//! | `%O`       | year of the era (e.g. 44 for Gregorian year -43)           |
+//! | `%{name}`  | component `name` of the date (e.g. `%{baktun}`, `%{week}`) |
+//! | `%%`       | literal "%"                                                |
+//!
+//! A `-` after `%` suppresses zero-padding (e.g. `%-d`), and a `#` after `%`
+//! writes numbers in Roman numerals (e.g. `%#O`, as in "an II" for year 2 of
+//! the French Republic). A `@` after `%` writes numbers in Hebrew numerals
+//! (gematria), omitting the thousands of years (e.g. `%@d ב%B %@Y` with
+//! [`Locale::Hebrew`] gives "ט״ו בניסן תשפ״ד" for 15 Nisan 5784). A `~` after
+//! `%` writes numbers in the native digits of the locale (see
+//! [`Locale::localize_digits`]), e.g. Devanagari digits in [`Locale::Hindi`].
+//!
+//! Names are abbreviated (`%a` and `%b`) only in the English, German, French,
+//! and Spanish locales; in the other locales, taking the first letters of a
+//! name yields no customary abbreviation, and `%a` and `%b` return an error.
+//!
+//! Years before the first year of an era are counted backwards from 1, as
+//! there is no year 0: Gregorian year 0 is 1 BCE, and year -43 is 44 BCE.
+//! Calendars with a single era (e.g. the Islamic "AH") have no years before
+//! the beginning of the era, so `%E` and `%O` return an error for these years
+//! (e.g. Islamic year 0).
+//! Dates with eras can be parsed back (see the [`parse`](crate::parse)
+//! module), e.g. "15 March 44 BC" as a Julian date.
+
+use crate::{
+    error::CalendarError,
+    hebrew::{hebrew_leap_year, hebrew_numeral, hebrew_year_numeral},
+    locale::Locale,
+    utility::Date,
+};
+
+/// Returns a format error for a given pattern and message.
+fn format_error(pattern: &str, message: &str) -> CalendarError {
+    return CalendarError::FormatError(format!("{} in pattern \"{}\"", message, pattern));
+}
+
+/// Returns the era and the year of the era of a given year of a calendar, or
+/// an error message if the calendar has no era, or the year precedes the
+/// only era of the calendar (e.g. Islamic year 0) or has no year of the era
+/// representable as `i64`. Old Hindu years are elapsed years, so the Kali
+/// Yuga begins with year 0.
+fn era(calendar: &str, year: i64) -> Result<(&'static str, i64), String> {
+    let (era, year_of_era, first_year) = match calendar {
+        "gregorian" | "iso" | "ordinal" if year > 0 => ("CE", Some(year), 1),
+        "gregorian" | "iso" | "ordinal" => ("BCE", 1_i64.checked_sub(year), 1),
+        "julian" if year > 0 => ("AD", Some(year), 1),
+        "julian" => ("BC", 1_i64.checked_sub(year), 1),
+        "islamic" | "ummAlQura" => ("AH", Some(year), 1),
+        "hebrew" => ("AM", Some(year), 1),
+        "french" => ("an", Some(year), 1),
+        "oldHinduSolar" | "oldHinduLunar" => ("KY", Some(year), 0),
+        _ => return Err(format!("{} calendar has no era", calendar)),
+    };
+    return match year_of_era {
+        Some(year_of_era) if year_of_era >= first_year => Ok((era, year_of_era)),
+        _ => Err(format!("year {} has no year of the {} era", year, era)),
+    };
+}
+
+/// Returns the year of a calendar corresponding to a given era (matched
+/// case-insensitively, see [`era`]) and year of the era, or `None` if the
+/// calendar has no such era or the year of the era precedes its first year
+/// (1, or 0 for the Kali Yuga). "AD" and "BC" are accepted as synonyms of "CE"
+/// and "BCE".
+pub(crate) fn year_from_era(calendar: &str, era: &str, year_of_era: i64) -> Option<i64> {
+    let era = era.to_uppercase();
+    if year_of_era < 1 && !(year_of_era == 0 && era == "KY") {
+        return None;
+    }
+    return match (calendar, era.as_str()) {
+        ("gregorian" | "iso" | "ordinal" | "julian", "CE" | "AD") => Some(year_of_era),
+        ("gregorian" | "iso" | "ordinal" | "julian", "BCE" | "BC") => Some(1 - year_of_era),
+        ("islamic" | "ummAlQura", "AH")
+        | ("hebrew", "AM")
+        | ("french", "AN")
+        | ("oldHinduSolar" | "oldHinduLunar", "KY") => Some(year_of_era),
+        _ => None,
+    };
+}
+
+/// Values and symbols of Roman numerals, including subtractive pairs
+static ROMAN_NUMERALS: [(i64, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+/// Returns a given number in Roman numerals, or `None` if the number lies
+/// outside 1 to 3999.
+///
+/// # Example
+///
+/// ```
+/// use calendars::format::{parse_roman_numeral, roman_numeral};
+///
+/// assert_eq!(roman_numeral(2).as_deref(), Some("II"));
+/// assert_eq!(roman_numeral(1994).as_deref(), Some("MCMXCIV"));
+/// assert_eq!(roman_numeral(0), None);
+/// assert_eq!(parse_roman_numeral("xiv"), Some(14));
+/// assert_eq!(parse_roman_numeral("IIII"), None);
+/// ```
+pub fn roman_numeral(number: i64) -> Option<String> {
+    if !(1..=3999).contains(&number) {
+        return None;
+    }
+    let mut rest = number;
+    let mut result = String::new();
+    for (value, symbol) in ROMAN_NUMERALS {
+        while rest >= value {
+            result.push_str(symbol);
+            rest -= value;
+        }
+    }
+    return Some(result);
+}
+
+/// Parses a number in Roman numerals (case-insensitively), or returns `None`
+/// if the string is not the canonical Roman numeral of a number from 1 to
+/// 3999.
+pub fn parse_roman_numeral(s: &str) -> Option<i64> {
+    let upper = s.to_uppercase();
+    let mut rest = upper.as_str();
+    let mut number = 0;
+    for (value, symbol) in ROMAN_NUMERALS {
+        while let Some(r) = rest.strip_prefix(symbol) {
+            number += value;
+            rest = r;
+        }
+    }
+    if !rest.is_empty() || roman_numeral(number).as_deref() != Some(upper.as_str()) {
+        return None;
+    }
+    return Some(number);
+}
+
+impl Date {
+    /// Returns the value of the component with a given name, or `None` if the
+    /// date has no such component.
+    fn component_value(&self, name: &str) -> Option<i64> {
+        return self
+            .component_names
+            .iter()
+            .position(|n| n == name)
+            .map(|i| self.components[i]);
+    }
+
+    /// Returns the name of the month of the date in a given locale, or `None`
+    /// if the date has no named months.
+    fn month_name_of(&self, locale: Locale) -> Option<String> {
+        let month = self.component_value("month")?;
+        let index = match self.calendar.as_str() {
+            // month names 13 and 14 are Adar I and Adar II of leap years
+            "hebrew" if hebrew_leap_year(self.component_value("year")?) && month >= 12 => month,
+            _ => month - 1,
+        };
+        let index = usize::try_from(index).ok()?;
+        let name = match locale.month_names(&self.calendar) {
+            Some(names) => names.get(index)?.trim(),
+            None => self.month_names.get(index)?.trim(),
+        };
+        if self.component_value("leapMonth") == Some(1) {
+            return Some(format!("{} {}", locale.leap_month_prefix(), name));
+        }
+        return Some(name.to_string());
+    }
+
+    /// Formats the date according to a given strftime-style pattern (see the
+    /// [`format`](crate::format) module for the supported tokens). Returns
+    /// an error if the date does not exist (see [`Date::try_to_absolute`]),
+    /// or the pattern is malformed or refers to information the date does
+    /// not have (e.g. `%B` for a Mayan Long Count, or `%A` for a Mayan Haab
+    /// date).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     error::CalendarError, french::French, gregorian::Gregorian, utility::Calendar,
+    /// };
+    ///
+    /// let d = Gregorian::new(2024, 5, 1).to_date();
+    /// assert_eq!(d.format_with("%A, %-d %B %Y").unwrap(), "Wednesday, 1 May 2024");
+    /// assert_eq!(d.format_with("%Y-%m-%d").unwrap(), "2024-05-01");
+    ///
+    /// // eras
+    /// let d = Gregorian::new(-43, 3, 15).to_date();
+    /// assert_eq!(d.format_with("%-d %B %O %E").unwrap(), "15 March 44 BCE");
+    /// let d = Gregorian::new(0, 1, 1).to_date();
+    /// assert_eq!(d.format_with("%O %E").unwrap(), "1 BCE");
+    /// let d = French::new(2, 8, 12).to_date();
+    /// assert_eq!(d.format_with("%-d %B %E %#O").unwrap(), "12 Floréal an II");
+    /// assert!(French::new(0, 8, 12).to_date().format_with("%#O").is_err());
+    /// assert!(Gregorian::new(i64::MIN, 1, 1).to_date().format_with("%O %E").is_err());
+    ///
+    /// // dates that do not exist
+    /// assert_eq!(
+    ///     Gregorian::new(2024, 0, 1).to_date().format_with("%B"),
+    ///     Err(CalendarError::InvalidComponent {
+    ///         calendar: "gregorian".to_string(),
+    ///         component: "month".to_string(),
+    ///         value: 0,
+    ///     })
+    /// );
+    /// ```
+    pub fn format_with(&self, pattern: &str) -> Result<String, CalendarError> {
+        return self.format_with_locale(pattern, Locale::English);
+    }
+
+    /// Formats the date according to a given strftime-style pattern, using
+    /// the month and weekday names of a given locale (see
+    /// [`Date::format_with`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{gregorian::Gregorian, locale::Locale, utility::Calendar};
+    ///
+    /// let d = Gregorian::new(2024, 5, 1).to_date();
+    /// assert_eq!(
+    ///     d.format_with_locale("%A, %-d. %B %Y", Locale::German).unwrap(),
+    ///     "Mittwoch, 1. Mai 2024"
+    /// );
+    /// assert_eq!(
+    ///     d.format_with_locale("%a %-d %b", Locale::Spanish).unwrap(),
+    ///     "mié 1 may"
+    /// );
+    /// assert!(d.format_with_locale("%a", Locale::Hebrew).is_err());
+    /// ```
+    pub fn format_with_locale(
+        &self,
+        pattern: &str,
+        locale: Locale,
+    ) -> Result<String, CalendarError> {
+        // reject dates that do not exist, also if the pattern only refers to
+        // valid components (cyclical Mayan dates have no absolute date)
+        match self.try_to_absolute() {
+            Ok(_) | Err(CalendarError::NotConvertible { .. }) => {}
+            Err(error) => return Err(error),
+        }
+        let mut result = String::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.push(c);
+                continue;
+            }
+            let mut token = chars
+                .next()
+                .ok_or_else(|| format_error(pattern, "incomplete token"))?;
+            let mut padded = true;
+            let mut roman = false;
+            let mut hebrew = false;
+            let mut native = false;
+            while token == '-' || token == '#' || token == '@' || token == '~' {
+                padded &= token != '-';
+                roman |= token == '#';
+                hebrew |= token == '@';
+                native |= token == '~';
+                token = chars
+                    .next()
+                    .ok_or_else(|| format_error(pattern, "incomplete token"))?;
+            }
+            let component = |name: &str| {
+                self.component_value(name).ok_or_else(|| {
+                    format_error(pattern, &format!("{} date has no {}", self.calendar, name))
+                })
+            };
+            let number = |value: i64, width: usize| {
+                if roman {
+                    roman_numeral(value).ok_or_else(|| {
+                        format_error(
+                            pattern,
+                            &format!("{} cannot be written in Roman numerals", value),
+                        )
+                    })
+                } else if hebrew {
+                    hebrew_numeral(value).ok_or_else(|| {
+                        format_error(
+                            pattern,
+                            &format!("{} cannot be written in Hebrew numerals", value),
+                        )
+                    })
+                } else if native && padded {
+                    Ok(locale.localize_digits(&format!("{:0width$}", value, width = width)))
+                } else if native {
+                    Ok(locale.localize_digits(&value.to_string()))
+                } else if padded {
+                    Ok(format!("{:0width$}", value, width = width))
+                } else {
+                    Ok(value.to_string())
+                }
+            };
+            let weekday = || {
+                self.weekday().map_err(|_| {
+                    format_error(pattern, &format!("{} date has no weekday", self.calendar))
+                })
+            };
+            let month_name = || {
+                self.month_name_of(locale).ok_or_else(|| {
+                    format_error(
+                        pattern,
+                        &format!("{} date has no month names", self.calendar),
+                    )
+                })
+            };
+            let abbreviate = |name: &str| {
+                if !locale.abbreviates_names() {
+                    return Err(format_error(
+                        pattern,
+                        &format!("{:?} names have no abbreviations", locale),
+                    ));
+                }
+                return Ok(name.chars().take(3).collect::<String>());
+            };
+            let era = || {
+                era(&self.calendar, component("year")?)
+                    .map_err(|message| format_error(pattern, &message))
+            };
+            let s = match token {
+                'Y' if hebrew => {
+                    let year = component("year")?;
+                    hebrew_year_numeral(year).ok_or_else(|| {
+                        format_error(
+                            pattern,
+                            &format!("{} cannot be written in Hebrew numerals", year),
+                        )
+                    })?
+                }
+                'Y' => number(component("year")?, 0)?,
+                'm' => number(component("month")?, 2)?,
+                'd' => number(component("day")?, 2)?,
+                'B' => month_name()?,
+                'b' => abbreviate(&month_name()?)?,
+                'A' => weekday()?.localized_name(locale).to_string(),
+                'a' => abbreviate(weekday()?.localized_name(locale))?,
+                'u' => match weekday()?.number() {
+                    0 => 7,
+                    n => n,
+                }
+                .to_string(),
+                'w' => weekday()?.number().to_string(),
+                'E' => era()?.0.to_string(),
+                'O' => number(era()?.1, 0)?,
+                '%' => "%".to_string(),
+                '{' => {
+                    let rest = chars.as_str();
+                    let (name, _) = rest
+                        .split_once('}')
+                        .ok_or_else(|| format_error(pattern, "unclosed component token"))?;
+                    chars = rest[name.len() + 1..].chars();
+                    number(component(name)?, 0)?
+                }
+                _ => return Err(format_error(pattern, &format!("unknown token %{}", token))),
+            };
+            result.push_str(&s);
+        }
+        return Ok(result);
+    }
+
+    /// Formats the date like [`Calendar::format`](crate::utility::Calendar::format),
+    /// using the month names of a given locale. Hebrew dates are written in
+    /// Hebrew script with Hebrew numerals in [`Locale::Hebrew`], and Hindu
+    /// dates with native digits in [`Locale::Hindi`], [`Locale::Tamil`], and
+    /// [`Locale::Telugu`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     hebrew::Hebrew,
+    ///     hindu::{OldHinduLunar, OldHinduSolar},
+    ///     locale::Locale,
+    ///     utility::Calendar,
+    /// };
+    ///
+    /// let d = Hebrew { year: 5784, month: 1, day: 15 }.to_date();
+    /// assert_eq!(d.format_localized(Locale::Hebrew), "ט״ו בניסן תשפ״ד");
+    /// let d = Hebrew { year: 5784, month: 13, day: 1 }.to_date();
+    /// assert_eq!(d.format_localized(Locale::Hebrew), "א׳ באדר ב׳ תשפ״ד");
+    /// assert_eq!(d.format_localized(Locale::English), "1 Adar II 5784");
+    ///
+    /// let d = OldHinduLunar { year: 5125, month: 1, leap_month: true, day: 15 }.to_date();
+    /// assert_eq!(d.format_localized(Locale::Hindi), "१५ अधिक चैत्र ५१२५");
+    /// let d = OldHinduSolar { year: 5125, month: 1, day: 15 }.to_date();
+    /// assert_eq!(d.format_localized(Locale::Tamil), "௧௫ சித்திரை ௫௧௨௫");
+    /// assert_eq!(d.format_localized(Locale::Telugu), "౧౫ మేషం ౫౧౨౫");
+    /// ```
+    pub fn format_localized(&self, locale: Locale) -> String {
+        let pattern = match self.calendar.as_str() {
+            "hebrew" if locale == Locale::Hebrew => "%@d ב%B %@Y",
+            "oldHinduSolar" | "oldHinduLunar"
+                if matches!(locale, Locale::Hindi | Locale::Tamil | Locale::Telugu) =>
+            {
+                "%~-d %B %~Y"
+            }
+            "gregorian" | "julian" | "islamic" | "ummAlQura" | "hebrew" | "french"
+            | "oldHinduSolar" | "oldHinduLunar" => "%-d %B %Y",
+            "mayanHaab" => "%-d %B",
+            _ => return self.format(),
+        };
+        return self
+            .format_with_locale(pattern, locale)
+            .unwrap_or_else(|_| self.format());
+    }
+}
@@ -1,6 +1,13 @@
 //! Functions converting from and to French Revolutionary calendar dates
 
-use crate::math::{floor_div, modulus, sum};
+use core::ops::RangeInclusive;
+
+use crate::{
+    astro::{solar_longitude, MEAN_TROPICAL_YEAR},
+    error::{check_component, check_range, CalendarError},
+    math::{clamp_to_range, count_while, floor_div, modulus},
+    moment::Moment,
+};
 
 /// French Revolutionary month names
 pub static FRENCH_MONTH_NAMES: [&str; 13] = [
@@ -19,6 +26,13 @@ pub static FRENCH_MONTH_NAMES: [&str; 13] = [
     "Sansculottides",
 ];
 
+/// Names of the days of the décade (the ten-day week of the French
+/// Revolutionary calendar)
+pub static FRENCH_DAY_NAMES: [&str; 10] = [
+    "Primidi", "Duodi", "Tridi", "Quartidi", "Quintidi", "Sextidi", "Septidi", "Octidi", "Nonidi",
+    "Décadi",
+];
+
 /// French monthless days added at the end of a given year
 pub static SANSCULOTTIDES: [&str; 6] = [
     "Jour de la vertu",
@@ -29,8 +43,21 @@ pub static SANSCULOTTIDES: [&str; 6] = [
     "Jour de la révolution",
 ];
 
+/// Range of years supported by the conversions of French Revolutionary dates.
+pub static FRENCH_YEAR_RANGE: RangeInclusive<i64> = 1..=1_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of French Revolutionary
+/// dates (the dates of the years in [`FRENCH_YEAR_RANGE`]).
+pub static FRENCH_ABSOLUTE_RANGE: RangeInclusive<i64> = 654_415..=365_242_904_414;
+
 /// French date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct French {
     pub year: i64,
     pub month: i64,
@@ -42,11 +69,38 @@ impl French {
     pub fn new(year: i64, month: i64, day: i64) -> Self {
         Self { year, month, day }
     }
+
+    /// Create a new French Revolutionary date, or return an error if the date
+    /// does not exist or its year lies outside [`FRENCH_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "french",
+            "year",
+            self.year,
+            *FRENCH_YEAR_RANGE.start(),
+            *FRENCH_YEAR_RANGE.end(),
+        )?;
+        check_component("french", "month", self.month, 1, 13)?;
+        let last_day = french_last_day_of_month(self.month, self.year);
+        return check_component("french", "day", self.day, 1, last_day);
+    }
 }
 
 /// Returns the last day of a given French Revolutionary month in a given
 /// French Revolutionary year
-fn french_last_day_of_month(month: i64, year: i64) -> i64 {
+pub fn french_last_day_of_month(month: i64, year: i64) -> i64 {
     return if month < 13 {
         30
     } else {
@@ -59,16 +113,19 @@ fn french_last_day_of_month(month: i64, year: i64) -> i64 {
 }
 
 /// Returns true if a given year is a leap year, and false otherwise
-fn french_leap_year(f_year: i64) -> bool {
-    return vec![3, 7, 11].contains(&f_year)
-        || vec![15, 20].contains(&f_year)
+pub fn french_leap_year(f_year: i64) -> bool {
+    return [3, 7, 11].contains(&f_year)
+        || [15, 20].contains(&f_year)
         || (f_year > 20
             && modulus(f_year, 4) == 0
-            && !vec![100, 200, 300].contains(&modulus(f_year, 400))
+            && ![100, 200, 300].contains(&modulus(f_year, 400))
             && modulus(f_year, 4000) != 0);
 }
 
 /// Returns the absolute (fixed) date from a given French Revolutionary date.
+///
+/// The result is only meaningful for years in [`FRENCH_YEAR_RANGE`]; see
+/// [`checked_absolute_from_french`].
 pub fn absolute_from_french(d: French) -> i64 {
     let year = d.year;
     let month = d.month;
@@ -87,40 +144,40 @@ pub fn absolute_from_french(d: French) -> i64 {
 
 /// Returns the French Revolutionary date corresponding to a given absolute
 /// (fixed) date.
+///
+/// Absolute dates outside [`FRENCH_ABSOLUTE_RANGE`] are clamped to the range,
+/// so that dates before the French Revolutionary epoch are returned as
+/// 1 Vendémiaire 1; see [`checked_french_from_absolute`] for a fallible
+/// alternative.
+///
+/// # Example
+///
+/// ```
+/// use calendars::french::{checked_french_from_absolute, french_from_absolute, French};
+///
+/// assert_eq!(french_from_absolute(0), French::new(1, 1, 1));
+/// assert!(checked_french_from_absolute(0).is_err());
+/// ```
 pub fn french_from_absolute(absolute_date: i64) -> French {
-    if absolute_date < 654415 {
-        return French {
-            year: 0,
-            month: 0,
-            day: 0,
-        };
-    }
+    let absolute_date = clamp_to_range(absolute_date, &FRENCH_ABSOLUTE_RANGE);
     let approx = floor_div(absolute_date - 654414, 366);
     let year = approx
-        + sum(
-            |_| 1.0,
-            approx,
-            |y| {
-                absolute_date
-                    >= absolute_from_french(French {
-                        year: (y as i64) + 1,
-                        month: 1,
-                        day: 1,
-                    })
-            },
-        ) as i64;
-    let month = 1 + sum(
-        |_| 1.0,
-        1,
-        |m| {
+        + count_while(approx, |y| {
             absolute_date
-                > absolute_from_french(French {
-                    year,
-                    month: m as i64,
-                    day: french_last_day_of_month(m as i64, year),
+                >= absolute_from_french(French {
+                    year: y + 1,
+                    month: 1,
+                    day: 1,
                 })
-        },
-    ) as i64;
+        });
+    let month = 1 + count_while(1, |m| {
+        absolute_date
+            > absolute_from_french(French {
+                year,
+                month: m,
+                day: french_last_day_of_month(m, year),
+            })
+    });
     let day = absolute_date
         - (absolute_from_french(French {
             year,
@@ -129,3 +186,120 @@ pub fn french_from_absolute(absolute_date: i64) -> French {
         }) - 1);
     return French { year, month, day };
 }
+
+/// Computes the absolute (fixed) date from a given French Revolutionary date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`FRENCH_YEAR_RANGE`].
+pub fn checked_absolute_from_french(d: French) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_french(d));
+}
+
+/// Computes the French Revolutionary date corresponding to a given absolute (fixed) date,
+/// or returns an error if the absolute date lies outside
+/// [`FRENCH_ABSOLUTE_RANGE`].
+pub fn checked_french_from_absolute(absolute_date: i64) -> Result<French, CalendarError> {
+    check_range("french", absolute_date, &FRENCH_ABSOLUTE_RANGE)?;
+    return Ok(french_from_absolute(absolute_date));
+}
+
+// Equinox rule
+
+/// Longitude of the Paris Observatory (in fractions of a day east of
+/// Greenwich), whose local mean time is used by the equinox rule
+static PARIS_LONGITUDE: f64 = (2.0 + 20.0 / 60.0 + 15.0 / 3600.0) / 360.0;
+
+/// Leap year rule of the French Revolutionary calendar
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     french::{absolute_from_french, French, FrenchRule},
+///     gregorian::{absolute_from_gregorian, Gregorian},
+/// };
+///
+/// // both rules agree on the historical leap years
+/// let leap_years = |rule: FrenchRule| (1..=30).filter(|y| rule.leap_year(*y)).collect::<Vec<_>>();
+/// assert_eq!(leap_years(FrenchRule::Equinox), [3, 7, 11, 15, 20, 24, 28]);
+/// assert_eq!(leap_years(FrenchRule::Arithmetic), [3, 7, 11, 15, 20, 24, 28]);
+///
+/// // but disagree on the beginning of year 53
+/// let d = French::new(53, 1, 1);
+/// assert_eq!(
+///     FrenchRule::Equinox.absolute_from_french(d),
+///     absolute_from_gregorian(Gregorian::new(1844, 9, 22))
+/// );
+/// assert_eq!(absolute_from_french(d), absolute_from_gregorian(Gregorian::new(1844, 9, 23)));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum FrenchRule {
+    /// Years 3, 7, 11, 15, and 20 are leap years, and later years follow the
+    /// rule proposed by Romme (every fourth year, except for most centuries),
+    /// as in [`absolute_from_french`] and [`french_from_absolute`]
+    Arithmetic,
+    /// Each year begins on the day (in Paris) of the true autumnal equinox, as
+    /// decreed in 1793. As the equinox is computed astronomically, the rule
+    /// is only meaningful for a few millennia around the present.
+    Equinox,
+}
+
+impl FrenchRule {
+    /// Returns the absolute (fixed) date of the first day of a given French
+    /// Revolutionary year.
+    pub fn new_year(&self, year: i64) -> i64 {
+        return match self {
+            FrenchRule::Arithmetic => absolute_from_french(French::new(year, 1, 1)),
+            FrenchRule::Equinox => {
+                let approx = 654415 + (MEAN_TROPICAL_YEAR * (year - 1) as f64).round() as i64;
+                // the first day at whose end (midnight in Paris) the sun has
+                // passed the autumnal equinox
+                let mut date = approx - 3;
+                while solar_longitude(Moment::from(date + 1) - PARIS_LONGITUDE) < 180.0 {
+                    date += 1;
+                }
+                date
+            }
+        };
+    }
+
+    /// Returns true if a given French Revolutionary year is a leap year, and
+    /// false otherwise.
+    pub fn leap_year(&self, year: i64) -> bool {
+        return self.new_year(year + 1) - self.new_year(year) == 366;
+    }
+
+    /// Returns the last day of a given French Revolutionary month in a given
+    /// French Revolutionary year.
+    pub fn last_day_of_month(&self, month: i64, year: i64) -> i64 {
+        return match month {
+            13 if self.leap_year(year) => 6,
+            13 => 5,
+            _ => 30,
+        };
+    }
+
+    /// Computes the absolute (fixed) date from a given French Revolutionary
+    /// date.
+    pub fn absolute_from_french(&self, d: French) -> i64 {
+        return self.new_year(d.year) + 30 * (d.month - 1) + d.day - 1;
+    }
+
+    /// Computes the French Revolutionary date corresponding to a given
+    /// absolute (fixed) date.
+    ///
+    /// Absolute dates outside [`FRENCH_ABSOLUTE_RANGE`] are clamped to the
+    /// range.
+    pub fn french_from_absolute(&self, absolute_date: i64) -> French {
+        let absolute_date = clamp_to_range(absolute_date, &FRENCH_ABSOLUTE_RANGE);
+        let mut year = ((absolute_date - 654415) as f64 / MEAN_TROPICAL_YEAR).floor() as i64 + 1;
+        while absolute_date < self.new_year(year) {
+            year -= 1;
+        }
+        while absolute_date >= self.new_year(year + 1) {
+            year += 1;
+        }
+        let day_of_year = absolute_date - self.new_year(year);
+        return French::new(year, day_of_year / 30 + 1, day_of_year % 30 + 1);
+    }
+}
@@ -1,6 +1,13 @@
 //! Functions converting from and to Gregorian calendar dates
 
-use crate::math::{floor_div, modulus, sum};
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    iso::format_iso_year,
+    math::{clamp_to_range, modulus},
+    weekday::{weekday_from_absolute, Weekday},
+};
 
 /// Gregorian month names
 pub static GREGORIAN_MONTH_NAMES: [&str; 12] = [
@@ -18,8 +25,24 @@ pub static GREGORIAN_MONTH_NAMES: [&str; 12] = [
     "December",
 ];
 
+/// Range of years supported by the conversions of Gregorian dates.
+pub static GREGORIAN_YEAR_RANGE: RangeInclusive<i64> =
+    -1_000_000_000_000_000..=1_000_000_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of Gregorian
+/// dates (the dates of the years in [`GREGORIAN_YEAR_RANGE`]).
+pub static GREGORIAN_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_gregorian(Gregorian::new(*GREGORIAN_YEAR_RANGE.start(), 1, 1))
+        ..=absolute_from_gregorian(Gregorian::new(*GREGORIAN_YEAR_RANGE.end(), 12, 31));
+
 /// Gregorian date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct Gregorian {
     pub year: i64,
     pub month: i64,
@@ -28,14 +51,131 @@ pub struct Gregorian {
 
 impl Gregorian {
     /// Create a new Gregorian date
-    pub fn new(year: i64, month: i64, day: i64) -> Self {
+    pub const fn new(year: i64, month: i64, day: i64) -> Self {
         Self { year, month, day }
     }
+
+    /// Returns the current Gregorian date (UTC) according to the system clock.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::gregorian::Gregorian;
+    ///
+    /// assert!(Gregorian::today().year >= 2024);
+    /// ```
+    #[cfg(feature = "clock")]
+    pub fn today() -> Self {
+        return gregorian_from_absolute(crate::interop::absolute_today());
+    }
+
+    /// Create a new Gregorian date, or return an error if the date does not
+    /// exist or its year lies outside [`GREGORIAN_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Returns the ISO 8601 calendar date representation of the date (e.g.
+    /// "2024-05-01", or "-0586-07-24" and "+10000-01-01" for years outside 0
+    /// to 9999).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::gregorian::Gregorian;
+    ///
+    /// assert_eq!(Gregorian::new(2024, 5, 1).to_iso_string(), "2024-05-01");
+    /// assert_eq!(Gregorian::new(-586, 7, 24).to_iso_string(), "-0586-07-24");
+    /// assert_eq!("-0586-07-24".parse(), Ok(Gregorian::new(-586, 7, 24)));
+    /// assert_eq!("+10000-01-01".parse(), Ok(Gregorian::new(10000, 1, 1)));
+    /// assert_eq!("20240501".parse(), Ok(Gregorian::new(2024, 5, 1)));
+    /// ```
+    pub fn to_iso_string(&self) -> String {
+        return format!(
+            "{}-{:02}-{:02}",
+            format_iso_year(self.year),
+            self.month,
+            self.day
+        );
+    }
+
+    /// Returns the number of the day within its year (1 January is day 1).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{gregorian::Gregorian, weekday::Weekday};
+    ///
+    /// let date = Gregorian::new(2024, 5, 1);
+    /// assert_eq!(date.day_of_year(), 122);
+    /// assert_eq!(date.days_remaining_in_year(), 244);
+    /// assert_eq!(date.quarter(), 2);
+    /// assert_eq!(date.week_of_month(Weekday::Sunday), 1);
+    /// assert_eq!(Gregorian::new(2024, 5, 31).week_of_month(Weekday::Monday), 5);
+    /// ```
+    pub fn day_of_year(&self) -> i64 {
+        return absolute_from_gregorian(*self)
+            - absolute_from_gregorian(Self::new(self.year, 1, 1))
+            + 1;
+    }
+
+    /// Returns the number of days following the date in its year.
+    pub fn days_remaining_in_year(&self) -> i64 {
+        let days_in_year = if gregorian_leap_year(self.year) {
+            366
+        } else {
+            365
+        };
+        return days_in_year - self.day_of_year();
+    }
+
+    /// Returns the number of the week within the month of the date, for
+    /// weeks beginning on a given weekday (the week containing the first day
+    /// of the month is week 1).
+    pub fn week_of_month(&self, first_day: Weekday) -> i64 {
+        let first =
+            weekday_from_absolute(absolute_from_gregorian(Self::new(self.year, self.month, 1)));
+        let offset = modulus(first.number() - first_day.number(), 7);
+        return (self.day - 1 + offset).div_euclid(7) + 1;
+    }
+
+    /// Returns the quarter of the year (from 1 to 4) of the date.
+    pub fn quarter(&self) -> i64 {
+        return (self.month - 1).div_euclid(3) + 1;
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "gregorian",
+            "year",
+            self.year,
+            *GREGORIAN_YEAR_RANGE.start(),
+            *GREGORIAN_YEAR_RANGE.end(),
+        )?;
+        check_component("gregorian", "month", self.month, 1, 12)?;
+        let last_day = last_day_of_gregorian_month(self.month, self.year);
+        return check_component("gregorian", "day", self.day, 1, last_day);
+    }
+}
+
+/// Returns true if a given Gregorian year is a leap year.
+pub const fn gregorian_leap_year(year: i64) -> bool {
+    return year.rem_euclid(4) == 0 && !matches!(year.rem_euclid(400), 100 | 200 | 300);
 }
 
-/// Returns the last day (number of days) of a given Gregorian month.
-pub fn last_day_of_gregorian_month(month: i64, year: i64) -> i64 {
-    if month == 2 && modulus(year, 4) == 0 && !vec![100, 200, 300].contains(&modulus(year, 400)) {
+/// Returns the last day (number of days) of a given Gregorian month. Months
+/// outside 1..=12 are clamped to the range.
+pub const fn last_day_of_gregorian_month(month: i64, year: i64) -> i64 {
+    let month = clamp_to_range(month, &(1..=12));
+    if month == 2 && gregorian_leap_year(year) {
         return 29;
     } else {
         let days: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
@@ -44,49 +184,87 @@ pub fn last_day_of_gregorian_month(month: i64, year: i64) -> i64 {
 }
 
 /// Computes the absolute (fixed) date from a Gregorian date.
-pub fn absolute_from_gregorian(d: Gregorian) -> i64 {
+///
+/// The result is only meaningful for years in [`GREGORIAN_YEAR_RANGE`]; see
+/// [`checked_absolute_from_gregorian`].
+pub const fn absolute_from_gregorian(d: Gregorian) -> i64 {
     let month = d.month;
     let year = d.year;
     let day = d.day;
 
-    let f = |m: f64| last_day_of_gregorian_month(m as i64, year) as f64;
-    let p = |m: f64| m < (month as f64);
+    // days in prior months of the year, assuming 30-day February, corrected
+    // for the actual length of February
+    let prior_days = (367 * month - 362).div_euclid(12)
+        - if month <= 2 {
+            0
+        } else if gregorian_leap_year(year) {
+            1
+        } else {
+            2
+        };
 
-    return day + sum(f, 1, p) as i64 + 365 * (year - 1) + floor_div(year - 1, 4)
-        - floor_div(year - 1, 100)
-        + floor_div(year - 1, 400);
+    // div_euclid equals floor_div for positive divisors
+    return day + prior_days + 365 * (year - 1) + (year - 1).div_euclid(4)
+        - (year - 1).div_euclid(100)
+        + (year - 1).div_euclid(400);
 }
 
 /// Computes the Gregorian date corresponding to a given absolute date.
-pub fn gregorian_from_absolute(absolute_date: i64) -> Gregorian {
+///
+/// Absolute dates outside [`GREGORIAN_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_gregorian_from_absolute`] for a fallible alternative.
+pub const fn gregorian_from_absolute(absolute_date: i64) -> Gregorian {
+    let absolute_date = clamp_to_range(absolute_date, &GREGORIAN_ABSOLUTE_RANGE);
     let d_0 = absolute_date - 1;
-    let n_400 = floor_div(d_0, 146097);
-    let d_1 = modulus(d_0, 146097);
-    let n_100 = floor_div(d_1, 36524);
-    let d_2 = modulus(d_1, 36524);
-    let n_4 = floor_div(d_2, 1461);
-    let d_3 = modulus(d_2, 1461);
-    let n_1 = floor_div(d_3, 365);
+    let n_400 = d_0.div_euclid(146097);
+    let d_1 = d_0.rem_euclid(146097);
+    let n_100 = d_1.div_euclid(36524);
+    let d_2 = d_1.rem_euclid(36524);
+    let n_4 = d_2.div_euclid(1461);
+    let d_3 = d_2.rem_euclid(1461);
+    let n_1 = d_3.div_euclid(365);
     //let d_4 = d_3 % 365;
     let year = if n_100 == 4 || n_1 == 4 {
         400 * n_400 + 100 * n_100 + 4 * n_4 + n_1
     } else {
         400 * n_400 + 100 * n_100 + 4 * n_4 + n_1 + 1
     };
-    let p = |m| {
-        let d = Gregorian {
-            year,
-            month: m as i64,
-            day: last_day_of_gregorian_month(m as i64, year),
-        };
-        return absolute_date > absolute_from_gregorian(d);
+    // days in prior months of the year, corrected as if February had 30 days
+    let prior_days = absolute_date - absolute_from_gregorian(Gregorian::new(year, 1, 1));
+    let correction = if absolute_date < absolute_from_gregorian(Gregorian::new(year, 3, 1)) {
+        0
+    } else if gregorian_leap_year(year) {
+        1
+    } else {
+        2
     };
-    let month = sum(|_| 1.0, 1, p) as i64 + 1;
-    let day = absolute_date
-        - (absolute_from_gregorian(Gregorian {
-            year,
-            month,
-            day: 1,
-        }) - 1);
+    let month = (12 * (prior_days + correction) + 373).div_euclid(367);
+    let day = absolute_date - absolute_from_gregorian(Gregorian::new(year, month, 1)) + 1;
     return Gregorian { year, month, day };
 }
+
+/// Computes the absolute (fixed) date from a Gregorian date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`GREGORIAN_YEAR_RANGE`].
+///
+/// # Example
+///
+/// ```
+/// use calendars::gregorian::{checked_absolute_from_gregorian, Gregorian};
+///
+/// assert_eq!(checked_absolute_from_gregorian(Gregorian::new(1, 1, 1)), Ok(1));
+/// assert!(checked_absolute_from_gregorian(Gregorian::new(i64::MAX, 1, 1)).is_err());
+/// assert!(Gregorian::try_new(i64::MAX, 1, 1).is_err());
+/// ```
+pub fn checked_absolute_from_gregorian(d: Gregorian) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_gregorian(d));
+}
+
+/// Computes the Gregorian date corresponding to a given absolute date, or
+/// returns an error if the absolute date lies outside
+/// [`GREGORIAN_ABSOLUTE_RANGE`].
+pub fn checked_gregorian_from_absolute(absolute_date: i64) -> Result<Gregorian, CalendarError> {
+    check_range("gregorian", absolute_date, &GREGORIAN_ABSOLUTE_RANGE)?;
+    return Ok(gregorian_from_absolute(absolute_date));
+}
@@ -1,6 +1,12 @@
 //! Functions converting from and to Hebrew calendar dates
 
-use crate::math::{floor_div, modulus, sum};
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    math::{clamp_to_range, floor_div, modulus},
+    weekday::{weekday_from_absolute, Weekday},
+};
 
 /// Hebrew month names
 pub static HEBREW_MONTH_NAMES: [&str; 14] = [
@@ -8,8 +14,21 @@ pub static HEBREW_MONTH_NAMES: [&str; 14] = [
     "Shevat", "Adar ", "Adar I", "Adar II",
 ];
 
+/// Range of years supported by the conversions of Hebrew dates.
+pub static HEBREW_YEAR_RANGE: RangeInclusive<i64> = -100_000_000_000..=100_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of Hebrew
+/// dates (the dates of the years in [`HEBREW_YEAR_RANGE`]).
+pub static HEBREW_ABSOLUTE_RANGE: RangeInclusive<i64> = -36_524_683_594_404..=36_524_680_847_165;
+
 /// Hebrew date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct Hebrew {
     pub year: i64,
     pub month: i64,
@@ -21,11 +40,39 @@ impl Hebrew {
     pub fn new(year: i64, month: i64, day: i64) -> Self {
         Self { year, month, day }
     }
+
+    /// Create a new Hebrew date, or return an error if the date does not exist
+    /// or its year lies outside [`HEBREW_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "hebrew",
+            "year",
+            self.year,
+            *HEBREW_YEAR_RANGE.start(),
+            *HEBREW_YEAR_RANGE.end(),
+        )?;
+        let last_month = last_month_of_hebrew_year(self.year);
+        check_component("hebrew", "month", self.month, 1, last_month)?;
+        let last_day = last_day_of_hebrew_month(self.month, self.year);
+        return check_component("hebrew", "day", self.day, 1, last_day);
+    }
 }
 
 /// Returns true if year is a Hebrew leap year.
 pub fn hebrew_leap_year(year: i64) -> bool {
-    return modulus((year * 7) + 1, 19) < 7;
+    return modulus((modulus(year, 19) * 7) + 1, 19) < 7;
 }
 
 /// Returns the last month of a given Hebrew year.
@@ -38,11 +85,17 @@ pub fn last_month_of_hebrew_year(year: i64) -> i64 {
 }
 
 /// Returns the day (number of days) of a given Hebrew month.
-fn last_day_of_hebrew_month(month: i64, year: i64) -> i64 {
+pub fn last_day_of_hebrew_month(month: i64, year: i64) -> i64 {
+    return hebrew_month_length(month, year, days_in_hebrew_year(year));
+}
+
+/// Returns the number of days of a given Hebrew month, given the number of
+/// days of its year (which determines the lengths of Heshvan and Kislev).
+fn hebrew_month_length(month: i64, year: i64, days_in_year: i64) -> i64 {
     if [2, 4, 6, 10, 13].contains(&month)
         || (month == 12 && !hebrew_leap_year(year))
-        || (month == 8 && !long_heshvan(year))
-        || (month == 9 && short_kislev(year))
+        || (month == 8 && modulus(days_in_year, 10) != 5)
+        || (month == 9 && modulus(days_in_year, 10) == 3)
     {
         return 29;
     } else {
@@ -50,13 +103,18 @@ fn last_day_of_hebrew_month(month: i64, year: i64) -> i64 {
     }
 }
 
-/// Computes the number of days elapsed from the Sunday prior to the start
-/// of the Hebrew calendar to the mean conjunction of Tishri of a given Hebrew
-/// year.
-fn hebrew_calendar_elapsed_days(year: i64) -> i64 {
-    let months_elapsed = 235 * floor_div(year - 1, 19)
+/// Computes the number of months elapsed from the start of the Hebrew
+/// calendar to Tishri of a given Hebrew year.
+fn hebrew_months_elapsed(year: i64) -> i64 {
+    return 235 * floor_div(year - 1, 19)
         + 12 * modulus(year - 1, 19)
         + floor_div(modulus(year - 1, 19) * 7 + 1, 19);
+}
+
+/// Computes the day (counted from the Sunday prior to the start of the Hebrew
+/// calendar) and the parts (counted from 6 pm of the previous evening) of the
+/// mean conjunction after a given number of elapsed months.
+fn molad_after_months(months_elapsed: i64) -> (i64, i64) {
     let parts_elapsed = 204 + (793 * modulus(months_elapsed, 1080));
     let hours_elapsed = 5
         + (12 * months_elapsed)
@@ -64,6 +122,14 @@ fn hebrew_calendar_elapsed_days(year: i64) -> i64 {
         + floor_div(parts_elapsed, 1080);
     let day = 1 + (29 * months_elapsed) + floor_div(hours_elapsed, 24);
     let parts = (1080 * modulus(hours_elapsed, 24)) + modulus(parts_elapsed, 1080);
+    return (day, parts);
+}
+
+/// Computes the number of days elapsed from the Sunday prior to the start
+/// of the Hebrew calendar to the mean conjunction of Tishri of a given Hebrew
+/// year.
+fn hebrew_calendar_elapsed_days(year: i64) -> i64 {
+    let (day, parts) = molad_after_months(hebrew_months_elapsed(year));
     let alternative_day = if (parts >= 19440)
         || (modulus(day, 7) == 2 && parts >= 9924 && !hebrew_leap_year(year))
         || (modulus(day, 7) == 1 && parts >= 16789 && hebrew_leap_year(year - 1))
@@ -79,8 +145,99 @@ fn hebrew_calendar_elapsed_days(year: i64) -> i64 {
     }
 }
 
+/// Mean conjunction (molad) of a Hebrew month
+///
+/// Following tradition, the hours of the molad are counted from 6 pm of the
+/// evening preceding its day (`absolute_date`), and divided into 1080 parts
+/// (chalakim).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Molad {
+    pub absolute_date: i64,
+    pub hours: i64,
+    pub chalakim: i64,
+}
+
+impl Molad {
+    /// Returns the day of the week of the molad.
+    pub fn weekday(&self) -> Weekday {
+        return weekday_from_absolute(self.absolute_date);
+    }
+}
+
+/// Computes the mean conjunction (molad) of a given month of a given Hebrew
+/// year.
+///
+/// Years outside [`HEBREW_YEAR_RANGE`] are clamped to the range, and months
+/// outside 1..=13 to the months of a leap year. In a common year, month 13
+/// (Adar II) is read as the month following Adar, so that the molad of
+/// Nisan (month 1) is returned; see [`checked_molad`] for an alternative
+/// returning an error instead.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     hebrew::{hebrew_new_year, molad},
+///     weekday::Weekday,
+/// };
+///
+/// // molad BaHaRaD: Monday, 5 hours, 204 parts
+/// let m = molad(7, 1);
+/// assert_eq!((m.weekday(), m.hours, m.chalakim), (Weekday::Monday, 5, 204));
+///
+/// // Rosh Hashanah is postponed by up to two days after the molad of Tishri
+/// let m = molad(7, 5785);
+/// assert!((0..=2).contains(&(hebrew_new_year(5785) - m.absolute_date)));
+///
+/// // the year is clamped to the supported range
+/// assert_eq!(molad(7, i64::MAX), molad(7, 100_000_000_000));
+/// ```
+pub fn molad(month: i64, year: i64) -> Molad {
+    let month = clamp_to_range(month, &(1..=13));
+    let year = clamp_to_range(year, &HEBREW_YEAR_RANGE);
+    let months_in_year = if month < 7 {
+        last_month_of_hebrew_year(year) - 7 + month
+    } else {
+        month - 7
+    };
+    let (day, parts) = molad_after_months(hebrew_months_elapsed(year) + months_in_year);
+    return Molad {
+        absolute_date: day - 1373428,
+        hours: parts / 1080,
+        chalakim: parts % 1080,
+    };
+}
+
+/// Computes the mean conjunction (molad) of a given month of a given Hebrew
+/// year, or returns an error if the year lies outside [`HEBREW_YEAR_RANGE`]
+/// or the month does not exist in the year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::hebrew::{checked_molad, molad};
+///
+/// // 5784 is a leap year, 5785 a common year
+/// assert_eq!(checked_molad(13, 5784), Ok(molad(13, 5784)));
+/// assert!(checked_molad(13, 5785).is_err());
+/// assert_eq!(molad(13, 5785), molad(1, 5785));
+/// assert!(checked_molad(0, 5785).is_err());
+/// assert!(checked_molad(7, i64::MAX).is_err());
+/// ```
+pub fn checked_molad(month: i64, year: i64) -> Result<Molad, CalendarError> {
+    check_component(
+        "hebrew",
+        "year",
+        year,
+        *HEBREW_YEAR_RANGE.start(),
+        *HEBREW_YEAR_RANGE.end(),
+    )?;
+    check_component("hebrew", "month", month, 1, last_month_of_hebrew_year(year))?;
+    return Ok(molad(month, year));
+}
+
 /// Computes the number of days in a given Hebrew year.
-fn days_in_hebrew_year(year: i64) -> i64 {
+pub fn days_in_hebrew_year(year: i64) -> i64 {
     return hebrew_calendar_elapsed_days(year + 1) - hebrew_calendar_elapsed_days(year);
 }
 
@@ -94,78 +251,276 @@ pub fn short_kislev(year: i64) -> bool {
     return modulus(days_in_hebrew_year(year), 10) == 3;
 }
 
+/// Computes the absolute (fixed) date of the Hebrew new year (1 Tishri) of a
+/// given Hebrew year.
+pub fn hebrew_new_year(year: i64) -> i64 {
+    return hebrew_calendar_elapsed_days(year) - 1373428;
+}
+
+/// Computes the number of days from 1 Tishri to the first day of a given
+/// month of a Hebrew year with a given number of days.
+fn days_before_hebrew_month(month: i64, year: i64, days_in_year: i64) -> i64 {
+    let length = |m| hebrew_month_length(m, year, days_in_year);
+    return if month < 7 {
+        (7..=last_month_of_hebrew_year(year))
+            .map(length)
+            .sum::<i64>()
+            + (1..month).map(length).sum::<i64>()
+    } else {
+        (7..month).map(length).sum()
+    };
+}
+
+/// Computes the absolute (fixed) date from a given Hebrew date, using a given
+/// function to compute the absolute dates of Hebrew new years.
+fn absolute_from_hebrew_with<F: Fn(i64) -> i64>(d: Hebrew, new_year: F) -> i64 {
+    let start = new_year(d.year);
+    let days_in_year = new_year(d.year + 1) - start;
+    return start + days_before_hebrew_month(d.month, d.year, days_in_year) + d.day - 1;
+}
+
+/// Computes the Hebrew date corresponding to a given absolute (fixed) date,
+/// starting the search for its year at `approx`, and using a given function
+/// to compute the absolute dates of Hebrew new years.
+fn hebrew_from_absolute_with<F: Fn(i64) -> i64>(
+    absolute_date: i64,
+    approx: i64,
+    new_year: F,
+) -> Hebrew {
+    let mut year = approx;
+    while absolute_date < new_year(year) {
+        year -= 1;
+    }
+    while absolute_date >= new_year(year + 1) {
+        year += 1;
+    }
+    let start = new_year(year);
+    let days_in_year = new_year(year + 1) - start;
+    let day_of_year = absolute_date - start;
+    let last_month = last_month_of_hebrew_year(year);
+    let mut month = 7;
+    let mut prior_days = 0;
+    loop {
+        let length = hebrew_month_length(month, year, days_in_year);
+        if day_of_year < prior_days + length {
+            break;
+        }
+        prior_days += length;
+        month = if month == last_month { 1 } else { month + 1 };
+    }
+    return Hebrew {
+        year,
+        month,
+        day: day_of_year - prior_days + 1,
+    };
+}
+
 /// Computes the absolute (fixed) date from a given Hebrew date.
+///
+/// The result is only meaningful for years in [`HEBREW_YEAR_RANGE`]; see
+/// [`checked_absolute_from_hebrew`].
 pub fn absolute_from_hebrew(d: Hebrew) -> i64 {
-    let year = d.year;
-    let month = d.month;
-    let day = d.day;
-    return day
-        + if month < 7 {
-            sum(
-                |m| last_day_of_hebrew_month(m as i64, year) as f64,
-                7,
-                |m| m as i64 <= last_month_of_hebrew_year(year),
-            ) as i64
-                + sum(
-                    |m| last_day_of_hebrew_month(m as i64, year) as f64,
-                    1,
-                    |m| (m as i64) < month,
-                ) as i64
-        } else {
-            sum(
-                |m| last_day_of_hebrew_month(m as i64, year) as f64,
-                7,
-                |m| (m as i64) < month,
-            ) as i64
-        }
-        + hebrew_calendar_elapsed_days(year)
-        - 1373429;
+    return absolute_from_hebrew_with(d, hebrew_new_year);
 }
 
-/// Computes the Hebrew date corresponding to a given absolute (fixed) date
+/// Computes the Hebrew date corresponding to a given absolute (fixed) date.
+///
+/// Absolute dates outside [`HEBREW_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_hebrew_from_absolute`] for a fallible alternative.
 pub fn hebrew_from_absolute(absolute_date: i64) -> Hebrew {
-    let approx = modulus(absolute_date + 1373429, 366);
-    let year = approx
-        + sum(
-            |_| 1.0,
-            approx,
-            |y| {
-                absolute_date
-                    >= absolute_from_hebrew(Hebrew {
-                        year: (y as i64) + 1,
-                        month: 7,
-                        day: 1,
-                    })
-            },
-        ) as i64;
-    let start = if absolute_date
-        < absolute_from_hebrew(Hebrew {
-            year,
-            month: 1,
-            day: 1,
-        }) {
-        7
-    } else {
-        1
+    let absolute_date = clamp_to_range(absolute_date, &HEBREW_ABSOLUTE_RANGE);
+    // the mean Hebrew year has 35975351/98496 days
+    let approx = floor_div((absolute_date + 1373429) * 98496, 35975351) + 1;
+    return hebrew_from_absolute_with(absolute_date, approx, hebrew_new_year);
+}
+
+/// Computes the absolute (fixed) date from a given Hebrew date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`HEBREW_YEAR_RANGE`].
+pub fn checked_absolute_from_hebrew(d: Hebrew) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_hebrew(d));
+}
+
+/// Computes the Hebrew date corresponding to a given absolute (fixed) date,
+/// or returns an error if the absolute date lies outside
+/// [`HEBREW_ABSOLUTE_RANGE`].
+pub fn checked_hebrew_from_absolute(absolute_date: i64) -> Result<Hebrew, CalendarError> {
+    check_range("hebrew", absolute_date, &HEBREW_ABSOLUTE_RANGE)?;
+    return Ok(hebrew_from_absolute(absolute_date));
+}
+
+/// Table of the absolute (fixed) dates of the Hebrew new years in a given
+/// range of years, speeding up bulk conversions of Hebrew dates within that
+/// range. Dates outside the range are converted as usual.
+///
+/// # Example
+///
+/// ```
+/// use calendars::hebrew::{hebrew_from_absolute, HebrewYearTable};
+///
+/// let table = HebrewYearTable::new(5700, 5800);
+/// for absolute_date in 730000..740000 {
+///     assert_eq!(
+///         table.hebrew_from_absolute(absolute_date),
+///         hebrew_from_absolute(absolute_date)
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HebrewYearTable {
+    first_year: i64,
+    new_years: Vec<i64>,
+}
+
+impl HebrewYearTable {
+    /// Create a new table for the Hebrew years from `first_year` to
+    /// `last_year` (inclusive).
+    pub fn new(first_year: i64, last_year: i64) -> Self {
+        let new_years = (first_year..=last_year + 1).map(hebrew_new_year).collect();
+        Self {
+            first_year,
+            new_years,
+        }
+    }
+
+    /// Returns the absolute (fixed) date of the new year of a given Hebrew
+    /// year.
+    fn new_year(&self, year: i64) -> i64 {
+        return match usize::try_from(year - self.first_year) {
+            Ok(i) if i < self.new_years.len() => self.new_years[i],
+            _ => hebrew_new_year(year),
+        };
+    }
+
+    /// Computes the absolute (fixed) date from a given Hebrew date.
+    pub fn absolute_from_hebrew(&self, d: Hebrew) -> i64 {
+        return absolute_from_hebrew_with(d, |year| self.new_year(year));
+    }
+
+    /// Computes the Hebrew date corresponding to a given absolute (fixed)
+    /// date.
+    pub fn hebrew_from_absolute(&self, absolute_date: i64) -> Hebrew {
+        // index of the first new year after the date
+        let i = self.new_years.partition_point(|n| *n <= absolute_date);
+        if i == 0 || i == self.new_years.len() {
+            return hebrew_from_absolute(absolute_date);
+        }
+        let approx = self.first_year + i as i64 - 1;
+        return hebrew_from_absolute_with(absolute_date, approx, |year| self.new_year(year));
+    }
+}
+
+/// Hebrew letters denoting the units 1 to 9
+static HEBREW_UNITS: [char; 9] = ['א', 'ב', 'ג', 'ד', 'ה', 'ו', 'ז', 'ח', 'ט'];
+
+/// Hebrew letters denoting the tens 10 to 90
+static HEBREW_TENS: [char; 9] = ['י', 'כ', 'ל', 'מ', 'נ', 'ס', 'ע', 'פ', 'צ'];
+
+/// Hebrew letters denoting the hundreds 100 to 400
+static HEBREW_HUNDREDS: [char; 4] = ['ק', 'ר', 'ש', 'ת'];
+
+/// Returns the letters of a number from 1 to 999 in Hebrew numerals.
+/// Hundreds beyond 400 are written with repeated tavs (e.g. 900 as תתק), 15
+/// and 16 are written as 9 + 6 and 9 + 7 to avoid spelling divine names, and
+/// the letters of 270, 275, 698, and 744 are rearranged, as is customary, to
+/// avoid spelling words of ill omen.
+fn hebrew_numeral_letters(number: i64) -> String {
+    match number {
+        270 => return "ער".to_string(),
+        275 => return "ערה".to_string(),
+        698 => return "תרחצ".to_string(),
+        744 => return "תשדמ".to_string(),
+        _ => {}
+    }
+    let mut letters = String::new();
+    let mut hundreds = number / 100;
+    while hundreds > 4 {
+        letters.push('ת');
+        hundreds -= 4;
+    }
+    if hundreds > 0 {
+        letters.push(HEBREW_HUNDREDS[hundreds as usize - 1]);
+    }
+    let (tens, units) = match number % 100 {
+        15 => (0, 15),
+        16 => (0, 16),
+        rest => (rest / 10, rest % 10),
+    };
+    if tens > 0 {
+        letters.push(HEBREW_TENS[tens as usize - 1]);
+    }
+    match units {
+        15 => letters.push_str("טו"),
+        16 => letters.push_str("טז"),
+        0 => {}
+        _ => letters.push(HEBREW_UNITS[units as usize - 1]),
+    }
+    return letters;
+}
+
+/// Punctuates the letters of a Hebrew numeral: a geresh (׳) follows a single
+/// letter, and gershayim (״) precede the last of several letters.
+fn punctuate_hebrew_numeral(letters: &str) -> String {
+    let mut chars: Vec<char> = letters.chars().collect();
+    match chars.len() {
+        0 => {}
+        1 => chars.push('׳'),
+        n => chars.insert(n - 1, '״'),
+    }
+    return chars.into_iter().collect();
+}
+
+/// Returns a given number in Hebrew numerals (gematria), or `None` if the
+/// number lies outside 1 to 999999. Thousands are written as a separate
+/// numeral followed by a geresh (e.g. 5784 as ה׳תשפ״ד); Hebrew years are
+/// commonly written without them (see [`hebrew_year_numeral`]).
+///
+/// # Example
+///
+/// ```
+/// use calendars::hebrew::{hebrew_numeral, hebrew_year_numeral};
+///
+/// assert_eq!(hebrew_numeral(1).as_deref(), Some("א׳"));
+/// assert_eq!(hebrew_numeral(15).as_deref(), Some("ט״ו"));
+/// assert_eq!(hebrew_numeral(16).as_deref(), Some("ט״ז"));
+/// assert_eq!(hebrew_numeral(30).as_deref(), Some("ל׳"));
+/// assert_eq!(hebrew_numeral(270).as_deref(), Some("ע״ר"));
+/// assert_eq!(hebrew_numeral(275).as_deref(), Some("ער״ה"));
+/// assert_eq!(hebrew_numeral(5784).as_deref(), Some("ה׳תשפ״ד"));
+/// assert_eq!(hebrew_numeral(0), None);
+/// assert_eq!(hebrew_year_numeral(5784).as_deref(), Some("תשפ״ד"));
+/// assert_eq!(hebrew_year_numeral(5744).as_deref(), Some("תשד״מ"));
+/// assert_eq!(hebrew_year_numeral(5698).as_deref(), Some("תרח״צ"));
+/// assert_eq!(hebrew_year_numeral(5800).as_deref(), Some("ת״ת"));
+/// assert_eq!(hebrew_year_numeral(6000).as_deref(), Some("ו׳"));
+/// ```
+pub fn hebrew_numeral(number: i64) -> Option<String> {
+    if !(1..=999_999).contains(&number) {
+        return None;
+    }
+    let (thousands, rest) = (number / 1000, number % 1000);
+    let mut numeral = String::new();
+    if thousands > 0 {
+        numeral.push_str(&punctuate_hebrew_numeral(&hebrew_numeral_letters(
+            thousands,
+        )));
+    }
+    if rest > 0 {
+        numeral.push_str(&punctuate_hebrew_numeral(&hebrew_numeral_letters(rest)));
+    }
+    return Some(numeral);
+}
+
+/// Returns a given Hebrew year in Hebrew numerals, omitting the thousands
+/// unless the year is a multiple of 1000, or `None` if the year lies outside
+/// 1 to 999999.
+pub fn hebrew_year_numeral(year: i64) -> Option<String> {
+    if !(1..=999_999).contains(&year) {
+        return None;
+    }
+    return match year % 1000 {
+        0 => hebrew_numeral(year),
+        rest => hebrew_numeral(rest),
     };
-    let month = start
-        + sum(
-            |_| 1.0,
-            start,
-            |m| {
-                absolute_date
-                    > absolute_from_hebrew(Hebrew {
-                        year,
-                        month: m as i64,
-                        day: last_day_of_hebrew_month(m as i64, year),
-                    })
-            },
-        ) as i64;
-    let day = absolute_date
-        - (absolute_from_hebrew(Hebrew {
-            year,
-            month,
-            day: 1,
-        }) - 1);
-    return Hebrew { year, month, day };
 }
@@ -9,6 +9,7 @@ use crate::{
     iso::Iso,
     julian::Julian,
     mayan::{MayanHaab, MayanLongCount, MayanTzolkin},
+    ordinal::Ordinal,
 };
 
 pub fn gregorian_from_slice(s: [i64; 3]) -> Gregorian {
@@ -27,6 +28,13 @@ pub fn iso_from_slice(s: [i64; 3]) -> Iso {
     };
 }
 
+pub fn ordinal_from_slice(s: [i64; 2]) -> Ordinal {
+    return Ordinal {
+        year: s[0],
+        day: s[1],
+    };
+}
+
 pub fn julian_from_slice(s: [i64; 3]) -> Julian {
     return Julian {
         year: s[0],
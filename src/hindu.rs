@@ -1,6 +1,13 @@
-//! Functions to convert from and to Old Hindu calendar dates
+//! Functions to convert from and to Old Hindu calendar dates, and to compute
+//! the tithi, nakshatra, yoga, and karana of a day (see [`panchang`])
 
-use crate::math::{amod, modulus, sum};
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    math::{amod, clamp_to_range, modulus},
+    weekday::{weekday_from_absolute, Weekday},
+};
 
 /// Hindu solar month names
 pub static HINDU_SOLAR_MONTH_NAMES: [&str; 12] = [
@@ -34,13 +41,44 @@ pub static HINDU_LUNAR_MONTH_NAMES: [&str; 12] = [
     "Phalguna",
 ];
 
-static SOLAR_SIDEREAL_YEAR: f64 = 365.0 + (279457.0 / 1080000.);
-static SOLAR_MONTH: f64 = SOLAR_SIDEREAL_YEAR / 12.0;
-static LUNAR_SIDEREAL_MONTH: f64 = 27.0 + (4644439.0 / 14438334.0);
-static LUNAR_SYNODIC_MONTH: f64 = 29.0 + (7087771.0 / 13358334.0);
+/// Prefix of the names of Hindu lunar leap months (adhika masa)
+pub static HINDU_LEAP_MONTH_PREFIX: &str = "Adhika";
+
+// The Old Hindu calendars count 394479457 days, 1080000 sidereal years,
+// 14438334 sidereal months, and 13358334 synodic months in a quarter of a
+// mahayuga, so the mean lengths of years and months are exact rationals.
+static YUGA_DAYS: i128 = 394479457;
+static YUGA_SOLAR_YEARS: i128 = 1080000;
+static YUGA_LUNAR_SIDEREAL_MONTHS: i128 = 14438334;
+static YUGA_LUNAR_SYNODIC_MONTHS: i128 = 13358334;
+
+static SOLAR_SIDEREAL_YEAR_F64: f64 = 365.0 + (279457.0 / 1080000.);
+static SOLAR_MONTH_F64: f64 = SOLAR_SIDEREAL_YEAR_F64 / 12.0;
+static LUNAR_SIDEREAL_MONTH_F64: f64 = 27.0 + (4644439.0 / 14438334.0);
+static LUNAR_SYNODIC_MONTH_F64: f64 = 29.0 + (7087771.0 / 13358334.0);
+
+/// Range of years supported by the conversions of Old Hindu solar and lunar
+/// dates.
+pub static OLD_HINDU_YEAR_RANGE: RangeInclusive<i64> = -1_000_000_000_000..=1_000_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of Old Hindu
+/// solar dates (the dates of the years in [`OLD_HINDU_YEAR_RANGE`]).
+pub static OLD_HINDU_SOLAR_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    -365_258_757_614_440..=365_258_755_348_887;
+
+/// Range of absolute (fixed) dates supported by the conversions of Old Hindu
+/// lunar dates (the dates of the years in [`OLD_HINDU_YEAR_RANGE`]).
+pub static OLD_HINDU_LUNAR_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    -365_258_757_614_447..=365_258_755_348_883;
 
 /// Old Hindu Solar date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct OldHinduSolar {
     pub year: i64,
     pub month: i64,
@@ -52,42 +90,185 @@ impl OldHinduSolar {
     pub fn new(year: i64, month: i64, day: i64) -> Self {
         Self { year, month, day }
     }
+
+    /// Create a new Old Hindu Solar date, or return an error if the date does
+    /// not exist or its year lies outside [`OLD_HINDU_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "oldHinduSolar",
+            "year",
+            self.year,
+            *OLD_HINDU_YEAR_RANGE.start(),
+            *OLD_HINDU_YEAR_RANGE.end(),
+        )?;
+        check_component("oldHinduSolar", "month", self.month, 1, 12)?;
+        check_component("oldHinduSolar", "day", self.day, 1, 31)?;
+        // solar months have a fractional number of days, so the last day of a
+        // month is determined by the round trip through the absolute date
+        let d = old_hindu_solar_from_absolute(absolute_from_old_hindu_solar(*self));
+        return if d == *self {
+            Ok(())
+        } else {
+            Err(CalendarError::InvalidComponent {
+                calendar: "oldHinduSolar".to_string(),
+                component: "day".to_string(),
+                value: self.day,
+            })
+        };
+    }
 }
 
-/// Returns the position of the sun (in degrees) for a given moment (day and
-/// fraction of a day).
-fn solar_longitude(days: f64) -> f64 {
-    return modulus(days / SOLAR_SIDEREAL_YEAR, 1.0) * 360.0;
+/// Moment (day and fraction of a day), given as an exact fraction of days
+#[derive(Debug, Copy, Clone)]
+struct Moment {
+    numerator: i128,
+    denominator: i128,
 }
 
-/// Returns the zodiacal sign for a given moment (day and fraction of day).
-fn zodiac(days: f64) -> i64 {
-    return ((solar_longitude(days) / 30.0).floor() + 1.0) as i64;
+/// Returns the moment of sunrise (a quarter of a day after midnight) of a
+/// given day.
+fn sunrise(days: i64) -> Moment {
+    return Moment {
+        numerator: 4 * days as i128 + 1,
+        denominator: 4,
+    };
+}
+
+/// Returns the number of completed revolutions at a given moment of a body
+/// making a given number of revolutions per (quarter) yuga, together with
+/// the fraction of the current revolution (as a numerator and denominator).
+fn revolutions(moment: Moment, per_yuga: i128) -> (i64, i128, i128) {
+    let numerator = moment.numerator * per_yuga;
+    let denominator = moment.denominator * YUGA_DAYS;
+    return (
+        numerator.div_euclid(denominator) as i64,
+        numerator.rem_euclid(denominator),
+        denominator,
+    );
+}
+
+/// Returns the zodiacal sign for a given moment.
+fn zodiac(moment: Moment) -> i64 {
+    // the sun passes through the 12 signs once per sidereal year
+    let (signs, _, _) = revolutions(moment, 12 * YUGA_SOLAR_YEARS);
+    return modulus(signs, 12) + 1;
 }
 
 /// Computes the Old Hindu solar date corresponding to a given absolute
 /// (fixed) date.
+///
+/// Absolute dates outside [`OLD_HINDU_SOLAR_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_old_hindu_solar_from_absolute`] for a fallible alternative.
 pub fn old_hindu_solar_from_absolute(absolute_date: i64) -> OldHinduSolar {
-    let h_date = absolute_date as f64 + 1132959.0 + (1.0 / 4.0);
-    let year = (h_date / SOLAR_SIDEREAL_YEAR).floor() as i64;
+    let absolute_date = clamp_to_range(absolute_date, &OLD_HINDU_SOLAR_ABSOLUTE_RANGE);
+    let h_date = sunrise(absolute_date + 1132959);
+    let (year, _, _) = revolutions(h_date, YUGA_SOLAR_YEARS);
     let month = zodiac(h_date);
-    let day = (modulus(h_date, SOLAR_MONTH).floor() + 1.0) as i64;
+    // the time elapsed since the beginning of the solar month is the
+    // fraction of the current zodiacal sign times the length of a solar month
+    let (_, fraction, _) = revolutions(h_date, 12 * YUGA_SOLAR_YEARS);
+    let day = fraction.div_euclid(h_date.denominator * 12 * YUGA_SOLAR_YEARS) as i64 + 1;
     return OldHinduSolar { year, month, day };
 }
 
 /// Returns the absolute (fixed) date from a given Old Hindu solar date.
+///
+/// The result is only meaningful for years in [`OLD_HINDU_YEAR_RANGE`]; see
+/// [`checked_absolute_from_old_hindu_solar`].
 pub fn absolute_from_old_hindu_solar(d: OldHinduSolar) -> i64 {
+    // floor(year * SOLAR_SIDEREAL_YEAR + (month - 1) * SOLAR_MONTH + day - 1/4)
+    let solar_months = 12 * d.year as i128 + (d.month - 1) as i128;
+    let days = 4 * solar_months * YUGA_DAYS + (4 * d.day as i128 - 1) * 12 * YUGA_SOLAR_YEARS;
+    return days.div_euclid(48 * YUGA_SOLAR_YEARS) as i64 - 1132959;
+}
+
+/// Computes the absolute (fixed) date from a given Old Hindu solar date, or
+/// returns an error if the date does not exist or its year lies outside
+/// [`OLD_HINDU_YEAR_RANGE`].
+pub fn checked_absolute_from_old_hindu_solar(d: OldHinduSolar) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_old_hindu_solar(d));
+}
+
+/// Computes the Old Hindu solar date corresponding to a given absolute
+/// (fixed) date, or returns an error if the absolute date lies outside
+/// [`OLD_HINDU_SOLAR_ABSOLUTE_RANGE`].
+pub fn checked_old_hindu_solar_from_absolute(
+    absolute_date: i64,
+) -> Result<OldHinduSolar, CalendarError> {
+    check_range(
+        "oldHinduSolar",
+        absolute_date,
+        &OLD_HINDU_SOLAR_ABSOLUTE_RANGE,
+    )?;
+    return Ok(old_hindu_solar_from_absolute(absolute_date));
+}
+
+/// Returns the position of the sun (in degrees) for a given moment, using
+/// floating point arithmetic. This is the mean sidereal longitude of the Old
+/// Hindu calendars; see [`crate::astro::solar_longitude`] for the true one.
+fn solar_longitude_f64(days: f64) -> f64 {
+    return modulus(days / SOLAR_SIDEREAL_YEAR_F64, 1.0) * 360.0;
+}
+
+/// Returns the zodiacal sign for a given moment, using floating point
+/// arithmetic.
+fn zodiac_f64(days: f64) -> i64 {
+    return ((solar_longitude_f64(days) / 30.0).floor() + 1.0) as i64;
+}
+
+/// Computes the Old Hindu solar date corresponding to a given absolute
+/// (fixed) date, using floating point arithmetic.
+///
+/// Unlike [`old_hindu_solar_from_absolute`], this approximates the mean year
+/// and month lengths, so rounding errors may shift the result by a day for
+/// dates close to the beginning of a month.
+pub fn old_hindu_solar_from_absolute_f64(absolute_date: i64) -> OldHinduSolar {
+    let h_date = absolute_date as f64 + 1132959.0 + (1.0 / 4.0);
+    let year = (h_date / SOLAR_SIDEREAL_YEAR_F64).floor() as i64;
+    let month = zodiac_f64(h_date);
+    let day = (modulus(h_date, SOLAR_MONTH_F64).floor() + 1.0) as i64;
+    return OldHinduSolar { year, month, day };
+}
+
+/// Returns the absolute (fixed) date from a given Old Hindu solar date, using
+/// floating point arithmetic.
+///
+/// Unlike [`absolute_from_old_hindu_solar`], this approximates the mean year
+/// and month lengths, so rounding errors may shift the result by a day for
+/// dates close to the beginning of a month.
+pub fn absolute_from_old_hindu_solar_f64(d: OldHinduSolar) -> i64 {
     let year = d.year;
     let month = d.month;
     let day = d.day;
-    return ((year as f64 * SOLAR_SIDEREAL_YEAR) + ((month - 1) as f64 * SOLAR_MONTH) + day as f64
+    return ((year as f64 * SOLAR_SIDEREAL_YEAR_F64)
+        + ((month - 1) as f64 * SOLAR_MONTH_F64)
+        + day as f64
         - (1.0 / 4.0)
         - 1132959.0)
         .floor() as i64;
 }
 
 /// Old Hindu Lunar date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct OldHinduLunar {
     pub year: i64,
     pub month: i64,
@@ -105,39 +286,137 @@ impl OldHinduLunar {
             day,
         }
     }
-}
 
-/// Returns the sidereal longitude of the moon (in degrees) at a given moment
-/// (date and fraction of a day).
-fn lunar_longitude(days: f64) -> f64 {
-    return modulus(days / LUNAR_SIDEREAL_MONTH, 1.0) * 360.0;
+    /// Create a new Old Hindu Lunar date, or return an error if the date does
+    /// not exist or its year lies outside [`OLD_HINDU_YEAR_RANGE`].
+    pub fn try_new(
+        year: i64,
+        month: i64,
+        leap_month: bool,
+        day: i64,
+    ) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, leap_month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "oldHinduLunar",
+            "year",
+            self.year,
+            *OLD_HINDU_YEAR_RANGE.start(),
+            *OLD_HINDU_YEAR_RANGE.end(),
+        )?;
+        check_component("oldHinduLunar", "month", self.month, 1, 12)?;
+        check_component("oldHinduLunar", "day", self.day, 1, 30)?;
+        // not every combination of month, leap month, and lunar day occurs
+        return match absolute_from_old_hindu_lunar(*self) {
+            Some(_) => Ok(()),
+            None => Err(CalendarError::InvalidComponent {
+                calendar: "oldHinduLunar".to_string(),
+                component: "day".to_string(),
+                value: self.day,
+            }),
+        };
+    }
 }
 
-/// Computes the lunar phase of the moon for a given moment (date and fraction
-/// of a day).
-fn lunar_phase(days: f64) -> i64 {
-    return (1.0 + (modulus(lunar_longitude(days) - solar_longitude(days), 360.0) / 12.0).floor())
-        as i64;
+/// Computes the lunar phase of the moon for a given moment.
+fn lunar_phase(moment: Moment) -> i64 {
+    // the elongation of the moon from the sun (lunar minus solar longitude)
+    // advances by one revolution per sidereal month and falls back by one
+    // per sidereal year
+    let (_, fraction, denominator) =
+        revolutions(moment, YUGA_LUNAR_SIDEREAL_MONTHS - YUGA_SOLAR_YEARS);
+    return 1 + (30 * fraction).div_euclid(denominator) as i64;
 }
 
-/// Determines the time of the most recent new moon for a given moment (date
-///  and fraction of day).
-fn new_moon(days: f64) -> f64 {
-    return days - modulus(days, LUNAR_SYNODIC_MONTH);
+/// Returns the moment of the n-th new moon (counted from the Hindu epoch).
+fn nth_new_moon(n: i64) -> Moment {
+    return Moment {
+        numerator: n as i128 * YUGA_DAYS,
+        denominator: YUGA_LUNAR_SYNODIC_MONTHS,
+    };
 }
 
 /// Computes the Old Hindu lunar date corresponding to a given absolute (fixed)
 /// date.
+///
+/// Absolute dates outside [`OLD_HINDU_LUNAR_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_old_hindu_lunar_from_absolute`] for a fallible alternative.
 pub fn old_hindu_lunar_from_absolute(absolute_date: i64) -> OldHinduLunar {
-    let h_date = absolute_date + 1132959;
-    let sunrise = h_date as f64 + (1.0 / 4.0);
-    let last_new_moon = new_moon(sunrise);
-    let next_new_moon = last_new_moon + LUNAR_SYNODIC_MONTH;
+    let absolute_date = clamp_to_range(absolute_date, &OLD_HINDU_LUNAR_ABSOLUTE_RANGE);
+    let sunrise = sunrise(absolute_date + 1132959);
+    // number of new moons since the Hindu epoch
+    let (months, _, _) = revolutions(sunrise, YUGA_LUNAR_SYNODIC_MONTHS);
+    let last_new_moon = nth_new_moon(months);
+    let next_new_moon = nth_new_moon(months + 1);
     let day = lunar_phase(sunrise);
     let month = amod(zodiac(last_new_moon) + 1, 12);
     let leap_month = zodiac(last_new_moon) == zodiac(next_new_moon);
-    let next_month = next_new_moon + if leap_month { LUNAR_SYNODIC_MONTH } else { 0.0 };
-    let year = (next_month / SOLAR_SIDEREAL_YEAR).floor() as i64;
+    let next_month = if leap_month {
+        nth_new_moon(months + 2)
+    } else {
+        next_new_moon
+    };
+    let (year, _, _) = revolutions(next_month, YUGA_SOLAR_YEARS);
+    return OldHinduLunar {
+        year,
+        month,
+        leap_month,
+        day,
+    };
+}
+
+/// Returns the sidereal longitude of the moon (in degrees) at a given moment,
+/// using floating point arithmetic. This is the mean longitude of the Old
+/// Hindu calendars; see [`crate::astro::lunar_longitude`] for the true one.
+fn lunar_longitude_f64(days: f64) -> f64 {
+    return modulus(days / LUNAR_SIDEREAL_MONTH_F64, 1.0) * 360.0;
+}
+
+/// Computes the lunar phase of the moon for a given moment, using floating
+/// point arithmetic.
+fn lunar_phase_f64(days: f64) -> i64 {
+    return (1.0
+        + (modulus(lunar_longitude_f64(days) - solar_longitude_f64(days), 360.0) / 12.0).floor())
+        as i64;
+}
+
+/// Determines the time of the most recent new moon for a given moment, using
+/// floating point arithmetic.
+fn new_moon_f64(days: f64) -> f64 {
+    return days - modulus(days, LUNAR_SYNODIC_MONTH_F64);
+}
+
+/// Computes the Old Hindu lunar date corresponding to a given absolute (fixed)
+/// date, using floating point arithmetic.
+///
+/// Unlike [`old_hindu_lunar_from_absolute`], this approximates the mean year
+/// and month lengths, so rounding errors may shift the result by a day for
+/// dates close to the beginning of a lunar day.
+pub fn old_hindu_lunar_from_absolute_f64(absolute_date: i64) -> OldHinduLunar {
+    let h_date = absolute_date + 1132959;
+    let sunrise = h_date as f64 + (1.0 / 4.0);
+    let last_new_moon = new_moon_f64(sunrise);
+    let next_new_moon = last_new_moon + LUNAR_SYNODIC_MONTH_F64;
+    let day = lunar_phase_f64(sunrise);
+    let month = amod(zodiac_f64(last_new_moon) + 1, 12);
+    let leap_month = zodiac_f64(last_new_moon) == zodiac_f64(next_new_moon);
+    let next_month = next_new_moon
+        + if leap_month {
+            LUNAR_SYNODIC_MONTH_F64
+        } else {
+            0.0
+        };
+    let year = (next_month / SOLAR_SIDEREAL_YEAR_F64).floor() as i64;
     return OldHinduLunar {
         year,
         month,
@@ -148,7 +427,7 @@ pub fn old_hindu_lunar_from_absolute(absolute_date: i64) -> OldHinduLunar {
 
 /// Returns true if a given Hindu lunar date d1 precedes (i.e. is smaller
 /// than) a given Hindu lunar date d2, and false otherwise.
-fn old_hindu_lunar_precedes(d1: OldHinduLunar, d2: OldHinduLunar) -> bool {
+pub fn old_hindu_lunar_precedes(d1: OldHinduLunar, d2: OldHinduLunar) -> bool {
     let year_1 = d1.year;
     let year_2 = d2.year;
     let month_1 = d1.month;
@@ -166,22 +445,396 @@ fn old_hindu_lunar_precedes(d1: OldHinduLunar, d2: OldHinduLunar) -> bool {
 }
 
 /// Returns the absolute (fixed) date corresponding to a given Old Hindu lunar
-/// date.
-pub fn absolute_from_old_hindu_lunar(d: OldHinduLunar) -> Option<i64> {
+/// date, using a given function to compute Old Hindu lunar dates.
+fn absolute_from_old_hindu_lunar_with<F: Fn(i64) -> OldHinduLunar>(
+    d: OldHinduLunar,
+    from_absolute: F,
+) -> Option<i64> {
     let years = d.year;
     let months = d.month - 2;
-    let approx = (years as f64 * SOLAR_SIDEREAL_YEAR).floor() as i64
-        + (months as f64 * LUNAR_SYNODIC_MONTH).floor() as i64
+    // floor(years * SOLAR_SIDEREAL_YEAR) + floor(months * LUNAR_SYNODIC_MONTH)
+    let approx = (years as i128 * YUGA_DAYS).div_euclid(YUGA_SOLAR_YEARS) as i64
+        + (months as i128 * YUGA_DAYS).div_euclid(YUGA_LUNAR_SYNODIC_MONTHS) as i64
         - 1132959;
-    let try_value = approx
-        + sum(
-            |_| 1.0,
-            approx,
-            |i| old_hindu_lunar_precedes(old_hindu_lunar_from_absolute(i as i64), d),
-        ) as i64;
-    return if old_hindu_lunar_from_absolute(try_value) == d {
+    // find the first day on or after approx whose date does not precede d,
+    // by doubling an upper bound and bisecting (the dates of successive days
+    // never decrease)
+    let precedes = |i: i64| old_hindu_lunar_precedes(from_absolute(i), d);
+    let mut low = approx;
+    let mut step = 32;
+    while precedes(low + step - 1) {
+        low += step;
+        step *= 2;
+    }
+    let mut high = low + step - 1;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if precedes(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    let try_value = low;
+    return if from_absolute(try_value) == d {
         Some(try_value)
     } else {
         None
     };
 }
+
+/// Returns the absolute (fixed) date corresponding to a given Old Hindu lunar
+/// date.
+///
+/// The result is only meaningful for years in [`OLD_HINDU_YEAR_RANGE`]; see
+/// [`checked_absolute_from_old_hindu_lunar`].
+pub fn absolute_from_old_hindu_lunar(d: OldHinduLunar) -> Option<i64> {
+    return absolute_from_old_hindu_lunar_with(d, old_hindu_lunar_from_absolute);
+}
+
+/// Returns the absolute (fixed) date corresponding to a given Old Hindu lunar
+/// date, using floating point arithmetic.
+///
+/// Unlike [`absolute_from_old_hindu_lunar`], this approximates the mean year
+/// and month lengths, so rounding errors may shift the result by a day for
+/// dates close to the beginning of a lunar day.
+pub fn absolute_from_old_hindu_lunar_f64(d: OldHinduLunar) -> Option<i64> {
+    return absolute_from_old_hindu_lunar_with(d, old_hindu_lunar_from_absolute_f64);
+}
+
+/// Returns the absolute (fixed) date corresponding to a given Old Hindu lunar
+/// date, or returns an error if the date does not exist or its year lies
+/// outside [`OLD_HINDU_YEAR_RANGE`].
+pub fn checked_absolute_from_old_hindu_lunar(d: OldHinduLunar) -> Result<i64, CalendarError> {
+    check_component(
+        "oldHinduLunar",
+        "year",
+        d.year,
+        *OLD_HINDU_YEAR_RANGE.start(),
+        *OLD_HINDU_YEAR_RANGE.end(),
+    )?;
+    check_component("oldHinduLunar", "month", d.month, 1, 12)?;
+    check_component("oldHinduLunar", "day", d.day, 1, 30)?;
+    return absolute_from_old_hindu_lunar(d).ok_or(CalendarError::NotConvertible {
+        calendar: "oldHinduLunar".to_string(),
+    });
+}
+
+/// Computes the Old Hindu lunar date corresponding to a given absolute (fixed)
+/// date, or returns an error if the absolute date lies outside
+/// [`OLD_HINDU_LUNAR_ABSOLUTE_RANGE`].
+pub fn checked_old_hindu_lunar_from_absolute(
+    absolute_date: i64,
+) -> Result<OldHinduLunar, CalendarError> {
+    check_range(
+        "oldHinduLunar",
+        absolute_date,
+        &OLD_HINDU_LUNAR_ABSOLUTE_RANGE,
+    )?;
+    return Ok(old_hindu_lunar_from_absolute(absolute_date));
+}
+
+// Elements of the panchang (tithi, nakshatra, yoga, karana)
+
+/// Names of the lunar days (tithis) of the bright (Shukla, 1 to 15) and dark
+/// (Krishna, 16 to 30) fortnights
+pub static HINDU_TITHI_NAMES: [&str; 30] = [
+    "Pratipada",
+    "Dvitiya",
+    "Tritiya",
+    "Chaturthi",
+    "Panchami",
+    "Shashthi",
+    "Saptami",
+    "Ashtami",
+    "Navami",
+    "Dashami",
+    "Ekadashi",
+    "Dvadashi",
+    "Trayodashi",
+    "Chaturdashi",
+    "Purnima",
+    "Pratipada",
+    "Dvitiya",
+    "Tritiya",
+    "Chaturthi",
+    "Panchami",
+    "Shashthi",
+    "Saptami",
+    "Ashtami",
+    "Navami",
+    "Dashami",
+    "Ekadashi",
+    "Dvadashi",
+    "Trayodashi",
+    "Chaturdashi",
+    "Amavasya",
+];
+
+/// Names of the lunar mansions (nakshatras)
+pub static HINDU_NAKSHATRA_NAMES: [&str; 27] = [
+    "Ashvini",
+    "Bharani",
+    "Krittika",
+    "Rohini",
+    "Mrigashira",
+    "Ardra",
+    "Punarvasu",
+    "Pushya",
+    "Ashlesha",
+    "Magha",
+    "Purva Phalguni",
+    "Uttara Phalguni",
+    "Hasta",
+    "Chitra",
+    "Svati",
+    "Vishakha",
+    "Anuradha",
+    "Jyeshtha",
+    "Mula",
+    "Purva Ashadha",
+    "Uttara Ashadha",
+    "Shravana",
+    "Dhanishtha",
+    "Shatabhisha",
+    "Purva Bhadrapada",
+    "Uttara Bhadrapada",
+    "Revati",
+];
+
+/// Names of the yogas
+pub static HINDU_YOGA_NAMES: [&str; 27] = [
+    "Vishkambha",
+    "Priti",
+    "Ayushman",
+    "Saubhagya",
+    "Shobhana",
+    "Atiganda",
+    "Sukarma",
+    "Dhriti",
+    "Shula",
+    "Ganda",
+    "Vriddhi",
+    "Dhruva",
+    "Vyaghata",
+    "Harshana",
+    "Vajra",
+    "Siddhi",
+    "Vyatipata",
+    "Variyana",
+    "Parigha",
+    "Shiva",
+    "Siddha",
+    "Sadhya",
+    "Shubha",
+    "Shukla",
+    "Brahma",
+    "Indra",
+    "Vaidhriti",
+];
+
+/// Names of the karanas: the 7 movable karanas, which repeat eight times
+/// from the second half of the first tithi, followed by the 4 fixed ones
+pub static HINDU_KARANA_NAMES: [&str; 11] = [
+    "Bava",
+    "Balava",
+    "Kaulava",
+    "Taitila",
+    "Garaja",
+    "Vanija",
+    "Vishti",
+    "Shakuni",
+    "Chatushpada",
+    "Naga",
+    "Kimstughna",
+];
+
+/// Returns the number (from 1 to `parts`) of the equal part of a revolution
+/// that a body making a given number of revolutions per yuga has reached at
+/// sunrise of a given absolute (fixed) date, clamping the date to
+/// [`OLD_HINDU_LUNAR_ABSOLUTE_RANGE`].
+fn part_at_sunrise(absolute_date: i64, per_yuga: i128, parts: i128) -> i64 {
+    let absolute_date = clamp_to_range(absolute_date, &OLD_HINDU_LUNAR_ABSOLUTE_RANGE);
+    let (_, fraction, denominator) = revolutions(sunrise(absolute_date + 1132959), per_yuga);
+    return 1 + (parts * fraction).div_euclid(denominator) as i64;
+}
+
+/// Returns the lunar day (tithi, from 1 to 30) at sunrise of a given absolute
+/// (fixed) date: the number of the 12° segment the moon has moved away from
+/// the sun, following the mean motions of the Old Hindu calendars. Tithis 1
+/// to 15 form the bright fortnight, 16 to 30 the dark one (see
+/// [`HINDU_TITHI_NAMES`]). This function, as well as [`nakshatra`],
+/// [`yoga`], and [`karana`], clamps absolute dates to
+/// [`OLD_HINDU_LUNAR_ABSOLUTE_RANGE`].
+///
+/// # Example
+///
+/// ```
+/// use calendars::hindu::{
+///     karana, karana_name, nakshatra, old_hindu_lunar_from_absolute, tithi, yoga,
+///     HINDU_NAKSHATRA_NAMES, HINDU_TITHI_NAMES, HINDU_YOGA_NAMES,
+/// };
+///
+/// let date = 739007; // 1 May 2024
+/// assert_eq!(tithi(date), old_hindu_lunar_from_absolute(date).day);
+/// assert_eq!(HINDU_TITHI_NAMES[(tithi(date) - 1) as usize], "Ashtami");
+/// assert_eq!(HINDU_NAKSHATRA_NAMES[(nakshatra(date) - 1) as usize], "Shravana");
+/// assert_eq!(HINDU_YOGA_NAMES[(yoga(date) - 1) as usize], "Shubha");
+/// assert_eq!(karana_name(karana(date)), "Kaulava");
+///
+/// let last = *calendars::hindu::OLD_HINDU_LUNAR_ABSOLUTE_RANGE.end();
+/// assert_eq!(tithi(i64::MAX), tithi(last));
+/// assert_eq!(karana(i64::MIN), karana(-365_258_757_614_447));
+/// ```
+pub fn tithi(absolute_date: i64) -> i64 {
+    // the elongation of the moon advances by one revolution per synodic month
+    return part_at_sunrise(
+        absolute_date,
+        YUGA_LUNAR_SIDEREAL_MONTHS - YUGA_SOLAR_YEARS,
+        30,
+    );
+}
+
+/// Returns the lunar mansion (nakshatra, from 1 to 27) at sunrise of a given
+/// absolute (fixed) date: the number of the 13°20' segment of the sidereal
+/// zodiac the moon is in (see [`HINDU_NAKSHATRA_NAMES`]).
+pub fn nakshatra(absolute_date: i64) -> i64 {
+    return part_at_sunrise(absolute_date, YUGA_LUNAR_SIDEREAL_MONTHS, 27);
+}
+
+/// Returns the yoga (from 1 to 27) at sunrise of a given absolute (fixed)
+/// date: the number of the 13°20' segment reached by the sum of the sidereal
+/// longitudes of the sun and the moon (see [`HINDU_YOGA_NAMES`]).
+pub fn yoga(absolute_date: i64) -> i64 {
+    return part_at_sunrise(
+        absolute_date,
+        YUGA_LUNAR_SIDEREAL_MONTHS + YUGA_SOLAR_YEARS,
+        27,
+    );
+}
+
+/// Returns the karana (half of a tithi, from 1 to 60) at sunrise of a given
+/// absolute (fixed) date; see [`karana_name`].
+pub fn karana(absolute_date: i64) -> i64 {
+    return part_at_sunrise(
+        absolute_date,
+        YUGA_LUNAR_SIDEREAL_MONTHS - YUGA_SOLAR_YEARS,
+        60,
+    );
+}
+
+/// Returns the name of a given karana (from 1 to 60): Kimstughna for the
+/// first, the 7 movable karanas in turn for the 2nd to the 57th, and
+/// Shakuni, Chatushpada and Naga for the last three.
+pub fn karana_name(karana: i64) -> &'static str {
+    return match amod(karana, 60) {
+        1 => HINDU_KARANA_NAMES[10],
+        k @ 2..=57 => HINDU_KARANA_NAMES[((k - 2) % 7) as usize],
+        k => HINDU_KARANA_NAMES[(k - 51) as usize],
+    };
+}
+
+/// Names of the days of the week (varas), beginning with Sunday
+pub static HINDU_WEEKDAY_NAMES: [&str; 7] = [
+    "Ravivara",
+    "Somavara",
+    "Mangalavara",
+    "Budhavara",
+    "Guruvara",
+    "Shukravara",
+    "Shanivara",
+];
+
+/// Summary of a day in the Hindu almanac (panchang), following the Old Hindu
+/// calendars
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Panchang {
+    pub solar: OldHinduSolar,
+    pub lunar: OldHinduLunar,
+    /// Lunar day (from 1 to 30), see [`tithi`]
+    pub tithi: i64,
+    /// Lunar mansion (from 1 to 27), see [`nakshatra`]
+    pub nakshatra: i64,
+    /// Yoga (from 1 to 27), see [`yoga`]
+    pub yoga: i64,
+    /// Half lunar day (from 1 to 60), see [`karana`]
+    pub karana: i64,
+    /// Day of the week
+    pub vara: Weekday,
+}
+
+impl Panchang {
+    /// Returns the name of the lunar day, prefixed by its fortnight (e.g.
+    /// "Shukla Panchami").
+    pub fn tithi_name(&self) -> String {
+        let paksha = if self.tithi <= 15 {
+            "Shukla"
+        } else {
+            "Krishna"
+        };
+        return format!(
+            "{} {}",
+            paksha,
+            HINDU_TITHI_NAMES[(self.tithi - 1) as usize]
+        );
+    }
+
+    /// Returns the name of the lunar mansion.
+    pub fn nakshatra_name(&self) -> &'static str {
+        return HINDU_NAKSHATRA_NAMES[(self.nakshatra - 1) as usize];
+    }
+
+    /// Returns the name of the yoga.
+    pub fn yoga_name(&self) -> &'static str {
+        return HINDU_YOGA_NAMES[(self.yoga - 1) as usize];
+    }
+
+    /// Returns the name of the karana.
+    pub fn karana_name(&self) -> &'static str {
+        return karana_name(self.karana);
+    }
+
+    /// Returns the Hindu name of the day of the week (e.g. "Budhavara").
+    pub fn vara_name(&self) -> &'static str {
+        return HINDU_WEEKDAY_NAMES[self.vara.number() as usize];
+    }
+}
+
+/// Returns the panchang of a given absolute (fixed) date: its Old Hindu solar
+/// and lunar dates, and the tithi, nakshatra, yoga, and karana at sunrise,
+/// together with the day of the week. Absolute dates outside
+/// [`OLD_HINDU_LUNAR_ABSOLUTE_RANGE`] are clamped to the range.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     hindu::{panchang, OLD_HINDU_LUNAR_ABSOLUTE_RANGE},
+///     weekday::Weekday,
+/// };
+///
+/// let day = panchang(739007); // 1 May 2024
+/// assert_eq!(day.vara, Weekday::Wednesday);
+/// assert_eq!(day.vara_name(), "Budhavara");
+/// assert_eq!(day.tithi_name(), "Krishna Ashtami");
+/// assert_eq!(day.nakshatra_name(), "Shravana");
+/// assert_eq!(day.yoga_name(), "Shubha");
+/// assert_eq!(day.karana_name(), "Kaulava");
+/// assert_eq!(day.lunar.day, day.tithi);
+///
+/// let last = panchang(*OLD_HINDU_LUNAR_ABSOLUTE_RANGE.end());
+/// assert_eq!(panchang(i64::MAX), last);
+/// assert_eq!(panchang(i64::MIN).lunar.day, panchang(i64::MIN).tithi);
+/// ```
+pub fn panchang(absolute_date: i64) -> Panchang {
+    let absolute_date = clamp_to_range(absolute_date, &OLD_HINDU_LUNAR_ABSOLUTE_RANGE);
+    return Panchang {
+        solar: old_hindu_solar_from_absolute(absolute_date),
+        lunar: old_hindu_lunar_from_absolute(absolute_date),
+        tithi: tithi(absolute_date),
+        nakshatra: nakshatra(absolute_date),
+        yoga: yoga(absolute_date),
+        karana: karana(absolute_date),
+        vara: weekday_from_absolute(absolute_date),
+    };
+}
@@ -1,45 +1,87 @@
 //! Provides functions to compute holiday dates
 
+use core::{fmt, ops::RangeInclusive, str::FromStr};
+
 use crate::{
-    gregorian::{absolute_from_gregorian, last_day_of_gregorian_month, Gregorian},
+    error::CalendarError,
+    gregorian::{
+        absolute_from_gregorian, gregorian_from_absolute, gregorian_leap_year, Gregorian,
+        GREGORIAN_YEAR_RANGE,
+    },
     hebrew::{
-        absolute_from_hebrew, hebrew_leap_year, last_month_of_hebrew_year, long_heshvan,
-        short_kislev, Hebrew,
+        absolute_from_hebrew, hebrew_from_absolute, hebrew_leap_year, last_day_of_hebrew_month,
+        last_month_of_hebrew_year, long_heshvan, short_kislev, Hebrew,
     },
     islamic::{absolute_from_islamic, islamic_from_absolute, Islamic},
-    iso::kday_on_or_before,
-    julian::{absolute_from_julian, julian_from_absolute, Julian},
-    math::{floor_div, modulus},
+    julian::{absolute_from_julian, julian_from_absolute, julian_leap_year, Julian},
+    locale::Locale,
+    math::{clamp_to_range, floor_div, modulus},
+    weekday::{kday_after, kday_on_or_before, nth_kday, weekday_from_absolute, Weekday},
 };
 
-/// Computes the absolute (fixed) date of the nth kth day in a given month in
-/// a given Gregorian year.
-fn nth_kday(n: i64, k: i64, month: i64, year: i64) -> i64 {
-    return if n > 0 {
-        kday_on_or_before(
-            absolute_from_gregorian(Gregorian {
-                year,
-                month,
-                day: 7,
-            }),
-            k,
-        ) + (7 * (n - 1))
-    } else {
-        kday_on_or_before(
-            absolute_from_gregorian(Gregorian {
-                year,
-                month,
-                day: last_day_of_gregorian_month(month, year),
-            }),
-            k,
-        ) + (7 * (n + 1))
-    };
-}
+/// Range of Gregorian years for which the holidays of all traditions can be
+/// computed without overflow (limited by the range of Hebrew years, see
+/// [`HEBREW_YEAR_RANGE`](crate::hebrew::HEBREW_YEAR_RANGE)). The holiday
+/// functions clamp years outside the range to it.
+///
+/// # Example
+///
+/// ```
+/// use calendars::holidays::{
+///     advent, christmas, daylight_savings_start, martin_luther_king_day, orthodox_easter_in_years,
+///     thanksgiving, DstRule, HOLIDAY_YEAR_RANGE,
+/// };
+///
+/// let (first, last) = (*HOLIDAY_YEAR_RANGE.start(), *HOLIDAY_YEAR_RANGE.end());
+/// assert_eq!(martin_luther_king_day(i64::MAX), martin_luther_king_day(last));
+/// assert_eq!(thanksgiving(i64::MIN), thanksgiving(first));
+/// assert_eq!(christmas(i64::MAX), christmas(last));
+/// assert_eq!(advent(i64::MAX), advent(last));
+/// assert_eq!(daylight_savings_start(i64::MAX), daylight_savings_start(last));
+/// assert_eq!(DstRule::EuropeanUnion.end(i64::MIN), DstRule::EuropeanUnion.end(first));
+/// assert_eq!(orthodox_easter_in_years(i64::MAX - 1..=i64::MAX).len(), 2);
+/// ```
+pub static HOLIDAY_YEAR_RANGE: RangeInclusive<i64> = -1_000_000_000..=1_000_000_000;
 
 // US holidays
+//
+// Gregorian years outside HOLIDAY_YEAR_RANGE are clamped to the range.
+
+/// Returns the absolute (fixed) date of New Year's Day in a given Gregorian
+/// year.
+pub fn new_years_day(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return absolute_from_gregorian(Gregorian {
+        year,
+        month: 1,
+        day: 1,
+    });
+}
+
+/// Returns the absolute (fixed) date of the nth given weekday of a Gregorian
+/// month that has at least n such weekdays (see [`nth_kday`]).
+fn kday_of_month(n: i64, weekday: Weekday, month: i64, year: i64) -> i64 {
+    return nth_kday(n, weekday, month, year)
+        .expect("every month has at least four of each weekday");
+}
+
+/// Returns the absolute (fixed) date of US Martin Luther King Jr. Day in a
+/// given Gregorian year.
+pub fn martin_luther_king_day(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return kday_of_month(3, Weekday::Monday, 1, year);
+}
+
+/// Returns the absolute (fixed) date of US Presidents' Day (Washington's
+/// Birthday) in a given Gregorian year.
+pub fn presidents_day(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return kday_of_month(3, Weekday::Monday, 2, year);
+}
 
 /// Returns the absolute (fixed) date of the US Independence Day.
 pub fn independence_day(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
     return absolute_from_gregorian(Gregorian {
         year,
         month: 7,
@@ -47,35 +89,198 @@ pub fn independence_day(year: i64) -> i64 {
     });
 }
 
+/// Returns the absolute (fixed) date of US Juneteenth National Independence
+/// Day in a given Gregorian year.
+pub fn juneteenth(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return absolute_from_gregorian(Gregorian {
+        year,
+        month: 6,
+        day: 19,
+    });
+}
+
 /// Returns the absolute (fixed) date of US Labor Day in a given Gregorian
 /// year.
 pub fn labor_day(year: i64) -> i64 {
-    return nth_kday(1, 1, 9, year);
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return kday_of_month(1, Weekday::Monday, 9, year);
+}
+
+/// Returns the absolute (fixed) date of US Columbus Day (Indigenous Peoples'
+/// Day) in a given Gregorian year.
+pub fn columbus_day(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return kday_of_month(2, Weekday::Monday, 10, year);
+}
+
+/// Returns the absolute (fixed) date of US Veterans Day in a given Gregorian
+/// year.
+pub fn veterans_day(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return absolute_from_gregorian(Gregorian {
+        year,
+        month: 11,
+        day: 11,
+    });
+}
+
+/// Returns the absolute (fixed) date of US Thanksgiving in a given Gregorian
+/// year.
+pub fn thanksgiving(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return kday_of_month(4, Weekday::Thursday, 11, year);
 }
 
 /// Returns the absolute (fixed) date of US Memorial Day in a given Gregorian
 /// year.
 pub fn memorial_day(year: i64) -> i64 {
-    return nth_kday(-1, 1, 5, year);
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return kday_of_month(-1, Weekday::Monday, 5, year);
 }
 
-/// Returns the absolute (fixed) date of the start of US daylight savings time.
+/// Returns the absolute (fixed) date of the start of US daylight savings time
+/// in a given Gregorian year (see [`DstRule::UnitedStates`]). Years before
+/// 1967, which had no uniform US rule, are reckoned by the rule of 1967.
 pub fn daylight_savings_start(year: i64) -> i64 {
-    return nth_kday(1, 0, 4, year); // before 2007
-                                    // return nth_kday(2, 0, 3, year); // since 2007
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return match year {
+        // emergency daylight savings time during the 1973 oil crisis
+        1974 => absolute_from_gregorian(Gregorian {
+            year,
+            month: 1,
+            day: 6,
+        }),
+        1975 => absolute_from_gregorian(Gregorian {
+            year,
+            month: 2,
+            day: 23,
+        }),
+        ..=1986 => kday_of_month(-1, Weekday::Sunday, 4, year),
+        1987..=2006 => kday_of_month(1, Weekday::Sunday, 4, year),
+        _ => kday_of_month(2, Weekday::Sunday, 3, year),
+    };
 }
 
-/// Returns the absolute (fixed) date of the end of US daylight savings time.
+/// Returns the absolute (fixed) date of the end of US daylight savings time
+/// in a given Gregorian year (see [`DstRule::UnitedStates`]). Years before
+/// 1967, which had no uniform US rule, are reckoned by the rule of 1967.
 pub fn daylight_savings_end(year: i64) -> i64 {
-    return nth_kday(-1, 0, 10, year); // before 2007
-                                      // return nth_kday(1, 0, 11, year); // since 2007
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return if year < 2007 {
+        kday_of_month(-1, Weekday::Sunday, 10, year)
+    } else {
+        kday_of_month(1, Weekday::Sunday, 11, year)
+    };
+}
+
+/// The nth given weekday of a Gregorian month (see [`nth_kday`])
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KdayRule {
+    pub n: i64,
+    pub weekday: Weekday,
+    pub month: i64,
+}
+
+impl KdayRule {
+    /// Returns the absolute (fixed) date of the day described by the rule in
+    /// a given Gregorian year, or `None` if there is no such day (see
+    /// [`nth_kday`]).
+    pub fn absolute_date(&self, year: i64) -> Option<i64> {
+        let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+        return nth_kday(self.n, self.weekday, self.month, year);
+    }
+}
+
+/// Rule determining the start and end of daylight savings time
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{DstRule, KdayRule},
+///     weekday::Weekday,
+/// };
+///
+/// assert_eq!(
+///     DstRule::EuropeanUnion.start(2024),
+///     Some(absolute_from_gregorian(Gregorian::new(2024, 3, 31)))
+/// );
+/// assert_eq!(
+///     DstRule::UnitedStates.end(2024),
+///     Some(absolute_from_gregorian(Gregorian::new(2024, 11, 3)))
+/// );
+/// assert_eq!(
+///     DstRule::UnitedStates.start(1980),
+///     Some(absolute_from_gregorian(Gregorian::new(1980, 4, 27)))
+/// );
+/// assert_eq!(DstRule::UnitedStates.start(1966), None);
+/// assert_eq!(DstRule::None.start(2024), None);
+///
+/// // first Sunday of October to first Sunday of April (e.g. New South Wales)
+/// let rule = DstRule::Custom {
+///     start: KdayRule { n: 1, weekday: Weekday::Sunday, month: 10 },
+///     end: KdayRule { n: 1, weekday: Weekday::Sunday, month: 4 },
+/// };
+/// assert_eq!(rule.start(2024), Some(absolute_from_gregorian(Gregorian::new(2024, 10, 6))));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DstRule {
+    /// US rules (since 1967): last Sunday of April to last Sunday of October
+    /// until 1986 (except for 1974 and 1975, when daylight savings time began
+    /// on 6 January and 23 February), first Sunday of April to last Sunday of
+    /// October from 1987 to 2006, second Sunday of March to first Sunday of
+    /// November since 2007. Not observed (`None`) before 1967.
+    UnitedStates,
+    /// EU rules: last Sunday of March to last Sunday of October.
+    EuropeanUnion,
+    /// Daylight savings time is not observed.
+    None,
+    /// Daylight savings time starts and ends on given days of the year.
+    Custom { start: KdayRule, end: KdayRule },
+}
+
+impl DstRule {
+    /// Returns the absolute (fixed) date of the start of daylight savings
+    /// time in a given Gregorian year, or `None` if it is not observed (or a
+    /// custom rule describes no day of the year).
+    pub fn start(&self, year: i64) -> Option<i64> {
+        let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+        return match self {
+            DstRule::UnitedStates if year < 1967 => None,
+            DstRule::UnitedStates => Some(daylight_savings_start(year)),
+            DstRule::EuropeanUnion => Some(kday_of_month(-1, Weekday::Sunday, 3, year)),
+            DstRule::None => None,
+            DstRule::Custom { start, .. } => start.absolute_date(year),
+        };
+    }
+
+    /// Returns the absolute (fixed) date of the end of daylight savings time
+    /// in a given Gregorian year, or `None` if it is not observed (or a
+    /// custom rule describes no day of the year).
+    pub fn end(&self, year: i64) -> Option<i64> {
+        let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+        return match self {
+            DstRule::UnitedStates if year < 1967 => None,
+            DstRule::UnitedStates => Some(daylight_savings_end(year)),
+            DstRule::EuropeanUnion => Some(kday_of_month(-1, Weekday::Sunday, 10, year)),
+            DstRule::None => None,
+            DstRule::Custom { end, .. } => end.absolute_date(year),
+        };
+    }
 }
 
 // Christian holidays
+//
+// Gregorian years outside HOLIDAY_YEAR_RANGE are clamped to the range,
+// except for (Western) Easter and the holidays depending on it, which
+// support the larger GREGORIAN_YEAR_RANGE.
 
 /// Returns the absolute (fixed) date of Gregorian Christmas in a given
 /// Gregorian year.
 pub fn christmas(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
     return absolute_from_gregorian(Gregorian {
         year,
         month: 12,
@@ -85,6 +290,7 @@ pub fn christmas(year: i64) -> i64 {
 
 /// Returns the absolute (fixed) date of Advent in a given Gregorian year.
 pub fn advent(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
     return kday_on_or_before(
         absolute_from_gregorian(Gregorian {
             year,
@@ -95,7 +301,21 @@ pub fn advent(year: i64) -> i64 {
     );
 }
 
-/// Returns the absolute (fixed) date of Epiphany in a given Gregorian year.
+/// Returns the absolute (fixed) date of Epiphany (6 January) following the
+/// Christmas of a given Gregorian year, which falls into the next Gregorian
+/// year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{epiphany, Holiday},
+/// };
+///
+/// assert_eq!(epiphany(2024), absolute_from_gregorian(Gregorian::new(2025, 1, 6)));
+/// assert_eq!(Holiday::Epiphany.dates_in_gregorian_year(2025), [epiphany(2024)]);
+/// ```
 pub fn epiphany(year: i64) -> i64 {
     return 12 + christmas(year);
 }
@@ -103,6 +323,7 @@ pub fn epiphany(year: i64) -> i64 {
 /// Returns the absolute (fixed) date of Eastern Orthodox Christmas in a given
 ///  Gregorian year.
 pub fn eastern_orthodox_christmas(year: i64) -> Vec<i64> {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
     let jan_1 = absolute_from_gregorian(Gregorian {
         year,
         month: 1,
@@ -136,17 +357,21 @@ pub fn eastern_orthodox_christmas(year: i64) -> Vec<i64> {
 
 // Computes the absolute (fixed) date of Easter in a given Julian year.
 pub fn nicaean_rule_easter(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
     let shifted_epact = modulus(14 + (11 * modulus(year, 19)), 30);
     let paschal_moon = absolute_from_julian(Julian {
         year,
         month: 4,
         day: 19,
     }) - shifted_epact;
-    return kday_on_or_before(paschal_moon + 7, 0);
+    return kday_after(paschal_moon, 0);
 }
 
 /// Computes the absolute (fixed) date of Easter in a given Gregorian year.
+///
+/// Years outside [`GREGORIAN_YEAR_RANGE`] are clamped to the range.
 pub fn easter(year: i64) -> i64 {
+    let year = clamp_to_range(year, &GREGORIAN_YEAR_RANGE);
     let century = floor_div(year, 100) + 1;
     let shifted_epact = modulus(
         14 + (11 * modulus(year, 19)) - floor_div(3 * century, 4)
@@ -164,19 +389,224 @@ pub fn easter(year: i64) -> i64 {
         month: 4,
         day: 19,
     }) - adjusted_epact;
-    return kday_on_or_before(paschal_moon + 7, 0);
+    return kday_after(paschal_moon, 0);
+}
+
+/// Computes the absolute (fixed) dates of Easter in a range of Gregorian
+/// years, in chronological order. Faster than calling [`easter`] for each
+/// year, as the century corrections of the epact are only computed once per
+/// century, and the date of April 19 is carried over from year to year.
+///
+/// Years outside [`GREGORIAN_YEAR_RANGE`] are clamped to the range.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{easter, easter_in_years},
+/// };
+///
+/// let dates = easter_in_years(1900..=2100);
+/// assert_eq!(dates.len(), 201);
+/// assert_eq!(dates[124], absolute_from_gregorian(Gregorian::new(2024, 3, 31)));
+/// assert!((1900..=2100).zip(dates).all(|(year, date)| date == easter(year)));
+/// assert_eq!(easter_in_years(i64::MAX - 1..=i64::MAX), [easter(i64::MAX); 2]);
+/// ```
+pub fn easter_in_years(years: RangeInclusive<i64>) -> Vec<i64> {
+    let mut dates = vec![];
+    let mut april_19 = absolute_from_gregorian(Gregorian {
+        year: clamp_to_range(*years.start(), &GREGORIAN_YEAR_RANGE),
+        month: 4,
+        day: 19,
+    });
+    let mut century = None;
+    let mut correction = 0;
+    for year in years {
+        if !GREGORIAN_YEAR_RANGE.contains(&year) {
+            dates.push(easter(year));
+            continue;
+        }
+        let c = floor_div(year, 100) + 1;
+        if century != Some(c) {
+            correction = -floor_div(3 * c, 4) + floor_div(5 + (8 * c), 25) + (30 * c);
+            century = Some(c);
+        }
+        let shifted_epact = modulus(14 + (11 * modulus(year, 19)) + correction, 30);
+        let adjusted_epact = if shifted_epact == 0 || (shifted_epact == 1 && 10 < modulus(year, 19))
+        {
+            shifted_epact + 1
+        } else {
+            shifted_epact
+        };
+        dates.push(kday_after(april_19 - adjusted_epact, 0));
+        april_19 += if gregorian_leap_year(year + 1) {
+            366
+        } else {
+            365
+        };
+    }
+    return dates;
+}
+
+/// Returns the absolute (fixed) date a given number of days after (or, if
+/// negative, before) Easter in a given Gregorian year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{easter_offset, good_friday},
+/// };
+///
+/// assert_eq!(good_friday(2024), absolute_from_gregorian(Gregorian::new(2024, 3, 29)));
+/// // Easter Monday
+/// assert_eq!(easter_offset(2024, 1), absolute_from_gregorian(Gregorian::new(2024, 4, 1)));
+/// ```
+pub fn easter_offset(year: i64, days: i64) -> i64 {
+    return easter(year) + days;
+}
+
+/// Returns the absolute (fixed) date of Septuagesima Sunday in a given
+/// Gregorian year.
+pub fn septuagesima(year: i64) -> i64 {
+    return easter_offset(year, -63);
+}
+
+/// Returns the absolute (fixed) date of Mardi Gras (Shrove Tuesday) in a given
+/// Gregorian year.
+pub fn mardi_gras(year: i64) -> i64 {
+    return easter_offset(year, -47);
+}
+
+/// Returns the absolute (fixed) date of Ash Wednesday in a given Gregorian
+/// year.
+pub fn ash_wednesday(year: i64) -> i64 {
+    return easter_offset(year, -46);
+}
+
+/// Returns the absolute (fixed) date of Palm Sunday in a given Gregorian year.
+pub fn palm_sunday(year: i64) -> i64 {
+    return easter_offset(year, -7);
+}
+
+/// Returns the absolute (fixed) date of Good Friday in a given Gregorian year.
+pub fn good_friday(year: i64) -> i64 {
+    return easter_offset(year, -2);
+}
+
+/// Returns the absolute (fixed) date of Ascension Day in a given Gregorian
+/// year.
+pub fn ascension(year: i64) -> i64 {
+    return easter_offset(year, 39);
 }
 
 /// Returns the absolute (fixed) date of Pentecost in a given Gregorian year.
 pub fn pentecost(year: i64) -> i64 {
-    return 49 + easter(year);
+    return easter_offset(year, 49);
+}
+
+/// Returns the absolute (fixed) date of Trinity Sunday in a given Gregorian
+/// year.
+pub fn trinity_sunday(year: i64) -> i64 {
+    return easter_offset(year, 56);
+}
+
+/// Returns the absolute (fixed) date of Corpus Christi in a given Gregorian
+/// year.
+pub fn corpus_christi(year: i64) -> i64 {
+    return easter_offset(year, 60);
+}
+
+/// Returns the absolute (fixed) date of Orthodox Easter (Pascha) in a given
+/// Gregorian year. Orthodox Easter is determined by the Nicaean rule (see
+/// [`nicaean_rule_easter`]), and always falls in the same Gregorian year as
+/// the Julian year it is computed for.
+pub fn orthodox_easter(year: i64) -> i64 {
+    return nicaean_rule_easter(year);
+}
+
+/// Computes the absolute (fixed) dates of Orthodox Easter in a range of
+/// Gregorian years, in chronological order (see [`easter_in_years`]).
+pub fn orthodox_easter_in_years(years: RangeInclusive<i64>) -> Vec<i64> {
+    let mut dates = vec![];
+    let mut april_19 = absolute_from_julian(Julian {
+        year: clamp_to_range(*years.start(), &HOLIDAY_YEAR_RANGE),
+        month: 4,
+        day: 19,
+    });
+    for year in years {
+        if !HOLIDAY_YEAR_RANGE.contains(&year) {
+            dates.push(orthodox_easter(year));
+            continue;
+        }
+        let shifted_epact = modulus(14 + (11 * modulus(year, 19)), 30);
+        dates.push(kday_after(april_19 - shifted_epact, 0));
+        april_19 += if julian_leap_year(year + 1) { 366 } else { 365 };
+    }
+    return dates;
+}
+
+/// Returns the absolute (fixed) date a given number of days after (or, if
+/// negative, before) Orthodox Easter in a given Gregorian year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{apostles_fast_end, apostles_fast_start, orthodox_easter, orthodox_easter_offset},
+/// };
+///
+/// assert_eq!(orthodox_easter(2024), absolute_from_gregorian(Gregorian::new(2024, 5, 5)));
+/// // Orthodox Good Friday
+/// assert_eq!(orthodox_easter_offset(2024, -2), absolute_from_gregorian(Gregorian::new(2024, 5, 3)));
+/// // the Apostles' Fast lasts from 1 July to 11 July 2024
+/// assert_eq!(apostles_fast_end(2024) - apostles_fast_start(2024) + 1, 11);
+/// ```
+pub fn orthodox_easter_offset(year: i64, days: i64) -> i64 {
+    return orthodox_easter(year) + days;
+}
+
+/// Returns the absolute (fixed) date of the start of Great Lent (Clean
+/// Monday) in a given Gregorian year.
+pub fn great_lent_start(year: i64) -> i64 {
+    return orthodox_easter_offset(year, -48);
+}
+
+/// Returns the absolute (fixed) date of Orthodox Pentecost in a given
+/// Gregorian year.
+pub fn orthodox_pentecost(year: i64) -> i64 {
+    return orthodox_easter_offset(year, 49);
+}
+
+/// Returns the absolute (fixed) date of the start of the Apostles' Fast (the
+/// Monday after All Saints' Sunday) in a given Gregorian year.
+pub fn apostles_fast_start(year: i64) -> i64 {
+    return orthodox_easter_offset(year, 57);
+}
+
+/// Returns the absolute (fixed) date of the end of the Apostles' Fast (the
+/// eve of the Feast of Saints Peter and Paul on Julian June 29) in a given
+/// Gregorian year.
+pub fn apostles_fast_end(year: i64) -> i64 {
+    let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+    return absolute_from_julian(Julian {
+        year,
+        month: 6,
+        day: 28,
+    });
 }
 
 // Islamic holidays
+//
+// Gregorian years outside HOLIDAY_YEAR_RANGE are clamped to the range.
 
 /// Returns a slice of absolute dates of a given Islamic date (month, day)
 /// that occur in a given Gregorian year (g_year).
 pub fn islamic_date_in_gregorian_year(month: i64, day: i64, g_year: i64) -> Vec<i64> {
+    let g_year = clamp_to_range(g_year, &HOLIDAY_YEAR_RANGE);
     let jan_1 = absolute_from_gregorian(Gregorian {
         year: g_year,
         month: 1,
@@ -216,37 +646,288 @@ pub fn islamic_date_in_gregorian_year(month: i64, day: i64, g_year: i64) -> Vec<
     return res;
 }
 
+/// Computes a vector of absolute (fixed) dates of the Islamic New Year (1
+/// Muharram) that occur in a given Gregorian year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{ashura, eid_al_adha, eid_al_fitr, islamic_new_year, ramadan_start},
+/// };
+///
+/// let date = |month, day| absolute_from_gregorian(Gregorian::new(2025, month, day));
+/// assert_eq!(ramadan_start(2025), [date(3, 1)]);
+/// assert_eq!(eid_al_fitr(2025), [date(3, 31)]);
+/// assert_eq!(eid_al_adha(2025), [date(6, 7)]);
+/// assert_eq!(islamic_new_year(2025), [date(6, 27)]);
+/// assert_eq!(ashura(2025), [date(7, 6)]);
+///
+/// // Eid al-Fitr occurs twice in 2033
+/// assert_eq!(eid_al_fitr(2033).len(), 2);
+/// ```
+pub fn islamic_new_year(g_year: i64) -> Vec<i64> {
+    return islamic_date_in_gregorian_year(1, 1, g_year);
+}
+
+/// Computes a vector of absolute (fixed) dates of Ashura (10 Muharram) that
+/// occur in a given Gregorian year.
+pub fn ashura(g_year: i64) -> Vec<i64> {
+    return islamic_date_in_gregorian_year(1, 10, g_year);
+}
+
 /// Computes a vector of absolute (fixed) dates of Mulad al Nabi that occur in
 /// a given Gregorian year.
 pub fn mulad_al_nabi(g_year: i64) -> Vec<i64> {
     return islamic_date_in_gregorian_year(3, 12, g_year);
 }
 
+/// Computes a vector of absolute (fixed) dates of the first day of Ramadan
+/// that occur in a given Gregorian year.
+pub fn ramadan_start(g_year: i64) -> Vec<i64> {
+    return islamic_date_in_gregorian_year(9, 1, g_year);
+}
+
+/// Computes a vector of absolute (fixed) dates of Laylat al-Qadr that occur in
+/// a given Gregorian year. The traditional date, 27 Ramadan, is used; the
+/// night itself begins on the evening before.
+pub fn laylat_al_qadr(g_year: i64) -> Vec<i64> {
+    return islamic_date_in_gregorian_year(9, 27, g_year);
+}
+
+/// Computes a vector of absolute (fixed) dates of Eid al-Fitr (1 Shawwal) that
+/// occur in a given Gregorian year.
+pub fn eid_al_fitr(g_year: i64) -> Vec<i64> {
+    return islamic_date_in_gregorian_year(10, 1, g_year);
+}
+
+/// Computes a vector of absolute (fixed) dates of Eid al-Adha (10 Dhu
+/// al-Hijjah) that occur in a given Gregorian year.
+pub fn eid_al_adha(g_year: i64) -> Vec<i64> {
+    return islamic_date_in_gregorian_year(12, 10, g_year);
+}
+
 // Jewish holidays
+//
+// Gregorian years outside HOLIDAY_YEAR_RANGE are clamped to the range.
+
+/// Returns the Hebrew year beginning in the autumn of a given Gregorian year,
+/// clamping the Gregorian year to [`HOLIDAY_YEAR_RANGE`].
+fn hebrew_year_starting_in(g_year: i64) -> i64 {
+    return clamp_to_range(g_year, &HOLIDAY_YEAR_RANGE) + 3761;
+}
+
+/// Returns the Hebrew year ending in the autumn of a given Gregorian year,
+/// clamping the Gregorian year to [`HOLIDAY_YEAR_RANGE`].
+fn hebrew_year_ending_in(g_year: i64) -> i64 {
+    return clamp_to_range(g_year, &HOLIDAY_YEAR_RANGE) + 3760;
+}
+
+/// Returns the absolute (fixed) date of Rosh Hashanah in a given Gregorian year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{lag_ba_omer, rosh_hashanah, shavuot, simchat_torah, sukkot, tu_bishvat},
+/// };
+///
+/// let date = |month, day| absolute_from_gregorian(Gregorian::new(2024, month, day));
+/// assert_eq!(tu_bishvat(2024), date(1, 25));
+/// assert_eq!(lag_ba_omer(2024), date(5, 26));
+/// assert_eq!(shavuot(2024), date(6, 12));
+/// assert_eq!(rosh_hashanah(2024), date(10, 3));
+/// assert_eq!(sukkot(2024), date(10, 17));
+/// assert_eq!(simchat_torah(2024), date(10, 25));
+/// assert_eq!(rosh_hashanah(i64::MAX), rosh_hashanah(1_000_000_000));
+/// ```
+pub fn rosh_hashanah(g_year: i64) -> i64 {
+    return absolute_from_hebrew(Hebrew {
+        year: hebrew_year_starting_in(g_year),
+        month: 7,
+        day: 1,
+    });
+}
 
 /// Returns the absolute (fixed) date of Yom Kippur in a given Gregorian year.
 pub fn yom_kippur(g_year: i64) -> i64 {
     return absolute_from_hebrew(Hebrew {
-        year: g_year + 3761,
+        year: hebrew_year_starting_in(g_year),
         month: 7,
         day: 10,
     });
 }
 
+/// Returns the absolute (fixed) date of Tzom Gedaliah in a given Gregorian
+/// year. The fast is postponed to Sunday if the 3rd of Tishri falls on the
+/// Sabbath.
+pub fn tzom_gedaliah(g_year: i64) -> i64 {
+    let third_of_tishri = absolute_from_hebrew(Hebrew {
+        year: hebrew_year_starting_in(g_year),
+        month: 7,
+        day: 3,
+    });
+    return if modulus(third_of_tishri, 7) == 6 {
+        third_of_tishri + 1
+    } else {
+        third_of_tishri
+    };
+}
+
+/// Returns the absolute (fixed) date of Sukkot in a given Gregorian year.
+pub fn sukkot(g_year: i64) -> i64 {
+    return absolute_from_hebrew(Hebrew {
+        year: hebrew_year_starting_in(g_year),
+        month: 7,
+        day: 15,
+    });
+}
+
+/// Returns the absolute (fixed) date of Shemini Atzeret in a given Gregorian year.
+pub fn shemini_atzeret(g_year: i64) -> i64 {
+    return absolute_from_hebrew(Hebrew {
+        year: hebrew_year_starting_in(g_year),
+        month: 7,
+        day: 22,
+    });
+}
+
+/// Returns the absolute (fixed) date of Simchat Torah outside of Israel in a
+/// given Gregorian year.
+pub fn simchat_torah(g_year: i64) -> i64 {
+    return shemini_atzeret(g_year) + 1;
+}
+
+/// Returns the absolute (fixed) date of Simchat Torah in Israel, where it
+/// coincides with Shemini Atzeret, in a given Gregorian year.
+pub fn simchat_torah_israel(g_year: i64) -> i64 {
+    return shemini_atzeret(g_year);
+}
+
+/// Returns the absolute (fixed) dates of the eight days of Hanukkah beginning
+/// in a given Gregorian year (in Kislev of the Hebrew year starting in its
+/// autumn). The last days may fall into the next Gregorian year; see
+/// [`Holiday::dates_in_gregorian_year`] for the days falling into a given
+/// Gregorian year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{hanukkah, Holiday},
+/// };
+///
+/// let days = hanukkah(2024);
+/// assert_eq!(days[0], absolute_from_gregorian(Gregorian::new(2024, 12, 26)));
+/// assert_eq!(days[7], absolute_from_gregorian(Gregorian::new(2025, 1, 2)));
+/// assert_eq!(Holiday::Hanukkah.dates_in_gregorian_year(2025)[..2], days[6..]);
+/// ```
+pub fn hanukkah(g_year: i64) -> Vec<i64> {
+    let first_day = absolute_from_hebrew(Hebrew {
+        year: hebrew_year_starting_in(g_year),
+        month: 9,
+        day: 25,
+    });
+    return (first_day..first_day + 8).collect();
+}
+
+/// Returns a vector of absolute (fixed) dates of the fast of the Tenth of
+/// Tevet that occur in a given Gregorian year. The fast is never postponed,
+/// as the 10th of Tevet cannot fall on the Sabbath.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{asarah_b_tevet, shiva_asar_b_tammuz, tzom_gedaliah},
+/// };
+///
+/// // the Tenth of Tevet occurs twice in 2025
+/// assert_eq!(
+///     asarah_b_tevet(2025),
+///     [
+///         absolute_from_gregorian(Gregorian::new(2025, 1, 10)),
+///         absolute_from_gregorian(Gregorian::new(2025, 12, 30)),
+///     ]
+/// );
+/// // the 17th of Tammuz 5782 and the 3rd of Tishri 5785 fell on the Sabbath
+/// assert_eq!(shiva_asar_b_tammuz(2022), absolute_from_gregorian(Gregorian::new(2022, 7, 17)));
+/// assert_eq!(tzom_gedaliah(2024), absolute_from_gregorian(Gregorian::new(2024, 10, 6)));
+/// ```
+pub fn asarah_b_tevet(g_year: i64) -> Vec<i64> {
+    let g_year = clamp_to_range(g_year, &HOLIDAY_YEAR_RANGE);
+    let jan_1 = absolute_from_gregorian(Gregorian {
+        year: g_year,
+        month: 1,
+        day: 1,
+    });
+    let dec_31 = absolute_from_gregorian(Gregorian {
+        year: g_year,
+        month: 12,
+        day: 31,
+    });
+    let mut res = vec![];
+    for h_year in [
+        hebrew_year_ending_in(g_year),
+        hebrew_year_starting_in(g_year),
+    ] {
+        let date = absolute_from_hebrew(Hebrew {
+            year: h_year,
+            month: 10,
+            day: 10,
+        });
+        if jan_1 <= date && date <= dec_31 {
+            res.push(date);
+        }
+    }
+    return res;
+}
+
+/// Returns the absolute (fixed) date of Tu BiShvat in a given Gregorian year.
+pub fn tu_bishvat(g_year: i64) -> i64 {
+    return absolute_from_hebrew(Hebrew {
+        year: hebrew_year_ending_in(g_year),
+        month: 11,
+        day: 15,
+    });
+}
+
 /// Returns the abolute (fixed) date of Passover in a given Gregorian year.
 pub fn passover(g_year: i64) -> i64 {
     return absolute_from_hebrew(Hebrew {
-        year: g_year + 3760,
+        year: hebrew_year_ending_in(g_year),
         month: 1,
         day: 15,
     });
 }
 
+/// Returns the absolute (fixed) date of Lag BaOmer in a given Gregorian year.
+pub fn lag_ba_omer(g_year: i64) -> i64 {
+    return absolute_from_hebrew(Hebrew {
+        year: hebrew_year_ending_in(g_year),
+        month: 2,
+        day: 18,
+    });
+}
+
+/// Returns the absolute (fixed) date of Shavuot in a given Gregorian year.
+pub fn shavuot(g_year: i64) -> i64 {
+    return absolute_from_hebrew(Hebrew {
+        year: hebrew_year_ending_in(g_year),
+        month: 3,
+        day: 6,
+    });
+}
+
 /// Returns the absolute (fixed) date of Purim in a given Gregorian year.
 pub fn purim(g_year: i64) -> i64 {
     return absolute_from_hebrew(Hebrew {
-        year: g_year + 3760,
-        month: last_month_of_hebrew_year(g_year + 3760),
+        year: hebrew_year_ending_in(g_year),
+        month: last_month_of_hebrew_year(hebrew_year_ending_in(g_year)),
         day: 14,
     });
 }
@@ -262,10 +943,26 @@ pub fn ta_anit_esther(g_year: i64) -> i64 {
     };
 }
 
+/// Returns the absolute (fixed) date of the fast of the Seventeenth of Tammuz
+/// in a given Gregorian year. The fast is postponed to Sunday if the 17th of
+/// Tammuz falls on the Sabbath.
+pub fn shiva_asar_b_tammuz(g_year: i64) -> i64 {
+    let seventeenth_of_tammuz = absolute_from_hebrew(Hebrew {
+        year: hebrew_year_ending_in(g_year),
+        month: 4,
+        day: 17,
+    });
+    return if modulus(seventeenth_of_tammuz, 7) == 6 {
+        seventeenth_of_tammuz + 1
+    } else {
+        seventeenth_of_tammuz
+    };
+}
+
 // Returns the absolute (fixed) date of Tisha B'Av in a given Gregorian year.
 pub fn tisha_b_av(g_year: i64) -> i64 {
     let ninth_of_av = absolute_from_hebrew(Hebrew {
-        year: g_year + 3760,
+        year: hebrew_year_ending_in(g_year),
         month: 5,
         day: 9,
     });
@@ -276,6 +973,215 @@ pub fn tisha_b_av(g_year: i64) -> i64 {
     };
 }
 
+/// Returns the day of the Omer (1 to 49) of a given absolute (fixed) date,
+/// or `None` if the date does not fall between Passover and Shavuot.
+///
+/// # Example
+///
+/// ```
+/// use calendars::holidays::{lag_ba_omer, omer, omer_count, passover, shavuot};
+///
+/// assert_eq!(omer(passover(2024)), None);
+/// assert_eq!(omer(passover(2024) + 1), Some(1));
+/// assert_eq!(omer(lag_ba_omer(2024)), Some(33));
+/// assert_eq!(omer(shavuot(2024)), None);
+/// assert_eq!(
+///     omer_count(lag_ba_omer(2024)).unwrap(),
+///     "Today is 33 days, which are 4 weeks and 5 days of the Omer."
+/// );
+/// ```
+pub fn omer(absolute_date: i64) -> Option<i64> {
+    let passover_date = absolute_from_hebrew(Hebrew {
+        year: hebrew_from_absolute(absolute_date).year,
+        month: 1,
+        day: 15,
+    });
+    let day = absolute_date - passover_date;
+    return if (1..=49).contains(&day) {
+        Some(day)
+    } else {
+        None
+    };
+}
+
+/// Returns the traditional count of the Omer (in days, and weeks and days)
+/// of a given absolute (fixed) date, or `None` if the date does not fall
+/// between Passover and Shavuot.
+pub fn omer_count(absolute_date: i64) -> Option<String> {
+    let day = omer(absolute_date)?;
+    let plural = |n: i64, unit: &str| {
+        if n == 1 {
+            format!("1 {}", unit)
+        } else {
+            format!("{} {}s", n, unit)
+        }
+    };
+    let (weeks, days) = (day / 7, day % 7);
+    let count = match (weeks, days) {
+        (0, _) => plural(day, "day"),
+        (_, 0) => format!(
+            "{}, which are {}",
+            plural(day, "day"),
+            plural(weeks, "week")
+        ),
+        _ => format!(
+            "{}, which are {} and {}",
+            plural(day, "day"),
+            plural(weeks, "week"),
+            plural(days, "day")
+        ),
+    };
+    return Some(format!("Today is {} of the Omer.", count));
+}
+
+/// Rosh Chodesh (new month) observance of a Hebrew month, lasting one or two
+/// days (the 30th day of the previous month and the 1st day of the month)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RoshChodesh {
+    pub year: i64,
+    pub month: i64,
+    pub first_day: i64,
+    pub last_day: i64,
+}
+
+impl RoshChodesh {
+    /// Returns the absolute (fixed) dates of the observance.
+    pub fn days(&self) -> Vec<i64> {
+        return (self.first_day..=self.last_day).collect();
+    }
+}
+
+/// Iterator over the Rosh Chodesh observances overlapping a range of
+/// absolute (fixed) dates. Tishri is skipped, as its first day is Rosh
+/// Hashanah.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::RoshChodeshIter,
+/// };
+///
+/// let months: Vec<_> = RoshChodeshIter::hebrew_year(5785).collect();
+/// assert_eq!(months.len(), 11);
+/// // Rosh Chodesh Heshvan lasts two days, Rosh Chodesh Shevat one
+/// assert_eq!(
+///     months[0].days(),
+///     [
+///         absolute_from_gregorian(Gregorian::new(2024, 11, 1)),
+///         absolute_from_gregorian(Gregorian::new(2024, 11, 2)),
+///     ]
+/// );
+/// assert_eq!(months[3].days(), [absolute_from_gregorian(Gregorian::new(2025, 1, 30))]);
+///
+/// // Tevet begins twice in 2025 (on January 1 and December 21)
+/// assert_eq!(RoshChodeshIter::gregorian_year(2025).count(), 12);
+///
+/// // Rosh Chodesh Tevet 5785 runs from 31 December 2024 to 1 January 2025
+/// let tevet = RoshChodeshIter::gregorian_year(2024).last().unwrap();
+/// assert_eq!((tevet.year, tevet.month), (5785, 10));
+/// assert_eq!(tevet.first_day, absolute_from_gregorian(Gregorian::new(2024, 12, 31)));
+/// assert_eq!(RoshChodeshIter::gregorian_year(2025).next(), Some(tevet));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RoshChodeshIter {
+    year: i64,
+    month: i64,
+    start: i64,
+    end: i64,
+}
+
+impl RoshChodeshIter {
+    /// Create a new iterator over the Rosh Chodesh observances overlapping
+    /// the days from `start` to `end` (inclusive).
+    pub fn new(start: i64, end: i64) -> Self {
+        let h = hebrew_from_absolute(start);
+        Self {
+            year: h.year,
+            month: h.month,
+            start,
+            end,
+        }
+    }
+
+    /// Create a new iterator over the Rosh Chodesh observances of a given
+    /// Hebrew year.
+    pub fn hebrew_year(year: i64) -> Self {
+        let new_year = absolute_from_hebrew(Hebrew {
+            year,
+            month: 7,
+            day: 1,
+        });
+        let next_new_year = absolute_from_hebrew(Hebrew {
+            year: year + 1,
+            month: 7,
+            day: 1,
+        });
+        return RoshChodeshIter::new(new_year, next_new_year - 1);
+    }
+
+    /// Create a new iterator over the Rosh Chodesh observances overlapping a
+    /// given Gregorian year, including those running from 31 December of the
+    /// preceding year or into the following year.
+    pub fn gregorian_year(year: i64) -> Self {
+        let jan_1 = absolute_from_gregorian(Gregorian {
+            year,
+            month: 1,
+            day: 1,
+        });
+        let dec_31 = absolute_from_gregorian(Gregorian {
+            year,
+            month: 12,
+            day: 31,
+        });
+        return RoshChodeshIter::new(jan_1, dec_31);
+    }
+}
+
+impl Iterator for RoshChodeshIter {
+    type Item = RoshChodesh;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (year, month) = (self.year, self.month);
+            let first_of_month = absolute_from_hebrew(Hebrew {
+                year,
+                month,
+                day: 1,
+            });
+            let previous_month = if month == 1 {
+                last_month_of_hebrew_year(year)
+            } else {
+                month - 1
+            };
+            let first_day = if last_day_of_hebrew_month(previous_month, year) == 30 {
+                first_of_month - 1
+            } else {
+                first_of_month
+            };
+            if first_day > self.end {
+                return None;
+            }
+            // advance to the next month, the year beginning with Tishri
+            (self.year, self.month) = match month {
+                6 => (year + 1, 7),
+                _ if month == last_month_of_hebrew_year(year) => (year, 1),
+                _ => (year, month + 1),
+            };
+            if month == 7 || first_of_month < self.start {
+                continue;
+            }
+            return Some(RoshChodesh {
+                year,
+                month,
+                first_day,
+                last_day: first_of_month,
+            });
+        }
+    }
+}
+
 /// Determines the absolute (fixed) date of the anniversary of a given Hebrew
 /// birth date in a given Hebrew year.
 pub fn hebrew_birthday(birthdate: Hebrew, h_year: i64) -> i64 {
@@ -337,3 +1243,789 @@ pub fn yahrzeit(death_date: Hebrew, year: i64) -> i64 {
         }),
     }
 }
+
+/// Kind of a Hebrew anniversary
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HebrewAnniversary {
+    /// Birthday (see [`hebrew_birthday`])
+    Birthday,
+    /// Anniversary of a death (see [`yahrzeit`])
+    Yahrzeit,
+}
+
+/// Iterator over the yearly anniversaries (as absolute dates) of a Hebrew
+/// birth or death date, beginning with the first anniversary.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     hebrew::Hebrew,
+///     holidays::{yahrzeit, HebrewAnniversaryIter},
+/// };
+///
+/// // death on 29 Elul 5784 (October 2, 2024)
+/// let death_date = Hebrew { year: 5784, month: 6, day: 29 };
+/// let yahrzeits: Vec<_> = HebrewAnniversaryIter::yahrzeits(death_date).gregorian().take(3).collect();
+/// assert_eq!(
+///     yahrzeits,
+///     [Gregorian::new(2025, 9, 22), Gregorian::new(2026, 9, 11), Gregorian::new(2027, 10, 1)]
+/// );
+///
+/// // next five yahrzeits from January 1, 2030 on
+/// let jan_1 = absolute_from_gregorian(Gregorian::new(2030, 1, 1));
+/// let next: Vec<_> = HebrewAnniversaryIter::yahrzeits(death_date)
+///     .starting_from(jan_1)
+///     .take(5)
+///     .collect();
+/// assert_eq!(next[0], yahrzeit(death_date, 5790));
+/// assert_eq!(next[4], yahrzeit(death_date, 5794));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HebrewAnniversaryIter {
+    date: Hebrew,
+    kind: HebrewAnniversary,
+    year: i64,
+}
+
+impl HebrewAnniversaryIter {
+    /// Create a new iterator over the anniversaries of a given kind of a
+    /// given Hebrew date.
+    pub fn new(date: Hebrew, kind: HebrewAnniversary) -> Self {
+        Self {
+            date,
+            kind,
+            year: date.year + 1,
+        }
+    }
+
+    /// Create a new iterator over the birthdays of a given Hebrew birth date.
+    pub fn birthdays(birthdate: Hebrew) -> Self {
+        return HebrewAnniversaryIter::new(birthdate, HebrewAnniversary::Birthday);
+    }
+
+    /// Create a new iterator over the yahrzeits of a given Hebrew death date.
+    pub fn yahrzeits(death_date: Hebrew) -> Self {
+        return HebrewAnniversaryIter::new(death_date, HebrewAnniversary::Yahrzeit);
+    }
+
+    /// Skips the anniversaries before a given absolute (fixed) date.
+    pub fn starting_from(self, absolute_date: i64) -> Self {
+        // the anniversary falls in the Hebrew year of the date, or the next
+        let year = hebrew_from_absolute(absolute_date).year.max(self.year);
+        let year = if self.anniversary(year) < absolute_date {
+            year + 1
+        } else {
+            year
+        };
+        Self { year, ..self }
+    }
+
+    /// Returns the anniversaries as Gregorian dates.
+    pub fn gregorian(self) -> impl Iterator<Item = Gregorian> {
+        return self.map(gregorian_from_absolute);
+    }
+
+    /// Returns the absolute (fixed) date of the anniversary in a given Hebrew
+    /// year.
+    fn anniversary(&self, year: i64) -> i64 {
+        return match self.kind {
+            HebrewAnniversary::Birthday => hebrew_birthday(self.date, year),
+            HebrewAnniversary::Yahrzeit => yahrzeit(self.date, year),
+        };
+    }
+}
+
+impl Iterator for HebrewAnniversaryIter {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let date = self.anniversary(self.year);
+        self.year += 1;
+        return Some(date);
+    }
+}
+
+// Observance
+
+/// Rule determining on which day a holiday falling on a weekend is observed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ObservancePolicy {
+    /// Holidays are observed on their actual date.
+    Actual,
+    /// Holidays falling on a Saturday are observed on the preceding Friday,
+    /// holidays falling on a Sunday on the following Monday (US federal
+    /// rule).
+    UsFederal,
+    /// Holidays falling on a Sunday are observed on the following Monday.
+    SundayToMonday,
+    /// Holidays falling on a Saturday or Sunday are observed on the
+    /// following Monday.
+    NextMonday,
+}
+
+impl ObservancePolicy {
+    /// Returns the absolute (fixed) date on which a holiday falling on a
+    /// given absolute date is observed, saturating at the bounds of `i64`.
+    pub fn observed(&self, absolute_date: i64) -> i64 {
+        let weekday = weekday_from_absolute(absolute_date);
+        return match (self, weekday) {
+            (ObservancePolicy::UsFederal, Weekday::Saturday) => absolute_date.saturating_sub(1),
+            (ObservancePolicy::NextMonday, Weekday::Saturday) => absolute_date.saturating_add(2),
+            (
+                ObservancePolicy::UsFederal
+                | ObservancePolicy::SundayToMonday
+                | ObservancePolicy::NextMonday,
+                Weekday::Sunday,
+            ) => absolute_date.saturating_add(1),
+            _ => absolute_date,
+        };
+    }
+}
+
+/// Actual and observed absolute (fixed) date of a holiday
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Observance {
+    pub actual: i64,
+    pub observed: i64,
+}
+
+/// Returns the actual and observed absolute (fixed) date of a holiday falling
+/// on a given absolute date according to a given observance policy.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     holidays::{independence_day, observance, ObservancePolicy},
+/// };
+///
+/// // Independence Day 2026 falls on a Saturday
+/// let o = observance(independence_day(2026), ObservancePolicy::UsFederal);
+/// assert_eq!(o.actual, absolute_from_gregorian(Gregorian::new(2026, 7, 4)));
+/// assert_eq!(o.observed, absolute_from_gregorian(Gregorian::new(2026, 7, 3)));
+/// ```
+pub fn observance(absolute_date: i64, policy: ObservancePolicy) -> Observance {
+    return Observance {
+        actual: absolute_date,
+        observed: policy.observed(absolute_date),
+    };
+}
+
+// Holiday registry
+
+/// Tradition or country a holiday belongs to
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Tag {
+    UnitedStates,
+    UsFederal,
+    Christian,
+    Orthodox,
+    Islamic,
+    Jewish,
+}
+
+impl Tag {
+    /// Returns the stable identifier of the tag (e.g. "us").
+    pub fn id(&self) -> &'static str {
+        return match self {
+            Tag::UnitedStates => "us",
+            Tag::UsFederal => "usFederal",
+            Tag::Christian => "christian",
+            Tag::Orthodox => "orthodox",
+            Tag::Islamic => "islamic",
+            Tag::Jewish => "jewish",
+        };
+    }
+}
+
+/// Built-in holiday
+///
+/// # Example
+///
+/// ```
+/// use calendars::holidays::{easter, Holiday, Tag, HOLIDAYS};
+///
+/// let jewish: Vec<&str> = HOLIDAYS
+///     .iter()
+///     .filter(|holiday| holiday.has_tag(Tag::Jewish))
+///     .map(|holiday| holiday.name())
+///     .collect();
+/// assert_eq!(jewish[..5], ["Yom Kippur", "Passover", "Purim", "Ta'anit Esther", "Tisha B'Av"]);
+///
+/// let holiday: Holiday = "easter".parse().unwrap();
+/// assert_eq!(holiday.dates_in_gregorian_year(2024), [easter(2024)]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Holiday {
+    IndependenceDay,
+    LaborDay,
+    MemorialDay,
+    DaylightSavingsStart,
+    DaylightSavingsEnd,
+    NewYearsDay,
+    MartinLutherKingDay,
+    PresidentsDay,
+    Juneteenth,
+    ColumbusDay,
+    VeteransDay,
+    Thanksgiving,
+    Christmas,
+    Advent,
+    Epiphany,
+    EasternOrthodoxChristmas,
+    NicaeanRuleEaster,
+    GreatLentStart,
+    OrthodoxPentecost,
+    ApostlesFast,
+    Easter,
+    Pentecost,
+    Septuagesima,
+    MardiGras,
+    AshWednesday,
+    PalmSunday,
+    GoodFriday,
+    Ascension,
+    TrinitySunday,
+    CorpusChristi,
+    IslamicNewYear,
+    Ashura,
+    MuladAlNabi,
+    RamadanStart,
+    LaylatAlQadr,
+    EidAlFitr,
+    EidAlAdha,
+    YomKippur,
+    Passover,
+    Purim,
+    TaAnitEsther,
+    TishaBAv,
+    RoshHashanah,
+    Sukkot,
+    SheminiAtzeret,
+    SimchatTorah,
+    SimchatTorahIsrael,
+    Hanukkah,
+    TuBiShvat,
+    LagBaOmer,
+    Shavuot,
+    TzomGedaliah,
+    AsarahBTevet,
+    ShivaAsarBTammuz,
+}
+
+/// Registry of all built-in holidays
+pub static HOLIDAYS: [Holiday; 54] = [
+    Holiday::IndependenceDay,
+    Holiday::LaborDay,
+    Holiday::MemorialDay,
+    Holiday::DaylightSavingsStart,
+    Holiday::DaylightSavingsEnd,
+    Holiday::NewYearsDay,
+    Holiday::MartinLutherKingDay,
+    Holiday::PresidentsDay,
+    Holiday::Juneteenth,
+    Holiday::ColumbusDay,
+    Holiday::VeteransDay,
+    Holiday::Thanksgiving,
+    Holiday::Christmas,
+    Holiday::Advent,
+    Holiday::Epiphany,
+    Holiday::EasternOrthodoxChristmas,
+    Holiday::NicaeanRuleEaster,
+    Holiday::GreatLentStart,
+    Holiday::OrthodoxPentecost,
+    Holiday::ApostlesFast,
+    Holiday::Easter,
+    Holiday::Pentecost,
+    Holiday::Septuagesima,
+    Holiday::MardiGras,
+    Holiday::AshWednesday,
+    Holiday::PalmSunday,
+    Holiday::GoodFriday,
+    Holiday::Ascension,
+    Holiday::TrinitySunday,
+    Holiday::CorpusChristi,
+    Holiday::IslamicNewYear,
+    Holiday::Ashura,
+    Holiday::MuladAlNabi,
+    Holiday::RamadanStart,
+    Holiday::LaylatAlQadr,
+    Holiday::EidAlFitr,
+    Holiday::EidAlAdha,
+    Holiday::YomKippur,
+    Holiday::Passover,
+    Holiday::Purim,
+    Holiday::TaAnitEsther,
+    Holiday::TishaBAv,
+    Holiday::RoshHashanah,
+    Holiday::Sukkot,
+    Holiday::SheminiAtzeret,
+    Holiday::SimchatTorah,
+    Holiday::SimchatTorahIsrael,
+    Holiday::Hanukkah,
+    Holiday::TuBiShvat,
+    Holiday::LagBaOmer,
+    Holiday::Shavuot,
+    Holiday::TzomGedaliah,
+    Holiday::AsarahBTevet,
+    Holiday::ShivaAsarBTammuz,
+];
+
+/// Returns the days of a holiday computed for the Gregorian year in whose
+/// winter it begins (see [`epiphany`] and [`hanukkah`]) that fall into a
+/// given Gregorian year, from the holidays computed for it and for the
+/// preceding year (which coincide at the start of [`HOLIDAY_YEAR_RANGE`]).
+fn days_in_gregorian_year(dates: impl IntoIterator<Item = i64>, year: i64) -> Vec<i64> {
+    let mut days: Vec<i64> = dates
+        .into_iter()
+        .filter(|date| gregorian_from_absolute(*date).year == year)
+        .collect();
+    days.dedup();
+    return days;
+}
+
+impl Holiday {
+    /// Returns the stable identifier of the holiday (e.g. "yomKippur").
+    pub fn id(&self) -> &'static str {
+        return match self {
+            Holiday::IndependenceDay => "independenceDay",
+            Holiday::LaborDay => "laborDay",
+            Holiday::MemorialDay => "memorialDay",
+            Holiday::DaylightSavingsStart => "daylightSavingsStart",
+            Holiday::DaylightSavingsEnd => "daylightSavingsEnd",
+            Holiday::NewYearsDay => "newYearsDay",
+            Holiday::MartinLutherKingDay => "martinLutherKingDay",
+            Holiday::PresidentsDay => "presidentsDay",
+            Holiday::Juneteenth => "juneteenth",
+            Holiday::ColumbusDay => "columbusDay",
+            Holiday::VeteransDay => "veteransDay",
+            Holiday::Thanksgiving => "thanksgiving",
+            Holiday::Christmas => "christmas",
+            Holiday::Advent => "advent",
+            Holiday::Epiphany => "epiphany",
+            Holiday::EasternOrthodoxChristmas => "easternOrthodoxChristmas",
+            Holiday::NicaeanRuleEaster => "nicaeanRuleEaster",
+            Holiday::GreatLentStart => "greatLentStart",
+            Holiday::OrthodoxPentecost => "orthodoxPentecost",
+            Holiday::ApostlesFast => "apostlesFast",
+            Holiday::Easter => "easter",
+            Holiday::Pentecost => "pentecost",
+            Holiday::Septuagesima => "septuagesima",
+            Holiday::MardiGras => "mardiGras",
+            Holiday::AshWednesday => "ashWednesday",
+            Holiday::PalmSunday => "palmSunday",
+            Holiday::GoodFriday => "goodFriday",
+            Holiday::Ascension => "ascension",
+            Holiday::TrinitySunday => "trinitySunday",
+            Holiday::CorpusChristi => "corpusChristi",
+            Holiday::IslamicNewYear => "islamicNewYear",
+            Holiday::Ashura => "ashura",
+            Holiday::MuladAlNabi => "muladAlNabi",
+            Holiday::RamadanStart => "ramadanStart",
+            Holiday::LaylatAlQadr => "laylatAlQadr",
+            Holiday::EidAlFitr => "eidAlFitr",
+            Holiday::EidAlAdha => "eidAlAdha",
+            Holiday::YomKippur => "yomKippur",
+            Holiday::Passover => "passover",
+            Holiday::Purim => "purim",
+            Holiday::TaAnitEsther => "taAnitEsther",
+            Holiday::TishaBAv => "tishaBAv",
+            Holiday::RoshHashanah => "roshHashanah",
+            Holiday::Sukkot => "sukkot",
+            Holiday::SheminiAtzeret => "sheminiAtzeret",
+            Holiday::SimchatTorah => "simchatTorah",
+            Holiday::SimchatTorahIsrael => "simchatTorahIsrael",
+            Holiday::Hanukkah => "hanukkah",
+            Holiday::TuBiShvat => "tuBiShvat",
+            Holiday::LagBaOmer => "lagBaOmer",
+            Holiday::Shavuot => "shavuot",
+            Holiday::TzomGedaliah => "tzomGedaliah",
+            Holiday::AsarahBTevet => "asarahBTevet",
+            Holiday::ShivaAsarBTammuz => "shivaAsarBTammuz",
+        };
+    }
+
+    /// Returns the (English) display name of the holiday.
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Holiday::IndependenceDay => "Independence Day",
+            Holiday::LaborDay => "Labor Day",
+            Holiday::MemorialDay => "Memorial Day",
+            Holiday::DaylightSavingsStart => "Daylight Saving Time begins",
+            Holiday::DaylightSavingsEnd => "Daylight Saving Time ends",
+            Holiday::NewYearsDay => "New Year's Day",
+            Holiday::MartinLutherKingDay => "Martin Luther King Jr. Day",
+            Holiday::PresidentsDay => "Presidents' Day",
+            Holiday::Juneteenth => "Juneteenth",
+            Holiday::ColumbusDay => "Columbus Day",
+            Holiday::VeteransDay => "Veterans Day",
+            Holiday::Thanksgiving => "Thanksgiving",
+            Holiday::Christmas => "Christmas",
+            Holiday::Advent => "Advent",
+            Holiday::Epiphany => "Epiphany",
+            Holiday::EasternOrthodoxChristmas => "Eastern Orthodox Christmas",
+            Holiday::NicaeanRuleEaster => "Easter (Nicaean rule)",
+            Holiday::GreatLentStart => "Clean Monday (Great Lent begins)",
+            Holiday::OrthodoxPentecost => "Pentecost (Orthodox)",
+            Holiday::ApostlesFast => "Apostles' Fast begins",
+            Holiday::Easter => "Easter",
+            Holiday::Pentecost => "Pentecost",
+            Holiday::Septuagesima => "Septuagesima",
+            Holiday::MardiGras => "Mardi Gras",
+            Holiday::AshWednesday => "Ash Wednesday",
+            Holiday::PalmSunday => "Palm Sunday",
+            Holiday::GoodFriday => "Good Friday",
+            Holiday::Ascension => "Ascension Day",
+            Holiday::TrinitySunday => "Trinity Sunday",
+            Holiday::CorpusChristi => "Corpus Christi",
+            Holiday::IslamicNewYear => "Islamic New Year",
+            Holiday::Ashura => "Ashura",
+            Holiday::MuladAlNabi => "Mulad al-Nabi",
+            Holiday::RamadanStart => "Start of Ramadan",
+            Holiday::LaylatAlQadr => "Laylat al-Qadr",
+            Holiday::EidAlFitr => "Eid al-Fitr",
+            Holiday::EidAlAdha => "Eid al-Adha",
+            Holiday::YomKippur => "Yom Kippur",
+            Holiday::Passover => "Passover",
+            Holiday::Purim => "Purim",
+            Holiday::TaAnitEsther => "Ta'anit Esther",
+            Holiday::TishaBAv => "Tisha B'Av",
+            Holiday::RoshHashanah => "Rosh Hashanah",
+            Holiday::Sukkot => "Sukkot",
+            Holiday::SheminiAtzeret => "Shemini Atzeret",
+            Holiday::SimchatTorah => "Simchat Torah",
+            Holiday::SimchatTorahIsrael => "Simchat Torah (Israel)",
+            Holiday::Hanukkah => "Hanukkah",
+            Holiday::TuBiShvat => "Tu BiShvat",
+            Holiday::LagBaOmer => "Lag BaOmer",
+            Holiday::Shavuot => "Shavuot",
+            Holiday::TzomGedaliah => "Tzom Gedaliah",
+            Holiday::AsarahBTevet => "Tenth of Tevet",
+            Holiday::ShivaAsarBTammuz => "Seventeenth of Tammuz",
+        };
+    }
+
+    /// Returns the display name of the holiday in a given language.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{holidays::Holiday, locale::Locale};
+    ///
+    /// assert_eq!(Holiday::Easter.localized_name(Locale::English), "Easter");
+    /// assert_eq!(Holiday::Easter.localized_name(Locale::German), "Ostern");
+    /// assert_eq!(Holiday::Christmas.localized_name(Locale::French), "Noël");
+    /// assert_eq!(Holiday::YomKippur.localized_name(Locale::Hebrew), "יום כיפור");
+    /// assert_eq!(Holiday::EidAlFitr.localized_name(Locale::Arabic), "عيد الفطر");
+    /// assert_eq!(Holiday::ShivaAsarBTammuz.localized_name(Locale::German), "Siebzehnter Tammus");
+    /// assert_eq!(Holiday::Easter.localized_name(Locale::Hindi), "Easter");
+    /// ```
+    pub fn localized_name(&self, locale: Locale) -> &'static str {
+        return locale.holiday_name(*self).unwrap_or_else(|| self.name());
+    }
+
+    /// Returns the traditions and countries the holiday belongs to.
+    pub fn tags(&self) -> &'static [Tag] {
+        return match self {
+            Holiday::NewYearsDay
+            | Holiday::MartinLutherKingDay
+            | Holiday::PresidentsDay
+            | Holiday::MemorialDay
+            | Holiday::Juneteenth
+            | Holiday::IndependenceDay
+            | Holiday::LaborDay
+            | Holiday::ColumbusDay
+            | Holiday::VeteransDay
+            | Holiday::Thanksgiving => &[Tag::UnitedStates, Tag::UsFederal],
+            Holiday::DaylightSavingsStart | Holiday::DaylightSavingsEnd => &[Tag::UnitedStates],
+            Holiday::Christmas => &[Tag::Christian, Tag::UsFederal],
+            Holiday::Advent
+            | Holiday::Epiphany
+            | Holiday::Easter
+            | Holiday::Pentecost
+            | Holiday::Septuagesima
+            | Holiday::MardiGras
+            | Holiday::AshWednesday
+            | Holiday::PalmSunday
+            | Holiday::GoodFriday
+            | Holiday::Ascension
+            | Holiday::TrinitySunday
+            | Holiday::CorpusChristi => &[Tag::Christian],
+            Holiday::EasternOrthodoxChristmas
+            | Holiday::NicaeanRuleEaster
+            | Holiday::GreatLentStart
+            | Holiday::OrthodoxPentecost
+            | Holiday::ApostlesFast => &[Tag::Christian, Tag::Orthodox],
+            Holiday::MuladAlNabi
+            | Holiday::IslamicNewYear
+            | Holiday::Ashura
+            | Holiday::RamadanStart
+            | Holiday::LaylatAlQadr
+            | Holiday::EidAlFitr
+            | Holiday::EidAlAdha => &[Tag::Islamic],
+            Holiday::YomKippur
+            | Holiday::Passover
+            | Holiday::Purim
+            | Holiday::TaAnitEsther
+            | Holiday::TishaBAv
+            | Holiday::RoshHashanah
+            | Holiday::Sukkot
+            | Holiday::SheminiAtzeret
+            | Holiday::SimchatTorah
+            | Holiday::SimchatTorahIsrael
+            | Holiday::Hanukkah
+            | Holiday::TuBiShvat
+            | Holiday::LagBaOmer
+            | Holiday::Shavuot
+            | Holiday::TzomGedaliah
+            | Holiday::AsarahBTevet
+            | Holiday::ShivaAsarBTammuz => &[Tag::Jewish],
+        };
+    }
+
+    /// Checks whether the holiday carries a given tag.
+    pub fn has_tag(&self, tag: Tag) -> bool {
+        return self.tags().contains(&tag);
+    }
+
+    /// Returns the absolute (fixed) dates of the holiday in a given Gregorian
+    /// year. Some holidays occur twice or not at all in a Gregorian year.
+    /// Years outside [`HOLIDAY_YEAR_RANGE`] are clamped to the range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::holidays::{Holiday, HOLIDAYS, HOLIDAY_YEAR_RANGE};
+    ///
+    /// for holiday in HOLIDAYS {
+    ///     assert_eq!(
+    ///         holiday.dates_in_gregorian_year(i64::MIN),
+    ///         holiday.dates_in_gregorian_year(*HOLIDAY_YEAR_RANGE.start())
+    ///     );
+    /// }
+    /// assert_eq!(Holiday::RoshHashanah.dates_in_gregorian_year(i64::MAX).len(), 1);
+    /// ```
+    pub fn dates_in_gregorian_year(&self, year: i64) -> Vec<i64> {
+        let year = clamp_to_range(year, &HOLIDAY_YEAR_RANGE);
+        return match self {
+            Holiday::IndependenceDay => vec![independence_day(year)],
+            Holiday::LaborDay => vec![labor_day(year)],
+            Holiday::MemorialDay => vec![memorial_day(year)],
+            Holiday::DaylightSavingsStart => {
+                DstRule::UnitedStates.start(year).into_iter().collect()
+            }
+            Holiday::DaylightSavingsEnd => DstRule::UnitedStates.end(year).into_iter().collect(),
+            Holiday::NewYearsDay => vec![new_years_day(year)],
+            Holiday::MartinLutherKingDay => vec![martin_luther_king_day(year)],
+            Holiday::PresidentsDay => vec![presidents_day(year)],
+            Holiday::Juneteenth => vec![juneteenth(year)],
+            Holiday::ColumbusDay => vec![columbus_day(year)],
+            Holiday::VeteransDay => vec![veterans_day(year)],
+            Holiday::Thanksgiving => vec![thanksgiving(year)],
+            Holiday::Christmas => vec![christmas(year)],
+            Holiday::Advent => vec![advent(year)],
+            Holiday::Epiphany => days_in_gregorian_year([epiphany(year - 1), epiphany(year)], year),
+            Holiday::EasternOrthodoxChristmas => eastern_orthodox_christmas(year),
+            // Julian Easter falls in the same Gregorian year
+            Holiday::NicaeanRuleEaster => vec![nicaean_rule_easter(year)],
+            Holiday::GreatLentStart => vec![great_lent_start(year)],
+            Holiday::OrthodoxPentecost => vec![orthodox_pentecost(year)],
+            Holiday::ApostlesFast => vec![apostles_fast_start(year)],
+            Holiday::Easter => vec![easter(year)],
+            Holiday::Pentecost => vec![pentecost(year)],
+            Holiday::Septuagesima => vec![septuagesima(year)],
+            Holiday::MardiGras => vec![mardi_gras(year)],
+            Holiday::AshWednesday => vec![ash_wednesday(year)],
+            Holiday::PalmSunday => vec![palm_sunday(year)],
+            Holiday::GoodFriday => vec![good_friday(year)],
+            Holiday::Ascension => vec![ascension(year)],
+            Holiday::TrinitySunday => vec![trinity_sunday(year)],
+            Holiday::CorpusChristi => vec![corpus_christi(year)],
+            Holiday::IslamicNewYear => islamic_new_year(year),
+            Holiday::Ashura => ashura(year),
+            Holiday::MuladAlNabi => mulad_al_nabi(year),
+            Holiday::RamadanStart => ramadan_start(year),
+            Holiday::LaylatAlQadr => laylat_al_qadr(year),
+            Holiday::EidAlFitr => eid_al_fitr(year),
+            Holiday::EidAlAdha => eid_al_adha(year),
+            Holiday::YomKippur => vec![yom_kippur(year)],
+            Holiday::Passover => vec![passover(year)],
+            Holiday::Purim => vec![purim(year)],
+            Holiday::TaAnitEsther => vec![ta_anit_esther(year)],
+            Holiday::TishaBAv => vec![tisha_b_av(year)],
+            Holiday::RoshHashanah => vec![rosh_hashanah(year)],
+            Holiday::Sukkot => vec![sukkot(year)],
+            Holiday::SheminiAtzeret => vec![shemini_atzeret(year)],
+            Holiday::SimchatTorah => vec![simchat_torah(year)],
+            Holiday::SimchatTorahIsrael => vec![simchat_torah_israel(year)],
+            Holiday::Hanukkah => {
+                days_in_gregorian_year(hanukkah(year - 1).into_iter().chain(hanukkah(year)), year)
+            }
+            Holiday::TuBiShvat => vec![tu_bishvat(year)],
+            Holiday::LagBaOmer => vec![lag_ba_omer(year)],
+            Holiday::Shavuot => vec![shavuot(year)],
+            Holiday::TzomGedaliah => vec![tzom_gedaliah(year)],
+            Holiday::AsarahBTevet => asarah_b_tevet(year),
+            Holiday::ShivaAsarBTammuz => vec![shiva_asar_b_tammuz(year)],
+        };
+    }
+
+    /// Returns the number of days from (Western or Orthodox) Easter to the
+    /// holiday, if the holiday is a movable feast.
+    fn easter_offset(&self) -> Option<(bool, i64)> {
+        return match self {
+            Holiday::NicaeanRuleEaster => Some((true, 0)),
+            Holiday::GreatLentStart => Some((true, -48)),
+            Holiday::OrthodoxPentecost => Some((true, 49)),
+            Holiday::ApostlesFast => Some((true, 57)),
+            Holiday::Easter => Some((false, 0)),
+            Holiday::Pentecost => Some((false, 49)),
+            Holiday::Septuagesima => Some((false, -63)),
+            Holiday::MardiGras => Some((false, -47)),
+            Holiday::AshWednesday => Some((false, -46)),
+            Holiday::PalmSunday => Some((false, -7)),
+            Holiday::GoodFriday => Some((false, -2)),
+            Holiday::Ascension => Some((false, 39)),
+            Holiday::TrinitySunday => Some((false, 56)),
+            Holiday::CorpusChristi => Some((false, 60)),
+            _ => None,
+        };
+    }
+
+    /// Returns the absolute (fixed) dates of the holiday in a range of
+    /// Gregorian years, in chronological order. The dates of Easter are
+    /// computed once for all movable feasts (see [`easter_in_years`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::holidays::Holiday;
+    ///
+    /// let years = 1900..=2100;
+    /// for holiday in [Holiday::Pentecost, Holiday::GreatLentStart, Holiday::Hanukkah] {
+    ///     let dates = holiday.dates_in_years(years.clone());
+    ///     let expected: Vec<i64> = years
+    ///         .clone()
+    ///         .flat_map(|year| holiday.dates_in_gregorian_year(year))
+    ///         .collect();
+    ///     assert_eq!(dates, expected);
+    /// }
+    /// ```
+    pub fn dates_in_years(&self, years: RangeInclusive<i64>) -> Vec<i64> {
+        return match self.easter_offset() {
+            Some((orthodox, days)) => {
+                let easter_dates = if orthodox {
+                    orthodox_easter_in_years(years)
+                } else {
+                    easter_in_years(years)
+                };
+                easter_dates.into_iter().map(|date| date + days).collect()
+            }
+            None => years
+                .flat_map(|year| self.dates_in_gregorian_year(year))
+                .collect(),
+        };
+    }
+
+    /// Returns the actual and observed absolute (fixed) dates of the holiday
+    /// in a given Gregorian year according to a given observance policy.
+    /// The actual dates fall within the year, whereas an observed date may
+    /// fall into the previous or next year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     gregorian::{absolute_from_gregorian, Gregorian},
+    ///     holidays::{Holiday, ObservancePolicy},
+    /// };
+    ///
+    /// // Christmas 2022 falls on a Sunday
+    /// let observances = Holiday::Christmas.observances_in_gregorian_year(2022, ObservancePolicy::UsFederal);
+    /// assert_eq!(observances[0].observed, absolute_from_gregorian(Gregorian::new(2022, 12, 26)));
+    /// ```
+    pub fn observances_in_gregorian_year(
+        &self,
+        year: i64,
+        policy: ObservancePolicy,
+    ) -> Vec<Observance> {
+        return self
+            .dates_in_gregorian_year(year)
+            .into_iter()
+            .map(|date| observance(date, policy))
+            .collect();
+    }
+}
+
+/// Implement fmt::Display trait for [`Holiday`], writing its display name
+impl fmt::Display for Holiday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Parse a [`Holiday`] from its identifier (see [`Holiday::id`]).
+impl FromStr for Holiday {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        return HOLIDAYS
+            .iter()
+            .find(|holiday| holiday.id() == s)
+            .copied()
+            .ok_or_else(|| CalendarError::ParseError(format!("unknown holiday \"{}\"", s)));
+    }
+}
+
+/// Returns the built-in holidays (see [`HOLIDAYS`]) that fall within a given
+/// range of absolute (fixed) dates (including `start` and `end`) and satisfy
+/// a given filter, as pairs of absolute dates and holidays ordered by date.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, gregorian_from_absolute, Gregorian},
+///     holidays::{holidays_in_range, Holiday, Tag},
+/// };
+///
+/// // Christian holidays in the 90 days from 1 December 2024
+/// let start = absolute_from_gregorian(Gregorian::new(2024, 12, 1));
+/// let found = holidays_in_range(start, start + 89, |h| h.has_tag(Tag::Christian));
+/// let ids: Vec<&str> = found.iter().map(|(_, holiday)| holiday.id()).collect();
+/// assert_eq!(
+///     ids,
+///     ["advent", "christmas", "epiphany", "easternOrthodoxChristmas", "septuagesima"]
+/// );
+/// assert_eq!(found[2].0, absolute_from_gregorian(Gregorian::new(2025, 1, 6)));
+/// ```
+pub fn holidays_in_range<F>(start: i64, end: i64, filter: F) -> Vec<(i64, Holiday)>
+where
+    F: Fn(&Holiday) -> bool,
+{
+    if end < start {
+        return Vec::new();
+    }
+    let first_year = gregorian_from_absolute(start).year;
+    let last_year = gregorian_from_absolute(end).year;
+    let mut found: Vec<(i64, Holiday)> = (first_year..=last_year)
+        .flat_map(|year| {
+            HOLIDAYS
+                .iter()
+                .filter(|holiday| filter(holiday))
+                .flat_map(move |holiday| {
+                    holiday
+                        .dates_in_gregorian_year(year)
+                        .into_iter()
+                        .map(move |date| (date, *holiday))
+                })
+        })
+        .filter(|(date, _)| start <= *date && *date <= end)
+        .collect();
+    // stable, so that holidays on the same date keep the registry order
+    found.sort_by_key(|(date, _)| *date);
+    return found;
+}
@@ -0,0 +1,210 @@
+//! Provides the export of holidays and other recurring dates as iCalendar
+//! data (RFC 5545, requires the `ics` feature)
+//!
+//! Each date is exported as an all-day `VEVENT`, so that the resulting `.ics`
+//! files can be imported into common calendar applications.
+//!
+//! # Example
+//!
+//! ```
+//! use calendars::{hebrew::Hebrew, holidays::yahrzeit, ics::IcsCalendar};
+//!
+//! let death_date = Hebrew::new(5750, 7, 10);
+//! let ics = IcsCalendar::new()
+//!     .timestamp(0)
+//!     .holidays(2024..=2025)
+//!     .recurring("Yahrzeit", 5785..=5786, |year| [yahrzeit(death_date, year)])
+//!     .to_string();
+//! assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+//! assert!(ics.contains("SUMMARY:Easter\r\nDTSTART;VALUE=DATE:20240331\r\n"));
+//! assert!(ics.contains("SUMMARY:Yahrzeit\r\nDTSTART;VALUE=DATE:20241012\r\n"));
+//! ```
+
+use core::{fmt, ops::RangeInclusive};
+
+use crate::{
+    gregorian::{gregorian_from_absolute, Gregorian},
+    holidays::HOLIDAYS,
+    interop::absolute_time_from_unix,
+};
+
+/// Maximum length of a content line (in octets, excluding the line break)
+static LINE_LENGTH: usize = 75;
+
+/// All-day event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsEvent {
+    pub summary: String,
+    pub absolute_date: i64,
+}
+
+/// iCalendar object consisting of all-day events
+///
+/// Events whose dates lie outside the Gregorian years 1 to 9999, which
+/// iCalendar dates cannot represent, are not exported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IcsCalendar {
+    events: Vec<IcsEvent>,
+    timestamp: i64,
+}
+
+impl Default for IcsCalendar {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IcsCalendar {
+    /// Create a new iCalendar object without events, created at the current
+    /// time according to the system clock (or at the Unix epoch without the
+    /// `clock` feature).
+    pub fn new() -> Self {
+        #[cfg(feature = "clock")]
+        let timestamp = crate::interop::unix_now();
+        #[cfg(not(feature = "clock"))]
+        let timestamp = 0;
+        Self {
+            events: Vec::new(),
+            timestamp,
+        }
+    }
+
+    /// Set the creation time of the events (`DTSTAMP`) to a given Unix
+    /// timestamp.
+    pub fn timestamp(self, seconds: i64) -> Self {
+        Self {
+            timestamp: seconds,
+            ..self
+        }
+    }
+
+    /// Add an event on a given absolute (fixed) date.
+    pub fn event(mut self, summary: &str, absolute_date: i64) -> Self {
+        self.events.push(IcsEvent {
+            summary: summary.to_string(),
+            absolute_date,
+        });
+        return self;
+    }
+
+    /// Add events on the absolute (fixed) dates returned by `dates` for each
+    /// of the given years (e.g. the yahrzeits of a Hebrew death date, see
+    /// [`yahrzeit`](crate::holidays::yahrzeit)).
+    pub fn recurring<F, I>(mut self, summary: &str, years: RangeInclusive<i64>, dates: F) -> Self
+    where
+        F: Fn(i64) -> I,
+        I: IntoIterator<Item = i64>,
+    {
+        for year in years {
+            for absolute_date in dates(year) {
+                self = self.event(summary, absolute_date);
+            }
+        }
+        return self;
+    }
+
+    /// Add the built-in holidays (see [`HOLIDAYS`]) in the given Gregorian
+    /// years.
+    pub fn holidays(self, years: RangeInclusive<i64>) -> Self {
+        return HOLIDAYS.iter().fold(self, |ics, holiday| {
+            ics.recurring(holiday.name(), years.clone(), |year| {
+                holiday.dates_in_gregorian_year(year)
+            })
+        });
+    }
+
+    /// Returns the events of the iCalendar object.
+    pub fn events(&self) -> &[IcsEvent] {
+        return &self.events;
+    }
+}
+
+/// Escapes the characters of a text value that have a special meaning in
+/// iCalendar data.
+fn escape_text(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    return escaped;
+}
+
+/// Writes a content line, folded into lines of at most 75 octets.
+fn write_line(f: &mut fmt::Formatter<'_>, line: &str) -> fmt::Result {
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > LINE_LENGTH {
+            // continuation lines begin with a space
+            f.write_str("\r\n ")?;
+            length = 1;
+        }
+        write!(f, "{}", c)?;
+        length += c.len_utf8();
+    }
+    return f.write_str("\r\n");
+}
+
+/// Returns the iCalendar date (YYYYMMDD) of a given absolute (fixed) date, or
+/// `None` if its Gregorian year lies outside 1 to 9999.
+fn ics_date(absolute_date: i64) -> Option<String> {
+    let Gregorian { year, month, day } = gregorian_from_absolute(absolute_date);
+    if !(1..=9999).contains(&year) {
+        return None;
+    }
+    return Some(format!("{:04}{:02}{:02}", year, month, day));
+}
+
+/// Returns a unique identifier of an event.
+fn uid(date: &str, summary: &str) -> String {
+    let slug: String = summary
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    return format!("{}-{}@calendars", date, slug);
+}
+
+/// Implement fmt::Display trait for [`IcsCalendar`], writing the iCalendar
+/// object (with CRLF line breaks)
+impl fmt::Display for IcsCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (date, hour, minute, second) = absolute_time_from_unix(self.timestamp);
+        let timestamp = format!(
+            "{}T{:02}{:02}{:02}Z",
+            ics_date(date).unwrap_or_else(|| "19700101".to_string()),
+            hour,
+            minute,
+            second
+        );
+        write_line(f, "BEGIN:VCALENDAR")?;
+        write_line(f, "VERSION:2.0")?;
+        write_line(f, "PRODID:-//staudtlex//calendars//EN")?;
+        write_line(f, "CALSCALE:GREGORIAN")?;
+        for event in &self.events {
+            let date = match ics_date(event.absolute_date) {
+                Some(date) => date,
+                None => continue,
+            };
+            write_line(f, "BEGIN:VEVENT")?;
+            write_line(f, &format!("UID:{}", uid(&date, &event.summary)))?;
+            write_line(f, &format!("DTSTAMP:{}", timestamp))?;
+            write_line(f, &format!("SUMMARY:{}", escape_text(&event.summary)))?;
+            write_line(f, &format!("DTSTART;VALUE=DATE:{}", date))?;
+            write_line(f, "TRANSP:TRANSPARENT")?;
+            write_line(f, "END:VEVENT")?;
+        }
+        return write_line(f, "END:VCALENDAR");
+    }
+}
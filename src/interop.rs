@@ -0,0 +1,313 @@
+//! Functions converting from and to date and time representations used by
+//! other software
+
+use crate::{
+    error::CalendarError,
+    gregorian::{absolute_from_gregorian, Gregorian},
+    math::{floor_div, modulus},
+    moment::Moment,
+};
+
+/// Absolute (fixed) date of the Unix epoch (1 January 1970, Gregorian).
+pub static UNIX_EPOCH: i64 = absolute_from_gregorian(Gregorian::new(1970, 1, 1));
+
+/// Number of seconds in a day.
+pub(crate) static SECONDS_PER_DAY: i64 = 86400;
+
+/// Computes the absolute (fixed) date of a given Unix timestamp (seconds
+/// since the Unix epoch, UTC).
+pub fn absolute_from_unix(seconds: i64) -> i64 {
+    return UNIX_EPOCH + floor_div(seconds, SECONDS_PER_DAY);
+}
+
+/// Computes the Unix timestamp of midnight (UTC) on a given absolute (fixed)
+/// date.
+///
+/// The timestamp saturates at the bounds of `i64` for absolute dates more
+/// than about 10^14 days away from the Unix epoch; see
+/// [`checked_unix_from_absolute`] for a fallible alternative.
+///
+/// # Example
+///
+/// ```
+/// use calendars::interop::{unix_from_absolute, UNIX_EPOCH};
+///
+/// assert_eq!(unix_from_absolute(UNIX_EPOCH + 1), 86400);
+/// assert_eq!(unix_from_absolute(i64::MAX), i64::MAX);
+/// assert_eq!(unix_from_absolute(i64::MIN), i64::MIN);
+/// ```
+pub fn unix_from_absolute(absolute_date: i64) -> i64 {
+    return absolute_date
+        .saturating_sub(UNIX_EPOCH)
+        .saturating_mul(SECONDS_PER_DAY);
+}
+
+/// Computes the Unix timestamp of midnight (UTC) on a given absolute (fixed)
+/// date, or returns an error if the timestamp does not fit into an `i64`.
+pub fn checked_unix_from_absolute(absolute_date: i64) -> Result<i64, CalendarError> {
+    return absolute_date
+        .checked_sub(UNIX_EPOCH)
+        .and_then(|days| days.checked_mul(SECONDS_PER_DAY))
+        .ok_or(CalendarError::OutOfRange {
+            calendar: "unix".to_string(),
+            absolute_date,
+        });
+}
+
+/// Returns the current Unix timestamp (in whole seconds) according to the
+/// system clock.
+#[cfg(feature = "clock")]
+pub fn unix_now() -> i64 {
+    use std::time::SystemTime;
+
+    return match SystemTime::now().duration_since(SystemTime::UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        // the system clock is set before the Unix epoch
+        Err(e) => {
+            let remaining = e.duration();
+            -(remaining.as_secs() as i64) - i64::from(remaining.subsec_nanos() > 0)
+        }
+    };
+}
+
+/// Returns the absolute (fixed) date of the current day (UTC) according to the
+/// system clock.
+#[cfg(feature = "clock")]
+pub fn absolute_today() -> i64 {
+    return absolute_from_unix(unix_now());
+}
+
+/// Computes the absolute (fixed) date and the time of day (hour, minute,
+/// second) of a given Unix timestamp.
+pub fn absolute_time_from_unix(seconds: i64) -> (i64, i64, i64, i64) {
+    let absolute_date = absolute_from_unix(seconds);
+    let second_of_day = modulus(seconds, SECONDS_PER_DAY);
+    let hour = floor_div(second_of_day, 3600);
+    let minute = floor_div(modulus(second_of_day, 3600), 60);
+    let second = modulus(second_of_day, 60);
+    return (absolute_date, hour, minute, second);
+}
+
+/// Computes the Unix timestamp of a given absolute (fixed) date and time of
+/// day (hour, minute, second), saturating at the bounds of `i64`.
+pub fn unix_from_absolute_time(absolute_date: i64, hour: i64, minute: i64, second: i64) -> i64 {
+    return unix_from_absolute(absolute_date)
+        .saturating_add(hour.saturating_mul(3600))
+        .saturating_add(minute.saturating_mul(60))
+        .saturating_add(second);
+}
+
+/// Computes the moment (in universal time) of a given Unix timestamp.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     interop::{moment_from_unix, unix_from_moment, UNIX_EPOCH},
+///     moment::{Moment, TimeOfDay},
+/// };
+///
+/// let moment = moment_from_unix(86400 + 43200 + 30);
+/// assert_eq!(moment, Moment::new(UNIX_EPOCH + 1, TimeOfDay::new(12, 0, 30)));
+/// assert_eq!(unix_from_moment(moment), 86400 + 43200 + 30);
+/// ```
+pub fn moment_from_unix(seconds: i64) -> Moment {
+    return Moment::from(absolute_from_unix(seconds))
+        + modulus(seconds, SECONDS_PER_DAY) as f64 / SECONDS_PER_DAY as f64;
+}
+
+/// Computes the Unix timestamp of a given moment (in universal time), rounded
+/// to the nearest second.
+pub fn unix_from_moment(moment: Moment) -> i64 {
+    return ((moment.0 - UNIX_EPOCH as f64) * SECONDS_PER_DAY as f64).round() as i64;
+}
+
+/// Absolute (fixed) date of serial day 0 in the Excel 1900 date system (31
+/// December 1899, Gregorian).
+static EXCEL_1900_EPOCH: i64 = absolute_from_gregorian(Gregorian::new(1899, 12, 31));
+
+/// Absolute (fixed) date of serial day 0 in the Excel 1904 date system (1
+/// January 1904, Gregorian).
+static EXCEL_1904_EPOCH: i64 = absolute_from_gregorian(Gregorian::new(1904, 1, 1));
+
+/// Serial day number of the (non-existent) 29 February 1900 in the Excel
+/// 1900 date system.
+static EXCEL_1900_LEAP_DAY: i64 = 60;
+
+/// Returns an Option with the absolute (fixed) date of a given serial day
+/// number in the Excel (or Lotus 1-2-3) 1900 date system. Result contains
+/// `None` for serial day 60, which denotes the non-existent 29 February 1900,
+/// and for serial days whose absolute date does not fit into an `i64`.
+///
+/// NB: Excel treats 1900 as a leap year, so all serial days after
+/// 28 February 1900 are one day ahead of the actual day count.
+pub fn absolute_from_excel_1900(serial: i64) -> Option<i64> {
+    return if serial < EXCEL_1900_LEAP_DAY {
+        EXCEL_1900_EPOCH.checked_add(serial)
+    } else if serial > EXCEL_1900_LEAP_DAY {
+        (EXCEL_1900_EPOCH - 1).checked_add(serial)
+    } else {
+        None
+    };
+}
+
+/// Computes the serial day number in the Excel (or Lotus 1-2-3) 1900 date
+/// system corresponding to a given absolute (fixed) date, saturating at the
+/// bounds of `i64`.
+pub fn excel_1900_from_absolute(absolute_date: i64) -> i64 {
+    let serial = absolute_date.saturating_sub(EXCEL_1900_EPOCH);
+    return if serial < EXCEL_1900_LEAP_DAY {
+        serial
+    } else {
+        serial.saturating_add(1)
+    };
+}
+
+/// Computes the absolute (fixed) date of a given serial day number in the
+/// Excel 1904 date system, saturating at the bounds of `i64`.
+pub fn absolute_from_excel_1904(serial: i64) -> i64 {
+    return EXCEL_1904_EPOCH.saturating_add(serial);
+}
+
+/// Computes the serial day number in the Excel 1904 date system
+/// corresponding to a given absolute (fixed) date, saturating at the bounds
+/// of `i64`.
+pub fn excel_1904_from_absolute(absolute_date: i64) -> i64 {
+    return absolute_date.saturating_sub(EXCEL_1904_EPOCH);
+}
+
+/// Julian day number of absolute (fixed) day 0.
+#[cfg(feature = "time")]
+static JULIAN_DAY_OF_ABSOLUTE_ZERO: i64 = 1721425;
+
+/// Computes the absolute (fixed) date of a given [`time::Date`].
+#[cfg(feature = "time")]
+pub fn absolute_from_time_date(d: time::Date) -> i64 {
+    return d.to_julian_day() as i64 - JULIAN_DAY_OF_ABSOLUTE_ZERO;
+}
+
+/// Computes the [`time::Date`] corresponding to a given absolute (fixed)
+/// date, or returns an error if the date lies outside the range of
+/// [`time::Date`].
+///
+/// # Example
+///
+/// ```
+/// use calendars::{gregorian::Gregorian, interop::*};
+///
+/// let d = time::Date::from_calendar_date(2024, time::Month::May, 1).unwrap();
+/// assert_eq!(time_date_from_absolute(absolute_from_time_date(d)), Ok(d));
+/// assert_eq!(Gregorian::from(d), Gregorian::new(2024, 5, 1));
+/// assert_eq!(time::Date::try_from(Gregorian::new(2024, 5, 1)), Ok(d));
+/// assert!(time::Date::try_from(Gregorian::new(100000, 1, 1)).is_err());
+/// ```
+#[cfg(feature = "time")]
+pub fn time_date_from_absolute(absolute_date: i64) -> Result<time::Date, CalendarError> {
+    return absolute_date
+        .checked_add(JULIAN_DAY_OF_ABSOLUTE_ZERO)
+        .and_then(|julian_day| i32::try_from(julian_day).ok())
+        .and_then(|julian_day| time::Date::from_julian_day(julian_day).ok())
+        .ok_or(CalendarError::OutOfRange {
+            calendar: "time".to_string(),
+            absolute_date,
+        });
+}
+
+/// Implement From<time::Date> for date types of this crate, and
+/// TryFrom<date type> for time::Date (which supports a limited range of
+/// years) using the checked conversion of the date type.
+#[cfg(feature = "time")]
+macro_rules! impl_time_date_conversions {
+    ($($t:ty => $checked:path),*) => {
+        $(
+            impl From<time::Date> for $t {
+                fn from(d: time::Date) -> Self {
+                    return <$t as crate::utility::Calendar>::from_absolute(absolute_from_time_date(d));
+                }
+            }
+
+            impl TryFrom<$t> for time::Date {
+                type Error = CalendarError;
+
+                fn try_from(d: $t) -> Result<Self, Self::Error> {
+                    return time_date_from_absolute($checked(d)?);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "time")]
+impl_time_date_conversions!(
+    Gregorian => crate::gregorian::checked_absolute_from_gregorian,
+    crate::iso::Iso => crate::iso::checked_absolute_from_iso,
+    crate::ordinal::Ordinal => crate::ordinal::checked_absolute_from_ordinal
+);
+
+/// Computes the absolute (fixed) date of a given [`jiff::civil::Date`].
+#[cfg(feature = "jiff")]
+pub fn absolute_from_jiff_date(d: jiff::civil::Date) -> i64 {
+    return absolute_from_gregorian(Gregorian::new(
+        i64::from(d.year()),
+        i64::from(d.month()),
+        i64::from(d.day()),
+    ));
+}
+
+/// Computes the [`jiff::civil::Date`] corresponding to a given absolute
+/// (fixed) date, or returns an error if the date lies outside the range of
+/// [`jiff::civil::Date`].
+///
+/// # Example
+///
+/// ```
+/// use calendars::{gregorian::Gregorian, interop::*};
+///
+/// let d = jiff::civil::date(2024, 5, 1);
+/// assert_eq!(jiff_date_from_absolute(absolute_from_jiff_date(d)), Ok(d));
+/// assert_eq!(Gregorian::from(d), Gregorian::new(2024, 5, 1));
+/// assert_eq!(jiff::civil::Date::try_from(Gregorian::new(2024, 5, 1)), Ok(d));
+/// assert!(jiff::civil::Date::try_from(Gregorian::new(100000, 1, 1)).is_err());
+/// ```
+#[cfg(feature = "jiff")]
+pub fn jiff_date_from_absolute(absolute_date: i64) -> Result<jiff::civil::Date, CalendarError> {
+    let d = crate::gregorian::gregorian_from_absolute(absolute_date);
+    return i16::try_from(d.year)
+        .ok()
+        .and_then(|year| jiff::civil::Date::new(year, d.month as i8, d.day as i8).ok())
+        .ok_or(CalendarError::OutOfRange {
+            calendar: "jiff".to_string(),
+            absolute_date,
+        });
+}
+
+/// Implement From<jiff::civil::Date> for date types of this crate, and
+/// TryFrom<date type> for jiff::civil::Date (which supports a limited range
+/// of years) using the checked conversion of the date type.
+#[cfg(feature = "jiff")]
+macro_rules! impl_jiff_date_conversions {
+    ($($t:ty => $checked:path),*) => {
+        $(
+            impl From<jiff::civil::Date> for $t {
+                fn from(d: jiff::civil::Date) -> Self {
+                    return <$t as crate::utility::Calendar>::from_absolute(absolute_from_jiff_date(d));
+                }
+            }
+
+            impl TryFrom<$t> for jiff::civil::Date {
+                type Error = CalendarError;
+
+                fn try_from(d: $t) -> Result<Self, Self::Error> {
+                    return jiff_date_from_absolute($checked(d)?);
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "jiff")]
+impl_jiff_date_conversions!(
+    Gregorian => crate::gregorian::checked_absolute_from_gregorian,
+    crate::iso::Iso => crate::iso::checked_absolute_from_iso,
+    crate::ordinal::Ordinal => crate::ordinal::checked_absolute_from_ordinal
+);
@@ -1,6 +1,11 @@
 //! Functions converting from and to Islamic calendar dates
 
-use crate::math::{floor_div, modulus, sum};
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    math::{clamp_to_range, modulus},
+};
 
 /// Islamic month names
 pub static ISLAMIC_MONTH_NAMES: [&str; 12] = [
@@ -18,8 +23,23 @@ pub static ISLAMIC_MONTH_NAMES: [&str; 12] = [
     "Dhu al-Hijjah",
 ];
 
+/// Range of years supported by the conversions of Islamic dates.
+pub static ISLAMIC_YEAR_RANGE: RangeInclusive<i64> = 1..=100_000_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of Islamic
+/// dates (the dates of the years in [`ISLAMIC_YEAR_RANGE`]).
+pub static ISLAMIC_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_islamic(Islamic::new(*ISLAMIC_YEAR_RANGE.start(), 1, 1))
+        ..=absolute_from_islamic(Islamic::new(*ISLAMIC_YEAR_RANGE.end() + 1, 1, 1)) - 1;
+
 /// Islamic date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct Islamic {
     pub year: i64,
     pub month: i64,
@@ -28,18 +48,45 @@ pub struct Islamic {
 
 impl Islamic {
     /// Create new Islamic date
-    pub fn new(year: i64, month: i64, day: i64) -> Self {
+    pub const fn new(year: i64, month: i64, day: i64) -> Self {
         Self { year, month, day }
     }
+
+    /// Create a new Islamic date, or return an error if the date does not exist
+    /// or its year lies outside [`ISLAMIC_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "islamic",
+            "year",
+            self.year,
+            *ISLAMIC_YEAR_RANGE.start(),
+            *ISLAMIC_YEAR_RANGE.end(),
+        )?;
+        check_component("islamic", "month", self.month, 1, 12)?;
+        let last_day = last_day_of_islamic_month(self.month, self.year);
+        return check_component("islamic", "day", self.day, 1, last_day);
+    }
 }
 
 /// Returns true if a given Islamic year is leap, and false otherwise.
 pub fn islamic_leap_year(year: i64) -> bool {
-    return modulus(14 + (11 * year), 30) < 11;
+    return modulus(14 + (11 * modulus(year, 30)), 30) < 11;
 }
 
 /// Determines the last day of an Islamic month.
-fn last_day_of_islamic_month(month: i64, year: i64) -> i64 {
+pub fn last_day_of_islamic_month(month: i64, year: i64) -> i64 {
     if modulus(month, 2) != 0 || (month == 12 && islamic_leap_year(year)) {
         return 30;
     } else {
@@ -48,53 +95,46 @@ fn last_day_of_islamic_month(month: i64, year: i64) -> i64 {
 }
 
 /// Computes the absolute date corresponding to a given Islamic date.
-pub fn absolute_from_islamic(d: Islamic) -> i64 {
+///
+/// The result is only meaningful for years in [`ISLAMIC_YEAR_RANGE`]; see
+/// [`checked_absolute_from_islamic`].
+pub const fn absolute_from_islamic(d: Islamic) -> i64 {
     let month = d.month;
     let year = d.year;
     let day = d.day;
+    // div_euclid equals floor_div for positive divisors
     return day
         + (29 * (month - 1))
-        + floor_div(month, 2)
+        + month.div_euclid(2)
         + (year - 1) * 354
-        + floor_div(3 + (11 * year), 30)
+        + (3 + (11 * year)).div_euclid(30)
         + 227014;
 }
 
 /// Computes the Islamic date corresponding to a given absolute date.
+///
+/// Absolute dates outside [`ISLAMIC_ABSOLUTE_RANGE`] are clamped to the range,
+/// so that dates before the Islamic epoch are returned as 1 Muharram 1; see
+/// [`checked_islamic_from_absolute`] for a fallible alternative.
+///
+/// # Example
+///
+/// ```
+/// use calendars::islamic::{checked_islamic_from_absolute, islamic_from_absolute, Islamic};
+///
+/// assert_eq!(islamic_from_absolute(0), Islamic::new(1, 1, 1));
+/// assert!(checked_islamic_from_absolute(0).is_err());
+/// ```
 pub fn islamic_from_absolute(absolute_date: i64) -> Islamic {
-    if absolute_date < 227014 {
-        return Islamic {
-            year: 0,
-            month: 0,
-            day: 0,
-        };
-    }
-    let approx = floor_div(absolute_date - 227014, 355);
-    let year = approx
-        + sum(
-            |_| return 1.0,
-            approx,
-            |y| {
-                return absolute_date
-                    >= absolute_from_islamic(Islamic {
-                        year: y as i64 + 1,
-                        month: 1,
-                        day: 1,
-                    });
-            },
-        ) as i64;
-    let month = 1 + sum(
-        |_| 1.0,
-        1,
-        |m| {
-            return absolute_date
-                > absolute_from_islamic(Islamic {
-                    year,
-                    month: m as i64,
-                    day: last_day_of_islamic_month(m as i64, year),
-                });
-        },
-    ) as i64;
+    let absolute_date = clamp_to_range(absolute_date, &ISLAMIC_ABSOLUTE_RANGE);
+    let year = (30 * (absolute_date - 227015) + 10646).div_euclid(10631);
+    let prior_days = absolute_date
+        - absolute_from_islamic(Islamic {
+            year,
+            month: 1,
+            day: 1,
+        });
+    let month = (11 * prior_days + 330).div_euclid(325);
     let day = absolute_date
         - (absolute_from_islamic(Islamic {
             year,
@@ -103,3 +143,382 @@ pub fn islamic_from_absolute(absolute_date: i64) -> Islamic {
         }) - 1);
     return Islamic { year, month, day };
 }
+
+/// Computes the absolute (fixed) date from a given Islamic date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`ISLAMIC_YEAR_RANGE`].
+pub fn checked_absolute_from_islamic(d: Islamic) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_islamic(d));
+}
+
+/// Computes the Islamic date corresponding to a given absolute (fixed) date,
+/// or returns an error if the absolute date lies outside
+/// [`ISLAMIC_ABSOLUTE_RANGE`].
+pub fn checked_islamic_from_absolute(absolute_date: i64) -> Result<Islamic, CalendarError> {
+    check_range("islamic", absolute_date, &ISLAMIC_ABSOLUTE_RANGE)?;
+    return Ok(islamic_from_absolute(absolute_date));
+}
+
+// Tabular schemes
+
+/// Leap years of the 30-year cycle of the tabular Islamic calendar. Each
+/// pattern has 11 leap years, in which Dhu al-Hijjah has 30 instead of 29
+/// days.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IslamicLeapYears {
+    /// Years 2, 5, 7, 10, 13, 15, 18, 21, 24, 26, and 29 (Kushyar ibn Labban)
+    Kushyar,
+    /// Years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, and 29 (the most common
+    /// pattern, used by [`absolute_from_islamic`] and
+    /// [`islamic_from_absolute`])
+    Standard,
+    /// Years 2, 5, 8, 10, 13, 16, 19, 21, 24, 27, and 29 (Fatimid, used by
+    /// the Ismaili and Bohra communities)
+    Fatimid,
+    /// Years 2, 5, 8, 11, 13, 16, 19, 21, 24, 27, and 30 (Habash al-Hasib)
+    HabashAlHasib,
+}
+
+impl IslamicLeapYears {
+    /// Returns the constant c of the pattern, such that a year y is leap if
+    /// (11y + c) mod 30 < 11.
+    fn offset(&self) -> i64 {
+        return match self {
+            IslamicLeapYears::Kushyar => 15,
+            IslamicLeapYears::Standard => 14,
+            IslamicLeapYears::Fatimid => 11,
+            IslamicLeapYears::HabashAlHasib => 9,
+        };
+    }
+}
+
+/// Epoch (1 Muharram 1 AH) of the tabular Islamic calendar
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum IslamicEpoch {
+    /// Friday, July 16, 622 (Julian), used by [`absolute_from_islamic`] and
+    /// [`islamic_from_absolute`]
+    Civil,
+    /// Thursday, July 15, 622 (Julian)
+    Astronomical,
+}
+
+/// Scheme of the tabular Islamic calendar, combining a leap year pattern with
+/// an epoch. Different communities use different schemes, which may disagree
+/// by a day or two.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     islamic::{absolute_from_islamic, islamic_from_absolute, Islamic, IslamicScheme},
+/// };
+///
+/// // Ramadan 1445 began on March 10, 2024 in the Fatimid calendar
+/// let date = IslamicScheme::FATIMID.absolute_from_islamic(Islamic::new(1445, 9, 1));
+/// assert_eq!(date, absolute_from_gregorian(Gregorian::new(2024, 3, 10)));
+/// assert_eq!(IslamicScheme::FATIMID.islamic_from_absolute(date), Islamic::new(1445, 9, 1));
+/// assert_eq!(islamic_from_absolute(date), Islamic::new(1445, 8, 29));
+///
+/// // the standard scheme agrees with the Islamic calendar functions
+/// for absolute_date in 730000..740000 {
+///     let d = IslamicScheme::STANDARD.islamic_from_absolute(absolute_date);
+///     assert_eq!(d, islamic_from_absolute(absolute_date));
+///     assert_eq!(absolute_from_islamic(d), absolute_date);
+/// }
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct IslamicScheme {
+    pub leap_years: IslamicLeapYears,
+    pub epoch: IslamicEpoch,
+}
+
+impl IslamicScheme {
+    /// Standard leap years and civil epoch (see [`absolute_from_islamic`])
+    pub const STANDARD: IslamicScheme = IslamicScheme {
+        leap_years: IslamicLeapYears::Standard,
+        epoch: IslamicEpoch::Civil,
+    };
+
+    /// Fatimid leap years and astronomical epoch, used by the Ismaili and
+    /// Bohra communities
+    pub const FATIMID: IslamicScheme = IslamicScheme {
+        leap_years: IslamicLeapYears::Fatimid,
+        epoch: IslamicEpoch::Astronomical,
+    };
+
+    /// Create a new scheme from a leap year pattern and an epoch.
+    pub const fn new(leap_years: IslamicLeapYears, epoch: IslamicEpoch) -> Self {
+        Self { leap_years, epoch }
+    }
+
+    /// Returns the absolute (fixed) date of the day before the epoch.
+    fn epoch_offset(&self) -> i64 {
+        return match self.epoch {
+            IslamicEpoch::Civil => 227014,
+            IslamicEpoch::Astronomical => 227013,
+        };
+    }
+
+    /// Returns true if a given Islamic year is leap, and false otherwise.
+    pub fn leap_year(&self, year: i64) -> bool {
+        return modulus(self.leap_years.offset() + (11 * year), 30) < 11;
+    }
+
+    /// Determines the last day of an Islamic month.
+    pub fn last_day_of_month(&self, month: i64, year: i64) -> i64 {
+        if modulus(month, 2) != 0 || (month == 12 && self.leap_year(year)) {
+            return 30;
+        } else {
+            return 29;
+        }
+    }
+
+    /// Computes the absolute (fixed) date corresponding to a given Islamic
+    /// date.
+    pub fn absolute_from_islamic(&self, d: Islamic) -> i64 {
+        return d.day
+            + (29 * (d.month - 1))
+            + d.month.div_euclid(2)
+            + (d.year - 1) * 354
+            + (self.leap_years.offset() - 11 + (11 * d.year)).div_euclid(30)
+            + self.epoch_offset();
+    }
+
+    /// Computes the Islamic date corresponding to a given absolute (fixed)
+    /// date.
+    ///
+    /// Absolute dates outside [`ISLAMIC_ABSOLUTE_RANGE`] are clamped to the
+    /// range.
+    pub fn islamic_from_absolute(&self, absolute_date: i64) -> Islamic {
+        let absolute_date = clamp_to_range(absolute_date, &ISLAMIC_ABSOLUTE_RANGE);
+        let new_year = |year| self.absolute_from_islamic(Islamic::new(year, 1, 1));
+        let mut year = (30 * (absolute_date - self.epoch_offset() - 1)).div_euclid(10631) + 1;
+        while absolute_date < new_year(year) {
+            year -= 1;
+        }
+        while absolute_date >= new_year(year + 1) {
+            year += 1;
+        }
+        let prior_days = absolute_date - new_year(year);
+        let month = (11 * prior_days + 330).div_euclid(325);
+        let day = absolute_date - self.absolute_from_islamic(Islamic::new(year, month, 1)) + 1;
+        return Islamic { year, month, day };
+    }
+}
+
+// Umm al-Qura calendar
+
+/// Range of years supported by the conversions of Umm al-Qura dates: the
+/// years of the published tables (1882 to 2174 CE).
+pub static UMM_AL_QURA_YEAR_RANGE: RangeInclusive<i64> = 1300..=1600;
+
+/// Absolute (fixed) date of 1 Muharram 1300 AH (12 November 1882), the first
+/// day of the published Umm al-Qura tables
+static UMM_AL_QURA_EPOCH: i64 = 687337;
+
+/// Lengths of the months of the years in [`UMM_AL_QURA_YEAR_RANGE`]
+/// according to the published Umm al-Qura tables: bit `m - 1` is set if month
+/// `m` has 30 days, and clear if it has 29 days.
+static UMM_AL_QURA_MONTH_LENGTHS: [u16; 301] = [
+    0x555, 0x2AB, 0x937, 0x2B6, 0x576, 0x36C, 0xB55, 0xAAA, 0x956, 0x49E, 0x95D, 0x2BA, 0x5B5,
+    0x3AA, 0xB4B, 0xA96, 0x52E, 0x2AD, 0x56D, 0xB5A, 0x752, 0xF25, 0xE8A, 0xD16, 0xA56, 0xAB5,
+    0x6B4, 0xDA9, 0xB92, 0xB25, 0x64B, 0xA9B, 0x35A, 0x6D9, 0x5D4, 0xDA5, 0xD4A, 0xA95, 0x536,
+    0x975, 0x2F4, 0x6E9, 0x6D4, 0x6A9, 0x535, 0x25D, 0x4BD, 0x9BA, 0x3B4, 0xB69, 0xB2A, 0xA55,
+    0x4AD, 0xA5D, 0x2DA, 0x6D9, 0xEAA, 0xE94, 0xD2A, 0xC56, 0x4AE, 0xA6D, 0x56A, 0xD55, 0xD4A,
+    0xA93, 0x52B, 0xA5B, 0x53A, 0x6B5, 0xEA9, 0xD52, 0xD29, 0xA55, 0x4AD, 0x56D, 0xAEA, 0x6E4,
+    0xED1, 0xDA2, 0xAAA, 0x95A, 0x2DA, 0x5B9, 0xBB2, 0x764, 0x6C9, 0x555, 0x2AB, 0x4DB, 0xABA,
+    0x5B4, 0xDA9, 0xD52, 0xAA5, 0x92D, 0x26D, 0x8ED, 0x2DA, 0xAD5, 0xAA5, 0xA4B, 0x497, 0x937,
+    0x2B6, 0x975, 0xD69, 0xD52, 0xC95, 0x92B, 0x25B, 0x4DB, 0x9D5, 0x5D2, 0xDA5, 0xD4A, 0xA95,
+    0x54D, 0xAAD, 0x3AA, 0xBD2, 0xBC4, 0xB89, 0xA95, 0x52D, 0x5AD, 0xB6A, 0x6D4, 0xDC9, 0xD92,
+    0xAA6, 0x956, 0x2AE, 0x56D, 0x36A, 0xB55, 0xAAA, 0x94D, 0x49D, 0x95D, 0x2BA, 0x5B5, 0x5AA,
+    0xD55, 0xA9A, 0x92E, 0x26E, 0x55D, 0xADA, 0x6D4, 0x6A5, 0xB27, 0xA4D, 0x4AD, 0x56D, 0xB5A,
+    0x754, 0xF49, 0xE92, 0xD26, 0xA56, 0x356, 0x6B5, 0xBAA, 0xB92, 0xB25, 0x68B, 0xA9B, 0x55A,
+    0xADA, 0x5B4, 0xDA9, 0xB52, 0xA9A, 0x536, 0x276, 0x575, 0xAF2, 0x6D4, 0x6A9, 0x555, 0x2AD,
+    0x4BD, 0x9BA, 0x574, 0xB69, 0xB52, 0xA95, 0x52D, 0xA5D, 0x4DA, 0xAD9, 0x6B2, 0xE95, 0xE2A,
+    0xC96, 0x92E, 0xAAD, 0x56A, 0xD65, 0xD4A, 0xD15, 0x62B, 0xC5B, 0x53A, 0x6B5, 0xDB2, 0xD64,
+    0xD29, 0xA55, 0x4AD, 0x96D, 0xAEA, 0x6E8, 0xED1, 0xDA4, 0xD4A, 0xA6A, 0x2DA, 0x5B9, 0xB72,
+    0xB68, 0x6D1, 0x655, 0x4AB, 0x95B, 0x2BA, 0x5B5, 0xDA9, 0xD52, 0xCA6, 0x94E, 0x46E, 0x95D,
+    0x4DA, 0xAD5, 0xAAA, 0xA4D, 0x49B, 0x937, 0x4B6, 0x975, 0xD6A, 0xD52, 0xAA5, 0x94B, 0x2AB,
+    0x55B, 0xAD9, 0x5D2, 0xDC5, 0xD92, 0xB25, 0x555, 0xAB5, 0x5B4, 0xBA9, 0x7A2, 0x745, 0x593,
+    0xAAB, 0x4D6, 0x9D6, 0x5D2, 0xBA5, 0xB4A, 0xA95, 0x4AD, 0x15D, 0x2DD, 0x9DA, 0x5B4, 0x5A9,
+    0x52D, 0x25B, 0x8B7, 0x176, 0x56D, 0xB6A, 0xACA, 0xA96, 0x52B, 0x15B, 0x2BB, 0x5B6, 0xDAA,
+    0xB94, 0xD46, 0xA8D, 0x52D, 0xA9D, 0x55A, 0x755, 0x749, 0xF13, 0xE4A, 0xA96, 0x556, 0x6B5,
+    0xBAA, 0xB94,
+];
+
+/// Returns the month lengths of a given year of the Umm al-Qura tables (see
+/// [`UMM_AL_QURA_MONTH_LENGTHS`]), or `None` if the year lies outside
+/// [`UMM_AL_QURA_YEAR_RANGE`].
+fn umm_al_qura_month_lengths(year: i64) -> Option<u16> {
+    if !UMM_AL_QURA_YEAR_RANGE.contains(&year) {
+        return None;
+    }
+    return Some(UMM_AL_QURA_MONTH_LENGTHS[(year - UMM_AL_QURA_YEAR_RANGE.start()) as usize]);
+}
+
+/// Computes the absolute (fixed) date of the first day of a given month of
+/// the Umm al-Qura calendar. Years outside [`UMM_AL_QURA_YEAR_RANGE`] fall
+/// back to the tabular [`Islamic`] calendar.
+pub fn umm_al_qura_month_start(month: i64, year: i64) -> i64 {
+    let Some(lengths) = umm_al_qura_month_lengths(year) else {
+        return absolute_from_islamic(Islamic::new(year, month, 1));
+    };
+    let prior_years: i64 = UMM_AL_QURA_MONTH_LENGTHS
+        [..(year - UMM_AL_QURA_YEAR_RANGE.start()) as usize]
+        .iter()
+        .map(|lengths| 12 * 29 + lengths.count_ones() as i64)
+        .sum();
+    let prior_months = (1..month.clamp(1, 13))
+        .map(|m| 29 + ((lengths >> (m - 1)) & 1) as i64)
+        .sum::<i64>();
+    return UMM_AL_QURA_EPOCH + prior_years + prior_months;
+}
+
+/// Determines the last day of a month of the Umm al-Qura calendar. Years
+/// outside [`UMM_AL_QURA_YEAR_RANGE`] fall back to the tabular [`Islamic`]
+/// calendar.
+pub fn last_day_of_umm_al_qura_month(month: i64, year: i64) -> i64 {
+    return match umm_al_qura_month_lengths(year) {
+        Some(lengths) if (1..=12).contains(&month) => 29 + ((lengths >> (month - 1)) & 1) as i64,
+        _ => last_day_of_islamic_month(month, year),
+    };
+}
+
+/// Islamic date of the Umm al-Qura calendar, the official calendar of Saudi
+/// Arabia. Unlike the tabular [`Islamic`] calendar, its months follow the
+/// moon as seen from Mecca; their lengths are taken from the published Umm
+/// al-Qura tables of the years 1300 to 1600 AH (see
+/// [`UMM_AL_QURA_YEAR_RANGE`]).
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     islamic::{absolute_from_islamic, absolute_from_umm_al_qura, Islamic, UmmAlQura},
+///     utility::Calendar,
+/// };
+///
+/// // Ramadan 1446 began on March 1, 2025; Shawwal on March 30, 2025
+/// let march_1 = absolute_from_gregorian(Gregorian::new(2025, 3, 1));
+/// assert_eq!(absolute_from_umm_al_qura(UmmAlQura::new(1446, 9, 1)), march_1);
+/// assert_eq!(absolute_from_umm_al_qura(UmmAlQura::new(1446, 10, 1)), march_1 + 29);
+/// // while the tabular calendar is a day late
+/// assert_eq!(absolute_from_islamic(Islamic::new(1446, 10, 1)), march_1 + 30);
+///
+/// let date = Gregorian::new(2025, 3, 30).to_date();
+/// assert_eq!(date.convert_to("ummAlQura").unwrap().to_string(), "ummAlQura:1446-10-1");
+/// assert_eq!(date.convert_to("islamic").unwrap().to_string(), "islamic:1446-9-30");
+///
+/// // direct conversions and comparisons with other date types
+/// assert_eq!(UmmAlQura::try_from(Gregorian::new(2025, 3, 30)), Ok(UmmAlQura::new(1446, 10, 1)));
+/// assert_eq!(Gregorian::from(UmmAlQura::new(1446, 9, 1)), Gregorian::new(2025, 3, 1));
+/// assert!(UmmAlQura::new(1446, 10, 1) == Gregorian::new(2025, 3, 30));
+/// assert!(UmmAlQura::try_new(1601, 1, 1).is_err());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UmmAlQura {
+    pub year: i64,
+    pub month: i64,
+    pub day: i64,
+}
+
+impl UmmAlQura {
+    /// Create new Umm al-Qura date
+    pub const fn new(year: i64, month: i64, day: i64) -> Self {
+        Self { year, month, day }
+    }
+
+    /// Create a new Umm al-Qura date, or return an error if the date does not
+    /// exist or its year lies outside [`UMM_AL_QURA_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "ummAlQura",
+            "year",
+            self.year,
+            *UMM_AL_QURA_YEAR_RANGE.start(),
+            *UMM_AL_QURA_YEAR_RANGE.end(),
+        )?;
+        check_component("ummAlQura", "month", self.month, 1, 12)?;
+        let last_day = last_day_of_umm_al_qura_month(self.month, self.year);
+        return check_component("ummAlQura", "day", self.day, 1, last_day);
+    }
+}
+
+/// Computes the absolute date corresponding to a given Umm al-Qura date.
+///
+/// The result is only meaningful for years in [`UMM_AL_QURA_YEAR_RANGE`];
+/// see [`checked_absolute_from_umm_al_qura`].
+pub fn absolute_from_umm_al_qura(d: UmmAlQura) -> i64 {
+    return umm_al_qura_month_start(d.month, d.year) + d.day - 1;
+}
+
+/// Returns the range of absolute (fixed) dates of the years in
+/// [`UMM_AL_QURA_YEAR_RANGE`].
+fn umm_al_qura_absolute_range() -> RangeInclusive<i64> {
+    let last_year = *UMM_AL_QURA_YEAR_RANGE.end();
+    return umm_al_qura_month_start(1, *UMM_AL_QURA_YEAR_RANGE.start())
+        ..=umm_al_qura_month_start(12, last_year) + last_day_of_umm_al_qura_month(12, last_year)
+            - 1;
+}
+
+/// Computes the Umm al-Qura date corresponding to a given absolute date.
+///
+/// Absolute dates outside the years of [`UMM_AL_QURA_YEAR_RANGE`] are clamped
+/// to the range; see [`checked_umm_al_qura_from_absolute`] for a fallible
+/// alternative.
+pub fn umm_al_qura_from_absolute(absolute_date: i64) -> UmmAlQura {
+    let absolute_date = clamp_to_range(absolute_date, &umm_al_qura_absolute_range());
+    // the tabular calendar differs by at most a few days
+    let approx = islamic_from_absolute(absolute_date);
+    let (mut year, mut month) = (approx.year, approx.month);
+    loop {
+        let start = umm_al_qura_month_start(month, year);
+        if absolute_date < start {
+            (year, month) = if month == 1 {
+                (year - 1, 12)
+            } else {
+                (year, month - 1)
+            };
+        } else if absolute_date >= start + last_day_of_umm_al_qura_month(month, year) {
+            (year, month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+        } else {
+            return UmmAlQura::new(year, month, absolute_date - start + 1);
+        }
+    }
+}
+
+/// Computes the absolute (fixed) date from a given Umm al-Qura date, or
+/// returns an error if the date does not exist or its year lies outside
+/// [`UMM_AL_QURA_YEAR_RANGE`].
+pub fn checked_absolute_from_umm_al_qura(d: UmmAlQura) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_umm_al_qura(d));
+}
+
+/// Computes the Umm al-Qura date corresponding to a given absolute (fixed)
+/// date, or returns an error if the absolute date lies outside the years of
+/// [`UMM_AL_QURA_YEAR_RANGE`].
+pub fn checked_umm_al_qura_from_absolute(absolute_date: i64) -> Result<UmmAlQura, CalendarError> {
+    check_range("ummAlQura", absolute_date, &umm_al_qura_absolute_range())?;
+    return Ok(umm_al_qura_from_absolute(absolute_date));
+}
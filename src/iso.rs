@@ -1,12 +1,38 @@
 //! Functions converting from and to ISO week calendar dates
 
+use core::ops::RangeInclusive;
+
 use crate::{
-    gregorian::{absolute_from_gregorian, gregorian_from_absolute, Gregorian},
-    math::modulus,
+    error::{check_component, check_range, CalendarError},
+    gregorian::{
+        absolute_from_gregorian, gregorian_from_absolute, Gregorian, GREGORIAN_ABSOLUTE_RANGE,
+    },
+    math::clamp_to_range,
 };
 
+pub use crate::weekday::kday_on_or_before;
+
+/// Range of years supported by the conversions of ISO dates.
+pub static ISO_YEAR_RANGE: RangeInclusive<i64> = -1_000_000_000_000_000..=1_000_000_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of ISO
+/// dates (the dates of the years in [`ISO_YEAR_RANGE`]).
+pub static ISO_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_iso(Iso::new(*ISO_YEAR_RANGE.start(), 1, 1))
+        ..=absolute_from_iso(Iso::new(
+            *ISO_YEAR_RANGE.end(),
+            iso_weeks_in_year(*ISO_YEAR_RANGE.end()),
+            7,
+        ));
+
 /// Iso week date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct Iso {
     pub year: i64,
     pub week: i64,
@@ -15,19 +41,65 @@ pub struct Iso {
 
 impl Iso {
     /// Create new ISO week date
-    pub fn new(year: i64, week: i64, day: i64) -> Self {
+    pub const fn new(year: i64, week: i64, day: i64) -> Self {
         Self { year, week, day }
     }
+
+    /// Create a new ISO week date, or return an error if the date does not
+    /// exist or its year lies outside [`ISO_YEAR_RANGE`].
+    pub fn try_new(year: i64, week: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, week, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "iso",
+            "year",
+            self.year,
+            *ISO_YEAR_RANGE.start(),
+            *ISO_YEAR_RANGE.end(),
+        )?;
+        check_component("iso", "week", self.week, 1, iso_weeks_in_year(self.year))?;
+        return check_component("iso", "day", self.day, 1, 7);
+    }
+}
+
+/// Returns the ISO 8601 representation of a year: four digits for the years
+/// 0 to 9999 (e.g. "0586"), and an expanded representation with a sign and
+/// at least four digits otherwise (e.g. "-0586" or "+10000").
+pub fn format_iso_year(year: i64) -> String {
+    return if (0..=9999).contains(&year) {
+        format!("{:04}", year)
+    } else if year < 0 {
+        format!("-{:04}", year.unsigned_abs())
+    } else {
+        format!("+{}", year)
+    };
 }
 
-/// Computes the absolute date of a given week day in the seven-day interval
-/// ending on date.
-pub fn kday_on_or_before(absolute_date: i64, k: i64) -> i64 {
-    return absolute_date - modulus(absolute_date - k, 7);
+/// Returns the number of weeks (52 or 53) of a given ISO year.
+pub const fn iso_weeks_in_year(year: i64) -> i64 {
+    return iso_from_absolute(absolute_from_gregorian(Gregorian {
+        year,
+        month: 12,
+        day: 28,
+    }))
+    .week;
 }
 
 /// Computes the absolute (fixed) date from an ISO date.
-pub fn absolute_from_iso(d: Iso) -> i64 {
+///
+/// The result is only meaningful for years in [`ISO_YEAR_RANGE`]; see
+/// [`checked_absolute_from_iso`].
+pub const fn absolute_from_iso(d: Iso) -> i64 {
     let year = d.year;
     let week = d.week;
     let day = d.day;
@@ -43,7 +115,13 @@ pub fn absolute_from_iso(d: Iso) -> i64 {
 }
 
 /// Computes the IsoDate corresponding to a given absolute (fixed) date.
-pub fn iso_from_absolute(absolute_date: i64) -> Iso {
+///
+/// The result is only meaningful for absolute dates in
+/// [`ISO_ABSOLUTE_RANGE`]; see [`checked_iso_from_absolute`]. Absolute dates
+/// outside [`GREGORIAN_ABSOLUTE_RANGE`] are clamped to that range.
+pub const fn iso_from_absolute(absolute_date: i64) -> Iso {
+    // ISO_ABSOLUTE_RANGE is itself computed using this function
+    let absolute_date = clamp_to_range(absolute_date, &GREGORIAN_ABSOLUTE_RANGE);
     let approx = gregorian_from_absolute(absolute_date - 3).year;
     let year = if absolute_date
         >= absolute_from_iso(Iso {
@@ -63,10 +141,26 @@ pub fn iso_from_absolute(absolute_date: i64) -> Iso {
         }))
         / 7)
         + 1;
-    let day = if modulus(absolute_date, 7) == 0 {
+    let day = if absolute_date.rem_euclid(7) == 0 {
         7
     } else {
-        modulus(absolute_date, 7)
+        absolute_date.rem_euclid(7)
     };
     return Iso { year, week, day };
 }
+
+/// Computes the absolute (fixed) date from a given ISO date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`ISO_YEAR_RANGE`].
+pub fn checked_absolute_from_iso(d: Iso) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_iso(d));
+}
+
+/// Computes the ISO date corresponding to a given absolute (fixed) date,
+/// or returns an error if the absolute date lies outside
+/// [`ISO_ABSOLUTE_RANGE`].
+pub fn checked_iso_from_absolute(absolute_date: i64) -> Result<Iso, CalendarError> {
+    check_range("iso", absolute_date, &ISO_ABSOLUTE_RANGE)?;
+    return Ok(iso_from_absolute(absolute_date));
+}
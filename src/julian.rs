@@ -1,9 +1,30 @@
 //! Functions converting from and to Julian calendar dates
 
-use crate::math::{floor_div, modulus, sum};
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    math::{clamp_to_range, modulus},
+    weekday::{weekday_from_absolute, Weekday},
+};
+
+/// Range of years supported by the conversions of Julian dates.
+pub static JULIAN_YEAR_RANGE: RangeInclusive<i64> = -1_000_000_000_000_000..=1_000_000_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of Julian
+/// dates (the dates of the years in [`JULIAN_YEAR_RANGE`]).
+pub static JULIAN_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_julian(Julian::new(*JULIAN_YEAR_RANGE.start(), 1, 1))
+        ..=absolute_from_julian(Julian::new(*JULIAN_YEAR_RANGE.end(), 12, 31));
 
 /// Julian date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct Julian {
     pub year: i64,
     pub month: i64,
@@ -12,14 +33,91 @@ pub struct Julian {
 
 impl Julian {
     /// Create new Julian date
-    pub fn new(year: i64, month: i64, day: i64) -> Self {
+    pub const fn new(year: i64, month: i64, day: i64) -> Self {
         Self { year, month, day }
     }
+
+    /// Create a new Julian date, or return an error if the date does not exist
+    /// or its year lies outside [`JULIAN_YEAR_RANGE`].
+    pub fn try_new(year: i64, month: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, month, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Returns the number of the day within its year (1 January is day 1).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{julian::Julian, weekday::Weekday};
+    ///
+    /// let date = Julian::new(2024, 5, 1);
+    /// assert_eq!(date.day_of_year(), 122);
+    /// assert_eq!(date.days_remaining_in_year(), 244);
+    /// assert_eq!(date.quarter(), 2);
+    /// assert_eq!(date.week_of_month(Weekday::Sunday), 1);
+    /// assert_eq!(Julian::new(2024, 5, 31).week_of_month(Weekday::Monday), 5);
+    /// ```
+    pub fn day_of_year(&self) -> i64 {
+        return absolute_from_julian(*self) - absolute_from_julian(Self::new(self.year, 1, 1)) + 1;
+    }
+
+    /// Returns the number of days following the date in its year.
+    pub fn days_remaining_in_year(&self) -> i64 {
+        let days_in_year = if julian_leap_year(self.year) {
+            366
+        } else {
+            365
+        };
+        return days_in_year - self.day_of_year();
+    }
+
+    /// Returns the number of the week within the month of the date, for
+    /// weeks beginning on a given weekday (the week containing the first day
+    /// of the month is week 1).
+    pub fn week_of_month(&self, first_day: Weekday) -> i64 {
+        let first =
+            weekday_from_absolute(absolute_from_julian(Self::new(self.year, self.month, 1)));
+        let offset = modulus(first.number() - first_day.number(), 7);
+        return (self.day - 1 + offset).div_euclid(7) + 1;
+    }
+
+    /// Returns the quarter of the year (from 1 to 4) of the date.
+    pub fn quarter(&self) -> i64 {
+        return (self.month - 1).div_euclid(3) + 1;
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "julian",
+            "year",
+            self.year,
+            *JULIAN_YEAR_RANGE.start(),
+            *JULIAN_YEAR_RANGE.end(),
+        )?;
+        check_component("julian", "month", self.month, 1, 12)?;
+        let last_day = last_day_of_julian_month(self.month, self.year);
+        return check_component("julian", "day", self.day, 1, last_day);
+    }
+}
+
+/// Returns true if a given Julian year is a leap year.
+pub const fn julian_leap_year(year: i64) -> bool {
+    return year.rem_euclid(4) == 0;
 }
 
-/// Returns the last day (number of days) of a given Julian month.
-fn last_day_of_julian_month(month: i64, year: i64) -> i64 {
-    if month == 2 && modulus(year, 4) == 0 {
+/// Returns the last day (number of days) of a given Julian month. Months
+/// outside 1..=12 are clamped to the range.
+pub const fn last_day_of_julian_month(month: i64, year: i64) -> i64 {
+    let month = clamp_to_range(month, &(1..=12));
+    if month == 2 && julian_leap_year(year) {
         return 29;
     } else {
         let days: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
@@ -28,38 +126,60 @@ fn last_day_of_julian_month(month: i64, year: i64) -> i64 {
 }
 
 /// Computes the absolute (fixed) date corresponding to a given Julian date.
-pub fn absolute_from_julian(d: Julian) -> i64 {
+///
+/// The result is only meaningful for years in [`JULIAN_YEAR_RANGE`]; see
+/// [`checked_absolute_from_julian`].
+pub const fn absolute_from_julian(d: Julian) -> i64 {
     let year = d.year;
     let month = d.month;
     let day = d.day;
-    let f = |m: f64| return last_day_of_julian_month(m as i64, year) as f64;
-    let p = |m: f64| return (m as i64) < month;
-    return day + (sum(f, 1, p) as i64) + 365 * (year - 1) + floor_div(year - 1, 4) - 2;
+    // days in prior months of the year, assuming 30-day February, corrected
+    // for the actual length of February
+    let prior_days = (367 * month - 362).div_euclid(12)
+        - if month <= 2 {
+            0
+        } else if julian_leap_year(year) {
+            1
+        } else {
+            2
+        };
+    return day + prior_days + 365 * (year - 1) + (year - 1).div_euclid(4) - 2;
 }
 
 /// Computes the Julian date corresponding to a given absolute date.
-pub fn julian_from_absolute(absolute_date: i64) -> Julian {
-    let approx = floor_div(absolute_date + 2, 366);
-    let year = approx
-        + sum(
-            |_| return 1.0,
-            approx,
-            |y| {
-                return absolute_date >= absolute_from_julian(Julian::new(y as i64 + 1, 1, 1));
-            },
-        ) as i64;
-    let month = 1 + sum(
-        |_| return 1.0,
-        1,
-        |m| {
-            return absolute_date
-                > absolute_from_julian(Julian::new(
-                    year,
-                    m as i64,
-                    last_day_of_julian_month(m as i64, year),
-                ));
-        },
-    ) as i64;
-    let day = absolute_date - (absolute_from_julian(Julian::new(year, month, 1)) - 1);
+///
+/// Absolute dates outside [`JULIAN_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_julian_from_absolute`] for a fallible alternative.
+pub const fn julian_from_absolute(absolute_date: i64) -> Julian {
+    let absolute_date = clamp_to_range(absolute_date, &JULIAN_ABSOLUTE_RANGE);
+    // absolute date of 1 January 1 (Julian) is -1
+    let year = (4 * (absolute_date + 1) + 1464).div_euclid(1461);
+    // days in prior months of the year, corrected as if February had 30 days
+    let prior_days = absolute_date - absolute_from_julian(Julian::new(year, 1, 1));
+    let correction = if absolute_date < absolute_from_julian(Julian::new(year, 3, 1)) {
+        0
+    } else if julian_leap_year(year) {
+        1
+    } else {
+        2
+    };
+    let month = (12 * (prior_days + correction) + 373).div_euclid(367);
+    let day = absolute_date - absolute_from_julian(Julian::new(year, month, 1)) + 1;
     return Julian { year, month, day };
 }
+
+/// Computes the absolute (fixed) date from a given Julian date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`JULIAN_YEAR_RANGE`].
+pub fn checked_absolute_from_julian(d: Julian) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_julian(d));
+}
+
+/// Computes the Julian date corresponding to a given absolute (fixed) date,
+/// or returns an error if the absolute date lies outside
+/// [`JULIAN_ABSOLUTE_RANGE`].
+pub fn checked_julian_from_absolute(absolute_date: i64) -> Result<Julian, CalendarError> {
+    check_range("julian", absolute_date, &JULIAN_ABSOLUTE_RANGE)?;
+    return Ok(julian_from_absolute(absolute_date));
+}
@@ -33,16 +33,99 @@
 //!
 //! `calendars` provides functions to compute and convert dates from
 //! 11 calendars.
+//!
+//! # Supported ranges and errors
+//!
+//! Each calendar module declares the years and absolute (fixed) dates its
+//! conversions support (e.g. [`gregorian::GREGORIAN_YEAR_RANGE`] and
+//! [`gregorian::GREGORIAN_ABSOLUTE_RANGE`]). Within these ranges, all
+//! conversions are exact. For arbitrary `i64` inputs:
+//!
+//! - the `checked_*` functions and the `try_*` methods (e.g.
+//!   [`utility::Date::try_to_absolute`]) return a [`error::CalendarError`]
+//!   for dates that do not exist or lie outside the supported ranges;
+//! - the unchecked `*_from_absolute` functions (and
+//!   [`utility::Calendar::from_absolute`]) clamp absolute dates to the
+//!   supported range (e.g. Islamic dates before the Islamic epoch are
+//!   returned as 1 Muharram 1), and the cyclical Mayan Haab and Tzolkin dates
+//!   are exact for all absolute dates;
+//! - formatting never fails, writing the numbers of months that do not
+//!   exist instead of their names;
+//! - the weekday functions (e.g. [`weekday::kday_after`]), the searches for
+//!   Mayan Haab and Tzolkin dates (e.g. [`mayan::mayan_haab_on_or_after`]),
+//!   and the Excel serial day conversions saturate at the bounds of `i64`.
+//!
+//! The unchecked `absolute_from_*` functions (and
+//! [`utility::FixedConvertible::to_absolute`]) do not validate their input,
+//! and may overflow for components far outside the supported ranges; use the
+//! `checked_absolute_from_*` functions for untrusted dates. As not every Old
+//! Hindu lunar date corresponds to an absolute date,
+//! [`hindu::OldHinduLunar`] does not implement
+//! [`utility::FixedConvertible`]; use
+//! [`utility::Calendar::try_to_absolute`] instead.
+//!
+//! ```
+//! use calendars::{
+//!     hebrew::Hebrew,
+//!     islamic::Islamic,
+//!     weekday::{kday_on_or_after, weekday_from_absolute, Weekday},
+//! };
+//!
+//! assert!(Hebrew::try_new(i64::MIN, 13, 1).is_err());
+//! assert!(Islamic::try_new(i64::MIN, 12, 30).is_err());
+//! let sunday = kday_on_or_after(i64::MAX, 0);
+//! assert_eq!(weekday_from_absolute(sunday), Weekday::Sunday);
+//! ```
 
+pub mod arithmetic;
+pub mod astro;
+pub mod builder;
+pub mod business;
+pub mod civil;
+pub mod component;
+pub mod conversion;
+#[cfg(feature = "csv")]
+pub mod csv;
+pub mod datetime;
+pub mod dynamic;
+pub mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod format;
 pub mod french;
 pub mod gregorian;
 pub mod hebrew;
 pub mod helper;
 pub mod hindu;
 pub mod holidays;
+#[cfg(feature = "ics")]
+pub mod ics;
+pub mod interop;
 pub mod islamic;
 pub mod iso;
 pub mod julian;
+pub mod liturgical;
+pub mod locale;
 pub mod math;
 pub mod mayan;
+pub mod moment;
+pub mod ordering;
+pub mod ordinal;
+#[cfg(feature = "parallel")]
+pub mod parallel;
+pub mod parashah;
+pub mod parse;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod range;
+pub mod recurrence;
+pub mod regnal;
+pub mod render;
+#[cfg(feature = "rrule")]
+pub mod rrule;
+pub mod system;
+pub mod testing;
 pub mod utility;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+pub mod weekday;
@@ -0,0 +1,166 @@
+//! Provides functions to compute the seasons of the Western Christian
+//! liturgical year
+
+use core::{fmt, ops::RangeInclusive};
+
+use crate::{
+    gregorian::{absolute_from_gregorian, gregorian_from_absolute, Gregorian},
+    holidays::{advent, ash_wednesday, christmas, easter, epiphany, pentecost, HOLIDAY_YEAR_RANGE},
+    math::{clamp_to_range, floor_div},
+    weekday::{kday_after, kday_on_or_before},
+};
+
+/// Range of absolute (fixed) dates supported by [`liturgical_week`] (the
+/// dates of the years in [`HOLIDAY_YEAR_RANGE`] but the first, as the season
+/// of a day may depend on the Christmas of the preceding year).
+pub static LITURGICAL_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_gregorian(Gregorian::new(*HOLIDAY_YEAR_RANGE.start() + 1, 1, 1))
+        ..=absolute_from_gregorian(Gregorian::new(*HOLIDAY_YEAR_RANGE.end(), 12, 31));
+
+/// Season of the Western Christian (Roman) liturgical year
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum LiturgicalSeason {
+    Advent,
+    Christmastide,
+    Lent,
+    Triduum,
+    Eastertide,
+    OrdinaryTime,
+}
+
+impl LiturgicalSeason {
+    /// Returns the (English) name of the season.
+    pub fn name(&self) -> &'static str {
+        return match self {
+            LiturgicalSeason::Advent => "Advent",
+            LiturgicalSeason::Christmastide => "Christmastide",
+            LiturgicalSeason::Lent => "Lent",
+            LiturgicalSeason::Triduum => "Triduum",
+            LiturgicalSeason::Eastertide => "Eastertide",
+            LiturgicalSeason::OrdinaryTime => "Ordinary Time",
+        };
+    }
+}
+
+/// Implement fmt::Display trait for [`LiturgicalSeason`], writing its name
+impl fmt::Display for LiturgicalSeason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Liturgical season of a day, with the number of the week within the season
+/// (weeks begin on Sunday)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct LiturgicalWeek {
+    pub season: LiturgicalSeason,
+    pub week: i64,
+}
+
+/// Implement fmt::Display trait for [`LiturgicalWeek`] (e.g. "Lent, week 2")
+impl fmt::Display for LiturgicalWeek {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, week {}", self.season, self.week)
+    }
+}
+
+/// Returns the number of the week of a date within a season whose first week
+/// is the one beginning on a given Sunday.
+fn week_from(first_sunday: i64, absolute_date: i64) -> i64 {
+    return floor_div(absolute_date - first_sunday, 7) + 1;
+}
+
+/// Returns the season of the Western Christian liturgical year of a given
+/// absolute (fixed) date, with the number of the week within the season:
+///
+/// - Advent, from the First Sunday of Advent to 24 December (weeks 1 to 4)
+/// - Christmastide, from Christmas to the Baptism of the Lord (the Sunday
+///   after 6 January), with week 1 beginning on the Sunday on or before
+///   Christmas
+/// - Ordinary Time, from the Monday after the Baptism of the Lord to Shrove
+///   Tuesday, and from the Monday after Pentecost to the Saturday before
+///   Advent (weeks 1 to 34, with week 34 ending before Advent)
+/// - Lent, from Ash Wednesday to Wednesday of Holy Week (week 0 before the
+///   First Sunday of Lent, and week 6 beginning on Palm Sunday)
+/// - Triduum, from Maundy Thursday to Holy Saturday (week 1)
+/// - Eastertide, from Easter Sunday to Pentecost (weeks 1 to 8)
+///
+/// Absolute dates outside [`LITURGICAL_ABSOLUTE_RANGE`] are clamped to the
+/// range.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     liturgical::{liturgical_week, LiturgicalSeason, LITURGICAL_ABSOLUTE_RANGE},
+/// };
+///
+/// let date = |year, month, day| absolute_from_gregorian(Gregorian::new(year, month, day));
+/// let season = |year, month, day| liturgical_week(date(year, month, day)).to_string();
+///
+/// assert_eq!(season(2024, 12, 1), "Advent, week 1");
+/// assert_eq!(season(2024, 12, 25), "Christmastide, week 1");
+/// assert_eq!(season(2025, 1, 12), "Christmastide, week 4");
+/// assert_eq!(season(2025, 1, 13), "Ordinary Time, week 1");
+/// assert_eq!(season(2025, 3, 5), "Lent, week 0");
+/// assert_eq!(season(2025, 3, 9), "Lent, week 1");
+/// assert_eq!(season(2025, 4, 13), "Lent, week 6");
+/// assert_eq!(season(2025, 4, 18), "Triduum, week 1");
+/// assert_eq!(season(2025, 4, 20), "Eastertide, week 1");
+/// assert_eq!(season(2025, 6, 8), "Eastertide, week 8");
+/// assert_eq!(season(2025, 11, 23), "Ordinary Time, week 34");
+/// assert_eq!(
+///     liturgical_week(date(2025, 7, 4)).season,
+///     LiturgicalSeason::OrdinaryTime
+/// );
+///
+/// let (first, last) = (*LITURGICAL_ABSOLUTE_RANGE.start(), *LITURGICAL_ABSOLUTE_RANGE.end());
+/// assert_eq!(liturgical_week(first).to_string(), "Christmastide, week 2");
+/// assert_eq!(liturgical_week(last).season, LiturgicalSeason::Christmastide);
+/// assert_eq!(liturgical_week(i64::MIN), liturgical_week(first));
+/// assert_eq!(liturgical_week(i64::MAX), liturgical_week(last));
+/// ```
+pub fn liturgical_week(absolute_date: i64) -> LiturgicalWeek {
+    let absolute_date = clamp_to_range(absolute_date, &LITURGICAL_ABSOLUTE_RANGE);
+    let year = gregorian_from_absolute(absolute_date).year;
+    let (season, week) = if absolute_date >= christmas(year) {
+        (
+            LiturgicalSeason::Christmastide,
+            week_from(kday_on_or_before(christmas(year), 0), absolute_date),
+        )
+    } else if absolute_date >= advent(year) {
+        (
+            LiturgicalSeason::Advent,
+            week_from(advent(year), absolute_date),
+        )
+    } else if absolute_date <= kday_after(epiphany(year - 1), 0) {
+        (
+            LiturgicalSeason::Christmastide,
+            week_from(kday_on_or_before(christmas(year - 1), 0), absolute_date),
+        )
+    } else if absolute_date < ash_wednesday(year) {
+        (
+            LiturgicalSeason::OrdinaryTime,
+            week_from(kday_after(epiphany(year - 1), 0), absolute_date),
+        )
+    } else if absolute_date < easter(year) - 3 {
+        (
+            LiturgicalSeason::Lent,
+            week_from(ash_wednesday(year) + 4, absolute_date),
+        )
+    } else if absolute_date < easter(year) {
+        (LiturgicalSeason::Triduum, 1)
+    } else if absolute_date <= pentecost(year) {
+        (
+            LiturgicalSeason::Eastertide,
+            week_from(easter(year), absolute_date),
+        )
+    } else {
+        (
+            LiturgicalSeason::OrdinaryTime,
+            34 - floor_div(advent(year) - 1 - absolute_date, 7),
+        )
+    };
+    return LiturgicalWeek { season, week };
+}
@@ -0,0 +1,780 @@
+//! Provides localized month, weekday, and holiday names
+
+use core::str::FromStr;
+
+use crate::{
+    error::CalendarError, hindu::HINDU_LEAP_MONTH_PREFIX, holidays::Holiday, weekday::WEEKDAY_NAMES,
+};
+
+/// German Gregorian (and Julian) month names
+static GERMAN_MONTH_NAMES: [&str; 12] = [
+    "Januar",
+    "Februar",
+    "März",
+    "April",
+    "Mai",
+    "Juni",
+    "Juli",
+    "August",
+    "September",
+    "Oktober",
+    "November",
+    "Dezember",
+];
+
+/// French Gregorian (and Julian) month names
+static FRENCH_MONTH_NAMES: [&str; 12] = [
+    "janvier",
+    "février",
+    "mars",
+    "avril",
+    "mai",
+    "juin",
+    "juillet",
+    "août",
+    "septembre",
+    "octobre",
+    "novembre",
+    "décembre",
+];
+
+/// Spanish Gregorian (and Julian) month names
+static SPANISH_MONTH_NAMES: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+/// Arabic Gregorian (and Julian) month names
+static ARABIC_MONTH_NAMES: [&str; 12] = [
+    "يناير",
+    "فبراير",
+    "مارس",
+    "أبريل",
+    "مايو",
+    "يونيو",
+    "يوليو",
+    "أغسطس",
+    "سبتمبر",
+    "أكتوبر",
+    "نوفمبر",
+    "ديسمبر",
+];
+
+/// Hebrew Gregorian (and Julian) month names
+static HEBREW_MONTH_NAMES: [&str; 12] = [
+    "ינואר",
+    "פברואר",
+    "מרץ",
+    "אפריל",
+    "מאי",
+    "יוני",
+    "יולי",
+    "אוגוסט",
+    "ספטמבר",
+    "אוקטובר",
+    "נובמבר",
+    "דצמבר",
+];
+
+/// Arabic Islamic month names
+static ARABIC_ISLAMIC_MONTH_NAMES: [&str; 12] = [
+    "محرم",
+    "صفر",
+    "ربيع الأول",
+    "ربيع الآخر",
+    "جمادى الأولى",
+    "جمادى الآخرة",
+    "رجب",
+    "شعبان",
+    "رمضان",
+    "شوال",
+    "ذو القعدة",
+    "ذو الحجة",
+];
+
+/// Hebrew Hebrew month names (in the order of
+/// [`HEBREW_MONTH_NAMES`](crate::hebrew::HEBREW_MONTH_NAMES))
+static HEBREW_HEBREW_MONTH_NAMES: [&str; 14] = [
+    "ניסן",
+    "אייר",
+    "סיוון",
+    "תמוז",
+    "אב",
+    "אלול",
+    "תשרי",
+    "חשוון",
+    "כסלו",
+    "טבת",
+    "שבט",
+    "אדר",
+    "אדר א׳",
+    "אדר ב׳",
+];
+
+/// German weekday names
+static GERMAN_WEEKDAY_NAMES: [&str; 7] = [
+    "Sonntag",
+    "Montag",
+    "Dienstag",
+    "Mittwoch",
+    "Donnerstag",
+    "Freitag",
+    "Samstag",
+];
+
+/// French weekday names
+static FRENCH_WEEKDAY_NAMES: [&str; 7] = [
+    "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+];
+
+/// Spanish weekday names
+static SPANISH_WEEKDAY_NAMES: [&str; 7] = [
+    "domingo",
+    "lunes",
+    "martes",
+    "miércoles",
+    "jueves",
+    "viernes",
+    "sábado",
+];
+
+/// Arabic weekday names
+static ARABIC_WEEKDAY_NAMES: [&str; 7] = [
+    "الأحد",
+    "الاثنين",
+    "الثلاثاء",
+    "الأربعاء",
+    "الخميس",
+    "الجمعة",
+    "السبت",
+];
+
+/// Hebrew weekday names
+static HEBREW_WEEKDAY_NAMES: [&str; 7] = [
+    "יום ראשון",
+    "יום שני",
+    "יום שלישי",
+    "יום רביעי",
+    "יום חמישי",
+    "יום שישי",
+    "שבת",
+];
+
+/// German holiday names
+fn german_holiday_name(holiday: Holiday) -> &'static str {
+    return match holiday {
+        Holiday::IndependenceDay => "Unabhängigkeitstag",
+        Holiday::LaborDay => "Tag der Arbeit",
+        Holiday::MemorialDay => "Memorial Day",
+        Holiday::DaylightSavingsStart => "Beginn der Sommerzeit",
+        Holiday::DaylightSavingsEnd => "Ende der Sommerzeit",
+        Holiday::NewYearsDay => "Neujahr",
+        Holiday::MartinLutherKingDay => "Martin-Luther-King-Tag",
+        Holiday::PresidentsDay => "Presidents' Day",
+        Holiday::Juneteenth => "Juneteenth",
+        Holiday::ColumbusDay => "Columbus Day",
+        Holiday::VeteransDay => "Veterans Day",
+        Holiday::Thanksgiving => "Thanksgiving",
+        Holiday::Christmas => "Weihnachten",
+        Holiday::Advent => "Advent",
+        Holiday::Epiphany => "Epiphanias",
+        Holiday::EasternOrthodoxChristmas => "Orthodoxes Weihnachten",
+        Holiday::NicaeanRuleEaster => "Ostern (nicänische Regel)",
+        Holiday::GreatLentStart => "Reiner Montag (Beginn der Großen Fastenzeit)",
+        Holiday::OrthodoxPentecost => "Pfingsten (orthodox)",
+        Holiday::ApostlesFast => "Beginn des Apostelfastens",
+        Holiday::Easter => "Ostern",
+        Holiday::Pentecost => "Pfingsten",
+        Holiday::Septuagesima => "Septuagesima",
+        Holiday::MardiGras => "Fastnacht",
+        Holiday::AshWednesday => "Aschermittwoch",
+        Holiday::PalmSunday => "Palmsonntag",
+        Holiday::GoodFriday => "Karfreitag",
+        Holiday::Ascension => "Christi Himmelfahrt",
+        Holiday::TrinitySunday => "Dreifaltigkeitssonntag",
+        Holiday::CorpusChristi => "Fronleichnam",
+        Holiday::IslamicNewYear => "Islamisches Neujahr",
+        Holiday::Ashura => "Aschura",
+        Holiday::MuladAlNabi => "Maulid an-Nabi",
+        Holiday::RamadanStart => "Beginn des Ramadan",
+        Holiday::LaylatAlQadr => "Nacht der Bestimmung",
+        Holiday::EidAlFitr => "Fest des Fastenbrechens",
+        Holiday::EidAlAdha => "Opferfest",
+        Holiday::YomKippur => "Jom Kippur",
+        Holiday::Passover => "Pessach",
+        Holiday::Purim => "Purim",
+        Holiday::TaAnitEsther => "Ta'anit Esther",
+        Holiday::TishaBAv => "Tischa beAv",
+        Holiday::RoshHashanah => "Rosch ha-Schana",
+        Holiday::Sukkot => "Sukkot",
+        Holiday::SheminiAtzeret => "Schemini Azeret",
+        Holiday::SimchatTorah => "Simchat Tora",
+        Holiday::SimchatTorahIsrael => "Simchat Tora (Israel)",
+        Holiday::Hanukkah => "Chanukka",
+        Holiday::TuBiShvat => "Tu biSchevat",
+        Holiday::LagBaOmer => "Lag baOmer",
+        Holiday::Shavuot => "Schawuot",
+        Holiday::TzomGedaliah => "Fasten Gedalja",
+        Holiday::AsarahBTevet => "Zehnter Tevet",
+        Holiday::ShivaAsarBTammuz => "Siebzehnter Tammus",
+    };
+}
+
+/// French holiday names
+fn french_holiday_name(holiday: Holiday) -> &'static str {
+    return match holiday {
+        Holiday::IndependenceDay => "Jour de l'Indépendance",
+        Holiday::LaborDay => "Fête du Travail",
+        Holiday::MemorialDay => "Memorial Day",
+        Holiday::DaylightSavingsStart => "Début de l'heure d'été",
+        Holiday::DaylightSavingsEnd => "Fin de l'heure d'été",
+        Holiday::NewYearsDay => "Jour de l'An",
+        Holiday::MartinLutherKingDay => "Journée Martin Luther King",
+        Holiday::PresidentsDay => "Presidents' Day",
+        Holiday::Juneteenth => "Juneteenth",
+        Holiday::ColumbusDay => "Columbus Day",
+        Holiday::VeteransDay => "Veterans Day",
+        Holiday::Thanksgiving => "Thanksgiving",
+        Holiday::Christmas => "Noël",
+        Holiday::Advent => "Avent",
+        Holiday::Epiphany => "Épiphanie",
+        Holiday::EasternOrthodoxChristmas => "Noël orthodoxe",
+        Holiday::NicaeanRuleEaster => "Pâques (règle de Nicée)",
+        Holiday::GreatLentStart => "Lundi pur (début du Grand Carême)",
+        Holiday::OrthodoxPentecost => "Pentecôte (orthodoxe)",
+        Holiday::ApostlesFast => "Début du jeûne des Apôtres",
+        Holiday::Easter => "Pâques",
+        Holiday::Pentecost => "Pentecôte",
+        Holiday::Septuagesima => "Septuagésime",
+        Holiday::MardiGras => "Mardi gras",
+        Holiday::AshWednesday => "Mercredi des Cendres",
+        Holiday::PalmSunday => "Dimanche des Rameaux",
+        Holiday::GoodFriday => "Vendredi saint",
+        Holiday::Ascension => "Ascension",
+        Holiday::TrinitySunday => "Dimanche de la Trinité",
+        Holiday::CorpusChristi => "Fête-Dieu",
+        Holiday::IslamicNewYear => "Nouvel An islamique",
+        Holiday::Ashura => "Achoura",
+        Holiday::MuladAlNabi => "Mawlid",
+        Holiday::RamadanStart => "Début du Ramadan",
+        Holiday::LaylatAlQadr => "Nuit du Destin",
+        Holiday::EidAlFitr => "Aïd el-Fitr",
+        Holiday::EidAlAdha => "Aïd el-Kebir",
+        Holiday::YomKippur => "Yom Kippour",
+        Holiday::Passover => "Pessa'h",
+        Holiday::Purim => "Pourim",
+        Holiday::TaAnitEsther => "Jeûne d'Esther",
+        Holiday::TishaBAv => "Tisha Be'Av",
+        Holiday::RoshHashanah => "Roch Hachana",
+        Holiday::Sukkot => "Souccot",
+        Holiday::SheminiAtzeret => "Chemini Atséret",
+        Holiday::SimchatTorah => "Simha Torah",
+        Holiday::SimchatTorahIsrael => "Simha Torah (Israël)",
+        Holiday::Hanukkah => "Hanoucca",
+        Holiday::TuBiShvat => "Tou Bichvat",
+        Holiday::LagBaOmer => "Lag Baomer",
+        Holiday::Shavuot => "Chavouot",
+        Holiday::TzomGedaliah => "Jeûne de Guedalia",
+        Holiday::AsarahBTevet => "Jeûne du 10 Tevet",
+        Holiday::ShivaAsarBTammuz => "Jeûne du 17 Tammouz",
+    };
+}
+
+/// Spanish holiday names
+fn spanish_holiday_name(holiday: Holiday) -> &'static str {
+    return match holiday {
+        Holiday::IndependenceDay => "Día de la Independencia",
+        Holiday::LaborDay => "Día del Trabajo",
+        Holiday::MemorialDay => "Día de los Caídos",
+        Holiday::DaylightSavingsStart => "Inicio del horario de verano",
+        Holiday::DaylightSavingsEnd => "Fin del horario de verano",
+        Holiday::NewYearsDay => "Año Nuevo",
+        Holiday::MartinLutherKingDay => "Día de Martin Luther King",
+        Holiday::PresidentsDay => "Día de los Presidentes",
+        Holiday::Juneteenth => "Juneteenth",
+        Holiday::ColumbusDay => "Día de la Raza",
+        Holiday::VeteransDay => "Día de los Veteranos",
+        Holiday::Thanksgiving => "Día de Acción de Gracias",
+        Holiday::Christmas => "Navidad",
+        Holiday::Advent => "Adviento",
+        Holiday::Epiphany => "Epifanía",
+        Holiday::EasternOrthodoxChristmas => "Navidad ortodoxa",
+        Holiday::NicaeanRuleEaster => "Pascua (regla de Nicea)",
+        Holiday::GreatLentStart => "Lunes limpio (inicio de la Gran Cuaresma)",
+        Holiday::OrthodoxPentecost => "Pentecostés (ortodoxo)",
+        Holiday::ApostlesFast => "Inicio del ayuno de los Apóstoles",
+        Holiday::Easter => "Pascua",
+        Holiday::Pentecost => "Pentecostés",
+        Holiday::Septuagesima => "Septuagésima",
+        Holiday::MardiGras => "Martes de Carnaval",
+        Holiday::AshWednesday => "Miércoles de Ceniza",
+        Holiday::PalmSunday => "Domingo de Ramos",
+        Holiday::GoodFriday => "Viernes Santo",
+        Holiday::Ascension => "Ascensión",
+        Holiday::TrinitySunday => "Domingo de la Trinidad",
+        Holiday::CorpusChristi => "Corpus Christi",
+        Holiday::IslamicNewYear => "Año Nuevo islámico",
+        Holiday::Ashura => "Ashura",
+        Holiday::MuladAlNabi => "Mawlid",
+        Holiday::RamadanStart => "Inicio del Ramadán",
+        Holiday::LaylatAlQadr => "Noche del Destino",
+        Holiday::EidAlFitr => "Eid al-Fitr",
+        Holiday::EidAlAdha => "Eid al-Adha",
+        Holiday::YomKippur => "Yom Kipur",
+        Holiday::Passover => "Pésaj",
+        Holiday::Purim => "Purim",
+        Holiday::TaAnitEsther => "Ayuno de Ester",
+        Holiday::TishaBAv => "Tishá b'Av",
+        Holiday::RoshHashanah => "Rosh Hashaná",
+        Holiday::Sukkot => "Sucot",
+        Holiday::SheminiAtzeret => "Shemini Atzeret",
+        Holiday::SimchatTorah => "Simjat Torá",
+        Holiday::SimchatTorahIsrael => "Simjat Torá (Israel)",
+        Holiday::Hanukkah => "Janucá",
+        Holiday::TuBiShvat => "Tu Bishvat",
+        Holiday::LagBaOmer => "Lag Baómer",
+        Holiday::Shavuot => "Shavuot",
+        Holiday::TzomGedaliah => "Ayuno de Guedalías",
+        Holiday::AsarahBTevet => "Diez de Tevet",
+        Holiday::ShivaAsarBTammuz => "Diecisiete de Tamuz",
+    };
+}
+
+/// Arabic holiday names
+fn arabic_holiday_name(holiday: Holiday) -> &'static str {
+    return match holiday {
+        Holiday::IndependenceDay => "يوم الاستقلال",
+        Holiday::LaborDay => "عيد العمال",
+        Holiday::MemorialDay => "يوم الذكرى",
+        Holiday::DaylightSavingsStart => "بداية التوقيت الصيفي",
+        Holiday::DaylightSavingsEnd => "نهاية التوقيت الصيفي",
+        Holiday::NewYearsDay => "رأس السنة الميلادية",
+        Holiday::MartinLutherKingDay => "يوم مارتن لوثر كينغ",
+        Holiday::PresidentsDay => "يوم الرؤساء",
+        Holiday::Juneteenth => "جونتينث",
+        Holiday::ColumbusDay => "يوم كولومبوس",
+        Holiday::VeteransDay => "يوم المحاربين القدامى",
+        Holiday::Thanksgiving => "عيد الشكر",
+        Holiday::Christmas => "عيد الميلاد",
+        Holiday::Advent => "زمن المجيء",
+        Holiday::Epiphany => "عيد الغطاس",
+        Holiday::EasternOrthodoxChristmas => "عيد الميلاد الأرثوذكسي",
+        Holiday::NicaeanRuleEaster => "عيد الفصح (قاعدة نيقية)",
+        Holiday::GreatLentStart => "الاثنين النظيف (بداية الصوم الكبير)",
+        Holiday::OrthodoxPentecost => "عيد العنصرة (الأرثوذكسي)",
+        Holiday::ApostlesFast => "بداية صوم الرسل",
+        Holiday::Easter => "عيد الفصح",
+        Holiday::Pentecost => "عيد العنصرة",
+        Holiday::Septuagesima => "أحد السبعين",
+        Holiday::MardiGras => "ثلاثاء المرفع",
+        Holiday::AshWednesday => "أربعاء الرماد",
+        Holiday::PalmSunday => "أحد الشعانين",
+        Holiday::GoodFriday => "الجمعة العظيمة",
+        Holiday::Ascension => "عيد الصعود",
+        Holiday::TrinitySunday => "أحد الثالوث",
+        Holiday::CorpusChristi => "عيد جسد الرب",
+        Holiday::IslamicNewYear => "رأس السنة الهجرية",
+        Holiday::Ashura => "عاشوراء",
+        Holiday::MuladAlNabi => "المولد النبوي",
+        Holiday::RamadanStart => "بداية رمضان",
+        Holiday::LaylatAlQadr => "ليلة القدر",
+        Holiday::EidAlFitr => "عيد الفطر",
+        Holiday::EidAlAdha => "عيد الأضحى",
+        Holiday::YomKippur => "يوم الغفران",
+        Holiday::Passover => "عيد الفصح اليهودي",
+        Holiday::Purim => "عيد المساخر",
+        Holiday::TaAnitEsther => "صوم إستير",
+        Holiday::TishaBAv => "التاسع من آب",
+        Holiday::RoshHashanah => "رأس السنة العبرية",
+        Holiday::Sukkot => "عيد المظال",
+        Holiday::SheminiAtzeret => "شميني عتسيرت",
+        Holiday::SimchatTorah => "فرحة التوراة",
+        Holiday::SimchatTorahIsrael => "فرحة التوراة (إسرائيل)",
+        Holiday::Hanukkah => "عيد الأنوار",
+        Holiday::TuBiShvat => "رأس السنة للأشجار",
+        Holiday::LagBaOmer => "لاغ بعومر",
+        Holiday::Shavuot => "عيد الأسابيع",
+        Holiday::TzomGedaliah => "صوم جدليا",
+        Holiday::AsarahBTevet => "العاشر من طيفت",
+        Holiday::ShivaAsarBTammuz => "السابع عشر من تموز",
+    };
+}
+
+/// Hebrew holiday names
+fn hebrew_holiday_name(holiday: Holiday) -> &'static str {
+    return match holiday {
+        Holiday::IndependenceDay => "יום העצמאות (ארצות הברית)",
+        Holiday::LaborDay => "יום העבודה",
+        Holiday::MemorialDay => "יום הזיכרון (ארצות הברית)",
+        Holiday::DaylightSavingsStart => "תחילת שעון הקיץ",
+        Holiday::DaylightSavingsEnd => "סוף שעון הקיץ",
+        Holiday::NewYearsDay => "ראש השנה האזרחית",
+        Holiday::MartinLutherKingDay => "יום מרטין לותר קינג",
+        Holiday::PresidentsDay => "יום הנשיאים",
+        Holiday::Juneteenth => "ג'ונטינת'",
+        Holiday::ColumbusDay => "יום קולומבוס",
+        Holiday::VeteransDay => "יום הוותיקים",
+        Holiday::Thanksgiving => "חג ההודיה",
+        Holiday::Christmas => "חג המולד",
+        Holiday::Advent => "אדוונט",
+        Holiday::Epiphany => "חג ההתגלות",
+        Holiday::EasternOrthodoxChristmas => "חג המולד האורתודוקסי",
+        Holiday::NicaeanRuleEaster => "פסחא (כלל ניקאה)",
+        Holiday::GreatLentStart => "יום שני הנקי (תחילת הצום הגדול)",
+        Holiday::OrthodoxPentecost => "חג השבועות הנוצרי (אורתודוקסי)",
+        Holiday::ApostlesFast => "תחילת צום השליחים",
+        Holiday::Easter => "פסחא",
+        Holiday::Pentecost => "חג השבועות הנוצרי",
+        Holiday::Septuagesima => "ספטואגסימה",
+        Holiday::MardiGras => "מרדי גרא",
+        Holiday::AshWednesday => "יום רביעי של האפר",
+        Holiday::PalmSunday => "יום ראשון של הדקלים",
+        Holiday::GoodFriday => "יום שישי הטוב",
+        Holiday::Ascension => "חג העלייה",
+        Holiday::TrinitySunday => "יום ראשון של השילוש",
+        Holiday::CorpusChristi => "קורפוס כריסטי",
+        Holiday::IslamicNewYear => "ראש השנה המוסלמי",
+        Holiday::Ashura => "עשוראא",
+        Holiday::MuladAlNabi => "מולד הנביא",
+        Holiday::RamadanStart => "תחילת הרמדאן",
+        Holiday::LaylatAlQadr => "ליל הגורל",
+        Holiday::EidAlFitr => "עיד אל-פיטר",
+        Holiday::EidAlAdha => "עיד אל-אדחא",
+        Holiday::YomKippur => "יום כיפור",
+        Holiday::Passover => "פסח",
+        Holiday::Purim => "פורים",
+        Holiday::TaAnitEsther => "תענית אסתר",
+        Holiday::TishaBAv => "תשעה באב",
+        Holiday::RoshHashanah => "ראש השנה",
+        Holiday::Sukkot => "סוכות",
+        Holiday::SheminiAtzeret => "שמיני עצרת",
+        Holiday::SimchatTorah => "שמחת תורה",
+        Holiday::SimchatTorahIsrael => "שמחת תורה (ארץ ישראל)",
+        Holiday::Hanukkah => "חנוכה",
+        Holiday::TuBiShvat => "ט\"ו בשבט",
+        Holiday::LagBaOmer => "ל\"ג בעומר",
+        Holiday::Shavuot => "שבועות",
+        Holiday::TzomGedaliah => "צום גדליה",
+        Holiday::AsarahBTevet => "עשרה בטבת",
+        Holiday::ShivaAsarBTammuz => "שבעה עשר בתמוז",
+    };
+}
+
+/// Hindi Gregorian (and Julian) month names
+static HINDI_MONTH_NAMES: [&str; 12] = [
+    "जनवरी",
+    "फ़रवरी",
+    "मार्च",
+    "अप्रैल",
+    "मई",
+    "जून",
+    "जुलाई",
+    "अगस्त",
+    "सितंबर",
+    "अक्टूबर",
+    "नवंबर",
+    "दिसंबर",
+];
+
+/// Hindi (Devanagari) Hindu solar month names
+static HINDI_HINDU_SOLAR_MONTH_NAMES: [&str; 12] = [
+    "मेष",
+    "वृषभ",
+    "मिथुन",
+    "कर्क",
+    "सिंह",
+    "कन्या",
+    "तुला",
+    "वृश्चिक",
+    "धनु",
+    "मकर",
+    "कुम्भ",
+    "मीन",
+];
+
+/// Hindi (Devanagari) Hindu lunar month names
+static HINDI_HINDU_LUNAR_MONTH_NAMES: [&str; 12] = [
+    "चैत्र",
+    "वैशाख",
+    "ज्येष्ठ",
+    "आषाढ़",
+    "श्रावण",
+    "भाद्रपद",
+    "आश्विन",
+    "कार्तिक",
+    "मार्गशीर्ष",
+    "पौष",
+    "माघ",
+    "फाल्गुन",
+];
+
+/// Hindi weekday names
+static HINDI_WEEKDAY_NAMES: [&str; 7] = [
+    "रविवार",
+    "सोमवार",
+    "मंगलवार",
+    "बुधवार",
+    "गुरुवार",
+    "शुक्रवार",
+    "शनिवार",
+];
+
+/// Tamil Hindu solar month names
+static TAMIL_HINDU_SOLAR_MONTH_NAMES: [&str; 12] = [
+    "சித்திரை",
+    "வைகாசி",
+    "ஆனி",
+    "ஆடி",
+    "ஆவணி",
+    "புரட்டாசி",
+    "ஐப்பசி",
+    "கார்த்திகை",
+    "மார்கழி",
+    "தை",
+    "மாசி",
+    "பங்குனி",
+];
+
+/// Tamil Hindu lunar month names
+static TAMIL_HINDU_LUNAR_MONTH_NAMES: [&str; 12] = [
+    "சைத்ர",
+    "வைசாக",
+    "ஜ்யேஷ்ட",
+    "ஆஷாட",
+    "ஶ்ராவண",
+    "பாத்ரபத",
+    "ஆஶ்வயுஜ",
+    "கார்த்திக",
+    "மார்கஶீர்ஷ",
+    "புஷ்ய",
+    "மாக",
+    "பால்குன",
+];
+
+/// Tamil weekday names
+static TAMIL_WEEKDAY_NAMES: [&str; 7] =
+    ["ஞாயிறு", "திங்கள்", "செவ்வாய்", "புதன்", "வியாழன்", "வெள்ளி", "சனி"];
+
+/// Telugu Hindu solar month names
+static TELUGU_HINDU_SOLAR_MONTH_NAMES: [&str; 12] = [
+    "మేషం",
+    "వృషభం",
+    "మిథునం",
+    "కర్కాటకం",
+    "సింహం",
+    "కన్య",
+    "తుల",
+    "వృశ్చికం",
+    "ధనుస్సు",
+    "మకరం",
+    "కుంభం",
+    "మీనం",
+];
+
+/// Telugu Hindu lunar month names
+static TELUGU_HINDU_LUNAR_MONTH_NAMES: [&str; 12] = [
+    "చైత్రము",
+    "వైశాఖము",
+    "జ్యేష్ఠము",
+    "ఆషాఢము",
+    "శ్రావణము",
+    "భాద్రపదము",
+    "ఆశ్వయుజము",
+    "కార్తీకము",
+    "మార్గశిరము",
+    "పుష్యము",
+    "మాఘము",
+    "ఫాల్గుణము",
+];
+
+/// Telugu weekday names
+static TELUGU_WEEKDAY_NAMES: [&str; 7] = [
+    "ఆదివారం",
+    "సోమవారం",
+    "మంగళవారం",
+    "బుధవారం",
+    "గురువారం",
+    "శుక్రవారం",
+    "శనివారం",
+];
+
+/// Language of month, weekday, and holiday names
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English (the names used throughout this crate)
+    #[default]
+    English,
+    German,
+    French,
+    Spanish,
+    Arabic,
+    Hebrew,
+    /// Hindi (in Devanagari script)
+    Hindi,
+    Tamil,
+    Telugu,
+}
+
+impl Locale {
+    /// Returns the localized month names of a given calendar, or `None` if
+    /// the calendar's default month names (see [`Date`](crate::utility::Date))
+    /// are to be used. Localized names are available for the Gregorian and
+    /// Julian calendars in all locales except Tamil and Telugu, for the
+    /// Islamic calendar in Arabic, for the Hebrew calendar in Hebrew, and for
+    /// the Hindu calendars in Hindi, Tamil, and Telugu.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::locale::Locale;
+    ///
+    /// assert_eq!(Locale::Hindi.month_names("oldHinduLunar").unwrap()[0], "चैत्र");
+    /// assert_eq!(Locale::Tamil.month_names("oldHinduSolar").unwrap()[0], "சித்திரை");
+    /// assert_eq!(Locale::Hindi.month_names("hebrew"), None);
+    /// ```
+    pub fn month_names(&self, calendar: &str) -> Option<&'static [&'static str]> {
+        return match (self, calendar) {
+            (Locale::German, "gregorian" | "julian") => Some(&GERMAN_MONTH_NAMES),
+            (Locale::French, "gregorian" | "julian") => Some(&FRENCH_MONTH_NAMES),
+            (Locale::Spanish, "gregorian" | "julian") => Some(&SPANISH_MONTH_NAMES),
+            (Locale::Arabic, "gregorian" | "julian") => Some(&ARABIC_MONTH_NAMES),
+            (Locale::Arabic, "islamic" | "ummAlQura") => Some(&ARABIC_ISLAMIC_MONTH_NAMES),
+            (Locale::Hebrew, "gregorian" | "julian") => Some(&HEBREW_MONTH_NAMES),
+            (Locale::Hebrew, "hebrew") => Some(&HEBREW_HEBREW_MONTH_NAMES),
+            (Locale::Hindi, "gregorian" | "julian") => Some(&HINDI_MONTH_NAMES),
+            (Locale::Hindi, "oldHinduSolar") => Some(&HINDI_HINDU_SOLAR_MONTH_NAMES),
+            (Locale::Hindi, "oldHinduLunar") => Some(&HINDI_HINDU_LUNAR_MONTH_NAMES),
+            (Locale::Tamil, "oldHinduSolar") => Some(&TAMIL_HINDU_SOLAR_MONTH_NAMES),
+            (Locale::Tamil, "oldHinduLunar") => Some(&TAMIL_HINDU_LUNAR_MONTH_NAMES),
+            (Locale::Telugu, "oldHinduSolar") => Some(&TELUGU_HINDU_SOLAR_MONTH_NAMES),
+            (Locale::Telugu, "oldHinduLunar") => Some(&TELUGU_HINDU_LUNAR_MONTH_NAMES),
+            _ => None,
+        };
+    }
+
+    /// Returns the localized name of a built-in holiday, or `None` for
+    /// English, whose names are given by
+    /// [`Holiday::name`](crate::holidays::Holiday::name), and for Hindi, Tamil,
+    /// and Telugu, which fall back to English.
+    pub(crate) fn holiday_name(&self, holiday: Holiday) -> Option<&'static str> {
+        return match self {
+            Locale::English | Locale::Hindi | Locale::Tamil | Locale::Telugu => None,
+            Locale::German => Some(german_holiday_name(holiday)),
+            Locale::French => Some(french_holiday_name(holiday)),
+            Locale::Spanish => Some(spanish_holiday_name(holiday)),
+            Locale::Arabic => Some(arabic_holiday_name(holiday)),
+            Locale::Hebrew => Some(hebrew_holiday_name(holiday)),
+        };
+    }
+
+    /// Returns the localized weekday names (starting with Sunday).
+    pub fn weekday_names(&self) -> &'static [&'static str; 7] {
+        return match self {
+            Locale::English => &WEEKDAY_NAMES,
+            Locale::German => &GERMAN_WEEKDAY_NAMES,
+            Locale::French => &FRENCH_WEEKDAY_NAMES,
+            Locale::Spanish => &SPANISH_WEEKDAY_NAMES,
+            Locale::Arabic => &ARABIC_WEEKDAY_NAMES,
+            Locale::Hebrew => &HEBREW_WEEKDAY_NAMES,
+            Locale::Hindi => &HINDI_WEEKDAY_NAMES,
+            Locale::Tamil => &TAMIL_WEEKDAY_NAMES,
+            Locale::Telugu => &TELUGU_WEEKDAY_NAMES,
+        };
+    }
+
+    /// Returns true if month and weekday names of the locale are abbreviated
+    /// to their first three letters, and false for the locales in which this
+    /// yields no customary abbreviations (Arabic, Hebrew, Hindi, Tamil, and
+    /// Telugu).
+    pub(crate) fn abbreviates_names(&self) -> bool {
+        return matches!(
+            self,
+            Locale::English | Locale::German | Locale::French | Locale::Spanish
+        );
+    }
+
+    /// Returns the localized prefix of leap months of Hindu lunar dates (see
+    /// [`HINDU_LEAP_MONTH_PREFIX`]).
+    pub(crate) fn leap_month_prefix(&self) -> &'static str {
+        return match self {
+            Locale::Hindi => "अधिक",
+            Locale::Tamil => "அதிக",
+            Locale::Telugu => "అధిక",
+            _ => HINDU_LEAP_MONTH_PREFIX,
+        };
+    }
+
+    /// Returns the digit zero of the locale's native script (followed by the
+    /// digits one to nine), or `None` if the locale uses Western Arabic digits.
+    fn native_digits(&self) -> Option<char> {
+        return match self {
+            Locale::Hindi => Some('\u{0966}'),
+            Locale::Tamil => Some('\u{0BE6}'),
+            Locale::Telugu => Some('\u{0C66}'),
+            _ => None,
+        };
+    }
+
+    /// Returns a given string with its digits written in the native digits
+    /// of the locale (Devanagari, Tamil, or Telugu digits), or unchanged if
+    /// the locale uses Western Arabic digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::locale::Locale;
+    ///
+    /// assert_eq!(Locale::Hindi.localize_digits("5125"), "५१२५");
+    /// assert_eq!(Locale::Tamil.localize_digits("-12"), "-௧௨");
+    /// assert_eq!(Locale::Telugu.localize_digits("2024"), "౨౦౨౪");
+    /// assert_eq!(Locale::German.localize_digits("2024"), "2024");
+    /// ```
+    pub fn localize_digits(&self, s: &str) -> String {
+        let zero = match self.native_digits() {
+            Some(zero) => zero as u32,
+            None => return s.to_string(),
+        };
+        return s
+            .chars()
+            .map(|c| match c.to_digit(10) {
+                Some(digit) => char::from_u32(zero + digit).unwrap_or(c),
+                None => c,
+            })
+            .collect();
+    }
+}
+
+/// Parse a [`Locale`] from a language tag (e.g. "de" or "de-DE"), ignoring
+/// case and region subtags.
+impl FromStr for Locale {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_']).next().unwrap_or_default();
+        return match language.to_ascii_lowercase().as_str() {
+            "en" => Ok(Locale::English),
+            "de" => Ok(Locale::German),
+            "fr" => Ok(Locale::French),
+            "es" => Ok(Locale::Spanish),
+            "ar" => Ok(Locale::Arabic),
+            "he" | "iw" => Ok(Locale::Hebrew),
+            "hi" => Ok(Locale::Hindi),
+            "ta" => Ok(Locale::Tamil),
+            "te" => Ok(Locale::Telugu),
+            _ => Err(CalendarError::ParseError(format!(
+                "unknown locale \"{}\"",
+                s
+            ))),
+        };
+    }
+}
@@ -2,6 +2,8 @@
 
 /// Computes the sum \Sigma_{i\ge k, p(i)} f(i), as long as the condition
 /// p(i) is true.
+///
+/// See [`sum_while`] and [`count_while`] for exact integer versions.
 pub fn sum<F, P>(f: F, k: i64, p: P) -> f64
 where
     F: Fn(f64) -> f64,
@@ -13,6 +15,29 @@ where
         .sum();
 }
 
+/// Computes the sum \Sigma_{i\ge k, p(i)} f(i) of integers, as long as the
+/// condition p(i) is true.
+pub fn sum_while<F, P>(f: F, k: i64, p: P) -> i64
+where
+    F: Fn(i64) -> i64,
+    P: Fn(i64) -> bool,
+{
+    return (k..).take_while(|i| p(*i)).map(f).sum();
+}
+
+/// Counts the integers i = k, k + 1, ... for which the condition p(i) holds,
+/// stopping at the first i for which it does not.
+pub fn count_while<P>(k: i64, p: P) -> i64
+where
+    P: Fn(i64) -> bool,
+{
+    let mut i = k;
+    while p(i) {
+        i += 1;
+    }
+    return i - k;
+}
+
 /// Computes the positive remainder of a mod b
 pub fn modulus<T>(a: T, b: T) -> T
 where
@@ -22,11 +47,48 @@ where
 }
 
 /// Computes the (floored) quotient of two integers a and b
-pub fn floor_div(a: i64, b: i64) -> i64 {
-    return ((a as f64) / (b as f64)).floor() as i64;
+///
+/// # Example
+///
+/// ```
+/// use calendars::math::floor_div;
+///
+/// assert_eq!(floor_div(-7, 2), -4);
+/// assert_eq!(floor_div(7, -2), -4);
+/// assert_eq!(floor_div(i64::MAX, 1), i64::MAX);
+/// ```
+pub const fn floor_div(a: i64, b: i64) -> i64 {
+    let quotient = a / b;
+    // integer division truncates towards zero
+    return if a % b != 0 && (a < 0) != (b < 0) {
+        quotient - 1
+    } else {
+        quotient
+    };
 }
 
 /// Computes the adjusted positive remainder modulus(a-1, b) + 1
 pub fn amod(a: i64, b: i64) -> i64 {
     return modulus(a - 1, b) + 1;
 }
+
+/// Clamps an integer to a given range, returning the nearest end of the range
+/// for integers outside of it.
+///
+/// # Example
+///
+/// ```
+/// use calendars::math::clamp_to_range;
+///
+/// assert_eq!(clamp_to_range(i64::MAX, &(1..=100)), 100);
+/// assert_eq!(clamp_to_range(50, &(1..=100)), 50);
+/// ```
+pub const fn clamp_to_range(a: i64, range: &core::ops::RangeInclusive<i64>) -> i64 {
+    return if a < *range.start() {
+        *range.start()
+    } else if a > *range.end() {
+        *range.end()
+    } else {
+        a
+    };
+}
@@ -1,9 +1,35 @@
 //! Functions converting from and to Mayan dates
 
-use crate::math::{amod, floor_div, modulus};
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    math::{amod, clamp_to_range, floor_div, modulus},
+};
+
+/// Range of baktuns supported by the conversions of Mayan long counts.
+pub static MAYAN_BAKTUN_RANGE: RangeInclusive<i64> = -10_000_000_000..=10_000_000_000;
+
+/// Range of absolute (fixed) dates supported by the conversions of Mayan
+/// dates (the dates of the baktuns in [`MAYAN_BAKTUN_RANGE`]).
+pub static MAYAN_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    absolute_from_mayan_long_count(MayanLongCount::new(*MAYAN_BAKTUN_RANGE.start(), 0, 0, 0, 0))
+        ..=absolute_from_mayan_long_count(MayanLongCount::new(
+            *MAYAN_BAKTUN_RANGE.end(),
+            19,
+            19,
+            17,
+            19,
+        ));
 
 /// Mayan Long Count
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct MayanLongCount {
     pub baktun: i64,
     pub katun: i64,
@@ -14,7 +40,7 @@ pub struct MayanLongCount {
 
 impl MayanLongCount {
     /// Create a new Mayan Long Count
-    pub fn new(baktun: i64, katun: i64, tun: i64, uinal: i64, kin: i64) -> Self {
+    pub const fn new(baktun: i64, katun: i64, tun: i64, uinal: i64, kin: i64) -> Self {
         Self {
             baktun,
             katun,
@@ -23,6 +49,33 @@ impl MayanLongCount {
             kin,
         }
     }
+
+    /// Create a new Mayan Long Count, or return an error if the date does not
+    /// exist.
+    pub fn try_new(
+        baktun: i64,
+        katun: i64,
+        tun: i64,
+        uinal: i64,
+        kin: i64,
+    ) -> Result<Self, CalendarError> {
+        let d = Self::new(baktun, katun, tun, uinal, kin);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component("mayanLongCount", "katun", self.katun, 0, 19)?;
+        check_component("mayanLongCount", "tun", self.tun, 0, 19)?;
+        check_component("mayanLongCount", "uinal", self.uinal, 0, 17)?;
+        return check_component("mayanLongCount", "kin", self.kin, 0, 19);
+    }
 }
 
 /// Number of days of the Mayan calendar epoch before absolute day 0,
@@ -34,13 +87,20 @@ impl MayanLongCount {
 static MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO: i64 = 1137142; //
 
 /// Returns the absolute (fixed) date of a given Mayan long count.
-pub fn absolute_from_mayan_long_count(d: MayanLongCount) -> i64 {
+///
+/// The result is only meaningful for baktuns in [`MAYAN_BAKTUN_RANGE`]; see
+/// [`checked_absolute_from_mayan_long_count`].
+pub const fn absolute_from_mayan_long_count(d: MayanLongCount) -> i64 {
     return d.baktun * 144000 + d.katun * 7200 + d.tun * 360 + d.uinal * 20 + d.kin
         - MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO;
 }
 
 /// Computes the Mayan long count corresponding to the given absolute date.
+///
+/// Absolute dates outside [`MAYAN_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_mayan_long_count_from_absolute`] for a fallible alternative.
 pub fn mayan_long_count_from_absolute(absolute_date: i64) -> MayanLongCount {
+    let absolute_date = clamp_to_range(absolute_date, &MAYAN_ABSOLUTE_RANGE);
     let long_count = absolute_date + MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO;
     let baktun = floor_div(long_count, 144000);
     let day_of_baktun = modulus(long_count, 144000);
@@ -59,8 +119,39 @@ pub fn mayan_long_count_from_absolute(absolute_date: i64) -> MayanLongCount {
     };
 }
 
+/// Computes the absolute (fixed) date of a given Mayan long count, or returns
+/// an error if the long count does not exist or its baktun lies outside
+/// [`MAYAN_BAKTUN_RANGE`].
+pub fn checked_absolute_from_mayan_long_count(d: MayanLongCount) -> Result<i64, CalendarError> {
+    check_component(
+        "mayanLongCount",
+        "baktun",
+        d.baktun,
+        *MAYAN_BAKTUN_RANGE.start(),
+        *MAYAN_BAKTUN_RANGE.end(),
+    )?;
+    d.validate()?;
+    return Ok(absolute_from_mayan_long_count(d));
+}
+
+/// Computes the Mayan long count corresponding to a given absolute date, or
+/// returns an error if the absolute date lies outside
+/// [`MAYAN_ABSOLUTE_RANGE`].
+pub fn checked_mayan_long_count_from_absolute(
+    absolute_date: i64,
+) -> Result<MayanLongCount, CalendarError> {
+    check_range("mayanLongCount", absolute_date, &MAYAN_ABSOLUTE_RANGE)?;
+    return Ok(mayan_long_count_from_absolute(absolute_date));
+}
+
 /// Mayan Haab date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct MayanHaab {
     pub day: i64,
     pub month: i64,
@@ -77,6 +168,46 @@ impl MayanHaab {
     pub fn new(day: i64, month: i64) -> Self {
         Self { day, month }
     }
+
+    /// Create a new Mayan Haab date, or return an error if the date does not
+    /// exist.
+    pub fn try_new(day: i64, month: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(day, month);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component("mayanHaab", "month", self.month, 1, 19)?;
+        let last_day = if self.month == 19 { 4 } else { 19 };
+        return check_component("mayanHaab", "day", self.day, 0, last_day);
+    }
+
+    /// Returns the Haab date a given number of days after (or, if negative,
+    /// before) the date.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::mayan::{MayanHaab, MayanTzolkin};
+    ///
+    /// assert_eq!(MayanHaab::new(4, 19).add_days(1), MayanHaab::new(0, 1));
+    /// assert_eq!(MayanHaab::new(0, 1).add_days(i64::MAX), MayanHaab::new(12, 15));
+    /// assert_eq!(MayanTzolkin::new(1, 1).add_days(i64::MIN), MayanTzolkin::new(6, 13));
+    /// ```
+    pub fn add_days(&self, days: i64) -> Self {
+        let day_of_haab = modulus(20 * (self.month - 1) + self.day + days.rem_euclid(365), 365);
+        return MayanHaab {
+            day: modulus(day_of_haab, 20),
+            month: floor_div(day_of_haab, 20) + 1,
+        };
+    }
 }
 
 /// Denotes the haab date at long count 0.0.0.0.0.
@@ -84,8 +215,12 @@ static MAYAN_HAAB_AT_EPOCH: MayanHaab = MayanHaab { day: 8, month: 18 };
 
 /// Returns the Mayan haab date corresponding to a given absolute (fixed)
 /// date.
+///
+/// The haab repeats every 365 days, so the result is correct for all absolute
+/// dates; see [`checked_mayan_haab_from_absolute`] to restrict them to
+/// [`MAYAN_ABSOLUTE_RANGE`].
 pub fn mayan_haab_from_absolute(absolute_date: i64) -> MayanHaab {
-    let long_count = absolute_date + MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO;
+    let long_count = absolute_date.rem_euclid(365) + MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO;
     let day_of_haab = modulus(
         long_count + MAYAN_HAAB_AT_EPOCH.day + 20 * (MAYAN_HAAB_AT_EPOCH.month - 1),
         365,
@@ -95,23 +230,81 @@ pub fn mayan_haab_from_absolute(absolute_date: i64) -> MayanHaab {
     return MayanHaab { day, month };
 }
 
+/// Computes the Mayan Haab date corresponding to a given absolute date, or
+/// returns an error if the absolute date lies outside
+/// [`MAYAN_ABSOLUTE_RANGE`].
+pub fn checked_mayan_haab_from_absolute(absolute_date: i64) -> Result<MayanHaab, CalendarError> {
+    check_range("mayanHaab", absolute_date, &MAYAN_ABSOLUTE_RANGE)?;
+    return Ok(mayan_haab_from_absolute(absolute_date));
+}
+
 /// Computes the number of days between two Haab dates.
 pub fn mayan_haab_difference(d1: MayanHaab, d2: MayanHaab) -> i64 {
     return modulus(20 * (d2.month - d1.month) + (d2.day - d1.day), 365);
 }
 
 /// Returns the absolute (fixed) date of a Mayan Haab date on or before a
-/// given absolute date.
+/// given absolute date. Like the other Haab and Tzolkin searches, the result
+/// saturates at the bounds of `i64`.
+///
+/// # Example
+///
+/// ```
+/// use calendars::mayan::{
+///     mayan_haab_from_absolute, mayan_haab_nearest, mayan_haab_on_or_after,
+///     mayan_haab_on_or_before,
+/// };
+///
+/// let d = mayan_haab_from_absolute(730000);
+/// assert_eq!(mayan_haab_on_or_after(d, 730001), 730365);
+/// assert_eq!(mayan_haab_on_or_before(d, 730364), 730000);
+/// assert_eq!(mayan_haab_nearest(d, 730182), 730000);
+/// assert_eq!(mayan_haab_on_or_after(d, i64::MAX), i64::MAX);
+/// assert_eq!(mayan_haab_on_or_before(d, i64::MIN), i64::MIN);
+/// ```
 pub fn mayan_haab_on_or_before(d: MayanHaab, absolute_date: i64) -> i64 {
-    return absolute_date
-        - modulus(
-            absolute_date - mayan_haab_difference(mayan_haab_from_absolute(0), d),
-            365,
-        );
+    let difference = mayan_haab_difference(mayan_haab_from_absolute(0), d);
+    return absolute_date.saturating_sub(modulus(absolute_date.rem_euclid(365) - difference, 365));
+}
+
+/// Returns the absolute (fixed) date of a Mayan Haab date on or after a given
+/// absolute date.
+pub fn mayan_haab_on_or_after(d: MayanHaab, absolute_date: i64) -> i64 {
+    let difference = mayan_haab_difference(mayan_haab_from_absolute(0), d);
+    return absolute_date.saturating_add(modulus(difference - absolute_date.rem_euclid(365), 365));
+}
+
+/// Returns the absolute (fixed) date of the Mayan Haab date nearest to a given
+/// absolute date.
+pub fn mayan_haab_nearest(d: MayanHaab, absolute_date: i64) -> i64 {
+    return mayan_haab_on_or_before(d, absolute_date.saturating_add(182));
+}
+
+/// Returns an iterator over the absolute (fixed) dates of all occurrences of
+/// a Mayan Haab date from `start` to `end` (inclusive).
+///
+/// # Example
+///
+/// ```
+/// use calendars::mayan::{mayan_haab_from_absolute, mayan_haab_occurrences, MayanHaab};
+///
+/// let d = MayanHaab::new(0, 1);
+/// let dates: Vec<i64> = mayan_haab_occurrences(d, 730000, 732000).collect();
+/// assert_eq!(dates.len(), 5);
+/// assert!(dates.iter().all(|date| mayan_haab_from_absolute(*date) == d));
+/// ```
+pub fn mayan_haab_occurrences(d: MayanHaab, start: i64, end: i64) -> impl Iterator<Item = i64> {
+    return (mayan_haab_on_or_after(d, start)..=end).step_by(365);
 }
 
 /// Mayan Tzolkin date
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
 pub struct MayanTzolkin {
     pub number: i64,
     pub name: i64,
@@ -128,6 +321,35 @@ impl MayanTzolkin {
     pub fn new(number: i64, name: i64) -> Self {
         Self { number, name }
     }
+
+    /// Create a new Mayan Tzolkin date, or return an error if the date does not
+    /// exist.
+    pub fn try_new(number: i64, name: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(number, name);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component("mayanTzolkin", "number", self.number, 1, 13)?;
+        return check_component("mayanTzolkin", "name", self.name, 1, 20);
+    }
+
+    /// Returns the Tzolkin date a given number of days after (or, if
+    /// negative, before) the date.
+    pub fn add_days(&self, days: i64) -> Self {
+        let days = days.rem_euclid(260);
+        return MayanTzolkin {
+            number: amod(self.number + days, 13),
+            name: amod(self.name + days, 20),
+        };
+    }
 }
 
 // Denotes the Tzolkin date at long count 0.0.0.0.0.
@@ -138,16 +360,41 @@ static MAYAN_TZOLKIN_AT_EPOCH: MayanTzolkin = MayanTzolkin {
 
 /// Returns a Mayan Tzolkin date corresponding to a given absolute (fixed)
 /// date.
+///
+/// The tzolkin repeats every 260 days, so the result is correct for all
+/// absolute dates; see [`checked_mayan_tzolkin_from_absolute`] to restrict them
+/// to [`MAYAN_ABSOLUTE_RANGE`].
 pub fn mayan_tzolkin_from_absolute(absolute_date: i64) -> MayanTzolkin {
-    let long_count = absolute_date + MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO;
+    let long_count = absolute_date.rem_euclid(260) + MAYAN_DAYS_BEFORE_ABSOLUTE_ZERO;
     let number = amod(long_count + MAYAN_TZOLKIN_AT_EPOCH.number, 13);
     let name = amod(long_count + MAYAN_TZOLKIN_AT_EPOCH.name, 20);
     return MayanTzolkin { number, name };
 }
 
+/// Computes the Mayan Tzolkin date corresponding to a given absolute date, or
+/// returns an error if the absolute date lies outside
+/// [`MAYAN_ABSOLUTE_RANGE`].
+pub fn checked_mayan_tzolkin_from_absolute(
+    absolute_date: i64,
+) -> Result<MayanTzolkin, CalendarError> {
+    check_range("mayanTzolkin", absolute_date, &MAYAN_ABSOLUTE_RANGE)?;
+    return Ok(mayan_tzolkin_from_absolute(absolute_date));
+}
+
 /// Returns the number of days between two given Mayan Tzolkin dates.
+///
+/// # Example
+///
+/// ```
+/// use calendars::mayan::{mayan_tzolkin_difference, MayanTzolkin};
+///
+/// let d1 = MayanTzolkin::new(4, 20);
+/// assert_eq!(mayan_tzolkin_difference(d1, MayanTzolkin::new(5, 1)), 1);
+/// assert_eq!(mayan_tzolkin_difference(d1, MayanTzolkin::new(3, 19)), 259);
+/// assert_eq!(mayan_tzolkin_difference(d1, d1), 0);
+/// ```
 pub fn mayan_tzolkin_difference(d1: MayanTzolkin, d2: MayanTzolkin) -> i64 {
-    let number_difference = d2.number - d2.number;
+    let number_difference = d2.number - d1.number;
     let name_difference = d2.name - d1.name;
     return modulus(
         number_difference + (13 * modulus(3 * (number_difference - name_difference), 20)),
@@ -158,11 +405,47 @@ pub fn mayan_tzolkin_difference(d1: MayanTzolkin, d2: MayanTzolkin) -> i64 {
 /// Returns the absolute (fixed) date of a Mayan Tzolkin date on or before a
 /// given absolute date.
 pub fn mayan_tzolkin_on_or_before(d: MayanTzolkin, absolute_date: i64) -> i64 {
-    return absolute_date
-        - modulus(
-            absolute_date - mayan_tzolkin_difference(mayan_tzolkin_from_absolute(0), d),
-            260,
-        );
+    let difference = mayan_tzolkin_difference(mayan_tzolkin_from_absolute(0), d);
+    return absolute_date.saturating_sub(modulus(absolute_date.rem_euclid(260) - difference, 260));
+}
+
+/// Returns the absolute (fixed) date of a Mayan Tzolkin date on or after a
+/// given absolute date.
+pub fn mayan_tzolkin_on_or_after(d: MayanTzolkin, absolute_date: i64) -> i64 {
+    let difference = mayan_tzolkin_difference(mayan_tzolkin_from_absolute(0), d);
+    return absolute_date.saturating_add(modulus(difference - absolute_date.rem_euclid(260), 260));
+}
+
+/// Returns the absolute (fixed) date of the Mayan Tzolkin date nearest to a
+/// given absolute date. Of two equally near dates, the later one is
+/// returned.
+///
+/// # Example
+///
+/// ```
+/// use calendars::mayan::{
+///     mayan_tzolkin_from_absolute, mayan_tzolkin_nearest, mayan_tzolkin_on_or_after,
+///     mayan_tzolkin_on_or_before,
+/// };
+///
+/// let d = mayan_tzolkin_from_absolute(730000);
+/// assert_eq!(mayan_tzolkin_on_or_after(d, 730001), 730260);
+/// assert_eq!(mayan_tzolkin_on_or_before(d, 730259), 730000);
+/// assert_eq!(mayan_tzolkin_nearest(d, 730129), 730000);
+/// assert_eq!(mayan_tzolkin_nearest(d, 730130), 730260);
+/// ```
+pub fn mayan_tzolkin_nearest(d: MayanTzolkin, absolute_date: i64) -> i64 {
+    return mayan_tzolkin_on_or_before(d, absolute_date.saturating_add(130));
+}
+
+/// Returns an iterator over the absolute (fixed) dates of all occurrences of
+/// a Mayan Tzolkin date from `start` to `end` (inclusive).
+pub fn mayan_tzolkin_occurrences(
+    d: MayanTzolkin,
+    start: i64,
+    end: i64,
+) -> impl Iterator<Item = i64> {
+    return (mayan_tzolkin_on_or_after(d, start)..=end).step_by(260);
 }
 
 /// Returns an Option with the absolute date of the latest date on or before a
@@ -177,13 +460,10 @@ pub fn mayan_haab_tzolkin_on_or_before(
     let tzolkin_difference = mayan_tzolkin_difference(mayan_tzolkin_from_absolute(0), dt);
     let difference = tzolkin_difference - haab_difference;
     return if modulus(difference, 5) == 0 {
-        Some(
-            absolute_date
-                - modulus(
-                    absolute_date - (haab_difference + (365 * difference)),
-                    18980,
-                ),
-        )
+        Some(absolute_date.saturating_sub(modulus(
+            absolute_date.rem_euclid(18980) - (haab_difference + (365 * difference)),
+            18980,
+        )))
     } else {
         None
     };
@@ -0,0 +1,192 @@
+//! Provides moments (absolute dates with a fraction of day) and times of day
+
+use core::{
+    fmt,
+    ops::{Add, Sub},
+};
+
+use crate::{
+    error::{check_component, CalendarError},
+    interop::SECONDS_PER_DAY,
+};
+
+/// Time of day (hour, minute, second)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeOfDay {
+    pub hour: i64,
+    pub minute: i64,
+    pub second: i64,
+}
+
+impl TimeOfDay {
+    /// Midnight (00:00:00)
+    pub const MIDNIGHT: Self = Self::new(0, 0, 0);
+
+    /// Noon (12:00:00)
+    pub const NOON: Self = Self::new(12, 0, 0);
+
+    /// Create a new time of day
+    pub const fn new(hour: i64, minute: i64, second: i64) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// Create a new time of day, or return an error if the time does not
+    /// exist.
+    pub fn try_new(hour: i64, minute: i64, second: i64) -> Result<Self, CalendarError> {
+        let t = Self::new(hour, minute, second);
+        t.validate()?;
+        return Ok(t);
+    }
+
+    /// Returns true if the time exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the time lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component("time", "hour", self.hour, 0, 23)?;
+        check_component("time", "minute", self.minute, 0, 59)?;
+        return check_component("time", "second", self.second, 0, 59);
+    }
+
+    /// Returns the number of seconds since midnight.
+    pub fn seconds(&self) -> i64 {
+        return 3600 * self.hour + 60 * self.minute + self.second;
+    }
+
+    /// Returns the time of day a given number of seconds after midnight
+    /// (modulo one day).
+    pub fn from_seconds(seconds: i64) -> Self {
+        let seconds = seconds.rem_euclid(SECONDS_PER_DAY);
+        return Self::new(seconds / 3600, seconds % 3600 / 60, seconds % 60);
+    }
+
+    /// Returns the fraction of day elapsed since midnight.
+    pub fn fraction(&self) -> f64 {
+        return self.seconds() as f64 / SECONDS_PER_DAY as f64;
+    }
+
+    /// Returns the time of day of a given fraction of day, rounded to the
+    /// nearest second.
+    pub fn from_fraction(fraction: f64) -> Self {
+        let seconds = (fraction.rem_euclid(1.0) * SECONDS_PER_DAY as f64).round() as i64;
+        return Self::from_seconds(seconds);
+    }
+}
+
+/// Implement fmt::Display trait for [`TimeOfDay`] (e.g. "12:00:00")
+impl fmt::Display for TimeOfDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}:{:02}", self.hour, self.minute, self.second)
+    }
+}
+
+/// Moment: an absolute (fixed) date with a fraction of day (e.g. 738000.5 is
+/// noon of absolute date 738000). Moments are in universal time unless noted
+/// otherwise.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     hebrew::{absolute_from_hebrew, Hebrew},
+///     moment::{Moment, TimeOfDay},
+/// };
+///
+/// // noon on 14 Nisan 5785
+/// let passover_eve = absolute_from_hebrew(Hebrew { year: 5785, month: 1, day: 14 });
+/// let noon = Moment::new(passover_eve, TimeOfDay::NOON);
+/// assert_eq!(noon.absolute_date(), passover_eve);
+/// assert_eq!(noon.time_of_day(), TimeOfDay::new(12, 0, 0));
+/// assert_eq!((noon + 0.25).time_of_day().to_string(), "18:00:00");
+/// assert_eq!(noon - Moment::from(passover_eve), 0.5);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Moment(pub f64);
+
+impl Moment {
+    /// Create the moment of a given time of day on a given absolute (fixed)
+    /// date
+    pub fn new(absolute_date: i64, time: TimeOfDay) -> Self {
+        return Self(absolute_date as f64 + time.fraction());
+    }
+
+    /// Returns the absolute (fixed) date of the moment.
+    pub fn absolute_date(&self) -> i64 {
+        return self.0.floor() as i64;
+    }
+
+    /// Returns the time of day of the moment, rounded to the nearest second.
+    pub fn time_of_day(&self) -> TimeOfDay {
+        return TimeOfDay::from_fraction(self.0 - self.0.floor());
+    }
+}
+
+/// Returns the moment of midnight on an absolute (fixed) date
+impl From<i64> for Moment {
+    fn from(absolute_date: i64) -> Self {
+        return Self(absolute_date as f64);
+    }
+}
+
+impl From<f64> for Moment {
+    fn from(days: f64) -> Self {
+        return Self(days);
+    }
+}
+
+impl From<Moment> for f64 {
+    fn from(moment: Moment) -> Self {
+        return moment.0;
+    }
+}
+
+/// Adds a number of days (or a fraction of day) to a moment
+impl Add<f64> for Moment {
+    type Output = Moment;
+
+    fn add(self, days: f64) -> Moment {
+        return Moment(self.0 + days);
+    }
+}
+
+/// Subtracts a number of days (or a fraction of day) from a moment
+impl Sub<f64> for Moment {
+    type Output = Moment;
+
+    fn sub(self, days: f64) -> Moment {
+        return Moment(self.0 - days);
+    }
+}
+
+/// Returns the number of days (with a fraction) between two moments
+impl Sub<Moment> for Moment {
+    type Output = f64;
+
+    fn sub(self, other: Moment) -> f64 {
+        return self.0 - other.0;
+    }
+}
+
+/// Implement fmt::Display trait for [`Moment`] (absolute date and time of
+/// day, e.g. "739353 12:00:00")
+impl fmt::Display for Moment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // round first, so that 23:59:59.6 is displayed as midnight of the
+        // next day
+        let seconds = (self.0 * SECONDS_PER_DAY as f64).round() as i64;
+        write!(
+            f,
+            "{} {}",
+            seconds.div_euclid(SECONDS_PER_DAY),
+            TimeOfDay::from_seconds(seconds)
+        )
+    }
+}
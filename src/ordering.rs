@@ -0,0 +1,208 @@
+//! Provides ordering of dates and equality of dates across calendars, based
+//! on their absolute (fixed) dates
+//!
+//! Dates of the same calendar with the same absolute date are ordered by
+//! their components, consistently with their (component-wise) equality.
+//!
+//! # Example
+//!
+//! ```
+//! use calendars::gregorian::Gregorian;
+//!
+//! let (february, march) = (Gregorian::new(2024, 2, 30), Gregorian::new(2024, 3, 1));
+//! assert_ne!(february, march);
+//! assert!(february < march);
+//! assert!(Gregorian::new(2024, 2, 29) < february);
+//! ```
+//!
+//! Dates of different calendars are compared by their absolute dates. Old
+//! Hindu lunar dates that do not exist are neither equal to nor ordered
+//! with dates of other calendars.
+//!
+//! ```
+//! use calendars::{gregorian::Gregorian, hindu::OldHinduLunar};
+//!
+//! let nonexistent = OldHinduLunar { year: 5000, month: 3, leap_month: true, day: 5 };
+//! assert!(Gregorian::new(2024, 1, 1) != nonexistent);
+//! assert_eq!(Gregorian::new(2024, 1, 1).partial_cmp(&nonexistent), None);
+//! assert_eq!(nonexistent.partial_cmp(&Gregorian::new(2024, 1, 1)), None);
+//! ```
+
+use crate::{
+    french::French,
+    gregorian::Gregorian,
+    hebrew::Hebrew,
+    hindu::{old_hindu_lunar_precedes, OldHinduLunar, OldHinduSolar},
+    islamic::{Islamic, UmmAlQura},
+    iso::Iso,
+    julian::Julian,
+    mayan::MayanLongCount,
+    ordinal::Ordinal,
+    utility::{Calendar, Date, FixedConvertible},
+};
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+};
+
+/// Implement Ord (and PartialOrd) for date types, keyed on absolute dates.
+/// Dates with the same absolute date (e.g. the non-normalized 30 February
+/// 2024 and 1 March 2024) are further ordered by their components, so that
+/// the ordering is consistent with equality.
+macro_rules! impl_ord {
+    ($($t:ty: $($field:ident),+);*) => {
+        $(
+            impl PartialOrd for $t {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    return Some(self.cmp(other));
+                }
+            }
+
+            impl Ord for $t {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    return self
+                        .to_absolute()
+                        .cmp(&other.to_absolute())
+                        .then_with(|| ($(self.$field),+).cmp(&($(other.$field),+)));
+                }
+            }
+        )*
+    };
+}
+
+/// Implement PartialEq and PartialOrd between a date type and other date
+/// types, keyed on absolute dates. Dates without an absolute date (e.g.
+/// nonexistent old Hindu lunar dates) are unequal to, and unordered with,
+/// any date of another calendar.
+macro_rules! impl_cross_calendar_cmp {
+    ($a:ty; $($b:ty),*) => {
+        $(
+            impl PartialEq<$b> for $a {
+                fn eq(&self, other: &$b) -> bool {
+                    return match (self.absolute_unchecked(), other.absolute_unchecked()) {
+                        (Some(a), Some(b)) => a == b,
+                        _ => false,
+                    };
+                }
+            }
+
+            impl PartialOrd<$b> for $a {
+                fn partial_cmp(&self, other: &$b) -> Option<Ordering> {
+                    return Some(self.absolute_unchecked()?.cmp(&other.absolute_unchecked()?));
+                }
+            }
+        )*
+    };
+}
+
+impl_ord!(
+    Gregorian: year, month, day;
+    Iso: year, week, day;
+    Ordinal: year, day;
+    Julian: year, month, day;
+    Islamic: year, month, day;
+    UmmAlQura: year, month, day;
+    Hebrew: year, month, day;
+    MayanLongCount: baktun, katun, tun, uinal, kin;
+    French: year, month, day;
+    OldHinduSolar: year, month, day
+);
+
+impl PartialOrd for OldHinduLunar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/// Old Hindu lunar dates are ordered by their components, as not every such
+/// date corresponds to an absolute date.
+impl Ord for OldHinduLunar {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return if old_hindu_lunar_precedes(*self, *other) {
+            Ordering::Less
+        } else if old_hindu_lunar_precedes(*other, *self) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        };
+    }
+}
+
+impl_cross_calendar_cmp!(Gregorian; Iso, Ordinal, Julian, Islamic, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(Iso; Gregorian, Ordinal, Julian, Islamic, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(Ordinal; Gregorian, Iso, Julian, Islamic, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(Julian; Gregorian, Iso, Ordinal, Islamic, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(Islamic; Gregorian, Iso, Ordinal, Julian, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(UmmAlQura; Gregorian, Iso, Ordinal, Julian, Islamic, Hebrew, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(Hebrew; Gregorian, Iso, Ordinal, Julian, Islamic, UmmAlQura, MayanLongCount, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(MayanLongCount; Gregorian, Iso, Ordinal, Julian, Islamic, UmmAlQura, Hebrew, French, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(French; Gregorian, Iso, Ordinal, Julian, Islamic, UmmAlQura, Hebrew, MayanLongCount, OldHinduSolar, OldHinduLunar);
+impl_cross_calendar_cmp!(OldHinduSolar; Gregorian, Iso, Ordinal, Julian, Islamic, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduLunar);
+impl_cross_calendar_cmp!(OldHinduLunar; Gregorian, Iso, Ordinal, Julian, Islamic, UmmAlQura, Hebrew, MayanLongCount, French, OldHinduSolar);
+
+impl Date {
+    /// Returns true if two dates (possibly of different calendars) refer to
+    /// the same day, and false otherwise.
+    pub fn same_day(&self, other: &Date) -> bool {
+        return match (self.absolute_unchecked(), other.absolute_unchecked()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self == other,
+        };
+    }
+}
+
+/// [`Date`]s are equal if they have the same calendar and components,
+/// regardless of their component and month names, so that they can be used
+/// as keys of hash maps and deduplicated in sets.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{gregorian::Gregorian, hebrew::Hebrew, utility::Calendar};
+/// use std::collections::HashSet;
+///
+/// let mut dates = HashSet::new();
+/// dates.insert(Gregorian::new(2024, 5, 1).to_date());
+/// dates.insert("gregorian:2024-5-1".parse().unwrap());
+/// dates.insert(Hebrew { year: 5784, month: 1, day: 23 }.to_date());
+/// assert_eq!(dates.len(), 2);
+///
+/// let mut gregorian = HashSet::new();
+/// gregorian.insert(Gregorian::new(2024, 5, 1));
+/// assert!(gregorian.contains(&Gregorian::new(2024, 5, 1)));
+/// ```
+impl PartialEq for Date {
+    fn eq(&self, other: &Self) -> bool {
+        return self.calendar == other.calendar && self.components == other.components;
+    }
+}
+
+impl Eq for Date {}
+
+/// Implement Hash for [`Date`] consistently with equality, i.e. keyed on
+/// calendar and components.
+impl Hash for Date {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.calendar.hash(state);
+        self.components.hash(state);
+    }
+}
+
+impl PartialOrd for Date {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+/// [`Date`]s are ordered by their absolute dates. Dates referring to the same
+/// day are further ordered by calendar name and components, so that the
+/// ordering is consistent with equality.
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return self
+            .absolute_unchecked()
+            .cmp(&other.absolute_unchecked())
+            .then_with(|| self.calendar.cmp(&other.calendar))
+            .then_with(|| self.components.cmp(&other.components));
+    }
+}
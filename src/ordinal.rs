@@ -0,0 +1,120 @@
+//! Functions converting from and to ISO ordinal dates
+
+use core::ops::RangeInclusive;
+
+use crate::{
+    error::{check_component, check_range, CalendarError},
+    gregorian::{
+        absolute_from_gregorian, gregorian_from_absolute, Gregorian, GREGORIAN_ABSOLUTE_RANGE,
+        GREGORIAN_YEAR_RANGE,
+    },
+    math::clamp_to_range,
+};
+
+/// Range of years supported by the conversions of ordinal dates.
+pub static ORDINAL_YEAR_RANGE: RangeInclusive<i64> =
+    *GREGORIAN_YEAR_RANGE.start()..=*GREGORIAN_YEAR_RANGE.end();
+
+/// Range of absolute (fixed) dates supported by the conversions of ordinal
+/// dates (the dates of the years in [`ORDINAL_YEAR_RANGE`]).
+pub static ORDINAL_ABSOLUTE_RANGE: RangeInclusive<i64> =
+    *GREGORIAN_ABSOLUTE_RANGE.start()..=*GREGORIAN_ABSOLUTE_RANGE.end();
+
+/// Ordinal date (year and day of the Gregorian year)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "python",
+    pyo3::pyclass(module = "calendars", get_all, set_all, eq, from_py_object)
+)]
+#[repr(C)]
+pub struct Ordinal {
+    pub year: i64,
+    pub day: i64,
+}
+
+impl Ordinal {
+    /// Create a new ordinal date
+    pub fn new(year: i64, day: i64) -> Self {
+        Self { year, day }
+    }
+
+    /// Create a new ordinal date, or return an error if the date does not exist
+    /// or its year lies outside [`ORDINAL_YEAR_RANGE`].
+    pub fn try_new(year: i64, day: i64) -> Result<Self, CalendarError> {
+        let d = Self::new(year, day);
+        d.validate()?;
+        return Ok(d);
+    }
+
+    /// Returns true if the date exists, and false otherwise.
+    pub fn is_valid(&self) -> bool {
+        return self.validate().is_ok();
+    }
+
+    /// Checks that all components of the date lie within their valid ranges.
+    fn validate(&self) -> Result<(), CalendarError> {
+        check_component(
+            "ordinal",
+            "year",
+            self.year,
+            *ORDINAL_YEAR_RANGE.start(),
+            *ORDINAL_YEAR_RANGE.end(),
+        )?;
+        let days_in_year = absolute_from_gregorian(Gregorian {
+            year: self.year,
+            month: 12,
+            day: 31,
+        }) - absolute_from_gregorian(Gregorian {
+            year: self.year - 1,
+            month: 12,
+            day: 31,
+        });
+        return check_component("ordinal", "day", self.day, 1, days_in_year);
+    }
+}
+
+/// Computes the absolute (fixed) date from an ordinal date.
+///
+/// The result is only meaningful for years in [`ORDINAL_YEAR_RANGE`]; see
+/// [`checked_absolute_from_ordinal`].
+pub fn absolute_from_ordinal(d: Ordinal) -> i64 {
+    return d.day
+        + absolute_from_gregorian(Gregorian {
+            year: d.year - 1,
+            month: 12,
+            day: 31,
+        });
+}
+
+/// Computes the ordinal date corresponding to a given absolute (fixed) date.
+///
+/// Absolute dates outside [`ORDINAL_ABSOLUTE_RANGE`] are clamped to the range; see
+/// [`checked_ordinal_from_absolute`] for a fallible alternative.
+pub fn ordinal_from_absolute(absolute_date: i64) -> Ordinal {
+    let absolute_date = clamp_to_range(absolute_date, &ORDINAL_ABSOLUTE_RANGE);
+    let year = gregorian_from_absolute(absolute_date).year;
+    let day = absolute_date
+        - absolute_from_gregorian(Gregorian {
+            year: year - 1,
+            month: 12,
+            day: 31,
+        });
+    return Ordinal { year, day };
+}
+
+/// Computes the absolute (fixed) date from a given ordinal date, or returns an
+/// error if the date does not exist or its year lies outside
+/// [`ORDINAL_YEAR_RANGE`].
+pub fn checked_absolute_from_ordinal(d: Ordinal) -> Result<i64, CalendarError> {
+    d.validate()?;
+    return Ok(absolute_from_ordinal(d));
+}
+
+/// Computes the ordinal date corresponding to a given absolute (fixed) date,
+/// or returns an error if the absolute date lies outside
+/// [`ORDINAL_ABSOLUTE_RANGE`].
+pub fn checked_ordinal_from_absolute(absolute_date: i64) -> Result<Ordinal, CalendarError> {
+    check_range("ordinal", absolute_date, &ORDINAL_ABSOLUTE_RANGE)?;
+    return Ok(ordinal_from_absolute(absolute_date));
+}
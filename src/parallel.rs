@@ -0,0 +1,75 @@
+//! Provides parallel batch conversions and holiday computations (requires
+//! the `parallel` feature)
+
+use core::ops::RangeInclusive;
+
+use rayon::prelude::*;
+
+use crate::{
+    error::CalendarError,
+    utility::{Calendar, Date, FixedConvertible},
+};
+
+/// Computes the dates of type `T` corresponding to given absolute (fixed)
+/// dates in parallel.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{gregorian::Gregorian, parallel::par_from_absolute};
+///
+/// let dates: Vec<Gregorian> = par_from_absolute(&[1, 738000]);
+/// assert_eq!(dates, [Gregorian::new(1, 1, 1), Gregorian::new(2021, 7, 29)]);
+/// ```
+pub fn par_from_absolute<T: Calendar + Send>(absolute_dates: &[i64]) -> Vec<T> {
+    return absolute_dates
+        .par_iter()
+        .map(|absolute_date| T::from_absolute(*absolute_date))
+        .collect();
+}
+
+/// Computes the dates of type `T` corresponding to given absolute (fixed)
+/// dates in parallel, with an error for each absolute date outside the range
+/// of `T`'s calendar (see [`Calendar::checked_from_absolute`]).
+pub fn par_checked_from_absolute<T: Calendar + Send>(
+    absolute_dates: &[i64],
+) -> Vec<Result<T, CalendarError>> {
+    return absolute_dates
+        .par_iter()
+        .map(|absolute_date| T::checked_from_absolute(*absolute_date))
+        .collect();
+}
+
+/// Computes the absolute (fixed) dates of given dates in parallel.
+pub fn par_to_absolute<T: FixedConvertible + Sync>(dates: &[T]) -> Vec<i64> {
+    return dates.par_iter().map(|d| d.to_absolute()).collect();
+}
+
+/// Converts given [`Date`]s to the calendar representation specified in
+/// `calendar` in parallel (see [`Date::try_convert_to`]).
+pub fn par_convert_dates(dates: &[Date], calendar: &str) -> Vec<Result<Date, CalendarError>> {
+    return dates
+        .par_iter()
+        .map(|d| d.try_convert_to(calendar))
+        .collect();
+}
+
+/// Computes a holiday (given as a function of the year, such as
+/// [`easter`](crate::holidays::easter)) for a range of years in parallel.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{holidays::christmas, parallel::par_holidays};
+///
+/// let dates = par_holidays(2000..=2099, christmas);
+/// assert_eq!(dates.len(), 100);
+/// assert_eq!(dates[0], christmas(2000));
+/// ```
+pub fn par_holidays<T, F>(years: RangeInclusive<i64>, holiday: F) -> Vec<T>
+where
+    T: Send,
+    F: Fn(i64) -> T + Sync + Send,
+{
+    return years.into_par_iter().map(holiday).collect();
+}
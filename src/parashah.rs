@@ -0,0 +1,393 @@
+//! Provides functions to compute the weekly Torah portion (parashah)
+
+use core::fmt;
+
+use crate::{
+    hebrew::{
+        absolute_from_hebrew, hebrew_from_absolute, hebrew_leap_year, hebrew_new_year, Hebrew,
+        HEBREW_ABSOLUTE_RANGE, HEBREW_YEAR_RANGE,
+    },
+    weekday::{kday_after, kday_on_or_after, weekday_from_absolute, Weekday},
+};
+
+/// Parashah names
+pub static PARASHAH_NAMES: [&str; 54] = [
+    "Bereshit",
+    "Noach",
+    "Lech-Lecha",
+    "Vayera",
+    "Chayei Sara",
+    "Toldot",
+    "Vayetzei",
+    "Vayishlach",
+    "Vayeshev",
+    "Miketz",
+    "Vayigash",
+    "Vayechi",
+    "Shemot",
+    "Vaera",
+    "Bo",
+    "Beshalach",
+    "Yitro",
+    "Mishpatim",
+    "Terumah",
+    "Tetzaveh",
+    "Ki Tisa",
+    "Vayakhel",
+    "Pekudei",
+    "Vayikra",
+    "Tzav",
+    "Shmini",
+    "Tazria",
+    "Metzora",
+    "Achrei Mot",
+    "Kedoshim",
+    "Emor",
+    "Behar",
+    "Bechukotai",
+    "Bamidbar",
+    "Nasso",
+    "Beha'alotcha",
+    "Sh'lach",
+    "Korach",
+    "Chukat",
+    "Balak",
+    "Pinchas",
+    "Matot",
+    "Masei",
+    "Devarim",
+    "Vaetchanan",
+    "Eikev",
+    "Re'eh",
+    "Shoftim",
+    "Ki Teitzei",
+    "Ki Tavo",
+    "Nitzavim",
+    "Vayeilech",
+    "Ha'azinu",
+    "Vezot Haberakhah",
+];
+
+/// Weekly Torah portion
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Parashah {
+    Bereshit,
+    Noach,
+    LechLecha,
+    Vayera,
+    ChayeiSara,
+    Toldot,
+    Vayetzei,
+    Vayishlach,
+    Vayeshev,
+    Miketz,
+    Vayigash,
+    Vayechi,
+    Shemot,
+    Vaera,
+    Bo,
+    Beshalach,
+    Yitro,
+    Mishpatim,
+    Terumah,
+    Tetzaveh,
+    KiTisa,
+    Vayakhel,
+    Pekudei,
+    Vayikra,
+    Tzav,
+    Shmini,
+    Tazria,
+    Metzora,
+    AchreiMot,
+    Kedoshim,
+    Emor,
+    Behar,
+    Bechukotai,
+    Bamidbar,
+    Nasso,
+    Behaalotcha,
+    Shlach,
+    Korach,
+    Chukat,
+    Balak,
+    Pinchas,
+    Matot,
+    Masei,
+    Devarim,
+    Vaetchanan,
+    Eikev,
+    Reeh,
+    Shoftim,
+    KiTeitzei,
+    KiTavo,
+    Nitzavim,
+    Vayeilech,
+    Haazinu,
+    VezotHaberakhah,
+}
+
+/// All parashiyot in the order in which they are read
+pub static PARASHIYOT: [Parashah; 54] = [
+    Parashah::Bereshit,
+    Parashah::Noach,
+    Parashah::LechLecha,
+    Parashah::Vayera,
+    Parashah::ChayeiSara,
+    Parashah::Toldot,
+    Parashah::Vayetzei,
+    Parashah::Vayishlach,
+    Parashah::Vayeshev,
+    Parashah::Miketz,
+    Parashah::Vayigash,
+    Parashah::Vayechi,
+    Parashah::Shemot,
+    Parashah::Vaera,
+    Parashah::Bo,
+    Parashah::Beshalach,
+    Parashah::Yitro,
+    Parashah::Mishpatim,
+    Parashah::Terumah,
+    Parashah::Tetzaveh,
+    Parashah::KiTisa,
+    Parashah::Vayakhel,
+    Parashah::Pekudei,
+    Parashah::Vayikra,
+    Parashah::Tzav,
+    Parashah::Shmini,
+    Parashah::Tazria,
+    Parashah::Metzora,
+    Parashah::AchreiMot,
+    Parashah::Kedoshim,
+    Parashah::Emor,
+    Parashah::Behar,
+    Parashah::Bechukotai,
+    Parashah::Bamidbar,
+    Parashah::Nasso,
+    Parashah::Behaalotcha,
+    Parashah::Shlach,
+    Parashah::Korach,
+    Parashah::Chukat,
+    Parashah::Balak,
+    Parashah::Pinchas,
+    Parashah::Matot,
+    Parashah::Masei,
+    Parashah::Devarim,
+    Parashah::Vaetchanan,
+    Parashah::Eikev,
+    Parashah::Reeh,
+    Parashah::Shoftim,
+    Parashah::KiTeitzei,
+    Parashah::KiTavo,
+    Parashah::Nitzavim,
+    Parashah::Vayeilech,
+    Parashah::Haazinu,
+    Parashah::VezotHaberakhah,
+];
+
+impl Parashah {
+    /// Returns the number of the parashah (0 = Bereshit, ..., 53 = Vezot
+    /// Haberakhah).
+    pub fn number(&self) -> usize {
+        return *self as usize;
+    }
+
+    /// Returns the (transliterated) name of the parashah.
+    pub fn name(&self) -> &'static str {
+        return PARASHAH_NAMES[self.number()];
+    }
+}
+
+/// Implement fmt::Display trait for [`Parashah`], writing its name
+impl fmt::Display for Parashah {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Torah reading of a Sabbath: a single parashah, or two combined parashiyot
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Reading {
+    pub parashah: Parashah,
+    pub combined_with: Option<Parashah>,
+}
+
+impl Reading {
+    fn single(number: usize) -> Self {
+        Self {
+            parashah: PARASHIYOT[number],
+            combined_with: None,
+        }
+    }
+
+    fn combined(number: usize) -> Self {
+        Self {
+            parashah: PARASHIYOT[number],
+            combined_with: Some(PARASHIYOT[number + 1]),
+        }
+    }
+}
+
+/// Implement fmt::Display trait for [`Reading`] (e.g. "Vayakhel-Pekudei")
+impl fmt::Display for Reading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.combined_with {
+            Some(second) => write!(f, "{}-{}", self.parashah, second),
+            None => write!(f, "{}", self.parashah),
+        }
+    }
+}
+
+/// Returns true if the Sabbath on a given absolute (fixed) date is a festival
+/// day with a special Torah reading during the annual cycle (Passover and
+/// Shavuot).
+fn festival_sabbath(sabbath: i64, israel: bool) -> bool {
+    let h = hebrew_from_absolute(sabbath);
+    return match h.month {
+        1 => h.day >= 15 && h.day <= if israel { 21 } else { 22 },
+        3 => h.day >= 6 && h.day <= if israel { 6 } else { 7 },
+        _ => false,
+    };
+}
+
+/// Returns the numbers of the parashiyot that are combined with their
+/// successor in the annual cycle beginning in a given Hebrew year.
+fn combined_parashiyot(year: i64, israel: bool) -> Vec<usize> {
+    let new_year = weekday_from_absolute(hebrew_new_year(year));
+    let next_new_year = weekday_from_absolute(hebrew_new_year(year + 1));
+    let complete = hebrew_new_year(year + 1) - hebrew_new_year(year) == 355;
+    let passover = weekday_from_absolute(absolute_from_hebrew(Hebrew {
+        year,
+        month: 1,
+        day: 15,
+    }));
+    let mut combined = vec![];
+    if !hebrew_leap_year(year) {
+        // Vayakhel-Pekudei, Tazria-Metzora, Achrei Mot-Kedoshim
+        if !(new_year == Weekday::Thursday && complete) {
+            combined.push(21);
+        }
+        combined.extend([26, 28]);
+        // Behar-Bechukotai, unless Israel is a week ahead of the diaspora
+        if !(israel && passover == Weekday::Saturday) {
+            combined.push(31);
+        }
+    }
+    // Chukat-Balak, if the second day of Shavuot falls on the Sabbath
+    if !israel && passover == Weekday::Thursday {
+        combined.push(38);
+    }
+    // Matot-Masei, unless in a leap year beginning on Thursday, or unless
+    // Israel is a week ahead of the diaspora in a leap year
+    let separate_matot = hebrew_leap_year(year)
+        && (new_year == Weekday::Thursday || (israel && passover == Weekday::Saturday));
+    if !separate_matot {
+        combined.push(41);
+    }
+    // Nitzavim-Vayeilech
+    if [Weekday::Thursday, Weekday::Saturday].contains(&next_new_year) {
+        combined.push(50);
+    }
+    return combined;
+}
+
+/// Returns the weekly Torah reading of the Sabbath on or after a given
+/// absolute (fixed) date, in Israel or in the diaspora. Returns `None` if the
+/// Sabbath is a festival day with a special reading instead (e.g. during
+/// Passover or Sukkot), or lies outside [`HEBREW_ABSOLUTE_RANGE`].
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     parashah::{parashah, Parashah},
+/// };
+///
+/// let date = |year, month, day| absolute_from_gregorian(Gregorian::new(year, month, day));
+/// let reading = parashah(date(2024, 10, 26), false).unwrap();
+/// assert_eq!(reading.parashah, Parashah::Bereshit);
+/// assert_eq!(parashah(date(2025, 4, 26), false).unwrap().to_string(), "Shmini");
+/// assert_eq!(parashah(date(2024, 5, 11), false).unwrap().to_string(), "Kedoshim");
+/// assert_eq!(parashah(date(2025, 5, 3), false).unwrap().to_string(), "Tazria-Metzora");
+/// assert_eq!(parashah(date(2025, 5, 24), false).unwrap().to_string(), "Behar-Bechukotai");
+///
+/// // Israel is a week ahead from the eighth day of Passover 2022 to Matot-Masei
+/// assert_eq!(parashah(date(2022, 4, 23), false), None);
+/// assert_eq!(parashah(date(2022, 4, 23), true).unwrap().to_string(), "Achrei Mot");
+/// assert_eq!(parashah(date(2022, 7, 30), false).unwrap().to_string(), "Matot-Masei");
+/// assert_eq!(parashah(date(2022, 7, 30), true).unwrap().to_string(), "Masei");
+///
+/// assert_eq!(parashah(i64::MAX, false), None);
+/// assert_eq!(parashah(i64::MIN, true), None);
+/// ```
+pub fn parashah(absolute_date: i64, israel: bool) -> Option<Reading> {
+    let sabbath = kday_on_or_after(absolute_date, 6);
+    if !HEBREW_ABSOLUTE_RANGE.contains(&sabbath) {
+        return None;
+    }
+    let year = hebrew_from_absolute(sabbath).year;
+    let new_year = hebrew_new_year(year);
+    // the cycle begins on the Sabbath after Shemini Atzeret
+    let bereshit = kday_after(new_year + 21, 6);
+    if sabbath < bereshit {
+        // Sabbaths of Tishri before Bereshit
+        let h = hebrew_from_absolute(sabbath);
+        if [1, 2, 10].contains(&h.day) || h.day >= 15 {
+            return None;
+        }
+        let first_sabbath = kday_after(new_year + 1, 6);
+        let two_sabbaths =
+            [Weekday::Monday, Weekday::Tuesday].contains(&weekday_from_absolute(new_year));
+        return if two_sabbaths && sabbath == first_sabbath {
+            Some(Reading::single(51))
+        } else {
+            Some(Reading::single(52))
+        };
+    }
+    let combined = combined_parashiyot(year, israel);
+    let mut number = 0;
+    let mut current = bereshit;
+    while current <= sabbath {
+        let festival = festival_sabbath(current, israel);
+        if current == sabbath {
+            return if festival {
+                None
+            } else if combined.contains(&number) {
+                Some(Reading::combined(number))
+            } else {
+                Some(Reading::single(number))
+            };
+        }
+        if !festival {
+            number += if combined.contains(&number) { 2 } else { 1 };
+        }
+        current += 7;
+    }
+    return None;
+}
+
+/// Returns the weekly Torah reading of a given week (1 = week of the first
+/// Sabbath after Rosh Hashanah) of a given Hebrew year, in Israel or in the
+/// diaspora (see [`parashah`]). Returns `None` for years outside
+/// [`HEBREW_YEAR_RANGE`].
+///
+/// # Example
+///
+/// ```
+/// use calendars::parashah::{parashah_of_week, Parashah};
+///
+/// assert_eq!(parashah_of_week(5785, 4, false).unwrap().parashah, Parashah::Bereshit);
+/// assert_eq!(parashah_of_week(i64::MAX, 1, false), None);
+/// assert_eq!(parashah_of_week(5785, i64::MIN, false), None);
+/// ```
+pub fn parashah_of_week(year: i64, week: i64, israel: bool) -> Option<Reading> {
+    if !HEBREW_YEAR_RANGE.contains(&year) {
+        return None;
+    }
+    let offset = week.checked_sub(1)?.checked_mul(7)?;
+    return parashah(
+        kday_after(hebrew_new_year(year), 6).checked_add(offset)?,
+        israel,
+    );
+}
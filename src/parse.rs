@@ -0,0 +1,479 @@
+//! Provides parsing of date types from strings
+//!
+//! Dates of calendars with year, month, and day components are parsed either
+//! from numeric "year-month-day" strings (e.g. "2024-05-01") or from the
+//! "day month year" strings returned by [`Calendar::format`] (e.g. "17 Ramadan
+//! 1445"). Month names are matched case-insensitively; months may also be
+//! given as numbers. The year may be preceded or followed by an era (e.g.
+//! "15 March 44 BC", "1 Nisan AM 5784", or "12 Floréal an II" with the year in
+//! Roman numerals). Parsed dates are validated, so strings that describe
+//! non-existent dates (e.g. "2023-02-29") are rejected.
+//!
+//! Generic [`Date`]s are parsed from the tagged form produced by their
+//! `Display` implementation (e.g. "hebrew:5784-1-15"), or from the formats
+//! of a given calendar with [`parse_date`].
+//!
+//! [`Calendar::format`]: crate::utility::Calendar::format
+
+use crate::{
+    error::CalendarError,
+    format::{parse_roman_numeral, year_from_era},
+    french::{French, FRENCH_MONTH_NAMES},
+    gregorian::{Gregorian, GREGORIAN_MONTH_NAMES},
+    hebrew::{Hebrew, HEBREW_MONTH_NAMES},
+    hindu::{
+        OldHinduLunar, OldHinduSolar, HINDU_LEAP_MONTH_PREFIX, HINDU_LUNAR_MONTH_NAMES,
+        HINDU_SOLAR_MONTH_NAMES,
+    },
+    islamic::{Islamic, UmmAlQura, ISLAMIC_MONTH_NAMES},
+    iso::Iso,
+    julian::Julian,
+    mayan::{MayanHaab, MayanLongCount, MayanTzolkin, MAYAN_MONTH_NAMES, MAYAN_TZOLKIN_NAMES},
+    ordinal::Ordinal,
+    utility::{dispatch_all, Calendar, Date},
+};
+use std::str::FromStr;
+
+/// Returns a parse error for a given calendar and input string.
+fn parse_error(calendar: &str, s: &str) -> CalendarError {
+    return CalendarError::ParseError(format!("invalid {} date: \"{}\"", calendar, s));
+}
+
+/// Parses an unsigned number consisting of ASCII digits only.
+fn parse_number(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    return s.parse().ok();
+}
+
+/// Splits a leading sign ("-", or "+" as in ISO 8601 expanded years) off a
+/// string.
+fn split_sign(s: &str) -> (i64, &str) {
+    if let Some(rest) = s.strip_prefix('-') {
+        return (-1, rest);
+    }
+    return (1, s.strip_prefix('+').unwrap_or(s));
+}
+
+/// Parses a year, which may be preceded by a sign.
+fn parse_year(s: &str) -> Option<i64> {
+    let (sign, digits) = split_sign(s);
+    return parse_number(digits).map(|year| sign * year);
+}
+
+/// Parses a given number of numbers separated by `separator`, the first of
+/// which (the year) may be preceded by a sign.
+fn parse_numeric(s: &str, separator: char, count: usize) -> Option<Vec<i64>> {
+    let (sign, unsigned) = split_sign(s);
+    let mut numbers = unsigned
+        .split(separator)
+        .map(parse_number)
+        .collect::<Option<Vec<i64>>>()?;
+    if numbers.len() != count {
+        return None;
+    }
+    numbers[0] *= sign;
+    return Some(numbers);
+}
+
+/// Parses a string of ASCII digits without separators (as in the ISO 8601
+/// basic format, e.g. "20240501"), splitting it into numbers of given widths.
+fn parse_basic(s: &str, widths: &[usize]) -> Option<Vec<i64>> {
+    if s.len() != widths.iter().sum::<usize>() || !s.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let mut start = 0;
+    let mut numbers = vec![];
+    for width in widths {
+        numbers.push(parse_number(&s[start..start + width])?);
+        start += width;
+    }
+    return Some(numbers);
+}
+
+/// Returns the position of a given month name in a list of month names.
+fn position_of_name(name: &str, names: &[&str]) -> Option<usize> {
+    let name = name.to_lowercase();
+    return names.iter().position(|n| n.trim().to_lowercase() == name);
+}
+
+/// Returns the month (number) corresponding to a given month name (or month
+/// number) in a list of month names.
+fn month_from_names(name: &str, names: &[&str]) -> Option<i64> {
+    return match parse_number(name) {
+        Some(month) => Some(month),
+        None => position_of_name(name, names).map(|i| i as i64 + 1),
+    };
+}
+
+/// Returns the Hebrew month corresponding to a given month name (or month
+/// number). "Adar" and "Adar I" both denote month 12, and "Adar II" denotes
+/// month 13.
+fn hebrew_month_from_name(name: &str) -> Option<i64> {
+    if let Some(month) = parse_number(name) {
+        return Some(month);
+    }
+    return match position_of_name(name, &HEBREW_MONTH_NAMES)? {
+        i if i < 12 => Some(i as i64 + 1),
+        i => Some(i as i64),
+    };
+}
+
+/// Parses a year of an era, given in Arabic or Roman numerals (e.g. "II").
+fn parse_year_of_era(s: &str) -> Option<i64> {
+    return parse_number(s).or_else(|| parse_roman_numeral(s));
+}
+
+/// Parses a "day month year" string, where the month is given by one or more
+/// words (e.g. "1 Rabi I 1445"). The year may be preceded or followed by an
+/// era of the calendar (e.g. "15 March 44 BC", "12 Floréal an II", see the
+/// [`format`](crate::format) module).
+fn parse_day_month_year(
+    s: &str,
+    calendar: &str,
+    month: fn(&str) -> Option<i64>,
+) -> Option<(i64, i64, i64)> {
+    let mut words: Vec<&str> = s.split_whitespace().collect();
+    let is_era = |word: &str| year_from_era(calendar, word, 1).is_some();
+    let year = match words.len() {
+        n if n >= 4 && is_era(words[n - 1]) => {
+            let era = words.pop()?;
+            year_from_era(calendar, era, parse_year_of_era(words.pop()?)?)?
+        }
+        n if n >= 4 && is_era(words[n - 2]) => {
+            let year_of_era = parse_year_of_era(words.pop()?)?;
+            year_from_era(calendar, words.pop()?, year_of_era)?
+        }
+        n if n >= 3 => parse_year(words.pop()?)?,
+        _ => return None,
+    };
+    let day = parse_number(words[0])?;
+    let month = month(&words[1..].join(" "))?;
+    return Some((year, month, day));
+}
+
+/// Parses a "year-month-day", "yyyymmdd", or "day month year" string.
+fn parse_year_month_day(
+    s: &str,
+    calendar: &str,
+    month: fn(&str) -> Option<i64>,
+) -> Option<(i64, i64, i64)> {
+    return match parse_numeric(s, '-', 3).or_else(|| parse_basic(s, &[4, 2, 2])) {
+        Some(numbers) => Some((numbers[0], numbers[1], numbers[2])),
+        None => parse_day_month_year(s, calendar, month),
+    };
+}
+
+/// Implement FromStr for date types with year, month, and day components.
+macro_rules! impl_from_str_year_month_day {
+    ($($t:ty, $calendar:literal, $month:expr);* $(;)?) => {
+        $(
+            impl FromStr for $t {
+                type Err = CalendarError;
+
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    let s = s.trim();
+                    let (year, month, day) =
+                        parse_year_month_day(s, $calendar, $month)
+                            .ok_or_else(|| parse_error($calendar, s))?;
+                    return <$t>::try_new(year, month, day);
+                }
+            }
+        )*
+    };
+}
+
+impl_from_str_year_month_day!(
+    Gregorian, "gregorian", |name| month_from_names(name, &GREGORIAN_MONTH_NAMES);
+    Julian, "julian", |name| month_from_names(name, &GREGORIAN_MONTH_NAMES);
+    Islamic, "islamic", |name| month_from_names(name, &ISLAMIC_MONTH_NAMES);
+    UmmAlQura, "ummAlQura", |name| month_from_names(name, &ISLAMIC_MONTH_NAMES);
+    Hebrew, "hebrew", hebrew_month_from_name;
+    French, "french", |name| month_from_names(name, &FRENCH_MONTH_NAMES);
+    OldHinduSolar, "oldHinduSolar", |name| month_from_names(name, &HINDU_SOLAR_MONTH_NAMES);
+);
+
+/// Parses ISO week dates of the form "2024-W18-3" or "2024W183".
+impl FromStr for Iso {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let week_day = match s.split_once("-W") {
+            Some((year, week_day)) => parse_year(year).zip(parse_numeric(week_day, '-', 2)),
+            None => s
+                .split_once('W')
+                .and_then(|(year, week_day)| parse_year(year).zip(parse_basic(week_day, &[2, 1]))),
+        };
+        let (year, week_day) = week_day.ok_or_else(|| parse_error("iso", s))?;
+        return Iso::try_new(year, week_day[0], week_day[1]);
+    }
+}
+
+/// Parses ordinal dates of the form "2024-122" or "2024122".
+impl FromStr for Ordinal {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let numbers = parse_numeric(s, '-', 2)
+            .or_else(|| parse_basic(s, &[4, 3]))
+            .ok_or_else(|| parse_error("ordinal", s))?;
+        return Ordinal::try_new(numbers[0], numbers[1]);
+    }
+}
+
+/// Parses Mayan Long Counts of the form "9.12.11.16.0".
+impl FromStr for MayanLongCount {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let n = parse_numeric(s, '.', 5).ok_or_else(|| parse_error("mayanLongCount", s))?;
+        return MayanLongCount::try_new(n[0], n[1], n[2], n[3], n[4]);
+    }
+}
+
+/// Parses Mayan Haab dates of the form "8 Cumku".
+impl FromStr for MayanHaab {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (day, month) = s
+            .split_once(char::is_whitespace)
+            .and_then(|(day, month)| {
+                Some((
+                    parse_number(day)?,
+                    month_from_names(month.trim(), &MAYAN_MONTH_NAMES)?,
+                ))
+            })
+            .ok_or_else(|| parse_error("mayanHaab", s))?;
+        return MayanHaab::try_new(day, month);
+    }
+}
+
+/// Parses Mayan Tzolkin dates of the form "4 Ahau".
+impl FromStr for MayanTzolkin {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (number, name) = s
+            .split_once(char::is_whitespace)
+            .and_then(|(number, name)| {
+                Some((
+                    parse_number(number)?,
+                    month_from_names(name.trim(), &MAYAN_TZOLKIN_NAMES)?,
+                ))
+            })
+            .ok_or_else(|| parse_error("mayanTzolkin", s))?;
+        return MayanTzolkin::try_new(number, name);
+    }
+}
+
+/// Parses Old Hindu lunar dates of the form "year-month-leapMonth-day" (e.g.
+/// "3570-11-1-19"), "year-month-day", or "day month year". Leap months are
+/// denoted by the prefix "Adhika" in the latter (e.g. "19 Adhika Magha 3570").
+impl FromStr for OldHinduLunar {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if let Some(n) = parse_numeric(s, '-', 4) {
+            if n[2] > 1 {
+                return Err(parse_error("oldHinduLunar", s));
+            }
+            return OldHinduLunar::try_new(n[0], n[1], n[2] == 1, n[3]);
+        }
+        let (year, month, day) = parse_year_month_day(s, "oldHinduLunar", |name| {
+            let name = match name.split_once(' ') {
+                Some((prefix, name)) if prefix.eq_ignore_ascii_case(HINDU_LEAP_MONTH_PREFIX) => {
+                    name
+                }
+                _ => name,
+            };
+            return month_from_names(name, &HINDU_LUNAR_MONTH_NAMES);
+        })
+        .ok_or_else(|| parse_error("oldHinduLunar", s))?;
+        let leap_month = s
+            .split_whitespace()
+            .nth(1)
+            .is_some_and(|word| word.eq_ignore_ascii_case(HINDU_LEAP_MONTH_PREFIX));
+        return OldHinduLunar::try_new(year, month, leap_month, day);
+    }
+}
+
+/// Parses the components of a tagged date string, separated by "-". Negative
+/// components are preceded by an additional "-" (e.g. "-586-7-24").
+fn parse_components(s: &str) -> Option<Vec<i64>> {
+    let mut components = Vec::new();
+    let mut negative = false;
+    for part in s.split('-') {
+        if part.is_empty() {
+            if negative {
+                return None;
+            }
+            negative = true;
+            continue;
+        }
+        let number = parse_number(part)?;
+        components.push(if negative { -number } else { number });
+        negative = false;
+    }
+    if negative {
+        return None;
+    }
+    return Some(components);
+}
+
+/// Parses generic dates of the tagged form "calendar:component-component-..."
+/// (e.g. "hebrew:5784-1-15" or "french:232-8-12"), as produced by the
+/// `Display` implementation of [`Date`]. See [`Date::convert_to`] for the
+/// supported calendars.
+impl FromStr for Date {
+    type Err = CalendarError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (calendar, components) = s
+            .split_once(':')
+            .ok_or_else(|| CalendarError::ParseError(format!("missing calendar tag: \"{}\"", s)))?;
+        let components = parse_components(components).ok_or_else(|| parse_error(calendar, s))?;
+        let date = Date {
+            calendar: calendar.to_string(),
+            components,
+            component_names: Vec::new(),
+            month_names: Vec::new(),
+        };
+        // rebuild the date to fill in component and month names
+        return Ok(date.try_to_calendar_date()?.to_date());
+    }
+}
+
+/// Parses a number of exactly `count` ASCII digits.
+fn parse_digits(s: &str, count: usize) -> Option<i64> {
+    return if s.len() == count {
+        parse_number(s)
+    } else {
+        None
+    };
+}
+
+/// Parses an ISO 8601 date in the extended or basic format, that is, a
+/// calendar date (e.g. "2024-05-01" or "20240501"), a week date (e.g.
+/// "2024-W18-3" or "2024W183"), or an ordinal date (e.g. "2024-122" or
+/// "2024122"), and returns its absolute (fixed) date. Years outside 0 to 9999
+/// are given in the expanded representation with a sign (e.g. "-0586-07-24"
+/// or "+10000-01-01"), which requires the extended format if the year has
+/// more than four digits. Parsing is strict: components must have exactly
+/// the number of digits prescribed by ISO 8601, so that reduced-precision
+/// dates (e.g. "2024-05" for May 2024), non-padded components, and
+/// surrounding whitespace are rejected.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     iso::iso_from_absolute,
+///     ordinal::ordinal_from_absolute,
+///     parse::parse_iso8601,
+///     utility::Calendar,
+/// };
+///
+/// let may_1 = absolute_from_gregorian(Gregorian::new(2024, 5, 1));
+/// for s in ["2024-05-01", "20240501", "2024-W18-3", "2024W183", "2024-122", "2024122"] {
+///     assert_eq!(parse_iso8601(s), Ok(may_1));
+/// }
+/// assert!(parse_iso8601("2024-W53-1").is_err());
+/// for s in ["2024-05", "2024-5-1", " 2024-05-01", "2024-05-01 ", "2024-12", "24-05-01"] {
+///     assert!(parse_iso8601(s).is_err(), "{}", s);
+/// }
+///
+/// // round trips, including expanded years
+/// for year in [-10000, -586, 0, 1, 999, 2024, 9999, 10000] {
+///     let start = absolute_from_gregorian(Gregorian::new(year, 1, 1));
+///     for date in (start - 7..start + 7).chain(start + 360..start + 372) {
+///         let gregorian = Gregorian::from_absolute(date).to_iso_string();
+///         let iso = iso_from_absolute(date).format();
+///         let ordinal = ordinal_from_absolute(date).format();
+///         for s in [gregorian, iso, ordinal] {
+///             assert_eq!(parse_iso8601(&s), Ok(date), "{}", s);
+///         }
+///     }
+/// }
+/// ```
+pub fn parse_iso8601(s: &str) -> Result<i64, CalendarError> {
+    let error = || parse_error("ISO 8601", s);
+    if !s.is_ascii() {
+        return Err(error());
+    }
+    let signed = s.starts_with(['+', '-']);
+    let (sign, unsigned) = split_sign(s);
+    // the year is followed by a hyphen in the extended format, and consists
+    // of four digits in the basic format
+    let (year, rest, extended) = match unsigned.split_once('-') {
+        Some((year, rest)) => (year, rest, true),
+        None if unsigned.len() > 4 => (&unsigned[..4], &unsigned[4..], false),
+        None => return Err(error()),
+    };
+    let year_digits = if signed && extended {
+        year.len().max(4)
+    } else {
+        4
+    };
+    let year = sign * parse_digits(year, year_digits).ok_or_else(error)?;
+    if let Some(week_date) = rest.strip_prefix('W') {
+        let (week, day) = match extended {
+            true => week_date.split_once('-').ok_or_else(error)?,
+            false if week_date.len() == 3 => week_date.split_at(2),
+            false => return Err(error()),
+        };
+        let week = parse_digits(week, 2).ok_or_else(error)?;
+        let day = parse_digits(day, 1).ok_or_else(error)?;
+        return Iso::try_new(year, week, day)?.try_to_absolute();
+    }
+    if rest.len() == 3 {
+        let day = parse_digits(rest, 3).ok_or_else(error)?;
+        return Ordinal::try_new(year, day)?.try_to_absolute();
+    }
+    let (month, day) = match extended {
+        true => rest.split_once('-').ok_or_else(error)?,
+        false if rest.len() == 4 => rest.split_at(2),
+        false => return Err(error()),
+    };
+    let month = parse_digits(month, 2).ok_or_else(error)?;
+    let day = parse_digits(day, 2).ok_or_else(error)?;
+    return Gregorian::try_new(year, month, day)?.try_to_absolute();
+}
+
+/// Parses a date of the calendar specified in `calendar` from the formats
+/// accepted by the calendar's date type (e.g. "2024-05-01" or "1 May 2024"
+/// for `"gregorian"`). See [`Date::convert_to`] for the supported calendars.
+///
+/// # Example
+///
+/// ```
+/// use calendars::parse::parse_date;
+///
+/// let d = parse_date("17 Ramadan 1445", "islamic").unwrap();
+/// assert_eq!(d.to_string(), "islamic:1445-9-17");
+/// assert!(parse_date("2023-02-29", "gregorian").is_err());
+///
+/// // eras
+/// assert_eq!(parse_date("15 March 44 BC", "julian").unwrap().to_string(), "julian:-43-3-15");
+/// assert_eq!(parse_date("1 January 1 BCE", "gregorian").unwrap().to_string(), "gregorian:0-1-1");
+/// assert_eq!(parse_date("1 Nisan AM 5784", "hebrew").unwrap().to_string(), "hebrew:5784-1-1");
+/// assert_eq!(parse_date("12 Floréal an II", "french").unwrap().to_string(), "french:2-8-12");
+/// assert!(parse_date("1 January 0 BCE", "gregorian").is_err());
+/// assert!(parse_date("1 Muharram 1445 AM", "islamic").is_err());
+/// ```
+pub fn parse_date(s: &str, calendar: &str) -> Result<Date, CalendarError> {
+    return dispatch_all!(
+        calendar,
+        |C| Ok(s.parse::<C>()?.to_date()),
+        Err(CalendarError::UnknownCalendar(calendar.to_string()))
+    );
+}
@@ -0,0 +1,343 @@
+//! Provides Python bindings (requires the `python` feature)
+//!
+//! The date types of this crate are exposed as Python classes with the same
+//! names (e.g. `calendars.Gregorian(2024, 5, 1)`), the holiday functions as
+//! functions of the Python module `calendars`. Errors are raised as
+//! `ValueError`.
+
+use pyo3::{exceptions::PyValueError, prelude::*};
+
+use crate::{
+    error::CalendarError,
+    french::{checked_absolute_from_french, checked_french_from_absolute, French},
+    gregorian::{checked_absolute_from_gregorian, checked_gregorian_from_absolute, Gregorian},
+    hebrew::{checked_absolute_from_hebrew, checked_hebrew_from_absolute, Hebrew},
+    hindu::{
+        checked_absolute_from_old_hindu_lunar, checked_absolute_from_old_hindu_solar,
+        checked_old_hindu_lunar_from_absolute, checked_old_hindu_solar_from_absolute,
+        OldHinduLunar, OldHinduSolar,
+    },
+    holidays,
+    islamic::{checked_absolute_from_islamic, checked_islamic_from_absolute, Islamic},
+    iso::{checked_absolute_from_iso, checked_iso_from_absolute, Iso},
+    julian::{checked_absolute_from_julian, checked_julian_from_absolute, Julian},
+    locale::Locale,
+    mayan::{
+        checked_absolute_from_mayan_long_count, checked_mayan_haab_from_absolute,
+        checked_mayan_long_count_from_absolute, checked_mayan_tzolkin_from_absolute, MayanHaab,
+        MayanLongCount, MayanTzolkin,
+    },
+    ordinal::{checked_absolute_from_ordinal, checked_ordinal_from_absolute, Ordinal},
+    utility::Calendar,
+};
+
+/// Implement From<CalendarError> trait for PyErr, raising a `ValueError`
+impl From<CalendarError> for PyErr {
+    fn from(e: CalendarError) -> Self {
+        return PyValueError::new_err(e.to_string());
+    }
+}
+
+/// Date components as written in Python source code
+trait PyLiteral {
+    fn py_literal(&self) -> String;
+}
+
+impl PyLiteral for i64 {
+    fn py_literal(&self) -> String {
+        return self.to_string();
+    }
+}
+
+impl PyLiteral for bool {
+    fn py_literal(&self) -> String {
+        return if *self { "True" } else { "False" }.to_string();
+    }
+}
+
+/// Implement the Python methods of date types: a validating constructor,
+/// `__str__` and `__repr__`, `from_absolute`, `to_absolute` (for dates
+/// corresponding to absolute dates), `format`, and `format_localized`.
+macro_rules! impl_py_methods {
+    ($t:ident, ($($field:ident: $ft:ty),*), $from_absolute:path $(, $to_absolute:path)?) => {
+        #[pymethods]
+        impl $t {
+            #[new]
+            fn py_new($($field: $ft),*) -> PyResult<Self> {
+                return Ok(Self::try_new($($field),*)?);
+            }
+
+            fn __str__(&self) -> String {
+                return self.to_string();
+            }
+
+            fn __repr__(&self) -> String {
+                let fields = [$(
+                    format!("{}={}", stringify!($field), self.$field.py_literal())
+                ),*];
+                return format!("{}({})", stringify!($t), fields.join(", "));
+            }
+
+            /// Returns the date corresponding to a given absolute (fixed) date.
+            #[staticmethod]
+            #[pyo3(name = "from_absolute")]
+            fn py_from_absolute(absolute_date: i64) -> PyResult<Self> {
+                return Ok($from_absolute(absolute_date)?);
+            }
+
+            $(
+                /// Returns the absolute (fixed) date of the date.
+                #[pyo3(name = "to_absolute")]
+                fn py_to_absolute(&self) -> PyResult<i64> {
+                    return Ok($to_absolute(*self)?);
+                }
+            )?
+
+            /// Formats the date according to a strftime-style pattern, using
+            /// the month and weekday names of a given locale (e.g. "de").
+            #[pyo3(name = "format", signature = (pattern, locale = "en"))]
+            fn py_format(&self, pattern: &str, locale: &str) -> PyResult<String> {
+                let locale = locale.parse::<Locale>()?;
+                return Ok(self.format_with_locale(pattern, locale)?);
+            }
+
+            /// Formats the date using the month names of a given locale (e.g.
+            /// "de").
+            #[pyo3(name = "format_localized", signature = (locale = "en"))]
+            fn py_format_localized(&self, locale: &str) -> PyResult<String> {
+                let locale = locale.parse::<Locale>()?;
+                return Ok(self.format_localized(locale));
+            }
+        }
+    };
+}
+
+impl_py_methods!(
+    Gregorian,
+    (year: i64, month: i64, day: i64),
+    checked_gregorian_from_absolute,
+    checked_absolute_from_gregorian
+);
+impl_py_methods!(
+    Julian,
+    (year: i64, month: i64, day: i64),
+    checked_julian_from_absolute,
+    checked_absolute_from_julian
+);
+impl_py_methods!(
+    Iso,
+    (year: i64, week: i64, day: i64),
+    checked_iso_from_absolute,
+    checked_absolute_from_iso
+);
+impl_py_methods!(
+    Ordinal,
+    (year: i64, day: i64),
+    checked_ordinal_from_absolute,
+    checked_absolute_from_ordinal
+);
+impl_py_methods!(
+    Islamic,
+    (year: i64, month: i64, day: i64),
+    checked_islamic_from_absolute,
+    checked_absolute_from_islamic
+);
+impl_py_methods!(
+    Hebrew,
+    (year: i64, month: i64, day: i64),
+    checked_hebrew_from_absolute,
+    checked_absolute_from_hebrew
+);
+impl_py_methods!(
+    French,
+    (year: i64, month: i64, day: i64),
+    checked_french_from_absolute,
+    checked_absolute_from_french
+);
+impl_py_methods!(
+    MayanLongCount,
+    (baktun: i64, katun: i64, tun: i64, uinal: i64, kin: i64),
+    checked_mayan_long_count_from_absolute,
+    checked_absolute_from_mayan_long_count
+);
+impl_py_methods!(
+    MayanHaab,
+    (day: i64, month: i64),
+    checked_mayan_haab_from_absolute
+);
+impl_py_methods!(
+    MayanTzolkin,
+    (number: i64, name: i64),
+    checked_mayan_tzolkin_from_absolute
+);
+impl_py_methods!(
+    OldHinduSolar,
+    (year: i64, month: i64, day: i64),
+    checked_old_hindu_solar_from_absolute,
+    checked_absolute_from_old_hindu_solar
+);
+impl_py_methods!(
+    OldHinduLunar,
+    (year: i64, month: i64, leap_month: bool, day: i64),
+    checked_old_hindu_lunar_from_absolute,
+    checked_absolute_from_old_hindu_lunar
+);
+
+/// Define Python functions computing the absolute (fixed) dates of holidays
+/// in a given Gregorian year, and a function adding them to a module.
+macro_rules! py_holidays {
+    ($($(#[$doc:meta])* $name:ident -> $ret:ty;)*) => {
+        $(
+            $(#[$doc])*
+            #[pyfunction]
+            fn $name(year: i64) -> $ret {
+                return holidays::$name(year);
+            }
+        )*
+
+        /// Adds the holiday functions to a Python module.
+        fn add_holidays(m: &Bound<'_, PyModule>) -> PyResult<()> {
+            $(m.add_function(wrap_pyfunction!($name, m)?)?;)*
+            return Ok(());
+        }
+    };
+}
+
+py_holidays! {
+    /// Returns the absolute (fixed) date of New Year's Day.
+    new_years_day -> i64;
+    /// Returns the absolute (fixed) date of Martin Luther King Jr. Day (US).
+    martin_luther_king_day -> i64;
+    /// Returns the absolute (fixed) date of Presidents' Day (US).
+    presidents_day -> i64;
+    /// Returns the absolute (fixed) date of Juneteenth (US).
+    juneteenth -> i64;
+    /// Returns the absolute (fixed) date of Independence Day (US).
+    independence_day -> i64;
+    /// Returns the absolute (fixed) date of Labor Day (US).
+    labor_day -> i64;
+    /// Returns the absolute (fixed) date of Memorial Day (US).
+    memorial_day -> i64;
+    /// Returns the absolute (fixed) date of Columbus Day (US).
+    columbus_day -> i64;
+    /// Returns the absolute (fixed) date of Veterans Day (US).
+    veterans_day -> i64;
+    /// Returns the absolute (fixed) date of Thanksgiving (US).
+    thanksgiving -> i64;
+    /// Returns the absolute (fixed) date of the start of daylight saving time
+    /// (US).
+    daylight_savings_start -> i64;
+    /// Returns the absolute (fixed) date of the end of daylight saving time
+    /// (US).
+    daylight_savings_end -> i64;
+    /// Returns the absolute (fixed) date of Christmas.
+    christmas -> i64;
+    /// Returns the absolute (fixed) date of Advent Sunday.
+    advent -> i64;
+    /// Returns the absolute (fixed) date of Epiphany.
+    epiphany -> i64;
+    /// Returns the absolute (fixed) dates of Eastern Orthodox Christmas.
+    eastern_orthodox_christmas -> Vec<i64>;
+    /// Returns the absolute (fixed) date of Easter according to the Nicaean
+    /// rule.
+    nicaean_rule_easter -> i64;
+    /// Returns the absolute (fixed) date of Orthodox Easter.
+    orthodox_easter -> i64;
+    /// Returns the absolute (fixed) date of the start of Great Lent (Orthodox).
+    great_lent_start -> i64;
+    /// Returns the absolute (fixed) date of Orthodox Pentecost.
+    orthodox_pentecost -> i64;
+    /// Returns the absolute (fixed) date of the start of the Apostles' Fast
+    /// (Orthodox).
+    apostles_fast_start -> i64;
+    /// Returns the absolute (fixed) date of the end of the Apostles' Fast
+    /// (Orthodox).
+    apostles_fast_end -> i64;
+    /// Returns the absolute (fixed) date of Easter.
+    easter -> i64;
+    /// Returns the absolute (fixed) date of Pentecost.
+    pentecost -> i64;
+    /// Returns the absolute (fixed) date of Septuagesima Sunday.
+    septuagesima -> i64;
+    /// Returns the absolute (fixed) date of Mardi Gras (Shrove Tuesday).
+    mardi_gras -> i64;
+    /// Returns the absolute (fixed) date of Ash Wednesday.
+    ash_wednesday -> i64;
+    /// Returns the absolute (fixed) date of Palm Sunday.
+    palm_sunday -> i64;
+    /// Returns the absolute (fixed) date of Good Friday.
+    good_friday -> i64;
+    /// Returns the absolute (fixed) date of Ascension Day.
+    ascension -> i64;
+    /// Returns the absolute (fixed) date of Trinity Sunday.
+    trinity_sunday -> i64;
+    /// Returns the absolute (fixed) date of Corpus Christi.
+    corpus_christi -> i64;
+    /// Returns the absolute (fixed) dates of the Islamic New Year.
+    islamic_new_year -> Vec<i64>;
+    /// Returns the absolute (fixed) dates of Ashura.
+    ashura -> Vec<i64>;
+    /// Returns the absolute (fixed) dates of Mulad al-Nabi.
+    mulad_al_nabi -> Vec<i64>;
+    /// Returns the absolute (fixed) dates of the first day of Ramadan.
+    ramadan_start -> Vec<i64>;
+    /// Returns the absolute (fixed) dates of Laylat al-Qadr.
+    laylat_al_qadr -> Vec<i64>;
+    /// Returns the absolute (fixed) dates of Eid al-Fitr.
+    eid_al_fitr -> Vec<i64>;
+    /// Returns the absolute (fixed) dates of Eid al-Adha.
+    eid_al_adha -> Vec<i64>;
+    /// Returns the absolute (fixed) date of Yom Kippur.
+    yom_kippur -> i64;
+    /// Returns the absolute (fixed) date of Passover.
+    passover -> i64;
+    /// Returns the absolute (fixed) date of Purim.
+    purim -> i64;
+    /// Returns the absolute (fixed) date of Ta'anit Esther.
+    ta_anit_esther -> i64;
+    /// Returns the absolute (fixed) date of Tisha B'Av.
+    tisha_b_av -> i64;
+    /// Returns the absolute (fixed) date of Rosh Hashanah.
+    rosh_hashanah -> i64;
+    /// Returns the absolute (fixed) date of Sukkot.
+    sukkot -> i64;
+    /// Returns the absolute (fixed) date of Shemini Atzeret.
+    shemini_atzeret -> i64;
+    /// Returns the absolute (fixed) date of Simchat Torah (outside of Israel).
+    simchat_torah -> i64;
+    /// Returns the absolute (fixed) date of Simchat Torah in Israel.
+    simchat_torah_israel -> i64;
+    /// Returns the absolute (fixed) dates of the eight days of Hanukkah.
+    hanukkah -> Vec<i64>;
+    /// Returns the absolute (fixed) date of Tu BiShvat.
+    tu_bishvat -> i64;
+    /// Returns the absolute (fixed) date of Lag BaOmer.
+    lag_ba_omer -> i64;
+    /// Returns the absolute (fixed) date of Shavuot.
+    shavuot -> i64;
+    /// Returns the absolute (fixed) date of Tzom Gedaliah.
+    tzom_gedaliah -> i64;
+    /// Returns the absolute (fixed) dates of the Tenth of Tevet.
+    asarah_b_tevet -> Vec<i64>;
+    /// Returns the absolute (fixed) date of the Seventeenth of Tammuz.
+    shiva_asar_b_tammuz -> i64;
+}
+
+/// Python module `calendars`
+#[pymodule]
+#[pyo3(name = "calendars")]
+fn calendars_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<Gregorian>()?;
+    m.add_class::<Julian>()?;
+    m.add_class::<Iso>()?;
+    m.add_class::<Ordinal>()?;
+    m.add_class::<Islamic>()?;
+    m.add_class::<Hebrew>()?;
+    m.add_class::<French>()?;
+    m.add_class::<MayanLongCount>()?;
+    m.add_class::<MayanHaab>()?;
+    m.add_class::<MayanTzolkin>()?;
+    m.add_class::<OldHinduSolar>()?;
+    m.add_class::<OldHinduLunar>()?;
+    return add_holidays(m);
+}
@@ -0,0 +1,92 @@
+//! Provides an iterator over ranges of absolute (fixed) dates
+
+use crate::utility::{date_from_absolute, Date, FixedConvertible};
+
+/// Range of absolute (fixed) dates from `start` to `end` (inclusive),
+/// iterated in steps of a given number of days.
+///
+/// # Example
+///
+/// ```
+/// use calendars::range::DateRange;
+///
+/// let dates: Vec<i64> = DateRange::new(1, 10).step_days(4).collect();
+/// assert_eq!(dates, [1, 5, 9]);
+/// let dates: Vec<i64> = DateRange::new(i64::MAX - 1, i64::MAX).collect();
+/// assert_eq!(dates, [i64::MAX - 1, i64::MAX]);
+/// let dates: Vec<i64> = DateRange::new(0, i64::MAX).step_weeks(i64::MAX).collect();
+/// assert_eq!(dates, [0, i64::MAX]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    current: i64,
+    end: i64,
+    step: i64,
+}
+
+impl DateRange {
+    /// Create a new day-by-day range of absolute dates from `start` to `end`
+    /// (inclusive).
+    pub fn new(start: i64, end: i64) -> Self {
+        Self {
+            current: start,
+            end,
+            step: 1,
+        }
+    }
+
+    /// Create a new day-by-day range between two dates (inclusive).
+    pub fn between<A: FixedConvertible, B: FixedConvertible>(start: &A, end: &B) -> Self {
+        return DateRange::new(start.to_absolute(), end.to_absolute());
+    }
+
+    /// Set the step of the range to a given number of days. Negative steps
+    /// iterate backwards from `start` down to `end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `days` is 0.
+    pub fn step_days(self, days: i64) -> Self {
+        assert!(days != 0, "step of a DateRange must not be 0");
+        Self { step: days, ..self }
+    }
+
+    /// Set the step of the range to a given number of weeks. Steps beyond
+    /// the bounds of `i64` (in days) saturate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weeks` is 0.
+    pub fn step_weeks(self, weeks: i64) -> Self {
+        self.step_days(weeks.saturating_mul(7))
+    }
+
+    /// Returns an iterator over the dates of the range, represented as
+    /// [`Date`]s of the calendar specified in `calendar` (see
+    /// [`Date::convert_to`] for the supported calendars).
+    pub fn dates(self, calendar: &str) -> impl Iterator<Item = Date> + '_ {
+        return self.map(move |d| date_from_absolute(d, calendar));
+    }
+}
+
+impl Iterator for DateRange {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<i64> {
+        let in_range = if self.step > 0 {
+            self.current <= self.end
+        } else {
+            self.current >= self.end
+        };
+        if !in_range {
+            return None;
+        }
+        let date = self.current;
+        match self.current.checked_add(self.step) {
+            Some(next) => self.current = next,
+            // the next date does not fit into an i64, so the range ends here
+            None => self.end = date - self.step.signum(),
+        }
+        return Some(date);
+    }
+}
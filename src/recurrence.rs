@@ -0,0 +1,248 @@
+//! Provides recurrence rules anchored to a calendar (e.g. "every 15 Nisan",
+//! or "the first Sunday after 2 Muharram")
+
+use crate::{
+    error::CalendarError,
+    french::French,
+    gregorian::{absolute_from_gregorian, Gregorian},
+    hebrew::Hebrew,
+    islamic::{Islamic, UmmAlQura},
+    julian::Julian,
+    utility::MonthLength,
+    weekday::{kday_after, Weekday},
+};
+
+/// Calendars whose dates have year, month, and day components, for which
+/// recurrence rules can be defined
+static RECURRENCE_CALENDARS: [&str; 6] = [
+    "gregorian",
+    "julian",
+    "islamic",
+    "ummAlQura",
+    "hebrew",
+    "french",
+];
+
+/// Recurrence rule, whose dates are computed with [`Recurrence::dates_between`]
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     recurrence::Recurrence,
+///     weekday::Weekday,
+/// };
+///
+/// let date = |year, month, day| absolute_from_gregorian(Gregorian::new(year, month, day));
+///
+/// // every 15 Nisan
+/// let passover = Recurrence::yearly("hebrew", 1, 15).unwrap();
+/// assert_eq!(passover.dates_in_gregorian_year(2025), [date(2025, 4, 13)]);
+///
+/// // the first Sunday after 2 Muharram
+/// let rule = Recurrence::yearly("islamic", 1, 2)
+///     .unwrap()
+///     .weekday_after(Weekday::Sunday, 1);
+/// assert_eq!(rule.dates_in_gregorian_year(2024), [date(2024, 7, 14)]);
+///
+/// // every décadi (the 10th, 20th, and 30th day of the French Revolutionary
+/// // months)
+/// let decadi = Recurrence::monthly("french", 10)
+///     .unwrap()
+///     .or(Recurrence::monthly("french", 20).unwrap())
+///     .or(Recurrence::monthly("french", 30).unwrap());
+/// let dates = decadi.dates_between(date(2024, 9, 22), date(2024, 11, 20));
+/// assert_eq!(dates[0], date(2024, 10, 1));
+/// assert_eq!(dates.len(), 6);
+/// assert!(dates.windows(2).all(|pair| pair[1] - pair[0] == 10));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Recurrence {
+    /// A given day of a given month of every year of a calendar
+    Yearly {
+        calendar: String,
+        month: i64,
+        day: i64,
+    },
+    /// A given day of every month of a calendar
+    Monthly { calendar: String, day: i64 },
+    /// The n-th given weekday after (not on) each date of another rule
+    WeekdayAfter {
+        weekday: Weekday,
+        n: i64,
+        base: Box<Recurrence>,
+    },
+    /// The dates of another rule, shifted by a number of days
+    Offset { days: i64, base: Box<Recurrence> },
+    /// The dates of any of several rules
+    Union(Vec<Recurrence>),
+}
+
+impl Recurrence {
+    /// Create a rule recurring on a given day of a given month of every year
+    /// of a calendar, or return an error if the calendar has no years of
+    /// months (see [`Date::convert_to`](crate::utility::Date::convert_to) for
+    /// the calendar names). Years in which the date does not exist (e.g. 30
+    /// Heshvan) are skipped.
+    pub fn yearly(calendar: &str, month: i64, day: i64) -> Result<Self, CalendarError> {
+        check_calendar(calendar)?;
+        return Ok(Recurrence::Yearly {
+            calendar: calendar.to_string(),
+            month,
+            day,
+        });
+    }
+
+    /// Create a rule recurring on a given day of every month of a calendar,
+    /// or return an error if the calendar has no years of months. Months
+    /// without that day are skipped.
+    pub fn monthly(calendar: &str, day: i64) -> Result<Self, CalendarError> {
+        check_calendar(calendar)?;
+        return Ok(Recurrence::Monthly {
+            calendar: calendar.to_string(),
+            day,
+        });
+    }
+
+    /// Returns the rule recurring on the n-th given weekday after each date
+    /// of the rule. Values of n below 1 are clamped to 1.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{recurrence::Recurrence, weekday::Weekday};
+    ///
+    /// let rule = Recurrence::monthly("gregorian", 1).unwrap();
+    /// assert_eq!(
+    ///     rule.clone().weekday_after(Weekday::Sunday, 0),
+    ///     rule.clone().weekday_after(Weekday::Sunday, 1)
+    /// );
+    /// let rule = rule.weekday_after(Weekday::Sunday, i64::MAX);
+    /// assert_eq!(rule.dates_between(i64::MIN, i64::MIN + 10), []);
+    /// ```
+    pub fn weekday_after(self, weekday: Weekday, n: i64) -> Self {
+        return Recurrence::WeekdayAfter {
+            weekday,
+            n: n.max(1),
+            base: Box::new(self),
+        };
+    }
+
+    /// Returns the rule recurring a given number of days after (or, if
+    /// negative, before) each date of the rule.
+    pub fn offset(self, days: i64) -> Self {
+        return Recurrence::Offset {
+            days,
+            base: Box::new(self),
+        };
+    }
+
+    /// Returns the rule recurring on the dates of either rule.
+    pub fn or(self, other: Recurrence) -> Self {
+        return match self {
+            Recurrence::Union(mut rules) => {
+                rules.push(other);
+                Recurrence::Union(rules)
+            }
+            _ => Recurrence::Union(vec![self, other]),
+        };
+    }
+
+    /// Returns the absolute (fixed) dates of the rule from `start` to `end`
+    /// (inclusive), in chronological order.
+    pub fn dates_between(&self, start: i64, end: i64) -> Vec<i64> {
+        if start > end {
+            return vec![];
+        }
+        return match self {
+            Recurrence::Yearly {
+                calendar,
+                month,
+                day,
+            } => calendar_dates(calendar, start, end, Some(*month), *day),
+            Recurrence::Monthly { calendar, day } => {
+                calendar_dates(calendar, start, end, None, *day)
+            }
+            Recurrence::WeekdayAfter { weekday, n, base } => {
+                // the n-th weekday after a date lies within n weeks of it
+                let weeks = (*n).max(1).saturating_mul(7);
+                base.dates_between(start.saturating_sub(weeks), end.saturating_sub(1))
+                    .into_iter()
+                    .map(|date| kday_after(date, weekday.number()).saturating_add(weeks - 7))
+                    .filter(|date| (start..=end).contains(date))
+                    .collect()
+            }
+            Recurrence::Offset { days, base } => base
+                .dates_between(start - days, end - days)
+                .into_iter()
+                .map(|date| date + days)
+                .collect(),
+            Recurrence::Union(rules) => {
+                let mut dates: Vec<i64> = rules
+                    .iter()
+                    .flat_map(|rule| rule.dates_between(start, end))
+                    .collect();
+                dates.sort();
+                dates.dedup();
+                dates
+            }
+        };
+    }
+
+    /// Returns the absolute (fixed) dates of the rule in a given Gregorian
+    /// year.
+    pub fn dates_in_gregorian_year(&self, year: i64) -> Vec<i64> {
+        return self.dates_between(
+            absolute_from_gregorian(Gregorian::new(year, 1, 1)),
+            absolute_from_gregorian(Gregorian::new(year, 12, 31)),
+        );
+    }
+}
+
+/// Checks that recurrence rules can be defined for a given calendar.
+fn check_calendar(calendar: &str) -> Result<(), CalendarError> {
+    return if RECURRENCE_CALENDARS.contains(&calendar) {
+        Ok(())
+    } else {
+        Err(CalendarError::UnknownCalendar(calendar.to_string()))
+    };
+}
+
+/// Returns the absolute (fixed) dates from `start` to `end` (inclusive) of a
+/// given day of a given month (or of every month) of a calendar.
+fn calendar_dates(calendar: &str, start: i64, end: i64, month: Option<i64>, day: i64) -> Vec<i64> {
+    return match calendar {
+        "gregorian" => month_day_dates::<Gregorian>(start, end, month, day),
+        "julian" => month_day_dates::<Julian>(start, end, month, day),
+        "islamic" => month_day_dates::<Islamic>(start, end, month, day),
+        "ummAlQura" => month_day_dates::<UmmAlQura>(start, end, month, day),
+        "hebrew" => month_day_dates::<Hebrew>(start, end, month, day),
+        "french" => month_day_dates::<French>(start, end, month, day),
+        _ => vec![],
+    };
+}
+
+/// Returns the absolute (fixed) dates from `start` to `end` (inclusive) of a
+/// given day of a given month (or of every month) of a calendar with year,
+/// month, and day components.
+fn month_day_dates<C: MonthLength>(start: i64, end: i64, month: Option<i64>, day: i64) -> Vec<i64> {
+    let (first_year, _, _) = C::from_absolute(start).year_month_day();
+    let (last_year, _, _) = C::from_absolute(end).year_month_day();
+    let mut dates = vec![];
+    for year in first_year..=last_year {
+        for m in C::months_of_year(year) {
+            if month.is_some_and(|month| month != m)
+                || day < 1
+                || day > C::last_day_of_month(m, year)
+            {
+                continue;
+            }
+            let date = C::from_year_month_day(year, m, day).to_absolute();
+            if (start..=end).contains(&date) {
+                dates.push(date);
+            }
+        }
+    }
+    return dates;
+}
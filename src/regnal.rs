@@ -0,0 +1,164 @@
+//! Provides functions to compute English regnal years (e.g. "12 Victoria"),
+//! as used to date statutes and deeds
+
+use core::fmt;
+
+use crate::civil::CivilCalendar;
+
+/// Reign of a monarch in a table of reigns (see [`ENGLISH_REIGNS`]). Dates
+/// are civil dates, i.e. Julian dates before 14 September 1752 in England.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Reign {
+    /// Name of the monarch, as used in regnal years (e.g. "Victoria")
+    pub monarch: &'static str,
+    /// First day of the reign (year, month, day)
+    pub start: (i64, i64, i64),
+    /// Day the regnal years are counted from (year, month, day), usually the
+    /// first day of the reign
+    pub epoch: (i64, i64, i64),
+}
+
+impl Reign {
+    /// Create a new reign, whose regnal years are counted from its first day.
+    pub const fn new(monarch: &'static str, year: i64, month: i64, day: i64) -> Self {
+        Self {
+            monarch,
+            start: (year, month, day),
+            epoch: (year, month, day),
+        }
+    }
+}
+
+/// Reigns of the monarchs of England (and, from 1707, of Great Britain and the
+/// United Kingdom) since Edward I. Charles II counted his regnal years from
+/// the death of Charles I, and William III continued the regnal years of
+/// William and Mary.
+pub static ENGLISH_REIGNS: &[Reign] = &[
+    Reign::new("Edward I", 1272, 11, 20),
+    Reign::new("Edward II", 1307, 7, 8),
+    Reign::new("Edward III", 1327, 1, 25),
+    Reign::new("Richard II", 1377, 6, 22),
+    Reign::new("Henry IV", 1399, 9, 30),
+    Reign::new("Henry V", 1413, 3, 21),
+    Reign::new("Henry VI", 1422, 9, 1),
+    Reign::new("Edward IV", 1461, 3, 4),
+    Reign::new("Edward V", 1483, 4, 9),
+    Reign::new("Richard III", 1483, 6, 26),
+    Reign::new("Henry VII", 1485, 8, 22),
+    Reign::new("Henry VIII", 1509, 4, 22),
+    Reign::new("Edward VI", 1547, 1, 28),
+    Reign::new("Mary", 1553, 7, 6),
+    Reign::new("Elizabeth", 1558, 11, 17),
+    Reign::new("James I", 1603, 3, 24),
+    Reign::new("Charles I", 1625, 3, 27),
+    Reign::new("Charles II", 1649, 1, 30),
+    Reign::new("James II", 1685, 2, 6),
+    Reign::new("William and Mary", 1689, 2, 13),
+    Reign {
+        monarch: "William III",
+        start: (1694, 12, 28),
+        epoch: (1689, 2, 13),
+    },
+    Reign::new("Anne", 1702, 3, 8),
+    Reign::new("George I", 1714, 8, 1),
+    Reign::new("George II", 1727, 6, 11),
+    Reign::new("George III", 1760, 10, 25),
+    Reign::new("George IV", 1820, 1, 29),
+    Reign::new("William IV", 1830, 6, 26),
+    Reign::new("Victoria", 1837, 6, 20),
+    Reign::new("Edward VII", 1901, 1, 22),
+    Reign::new("George V", 1910, 5, 6),
+    Reign::new("Edward VIII", 1936, 1, 20),
+    Reign::new("George VI", 1936, 12, 11),
+    Reign::new("Elizabeth II", 1952, 2, 6),
+    Reign::new("Charles III", 2022, 9, 8),
+];
+
+/// Regnal year: the number of the year of a monarch's reign
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RegnalYear {
+    pub monarch: &'static str,
+    pub year: i64,
+}
+
+/// Implement fmt::Display trait for [`RegnalYear`] (e.g. "12 Victoria")
+impl fmt::Display for RegnalYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.year, self.monarch)
+    }
+}
+
+/// Returns the English regnal year of a given absolute (fixed) date, or `None`
+/// if the date lies before the reign of Edward I (see [`ENGLISH_REIGNS`]).
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     julian::{absolute_from_julian, Julian},
+///     regnal::english_regnal_year,
+/// };
+///
+/// let regnal_year = |date| english_regnal_year(date).unwrap().to_string();
+///
+/// assert_eq!(regnal_year(absolute_from_gregorian(Gregorian::new(1848, 8, 14))), "12 Victoria");
+/// assert_eq!(regnal_year(absolute_from_gregorian(Gregorian::new(2027, 9, 8))), "6 Charles III");
+/// assert_eq!(regnal_year(absolute_from_gregorian(Gregorian::new(2027, 9, 7))), "5 Charles III");
+/// // the Bill of Rights, 16 December 1689 (Julian)
+/// assert_eq!(regnal_year(absolute_from_julian(Julian::new(1689, 12, 16))), "1 William and Mary");
+/// assert_eq!(regnal_year(absolute_from_julian(Julian::new(1701, 6, 12))), "13 William III");
+/// assert_eq!(english_regnal_year(absolute_from_julian(Julian::new(1200, 1, 1))), None);
+/// ```
+pub fn english_regnal_year(absolute_date: i64) -> Option<RegnalYear> {
+    return regnal_year(ENGLISH_REIGNS, CivilCalendar::BRITAIN, absolute_date);
+}
+
+/// Returns the regnal year of a given absolute (fixed) date according to a
+/// table of reigns (in chronological order) whose dates are dates of a given
+/// civil calendar, or `None` if the date lies before the first reign.
+/// Regnal years begin on the anniversaries of the reign's epoch in the civil
+/// calendar.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     civil::CivilCalendar,
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     regnal::{regnal_year, Reign},
+/// };
+///
+/// // Kings of Denmark
+/// let reigns = [
+///     Reign::new("Frederik IX", 1947, 4, 20),
+///     Reign::new("Margrethe II", 1972, 1, 14),
+///     Reign::new("Frederik X", 2024, 1, 14),
+/// ];
+/// let date = absolute_from_gregorian(Gregorian::new(2000, 1, 1));
+/// let year = regnal_year(&reigns, CivilCalendar::DENMARK, date).unwrap();
+/// assert_eq!(year.to_string(), "28 Margrethe II");
+/// ```
+pub fn regnal_year(
+    reigns: &[Reign],
+    calendar: CivilCalendar,
+    absolute_date: i64,
+) -> Option<RegnalYear> {
+    let date = calendar.from_absolute(absolute_date);
+    let reign = reigns
+        .iter()
+        .take_while(|reign| {
+            let (year, month, day) = reign.start;
+            match calendar.date(year, month, day) {
+                Ok(start) => start.to_absolute() <= absolute_date,
+                Err(_) => false,
+            }
+        })
+        .last()?;
+    let (year, month, day) = reign.epoch;
+    let anniversary_passed = (date.month, date.day) >= (month, day);
+    return Some(RegnalYear {
+        monarch: reign.monarch,
+        year: date.year - year + if anniversary_passed { 1 } else { 0 },
+    });
+}
@@ -0,0 +1,262 @@
+//! Provides cal-style text rendering of months and years
+//!
+//! Months are rendered as grids of weeks, with one column per day of the
+//! week, for calendars with year, month, and day components (see
+//! [`MonthLength`]). Highlighted days (e.g. holidays) are marked with a `*`.
+//!
+//! # Example
+//!
+//! ```
+//! use calendars::{gregorian::Gregorian, render::{render_month, RenderOptions}};
+//!
+//! let month = render_month::<Gregorian>(2024, 5, &RenderOptions::default()).unwrap();
+//! assert_eq!(
+//!     month,
+//!     "      May 2024
+//! Su Mo Tu We Th Fr Sa
+//!           1  2  3  4
+//!  5  6  7  8  9 10 11
+//! 12 13 14 15 16 17 18
+//! 19 20 21 22 23 24 25
+//! 26 27 28 29 30 31
+//! "
+//! );
+//! ```
+
+use crate::{
+    error::CalendarError,
+    french::French,
+    gregorian::Gregorian,
+    hebrew::Hebrew,
+    islamic::{Islamic, UmmAlQura},
+    julian::Julian,
+    locale::Locale,
+    math::modulus,
+    utility::MonthLength,
+    weekday::{weekday_from_absolute, Weekday},
+};
+
+/// Width of a rendered month (seven columns of three characters, without the
+/// separator after the last column)
+static MONTH_WIDTH: usize = 20;
+
+/// Number of week rows of a rendered month
+static WEEK_ROWS: usize = 6;
+
+/// Number of months per row of a rendered year
+static MONTHS_PER_ROW: usize = 3;
+
+/// Options of the rendering of months and years
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Language of the month and weekday names
+    pub locale: Locale,
+    /// Day of the week shown in the first column
+    pub first_weekday: Weekday,
+    /// Absolute (fixed) dates of the days to be highlighted
+    pub highlight: Vec<i64>,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            locale: Locale::default(),
+            first_weekday: Weekday::Sunday,
+            highlight: Vec::new(),
+        }
+    }
+}
+
+/// Centers a text within a given width.
+fn center(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.chars().count());
+    let left = padding / 2;
+    return format!("{}{}{}", " ".repeat(left), text, " ".repeat(padding - left));
+}
+
+/// Abbreviates a weekday name to the first two letters of its last word,
+/// without the Arabic article (e.g. "רא" for "יום ראשון", "أح" for "الأحد").
+fn abbreviate_weekday(name: &str) -> String {
+    let word = name.rsplit(' ').next().unwrap_or(name);
+    let word = word.strip_prefix("ال").unwrap_or(word);
+    return word.chars().take(2).collect();
+}
+
+/// Returns the header of weekday abbreviations, starting with the first
+/// weekday of the options.
+fn weekday_header(options: &RenderOptions) -> String {
+    let names: Vec<String> = (0..7)
+        .map(|i| {
+            let weekday = Weekday::from_number(options.first_weekday.number() + i);
+            return format!(
+                "{:>2}",
+                abbreviate_weekday(weekday.localized_name(options.locale))
+            );
+        })
+        .collect();
+    return names.join(" ");
+}
+
+/// Returns the lines of a given month, each padded to the width of a month:
+/// a title, the weekday header, and one line per week.
+fn month_lines<C: MonthLength>(
+    year: i64,
+    month: i64,
+    title_pattern: &str,
+    options: &RenderOptions,
+) -> Result<Vec<String>, CalendarError> {
+    let first = C::from_year_month_day(year, month, 1);
+    let title = first.format_with_locale(title_pattern, options.locale)?;
+    let mut lines = vec![center(&title, MONTH_WIDTH), weekday_header(options)];
+    let first_absolute = first.to_absolute();
+    let offset = modulus(
+        weekday_from_absolute(first_absolute).number() - options.first_weekday.number(),
+        7,
+    );
+    let cells: Vec<String> = (0..offset)
+        .map(|_| "   ".to_string())
+        .chain((1..=C::last_day_of_month(month, year)).map(|day| {
+            let marker = if options.highlight.contains(&(first_absolute + day - 1)) {
+                '*'
+            } else {
+                ' '
+            };
+            return format!("{:>2}{}", day, marker);
+        }))
+        .collect();
+    for week in cells.chunks(7) {
+        lines.push(week.concat());
+    }
+    while lines.len() < WEEK_ROWS + 2 {
+        lines.push(String::new());
+    }
+    return Ok(lines
+        .into_iter()
+        .map(|line| format!("{:<width$}", line, width = MONTH_WIDTH + 1))
+        .collect());
+}
+
+/// Checks that a given month exists in a given year.
+fn check_month<C: MonthLength>(year: i64, month: i64) -> Result<(), CalendarError> {
+    if C::months_of_year(year).any(|m| m == month) {
+        return Ok(());
+    }
+    return Err(CalendarError::InvalidComponent {
+        calendar: C::from_year_month_day(year, 1, 1).to_date().calendar,
+        component: "month".to_string(),
+        value: month,
+    });
+}
+
+/// Joins lines, removing trailing whitespace and trailing empty lines.
+fn join_lines(lines: &[String]) -> String {
+    let mut text = String::new();
+    for line in lines {
+        text.push_str(line.trim_end());
+        text.push('\n');
+    }
+    while text.ends_with("\n\n") {
+        text.pop();
+    }
+    return text;
+}
+
+/// Renders a given month of a given year as a grid of weeks, titled with the
+/// month name and year. Returns an error if the month does not exist.
+pub fn render_month<C: MonthLength>(
+    year: i64,
+    month: i64,
+    options: &RenderOptions,
+) -> Result<String, CalendarError> {
+    check_month::<C>(year, month)?;
+    return Ok(join_lines(&month_lines::<C>(
+        year, month, "%B %Y", options,
+    )?));
+}
+
+/// Renders the months of a given year (in calendar order, e.g. starting with
+/// Tishri for Hebrew years), three months per row, titled with the year.
+pub fn render_year<C: MonthLength>(
+    year: i64,
+    options: &RenderOptions,
+) -> Result<String, CalendarError> {
+    let months = C::months_of_year(year)
+        .map(|month| month_lines::<C>(year, month, "%B", options))
+        .collect::<Result<Vec<Vec<String>>, CalendarError>>()?;
+    let width = MONTHS_PER_ROW * (MONTH_WIDTH + 2) - 2;
+    let mut lines = vec![center(&year.to_string(), width), String::new()];
+    for row in months.chunks(MONTHS_PER_ROW) {
+        for i in 0..WEEK_ROWS + 2 {
+            let line: Vec<&str> = row.iter().map(|month| month[i].as_str()).collect();
+            lines.push(line.join(" "));
+        }
+        lines.push(String::new());
+    }
+    return Ok(join_lines(&lines));
+}
+
+/// Renders a given month (or, if `month` is `None`, the whole year) of a
+/// calendar specified by name: `"gregorian"`, `"julian"`, `"islamic"`,
+/// `"ummAlQura"`, `"hebrew"`, or `"french"`.
+pub fn render(
+    calendar: &str,
+    year: i64,
+    month: Option<i64>,
+    options: &RenderOptions,
+) -> Result<String, CalendarError> {
+    fn render_calendar<C: MonthLength>(
+        year: i64,
+        month: Option<i64>,
+        options: &RenderOptions,
+    ) -> Result<String, CalendarError> {
+        return match month {
+            Some(month) => render_month::<C>(year, month, options),
+            None => render_year::<C>(year, options),
+        };
+    }
+    return match calendar {
+        "gregorian" => render_calendar::<Gregorian>(year, month, options),
+        "julian" => render_calendar::<Julian>(year, month, options),
+        "islamic" => render_calendar::<Islamic>(year, month, options),
+        "ummAlQura" => render_calendar::<UmmAlQura>(year, month, options),
+        "hebrew" => render_calendar::<Hebrew>(year, month, options),
+        "french" => render_calendar::<French>(year, month, options),
+        _ => Err(CalendarError::UnknownCalendar(calendar.to_string())),
+    };
+}
+
+/// Returns the absolute (fixed) dates of the first and last day of a given
+/// month (or, if `month` is `None`, of the whole year) of a calendar
+/// specified by name (see [`render`]), e.g. to compute the holidays to be
+/// highlighted.
+pub fn rendered_range(
+    calendar: &str,
+    year: i64,
+    month: Option<i64>,
+) -> Result<(i64, i64), CalendarError> {
+    fn range<C: MonthLength>(year: i64, month: Option<i64>) -> Result<(i64, i64), CalendarError> {
+        let (first_month, last_month) = match month {
+            Some(month) => {
+                check_month::<C>(year, month)?;
+                (month, month)
+            }
+            None => (
+                C::first_month_of_year(year),
+                C::months_of_year(year).last().unwrap_or(1),
+            ),
+        };
+        let first = C::from_year_month_day(year, first_month, 1).to_absolute();
+        let last = C::from_year_month_day(year, last_month, C::last_day_of_month(last_month, year))
+            .to_absolute();
+        return Ok((first, last));
+    }
+    return match calendar {
+        "gregorian" => range::<Gregorian>(year, month),
+        "julian" => range::<Julian>(year, month),
+        "islamic" => range::<Islamic>(year, month),
+        "ummAlQura" => range::<UmmAlQura>(year, month),
+        "hebrew" => range::<Hebrew>(year, month),
+        "french" => range::<French>(year, month),
+        _ => Err(CalendarError::UnknownCalendar(calendar.to_string())),
+    };
+}
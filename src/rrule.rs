@@ -0,0 +1,515 @@
+//! Provides the evaluation of iCalendar recurrence rules (RRULE, RFC 5545,
+//! requires the `rrule` feature)
+//!
+//! A practical subset of the rule parts is supported: `FREQ` (`DAILY`,
+//! `WEEKLY`, `MONTHLY`, or `YEARLY`), `INTERVAL`, `BYMONTH`, `BYDAY` (with
+//! ordinals such as `-1FR` in monthly and yearly rules), `BYMONTHDAY`
+//! (negative days count from the end of the month), `UNTIL`, and `COUNT`.
+//! Other parts (e.g. `WKST`, `BYSETPOS`) are rejected. Weeks begin on
+//! Monday, and the time of day of `UNTIL` is ignored.
+//!
+//! # Example
+//!
+//! ```
+//! use calendars::{
+//!     gregorian::{absolute_from_gregorian, gregorian_from_absolute, Gregorian},
+//!     rrule::RRule,
+//! };
+//!
+//! // US Thanksgiving (fourth Thursday of November)
+//! let rule: RRule = "FREQ=YEARLY;BYMONTH=11;BYDAY=4TH;COUNT=3".parse().unwrap();
+//! let start = absolute_from_gregorian(Gregorian::new(2024, 1, 1));
+//! let dates: Vec<_> = rule.occurrences(start).map(gregorian_from_absolute).collect();
+//! assert_eq!(
+//!     dates,
+//!     [Gregorian::new(2024, 11, 28), Gregorian::new(2025, 11, 27), Gregorian::new(2026, 11, 26)]
+//! );
+//!
+//! // every other week on Monday and Wednesday
+//! let rule: RRule = "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20240131".parse().unwrap();
+//! let dates: Vec<_> = rule.occurrences(start).map(|d| gregorian_from_absolute(d).day).collect();
+//! assert_eq!(dates, [1, 3, 15, 17, 29, 31]);
+//! assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;UNTIL=20240131");
+//!
+//! // last Friday and last day of each month
+//! let fridays: RRule = "RRULE:FREQ=MONTHLY;BYDAY=-1FR".parse().unwrap();
+//! assert_eq!(fridays.dates_between(start, start, start + 59), [start + 25, start + 53]);
+//! let last_days: RRule = "FREQ=MONTHLY;BYMONTHDAY=-1".parse().unwrap();
+//! assert_eq!(last_days.occurrences(start).nth(1), Some(start + 59));
+//! assert!("FREQ=MONTHLY;BYSETPOS=1".parse::<RRule>().is_err());
+//! ```
+
+use core::{fmt, str::FromStr};
+
+use crate::{
+    error::CalendarError,
+    gregorian::{
+        absolute_from_gregorian, gregorian_from_absolute, last_day_of_gregorian_month, Gregorian,
+        GREGORIAN_ABSOLUTE_RANGE, GREGORIAN_YEAR_RANGE,
+    },
+    weekday::{kday_on_or_after, kday_on_or_before, weekday_from_absolute, Weekday},
+};
+
+/// Two-letter weekday codes of iCalendar (SU = Sunday, ..., SA = Saturday)
+static WEEKDAY_CODES: [&str; 7] = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+
+/// Maximum number of consecutive periods without occurrences before the
+/// expansion of a rule stops (e.g. for BYMONTH=2;BYMONTHDAY=30)
+static MAX_EMPTY_PERIODS: i64 = 10000;
+
+/// Frequency of a recurrence rule
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+impl Frequency {
+    /// Returns the iCalendar name of the frequency (e.g. "YEARLY").
+    pub fn name(&self) -> &'static str {
+        return match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+    }
+}
+
+/// Weekday of a BYDAY rule part, optionally with an ordinal (e.g. 2 for the
+/// second Tuesday, -1 for the last Friday of the month or year)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ByDay {
+    pub ordinal: Option<i64>,
+    pub weekday: Weekday,
+}
+
+/// Implement fmt::Display trait for [`ByDay`] (e.g. "-1FR")
+impl fmt::Display for ByDay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ordinal) = self.ordinal {
+            write!(f, "{}", ordinal)?;
+        }
+        write!(f, "{}", WEEKDAY_CODES[self.weekday.number() as usize])
+    }
+}
+
+/// Recurrence rule (see the [module documentation](self) for the supported
+/// rule parts)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RRule {
+    pub frequency: Frequency,
+    pub interval: i64,
+    pub by_month: Vec<i64>,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i64>,
+    /// Absolute (fixed) date of the last possible occurrence
+    pub until: Option<i64>,
+    pub count: Option<i64>,
+}
+
+impl RRule {
+    /// Create a new rule of a given frequency, without further rule parts
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            by_month: vec![],
+            by_day: vec![],
+            by_month_day: vec![],
+            until: None,
+            count: None,
+        }
+    }
+
+    /// Returns an iterator over the absolute (fixed) dates of the
+    /// occurrences of the rule, beginning on a given start date (DTSTART).
+    /// The iterator is infinite unless the rule has an `UNTIL` or `COUNT`
+    /// part; it ends once the occurrences leave
+    /// [`GREGORIAN_ABSOLUTE_RANGE`].
+    ///
+    /// ```
+    /// use calendars::rrule::RRule;
+    ///
+    /// let rule: RRule = "FREQ=MONTHLY;INTERVAL=922337203685477580".parse().unwrap();
+    /// assert_eq!(rule.occurrences(0).collect::<Vec<_>>(), [0]);
+    /// let rule: RRule = "FREQ=DAILY".parse().unwrap();
+    /// assert_eq!(rule.occurrences(i64::MAX).count(), 0);
+    /// ```
+    pub fn occurrences(&self, start: i64) -> RRuleIter<'_> {
+        return RRuleIter {
+            rule: self,
+            start,
+            period: 0,
+            pending: vec![],
+            emitted: 0,
+            done: false,
+        };
+    }
+
+    /// Returns the absolute (fixed) dates of the occurrences of the rule
+    /// (beginning on `start`) from `from` to `to` (inclusive).
+    pub fn dates_between(&self, start: i64, from: i64, to: i64) -> Vec<i64> {
+        return self
+            .occurrences(start)
+            .take_while(|date| *date <= to)
+            .filter(|date| *date >= from)
+            .collect();
+    }
+
+    /// Returns the candidate dates of a given period (counted in intervals
+    /// from the period of the start date), in chronological order, or None
+    /// if the period lies outside [`GREGORIAN_ABSOLUTE_RANGE`].
+    fn period_dates(&self, start: i64, period: i64) -> Option<Vec<i64>> {
+        let s = gregorian_from_absolute(start);
+        let offset = period.checked_mul(self.interval)?;
+        let mut dates = match self.frequency {
+            Frequency::Daily => {
+                let date = start.checked_add(offset)?;
+                if !GREGORIAN_ABSOLUTE_RANGE.contains(&date) {
+                    return None;
+                }
+                let d = gregorian_from_absolute(date);
+                let month_day_matches = self.by_month_day.is_empty()
+                    || self.month_days(d.year, d.month).contains(&date);
+                let weekday_matches = self.by_day.is_empty()
+                    || self
+                        .by_day
+                        .iter()
+                        .any(|b| b.weekday == weekday_from_absolute(date));
+                if month_day_matches && weekday_matches {
+                    vec![date]
+                } else {
+                    vec![]
+                }
+            }
+            Frequency::Weekly => {
+                // weeks begin on Monday
+                let week = kday_on_or_before(start, 1).checked_add(offset.checked_mul(7)?)?;
+                if !GREGORIAN_ABSOLUTE_RANGE.contains(&week.saturating_add(6)) {
+                    return None;
+                }
+                let weekdays: Vec<Weekday> = if self.by_day.is_empty() {
+                    vec![weekday_from_absolute(start)]
+                } else {
+                    self.by_day.iter().map(|b| b.weekday).collect()
+                };
+                let mut dates: Vec<i64> = weekdays
+                    .iter()
+                    .map(|w| kday_on_or_after(week, w.number()))
+                    .collect();
+                dates.sort();
+                dates.dedup();
+                dates
+            }
+            Frequency::Monthly => {
+                let months = (12 * s.year + s.month - 1).checked_add(offset)?;
+                if !GREGORIAN_YEAR_RANGE.contains(&months.div_euclid(12)) {
+                    return None;
+                }
+                self.month_dates(months.div_euclid(12), months.rem_euclid(12) + 1, s.day)
+            }
+            Frequency::Yearly => {
+                let year = s.year.checked_add(offset)?;
+                if !GREGORIAN_YEAR_RANGE.contains(&year) {
+                    return None;
+                }
+                if !self.by_day.is_empty()
+                    && self.by_month.is_empty()
+                    && self.by_month_day.is_empty()
+                {
+                    // ordinals count the weekdays of the year
+                    let first = absolute_from_gregorian(Gregorian::new(year, 1, 1));
+                    let last = absolute_from_gregorian(Gregorian::new(year, 12, 31));
+                    self.weekday_dates(first, last)
+                } else {
+                    let months = if !self.by_month.is_empty() {
+                        let mut months = self.by_month.clone();
+                        months.sort();
+                        months
+                    } else if !self.by_month_day.is_empty() {
+                        (1..=12).collect()
+                    } else {
+                        vec![s.month]
+                    };
+                    months
+                        .into_iter()
+                        .flat_map(|month| self.month_dates(year, month, s.day))
+                        .collect()
+                }
+            }
+        };
+        if !self.by_month.is_empty() {
+            dates.retain(|date| {
+                self.by_month
+                    .contains(&gregorian_from_absolute(*date).month)
+            });
+        }
+        return Some(dates);
+    }
+
+    /// Returns the candidate dates of a given month, defaulting to a given
+    /// day of the month.
+    fn month_dates(&self, year: i64, month: i64, day: i64) -> Vec<i64> {
+        if !(1..=12).contains(&month) {
+            return vec![];
+        }
+        let first = absolute_from_gregorian(Gregorian::new(year, month, 1));
+        let last = first + last_day_of_gregorian_month(month, year) - 1;
+        return match (self.by_month_day.is_empty(), self.by_day.is_empty()) {
+            (true, true) if day <= last - first + 1 => vec![first + day - 1],
+            (true, true) => vec![],
+            (true, false) => self.weekday_dates(first, last),
+            (false, true) => self.month_days(year, month),
+            (false, false) => {
+                let weekdays = self.weekday_dates(first, last);
+                self.month_days(year, month)
+                    .into_iter()
+                    .filter(|date| weekdays.contains(date))
+                    .collect()
+            }
+        };
+    }
+
+    /// Returns the dates of the BYMONTHDAY part in a given month, in
+    /// chronological order.
+    fn month_days(&self, year: i64, month: i64) -> Vec<i64> {
+        let first = absolute_from_gregorian(Gregorian::new(year, month, 1));
+        let length = last_day_of_gregorian_month(month, year);
+        let mut dates: Vec<i64> = self
+            .by_month_day
+            .iter()
+            .map(|&day| if day < 0 { length + day + 1 } else { day })
+            .filter(|day| (1..=length).contains(day))
+            .map(|day| first + day - 1)
+            .collect();
+        dates.sort();
+        dates.dedup();
+        return dates;
+    }
+
+    /// Returns the dates of the BYDAY part from `first` to `last`, with
+    /// ordinals counting the weekdays of that span, in chronological order.
+    fn weekday_dates(&self, first: i64, last: i64) -> Vec<i64> {
+        let mut dates = vec![];
+        for b in &self.by_day {
+            let all: Vec<i64> = (kday_on_or_after(first, b.weekday.number())..=last)
+                .step_by(7)
+                .collect();
+            match b.ordinal {
+                None => dates.extend(all),
+                Some(n) if n > 0 && n as usize <= all.len() => dates.push(all[n as usize - 1]),
+                Some(n) if n < 0 && n.unsigned_abs() as usize <= all.len() => {
+                    dates.push(all[all.len() - n.unsigned_abs() as usize])
+                }
+                Some(_) => {}
+            }
+        }
+        dates.sort();
+        dates.dedup();
+        return dates;
+    }
+}
+
+/// Iterator over the occurrences of a recurrence rule (see
+/// [`RRule::occurrences`])
+#[derive(Debug, Clone)]
+pub struct RRuleIter<'a> {
+    rule: &'a RRule,
+    start: i64,
+    period: i64,
+    pending: Vec<i64>,
+    emitted: i64,
+    done: bool,
+}
+
+impl Iterator for RRuleIter<'_> {
+    type Item = i64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done || self.rule.count.is_some_and(|count| self.emitted >= count) {
+            return None;
+        }
+        let mut empty_periods = 0;
+        while self.pending.is_empty() {
+            if empty_periods >= MAX_EMPTY_PERIODS {
+                self.done = true;
+                return None;
+            }
+            let start = self.start;
+            let Some(dates) = self.rule.period_dates(start, self.period) else {
+                self.done = true;
+                return None;
+            };
+            self.pending = dates
+                .into_iter()
+                .filter(|date| *date >= start)
+                .rev()
+                .collect();
+            self.period += 1;
+            empty_periods += 1;
+        }
+        let date = self.pending.pop()?;
+        if self.rule.until.is_some_and(|until| date > until) {
+            self.done = true;
+            return None;
+        }
+        self.emitted += 1;
+        return Some(date);
+    }
+}
+
+/// Returns a parse error for a given rule part.
+fn invalid_part(part: &str) -> CalendarError {
+    return CalendarError::ParseError(format!("invalid RRULE part: \"{}\"", part));
+}
+
+/// Parses a comma-separated list of integers, each within a given range
+/// (excluding 0).
+fn parse_numbers(part: &str, value: &str, max: i64) -> Result<Vec<i64>, CalendarError> {
+    return value
+        .split(',')
+        .map(|n| match n.parse::<i64>() {
+            Ok(n) if n != 0 && n.abs() <= max => Ok(n),
+            _ => Err(invalid_part(part)),
+        })
+        .collect();
+}
+
+/// Parses a weekday of a BYDAY rule part (e.g. "MO", "2TU", or "-1FR").
+fn parse_by_day(part: &str, value: &str) -> Result<ByDay, CalendarError> {
+    let split = value.len().saturating_sub(2);
+    let (ordinal, code) = value.split_at(split);
+    let weekday = WEEKDAY_CODES
+        .iter()
+        .position(|c| *c == code)
+        .ok_or_else(|| invalid_part(part))?;
+    let ordinal = match ordinal {
+        "" => None,
+        _ => match ordinal.parse::<i64>() {
+            Ok(n) if n != 0 && n.abs() <= 53 => Some(n),
+            _ => return Err(invalid_part(part)),
+        },
+    };
+    return Ok(ByDay {
+        ordinal,
+        weekday: Weekday::from_number(weekday as i64),
+    });
+}
+
+/// Parses an iCalendar date (e.g. "20240131" or "20240131T120000Z"),
+/// ignoring its time of day.
+fn parse_until(part: &str, value: &str) -> Result<i64, CalendarError> {
+    let digits = value.get(..8).ok_or_else(|| invalid_part(part))?;
+    if !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid_part(part));
+    }
+    let number = |range: core::ops::Range<usize>| digits[range].parse::<i64>().unwrap();
+    let date = Gregorian::new(number(0..4), number(4..6), number(6..8));
+    if !date.is_valid() {
+        return Err(invalid_part(part));
+    }
+    return Ok(absolute_from_gregorian(date));
+}
+
+impl FromStr for RRule {
+    type Err = CalendarError;
+
+    /// Parses a recurrence rule (e.g. "FREQ=MONTHLY;BYDAY=-1FR"), optionally
+    /// preceded by "RRULE:".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+        let mut frequency = None;
+        let mut rule = RRule::new(Frequency::Daily);
+        for part in s.split(';').filter(|part| !part.is_empty()) {
+            let (name, value) = part.split_once('=').ok_or_else(|| invalid_part(part))?;
+            match name {
+                "FREQ" => {
+                    frequency = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(invalid_part(part)),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = match value.parse::<i64>() {
+                        Ok(n) if n >= 1 => n,
+                        _ => return Err(invalid_part(part)),
+                    }
+                }
+                "BYMONTH" => {
+                    rule.by_month = parse_numbers(part, value, 12)?;
+                    if rule.by_month.iter().any(|m| *m < 0) {
+                        return Err(invalid_part(part));
+                    }
+                }
+                "BYMONTHDAY" => rule.by_month_day = parse_numbers(part, value, 31)?,
+                "BYDAY" => {
+                    rule.by_day = value
+                        .split(',')
+                        .map(|day| parse_by_day(part, day))
+                        .collect::<Result<_, _>>()?
+                }
+                "UNTIL" => rule.until = Some(parse_until(part, value)?),
+                "COUNT" => {
+                    rule.count = match value.parse::<i64>() {
+                        Ok(n) if n >= 1 => Some(n),
+                        _ => return Err(invalid_part(part)),
+                    }
+                }
+                _ => {
+                    return Err(CalendarError::ParseError(format!(
+                        "unsupported RRULE part: \"{}\"",
+                        part
+                    )))
+                }
+            }
+        }
+        rule.frequency = frequency
+            .ok_or_else(|| CalendarError::ParseError(format!("missing FREQ: \"{}\"", s)))?;
+        if rule.until.is_some() && rule.count.is_some() {
+            return Err(CalendarError::ParseError(format!(
+                "UNTIL and COUNT are mutually exclusive: \"{}\"",
+                s
+            )));
+        }
+        return Ok(rule);
+    }
+}
+
+/// Implement fmt::Display trait for [`RRule`], writing it as an RRULE value
+/// (e.g. "FREQ=MONTHLY;BYDAY=-1FR")
+impl fmt::Display for RRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |values: Vec<String>| values.join(",");
+        write!(f, "FREQ={}", self.frequency.name())?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_month.is_empty() {
+            let months = self.by_month.iter().map(|m| m.to_string()).collect();
+            write!(f, ";BYMONTH={}", join(months))?;
+        }
+        if !self.by_day.is_empty() {
+            let days = self.by_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYDAY={}", join(days))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", join(days))?;
+        }
+        if let Some(until) = self.until {
+            let d = gregorian_from_absolute(until);
+            write!(f, ";UNTIL={:04}{:02}{:02}", d.year, d.month, d.day)?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        return Ok(());
+    }
+}
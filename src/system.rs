@@ -0,0 +1,242 @@
+//! Provides metadata of the supported calendars (kind, epoch, mean year
+//! length, and month names)
+
+use crate::{
+    french::{French, FRENCH_MONTH_NAMES},
+    gregorian::{Gregorian, GREGORIAN_MONTH_NAMES},
+    hebrew::{Hebrew, HEBREW_MONTH_NAMES},
+    hindu::{OldHinduLunar, OldHinduSolar, HINDU_LUNAR_MONTH_NAMES, HINDU_SOLAR_MONTH_NAMES},
+    islamic::{Islamic, UmmAlQura, ISLAMIC_MONTH_NAMES},
+    iso::Iso,
+    julian::Julian,
+    mayan::{MayanHaab, MayanLongCount, MayanTzolkin, MAYAN_MONTH_NAMES},
+    ordinal::Ordinal,
+};
+
+/// Kind of a calendar, according to the astronomical cycles it follows
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CalendarKind {
+    /// Years follow the sun (e.g. Gregorian)
+    Solar,
+    /// Months follow the moon, regardless of the seasons (e.g. Islamic)
+    Lunar,
+    /// Months follow the moon, and leap months keep the years in line with
+    /// the sun (e.g. Hebrew)
+    Lunisolar,
+    /// Dates are positions in cycles of days (e.g. Mayan)
+    Cyclical,
+}
+
+/// Metadata of a calendar
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CalendarInfo {
+    /// Name of the calendar (see [`Date::convert_to`](crate::utility::Date::convert_to))
+    pub name: &'static str,
+    pub kind: CalendarKind,
+    /// Absolute (fixed) date of the first day of the calendar's era, or
+    /// `None` for calendars without an era (Mayan Haab and Tzolkin)
+    pub epoch: Option<i64>,
+    /// Mean length of a year (or, for the Mayan Haab and Tzolkin, of a
+    /// cycle) in days, or `None` for calendars without years
+    pub mean_year_length: Option<f64>,
+    /// Month names, as used by [`Date`](crate::utility::Date) (empty for
+    /// calendars without named months)
+    pub month_names: &'static [&'static str],
+    /// Names of the components of a [`Date`](crate::utility::Date) of the
+    /// calendar, in order
+    pub component_names: &'static [&'static str],
+}
+
+/// Trait for date types exposing the metadata of their calendar
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     hebrew::Hebrew,
+///     system::{calendar_info, CalendarKind, CalendarSystem, CALENDARS},
+///     utility::FixedConvertible,
+/// };
+///
+/// assert_eq!(Hebrew::INFO.kind, CalendarKind::Lunisolar);
+/// // 1 Tishri AM 1
+/// assert_eq!(Hebrew::INFO.epoch, Some(Hebrew { year: 1, month: 7, day: 1 }.to_absolute()));
+/// assert_eq!(calendar_info("islamic").unwrap().month_names[8], "Ramadan");
+///
+/// // list the lunar calendars
+/// let lunar: Vec<&str> = CALENDARS
+///     .iter()
+///     .filter(|info| info.kind == CalendarKind::Lunar)
+///     .map(|info| info.name)
+///     .collect();
+/// assert_eq!(lunar, ["islamic", "ummAlQura"]);
+/// ```
+pub trait CalendarSystem {
+    const INFO: CalendarInfo;
+}
+
+impl CalendarSystem for Gregorian {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "gregorian",
+        kind: CalendarKind::Solar,
+        epoch: Some(1),
+        mean_year_length: Some(365.2425),
+        month_names: &GREGORIAN_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for Iso {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "iso",
+        kind: CalendarKind::Solar,
+        epoch: Some(1),
+        mean_year_length: Some(365.2425),
+        month_names: &[],
+        component_names: &["year", "week", "day"],
+    };
+}
+
+impl CalendarSystem for Ordinal {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "ordinal",
+        kind: CalendarKind::Solar,
+        epoch: Some(1),
+        mean_year_length: Some(365.2425),
+        month_names: &[],
+        component_names: &["year", "day"],
+    };
+}
+
+impl CalendarSystem for Julian {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "julian",
+        kind: CalendarKind::Solar,
+        epoch: Some(-1),
+        mean_year_length: Some(365.25),
+        month_names: &GREGORIAN_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for Islamic {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "islamic",
+        kind: CalendarKind::Lunar,
+        epoch: Some(227015),
+        mean_year_length: Some(354.0 + 11.0 / 30.0),
+        month_names: &ISLAMIC_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for UmmAlQura {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "ummAlQura",
+        kind: CalendarKind::Lunar,
+        epoch: Some(227014),
+        mean_year_length: Some(12.0 * 29.530588861),
+        month_names: &ISLAMIC_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for Hebrew {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "hebrew",
+        kind: CalendarKind::Lunisolar,
+        epoch: Some(-1373427),
+        mean_year_length: Some(35975351.0 / 98496.0),
+        month_names: &HEBREW_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for MayanLongCount {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "mayanLongCount",
+        kind: CalendarKind::Cyclical,
+        epoch: Some(-1137142),
+        mean_year_length: None,
+        month_names: &[],
+        component_names: &["baktun", "katun", "tun", "uinal", "kin"],
+    };
+}
+
+impl CalendarSystem for MayanHaab {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "mayanHaab",
+        kind: CalendarKind::Cyclical,
+        epoch: None,
+        mean_year_length: Some(365.0),
+        month_names: &MAYAN_MONTH_NAMES,
+        component_names: &["day", "month"],
+    };
+}
+
+impl CalendarSystem for MayanTzolkin {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "mayanTzolkin",
+        kind: CalendarKind::Cyclical,
+        epoch: None,
+        mean_year_length: Some(260.0),
+        month_names: &[],
+        component_names: &["number", "name"],
+    };
+}
+
+impl CalendarSystem for French {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "french",
+        kind: CalendarKind::Solar,
+        epoch: Some(654415),
+        mean_year_length: Some(365.24225),
+        month_names: &FRENCH_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for OldHinduSolar {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "oldHinduSolar",
+        kind: CalendarKind::Solar,
+        epoch: Some(-1132959),
+        mean_year_length: Some(365.0 + 279457.0 / 1080000.0),
+        month_names: &HINDU_SOLAR_MONTH_NAMES,
+        component_names: &["year", "month", "day"],
+    };
+}
+
+impl CalendarSystem for OldHinduLunar {
+    const INFO: CalendarInfo = CalendarInfo {
+        name: "oldHinduLunar",
+        kind: CalendarKind::Lunisolar,
+        epoch: Some(-1132959),
+        mean_year_length: Some(365.0 + 279457.0 / 1080000.0),
+        month_names: &HINDU_LUNAR_MONTH_NAMES,
+        component_names: &["year", "month", "leapMonth", "day"],
+    };
+}
+
+/// Metadata of all supported calendars
+pub static CALENDARS: [CalendarInfo; 13] = [
+    Gregorian::INFO,
+    Iso::INFO,
+    Ordinal::INFO,
+    Julian::INFO,
+    Islamic::INFO,
+    UmmAlQura::INFO,
+    Hebrew::INFO,
+    MayanLongCount::INFO,
+    MayanHaab::INFO,
+    MayanTzolkin::INFO,
+    French::INFO,
+    OldHinduSolar::INFO,
+    OldHinduLunar::INFO,
+];
+
+/// Returns the metadata of the calendar with a given name (e.g. "hebrew"),
+/// or `None` if the calendar is unknown.
+pub fn calendar_info(name: &str) -> Option<&'static CalendarInfo> {
+    return CALENDARS.iter().find(|info| info.name == name);
+}
@@ -0,0 +1,168 @@
+//! Provides functions asserting the invariants of calendar implementations
+//!
+//! The functions panic with a descriptive message if an invariant does not
+//! hold, so they can be used directly in tests of downstream crates or of new
+//! calendars. With the `proptest` feature enabled, [`absolute_dates`] and
+//! [`dates`] provide [proptest](https://docs.rs/proptest) strategies to check
+//! invariants on random dates.
+
+use core::{fmt::Debug, ops::RangeInclusive};
+
+use crate::utility::{FixedConvertible, MonthLength};
+
+/// Asserts that converting each absolute (fixed) date of a range to a date
+/// of a calendar and back yields the same absolute date, and that converting
+/// the date to an absolute date and back yields the same date.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{hebrew::Hebrew, iso::Iso, testing::assert_roundtrip};
+///
+/// assert_roundtrip::<Hebrew>(738000..=740000);
+/// assert_roundtrip::<Iso>(-1000..=1000);
+/// ```
+pub fn assert_roundtrip<C>(absolute_dates: RangeInclusive<i64>)
+where
+    C: FixedConvertible + PartialEq + Debug,
+{
+    for absolute_date in absolute_dates {
+        let date = C::from_absolute(absolute_date);
+        assert_eq!(
+            date.to_absolute(),
+            absolute_date,
+            "{:?} does not convert back to absolute date {}",
+            date,
+            absolute_date
+        );
+        assert_eq!(
+            C::from_absolute(date.to_absolute()),
+            date,
+            "{:?} does not convert back to itself",
+            date
+        );
+    }
+}
+
+/// Asserts that the dates of a calendar increase monotonically over a range
+/// of absolute (fixed) dates: each date is followed by the next day of its
+/// month, or by the first day of the next month (in calendar order).
+///
+/// # Example
+///
+/// ```
+/// use calendars::{french::French, hebrew::Hebrew, testing::assert_monotonic};
+///
+/// assert_monotonic::<Hebrew>(738000..=740000);
+/// assert_monotonic::<French>(739000..=740000);
+/// ```
+pub fn assert_monotonic<C>(absolute_dates: RangeInclusive<i64>)
+where
+    C: MonthLength + Debug,
+{
+    let (start, end) = (*absolute_dates.start(), *absolute_dates.end());
+    for absolute_date in start..end {
+        let date = C::from_absolute(absolute_date);
+        let next = C::from_absolute(absolute_date + 1);
+        let (year, month, day) = date.year_month_day();
+        let expected = if day < C::last_day_of_month(month, year) {
+            (year, month, day + 1)
+        } else {
+            let (next_year, next_month) = C::next_month(year, month);
+            (next_year, next_month, 1)
+        };
+        assert_eq!(
+            next.year_month_day(),
+            expected,
+            "{:?} is not followed by the next day (absolute date {})",
+            date,
+            absolute_date
+        );
+    }
+}
+
+/// Asserts that the month lengths of a calendar are consistent with its
+/// conversions over a range of years: the last day of each month is
+/// followed by the first day of the next month, and the days of a year add
+/// up to the number of days between the first days of the year and of the
+/// next year.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{islamic::UmmAlQura, julian::Julian, testing::assert_month_lengths};
+///
+/// assert_month_lengths::<Julian>(-100..=100);
+/// assert_month_lengths::<UmmAlQura>(1300..=1500);
+/// ```
+pub fn assert_month_lengths<C>(years: RangeInclusive<i64>)
+where
+    C: MonthLength + Debug,
+{
+    for year in years {
+        let mut days_of_year = 0;
+        for month in C::months_of_year(year) {
+            let last_day = C::last_day_of_month(month, year);
+            let last = C::from_year_month_day(year, month, last_day);
+            assert_eq!(
+                C::from_absolute(last.to_absolute()).year_month_day(),
+                (year, month, last_day),
+                "{:?} does not exist",
+                last
+            );
+            let (next_year, next_month) = C::next_month(year, month);
+            assert_eq!(
+                C::from_year_month_day(next_year, next_month, 1).to_absolute(),
+                last.to_absolute() + 1,
+                "{:?} is not followed by the first day of the next month",
+                last
+            );
+            days_of_year += last_day;
+        }
+        let first_month = C::first_month_of_year(year);
+        let first = C::from_year_month_day(year, first_month, 1).to_absolute();
+        let next_first =
+            C::from_year_month_day(year + 1, C::first_month_of_year(year + 1), 1).to_absolute();
+        assert_eq!(
+            next_first - first,
+            days_of_year,
+            "the months of year {} do not add up to the length of the year",
+            year
+        );
+    }
+}
+
+/// Returns a proptest strategy generating absolute (fixed) dates of a range.
+#[cfg(feature = "proptest")]
+pub fn absolute_dates(
+    absolute_dates: RangeInclusive<i64>,
+) -> impl proptest::strategy::Strategy<Value = i64> {
+    return absolute_dates;
+}
+
+/// Returns a proptest strategy generating the dates of a calendar
+/// corresponding to the absolute (fixed) dates of a range.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     hebrew::Hebrew,
+///     testing::dates,
+///     utility::{Calendar, FixedConvertible},
+/// };
+/// use proptest::prelude::*;
+///
+/// proptest!(|(date in dates::<Hebrew>(0..=1_000_000))| {
+///     prop_assert_eq!(Hebrew::from_absolute(date.to_absolute()), date);
+/// });
+/// ```
+#[cfg(feature = "proptest")]
+pub fn dates<C>(absolute_dates: RangeInclusive<i64>) -> impl proptest::strategy::Strategy<Value = C>
+where
+    C: FixedConvertible + Debug,
+{
+    use proptest::strategy::Strategy;
+
+    return absolute_dates.prop_map(C::from_absolute);
+}
@@ -1,50 +1,522 @@
 //! Provides generic date struct and Calendar trait for easier date conversion
 
 use crate::{
-    french::{absolute_from_french, french_from_absolute, French, FRENCH_MONTH_NAMES},
+    arithmetic::{AnniversaryPolicy, Difference, MonthArithmetic, Overflow},
+    component::{Day, Month, Year},
+    dynamic,
+    error::{check_component, CalendarError},
+    french::{
+        absolute_from_french, checked_absolute_from_french, checked_french_from_absolute,
+        french_from_absolute, french_last_day_of_month, french_leap_year, French, FRENCH_DAY_NAMES,
+        FRENCH_MONTH_NAMES, SANSCULOTTIDES,
+    },
     gregorian::{
-        absolute_from_gregorian, gregorian_from_absolute, Gregorian, GREGORIAN_MONTH_NAMES,
+        absolute_from_gregorian, checked_absolute_from_gregorian, checked_gregorian_from_absolute,
+        gregorian_from_absolute, gregorian_leap_year, last_day_of_gregorian_month, Gregorian,
+        GREGORIAN_MONTH_NAMES,
     },
     hebrew::{
-        absolute_from_hebrew, hebrew_from_absolute, hebrew_leap_year, Hebrew, HEBREW_MONTH_NAMES,
+        absolute_from_hebrew, checked_absolute_from_hebrew, checked_hebrew_from_absolute,
+        hebrew_from_absolute, hebrew_leap_year, last_day_of_hebrew_month,
+        last_month_of_hebrew_year, Hebrew, HEBREW_MONTH_NAMES,
     },
     hindu::{
         absolute_from_old_hindu_lunar, absolute_from_old_hindu_solar,
+        checked_absolute_from_old_hindu_lunar, checked_absolute_from_old_hindu_solar,
+        checked_old_hindu_lunar_from_absolute, checked_old_hindu_solar_from_absolute,
         old_hindu_lunar_from_absolute, old_hindu_solar_from_absolute, OldHinduLunar, OldHinduSolar,
         HINDU_LUNAR_MONTH_NAMES, HINDU_SOLAR_MONTH_NAMES,
     },
-    islamic::{absolute_from_islamic, islamic_from_absolute, Islamic, ISLAMIC_MONTH_NAMES},
-    iso::{absolute_from_iso, iso_from_absolute, Iso},
-    julian::{absolute_from_julian, julian_from_absolute, Julian},
+    islamic::{
+        absolute_from_islamic, absolute_from_umm_al_qura, checked_absolute_from_islamic,
+        checked_absolute_from_umm_al_qura, checked_islamic_from_absolute,
+        checked_umm_al_qura_from_absolute, islamic_from_absolute, islamic_leap_year,
+        last_day_of_islamic_month, last_day_of_umm_al_qura_month, umm_al_qura_from_absolute,
+        Islamic, UmmAlQura, ISLAMIC_MONTH_NAMES,
+    },
+    iso::{
+        absolute_from_iso, checked_absolute_from_iso, checked_iso_from_absolute, format_iso_year,
+        iso_from_absolute, Iso,
+    },
+    julian::{
+        absolute_from_julian, checked_absolute_from_julian, checked_julian_from_absolute,
+        julian_from_absolute, julian_leap_year, last_day_of_julian_month, Julian,
+    },
+    locale::Locale,
     mayan::{
-        absolute_from_mayan_long_count, mayan_haab_from_absolute, mayan_long_count_from_absolute,
-        mayan_tzolkin_from_absolute, MayanHaab, MayanLongCount, MayanTzolkin, MAYAN_MONTH_NAMES,
-        MAYAN_TZOLKIN_NAMES,
+        absolute_from_mayan_long_count, checked_absolute_from_mayan_long_count,
+        checked_mayan_haab_from_absolute, checked_mayan_long_count_from_absolute,
+        checked_mayan_tzolkin_from_absolute, mayan_haab_from_absolute,
+        mayan_long_count_from_absolute, mayan_tzolkin_from_absolute, MayanHaab, MayanLongCount,
+        MayanTzolkin, MAYAN_MONTH_NAMES, MAYAN_TZOLKIN_NAMES,
     },
+    ordinal::{
+        absolute_from_ordinal, checked_absolute_from_ordinal, checked_ordinal_from_absolute,
+        ordinal_from_absolute, Ordinal,
+    },
+    system::{calendar_info, CalendarSystem},
+    weekday::{weekday_from_absolute, Weekday},
 };
-use core::panic;
 use std::fmt;
 
-// Calendar trait
-pub trait Calendar {
+/// Trait for date types. Each date type converts from absolute (fixed) dates
+/// and exposes the metadata of its calendar (see [`CalendarSystem`]), which
+/// allows generic conversions between date types (see
+/// [`convert`](crate::conversion::convert)). As the trait has associated
+/// constants, it cannot be used as a trait object; use
+/// [`DynCalendar`](crate::dynamic::DynCalendar) for boxed dates of any
+/// calendar instead.
+pub trait Calendar: CalendarSystem {
     fn to_date(&self) -> Date;
-    fn to_absolute(&self) -> i64;
+    fn from_absolute(absolute_date: i64) -> Self;
     fn format(&self) -> String;
+
+    /// Returns the date of a given absolute (fixed) date, or an error if the
+    /// absolute date lies outside the range of the calendar (see the
+    /// `*_ABSOLUTE_RANGE` statics of the calendar modules).
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError>
+    where
+        Self: Sized;
+
+    /// Returns the absolute (fixed) date of the date, or an error if the date
+    /// does not exist, lies outside the range supported by its calendar, or
+    /// has no absolute date (Mayan Haab and Tzolkin dates).
+    fn try_to_absolute(&self) -> Result<i64, CalendarError>;
+
+    /// Returns the absolute (fixed) date of the date, or an error if the date
+    /// does not exist, lies outside the range supported by its calendar, or
+    /// has no absolute date.
+    #[deprecated(since = "0.6.0", note = "use `try_to_absolute` instead")]
+    fn checked_to_absolute(&self) -> Result<i64, CalendarError> {
+        return self.try_to_absolute();
+    }
+
+    /// Formats the date according to a given strftime-style pattern (see
+    /// [`Date::format_with`]).
+    fn format_with(&self, pattern: &str) -> Result<String, CalendarError> {
+        return self.to_date().format_with(pattern);
+    }
+
+    /// Formats the date according to a given strftime-style pattern, using
+    /// the month and weekday names of a given locale (see
+    /// [`Date::format_with_locale`]).
+    fn format_with_locale(&self, pattern: &str, locale: Locale) -> Result<String, CalendarError> {
+        return self.to_date().format_with_locale(pattern, locale);
+    }
+
+    /// Formats the date like [`Calendar::format`], using the month names of a
+    /// given locale (see [`Date::format_localized`]).
+    fn format_localized(&self, locale: Locale) -> String {
+        return self.to_date().format_localized(locale);
+    }
+
+    /// Returns the absolute (fixed) date of the date without validating it,
+    /// or `None` if the date cannot be related to an absolute date (e.g.
+    /// cyclical Mayan Haab and Tzolkin dates). Used by the unchecked
+    /// conversions and comparisons; prefer [`Calendar::try_to_absolute`].
+    #[doc(hidden)]
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return self.try_to_absolute().ok();
+    }
+
+    /// Returns the (English or transliterated) name of the month of the date,
+    /// or `None` if the calendar has no named months or the month does not
+    /// exist.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     french::French, gregorian::Gregorian, hebrew::Hebrew, iso::Iso, utility::Calendar,
+    /// };
+    ///
+    /// assert_eq!(Gregorian::new(2024, 5, 1).month_name(), Some("May"));
+    /// assert_eq!(Hebrew { year: 5783, month: 12, day: 14 }.month_name(), Some("Adar"));
+    /// assert_eq!(Hebrew { year: 5784, month: 12, day: 14 }.month_name(), Some("Adar I"));
+    /// assert_eq!(Hebrew { year: 5784, month: 13, day: 14 }.month_name(), Some("Adar II"));
+    /// assert_eq!(Iso::new(2024, 18, 3).month_name(), None);
+    /// assert_eq!(Gregorian::new(2024, 13, 1).month_name(), None);
+    /// assert_eq!(Gregorian::new(2024, 13, 1).format(), "1 13 2024");
+    ///
+    /// assert_eq!(Gregorian::new(2024, 5, 1).weekday_name(), Some("Wednesday"));
+    /// assert_eq!(French::new(233, 1, 10).weekday_name(), Some("Décadi"));
+    /// assert_eq!(French::new(232, 13, 6).month_name(), Some("Sansculottides"));
+    /// assert_eq!(French::new(232, 13, 6).weekday_name(), Some("Jour de la révolution"));
+    /// ```
+    fn month_name(&self) -> Option<&'static str> {
+        return None;
+    }
+
+    /// Returns the name of the day of the week of the date, or `None` if the
+    /// date cannot be related to an absolute date. French Revolutionary dates
+    /// are named after the days of the décade (e.g. "Primidi"), or after the
+    /// sansculottides (e.g. "Jour de la vertu").
+    fn weekday_name(&self) -> Option<&'static str> {
+        return self
+            .absolute_unchecked()
+            .map(|date| weekday_from_absolute(date).name());
+    }
+}
+
+// Trait for calendars whose dates each correspond to an absolute date
+pub trait FixedConvertible: Calendar {
+    fn to_absolute(&self) -> i64;
+
+    /// Returns the day of the week of the date.
+    fn weekday(&self) -> Weekday {
+        return weekday_from_absolute(self.to_absolute());
+    }
+
+    /// Returns the date a given number of days after (or, if negative,
+    /// before) the date. The resulting date is clamped to the range of the
+    /// calendar (see [`FixedConvertible::checked_add_days`] for a fallible
+    /// alternative).
+    fn add_days(&self, days: i64) -> Self
+    where
+        Self: Sized,
+    {
+        return Self::from_absolute(self.to_absolute().saturating_add(days));
+    }
+
+    /// Returns the date a given number of days after (or, if negative,
+    /// before) the date, or an error if the date does not exist or the
+    /// resulting date lies outside the range of the calendar.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{gregorian::Gregorian, utility::FixedConvertible};
+    ///
+    /// let date = Gregorian::new(2024, 1, 1);
+    /// assert_eq!(date.checked_add_days(60), Ok(Gregorian::new(2024, 3, 1)));
+    /// assert!(date.checked_add_days(i64::MAX).is_err());
+    /// assert!(date.checked_add_days(i64::MIN).is_err());
+    /// assert!(Gregorian::new(2024, 2, 30).checked_add_days(1).is_err());
+    /// assert_eq!(date.add_days(i64::MAX), Gregorian::new(1_000_000_000_000_000, 12, 31));
+    /// ```
+    fn checked_add_days(&self, days: i64) -> Result<Self, CalendarError>
+    where
+        Self: Sized,
+    {
+        return Self::checked_from_absolute(self.try_to_absolute()?.saturating_add(days));
+    }
+
+    /// Converts the date to the date of another calendar with the same
+    /// absolute (fixed) date. The target calendar is checked at compile time;
+    /// use [`Date::convert_to`] to choose it at runtime. The target date is
+    /// clamped to the range of its calendar (see
+    /// [`FixedConvertible::checked_convert`] for a fallible alternative).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     french::French, gregorian::Gregorian, hebrew::Hebrew, utility::FixedConvertible,
+    /// };
+    ///
+    /// let date = Gregorian::new(2024, 5, 1);
+    /// assert_eq!(date.convert::<Hebrew>(), Hebrew { year: 5784, month: 1, day: 23 });
+    /// assert_eq!(date.convert::<Hebrew>().convert::<Gregorian>(), date);
+    /// assert_eq!(date.checked_convert::<French>().unwrap().to_string(), "13 Floréal 232");
+    /// assert!(Gregorian::new(1700, 1, 1).checked_convert::<French>().is_err());
+    /// ```
+    fn convert<B: Calendar>(&self) -> B
+    where
+        Self: Sized,
+    {
+        return B::from_absolute(self.to_absolute());
+    }
+
+    /// Converts the date to the date of another calendar with the same
+    /// absolute (fixed) date, or returns an error if the absolute date lies
+    /// outside the range of the target calendar.
+    fn checked_convert<B: Calendar>(&self) -> Result<B, CalendarError>
+    where
+        Self: Sized,
+    {
+        return B::checked_from_absolute(self.to_absolute());
+    }
+}
+
+// Month length trait for calendars with year, month, and day components
+pub trait MonthLength: FixedConvertible {
+    /// Create a date from its year, month, and day components.
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self;
+
+    /// Returns the year, month, and day components of the date.
+    fn year_month_day(&self) -> (i64, i64, i64);
+
+    /// Create a date from its typed year, month, and day components (see the
+    /// [`component`](crate::component) module).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{
+    ///     component::{Day, Month, Year},
+    ///     gregorian::Gregorian,
+    ///     utility::MonthLength,
+    /// };
+    ///
+    /// let date = Gregorian::from_components(Year(2024), Month(5), Day(1));
+    /// assert_eq!(date, Gregorian::new(2024, 5, 1));
+    /// assert!(Gregorian::try_from_components(Year(2023), Month(2), Day(29)).is_err());
+    /// ```
+    ///
+    /// Transposed components do not compile:
+    ///
+    /// ```compile_fail
+    /// # use calendars::{component::{Day, Month, Year}, gregorian::Gregorian, utility::MonthLength};
+    /// let date = Gregorian::from_components(Month(5), Year(2024), Day(1));
+    /// ```
+    fn from_components(year: Year, month: Month, day: Day) -> Self
+    where
+        Self: Sized,
+    {
+        return Self::from_year_month_day(year.0, month.0, day.0);
+    }
+
+    /// Create a date from its typed year, month, and day components, or
+    /// return an error if the date does not exist or lies outside the range
+    /// supported by its calendar.
+    fn try_from_components(year: Year, month: Month, day: Day) -> Result<Self, CalendarError>
+    where
+        Self: Sized,
+    {
+        let day = Day::checked::<Self>(year, month, day.0)?;
+        return Ok(Self::from_components(year, month, day));
+    }
+
+    /// Returns the last day (number of days) of a given month in a given
+    /// year.
+    fn last_day_of_month(month: i64, year: i64) -> i64;
+
+    /// Returns the last month (number of months) of a given year.
+    fn last_month_of_year(_year: i64) -> i64 {
+        return 12;
+    }
+
+    /// Returns the year and month following a given month.
+    fn next_month(year: i64, month: i64) -> (i64, i64) {
+        return if month < Self::last_month_of_year(year) {
+            (year, month + 1)
+        } else {
+            (year + 1, 1)
+        };
+    }
+
+    /// Returns the year and month preceding a given month.
+    fn previous_month(year: i64, month: i64) -> (i64, i64) {
+        return if month > 1 {
+            (year, month - 1)
+        } else {
+            (year - 1, Self::last_month_of_year(year - 1))
+        };
+    }
+
+    /// Returns the first month of a given year.
+    fn first_month_of_year(_year: i64) -> i64 {
+        return 1;
+    }
+
+    /// Returns the number of months of the years preceding a given year,
+    /// counted from year 1 (negative for years before year 1), or `None` if
+    /// it overflows. By default, all years are assumed to have the same
+    /// number of months.
+    fn months_before_year(year: i64) -> Option<i64> {
+        return year
+            .checked_sub(1)?
+            .checked_mul(Self::last_month_of_year(year));
+    }
+
+    /// Returns an iterator over the months of a given year, in calendar
+    /// order.
+    fn months_of_year(year: i64) -> impl Iterator<Item = i64> {
+        let first = (year, Self::first_month_of_year(year));
+        return std::iter::successors(Some(first), |&(y, m)| Some(Self::next_month(y, m)))
+            .take_while(move |&(y, _)| y == year)
+            .map(|(_, m)| m);
+    }
+
+    /// Returns an iterator over the days of a given month in a given year,
+    /// which is empty if the month does not exist in that year.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{gregorian::Gregorian, utility::MonthLength};
+    ///
+    /// assert_eq!(Gregorian::days_of_month(2024, 2).count(), 29);
+    /// assert_eq!(Gregorian::days_of_month(2024, 0).count(), 0);
+    /// assert_eq!(Gregorian::days_of_month(2024, 13).count(), 0);
+    /// ```
+    fn days_of_month(year: i64, month: i64) -> impl Iterator<Item = Self>
+    where
+        Self: Sized,
+    {
+        let last_day = if Self::months_of_year(year).any(|m| m == month) {
+            Self::last_day_of_month(month, year)
+        } else {
+            0
+        };
+        return (1..=last_day).map(move |day| Self::from_year_month_day(year, month, day));
+    }
+
+    /// Returns an iterator over the days of a given year, in calendar order.
+    fn days_of_year(year: i64) -> impl Iterator<Item = Self>
+    where
+        Self: Sized,
+    {
+        return Self::months_of_year(year).flat_map(move |month| Self::days_of_month(year, month));
+    }
+
+    /// Returns true if a given year is a leap year (a year with an additional
+    /// day or month), and false otherwise.
+    fn leap_year(year: i64) -> bool;
+
+    /// Returns the properties of a given year (see [`YearInfo`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{hebrew::Hebrew, islamic::Islamic, utility::MonthLength};
+    ///
+    /// let info = Hebrew::year_info(5784);
+    /// assert!(info.leap_year);
+    /// assert_eq!(info.days, 383);
+    /// assert_eq!(info.months(), 13);
+    /// // the year begins with Tishri (month 7), and Adar I has 30 days
+    /// assert_eq!(info.month_lengths[0], (7, 30));
+    /// assert_eq!(info.month_length(12), Some(30));
+    ///
+    /// assert_eq!(Islamic::year_info(1445).days, 355);
+    /// assert_eq!(Islamic::year_info(1446).days, 354);
+    /// ```
+    fn year_info(year: i64) -> YearInfo {
+        let month_lengths: Vec<(i64, i64)> = Self::months_of_year(year)
+            .map(|month| (month, Self::last_day_of_month(month, year)))
+            .collect();
+        return YearInfo {
+            year,
+            leap_year: Self::leap_year(year),
+            days: month_lengths.iter().map(|(_, days)| days).sum(),
+            month_lengths,
+        };
+    }
 }
 
+/// Properties of a year of a calendar with year, month, and day components
+/// (see [`MonthLength::year_info`])
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct YearInfo {
+    pub year: i64,
+    pub leap_year: bool,
+    /// Number of days of the year
+    pub days: i64,
+    /// Months of the year with their numbers of days, in calendar order
+    pub month_lengths: Vec<(i64, i64)>,
+}
+
+impl YearInfo {
+    /// Returns the number of months of the year.
+    pub fn months(&self) -> i64 {
+        return self.month_lengths.len() as i64;
+    }
+
+    /// Returns the number of days of a given month, or `None` if the year
+    /// has no such month.
+    pub fn month_length(&self, month: i64) -> Option<i64> {
+        return self
+            .month_lengths
+            .iter()
+            .find(|(m, _)| *m == month)
+            .map(|(_, days)| *days);
+    }
+}
+
+/// Evaluate an expression for the date type of a list whose calendar has a
+/// given name (see [`CalendarInfo::name`](crate::system::CalendarInfo)),
+/// with the date type bound to a type alias, or evaluate a default
+/// expression if no date type of the list matches.
+macro_rules! dispatch {
+    ($calendar:expr, [$($t:ty),*], |$c:ident| $body:expr, $default:expr) => {
+        'dispatch: {
+            let calendar: &str = $calendar;
+            $(
+                if calendar == <$t as $crate::system::CalendarSystem>::INFO.name {
+                    type $c = $t;
+                    break 'dispatch $body;
+                }
+            )*
+            $default
+        }
+    };
+}
+
+/// Dispatch over all date types (see [`dispatch`]).
+macro_rules! dispatch_all {
+    ($calendar:expr, |$c:ident| $body:expr, $default:expr) => {
+        $crate::utility::dispatch!(
+            $calendar,
+            [
+                Gregorian,
+                Iso,
+                Ordinal,
+                Julian,
+                Islamic,
+                UmmAlQura,
+                Hebrew,
+                MayanLongCount,
+                MayanHaab,
+                MayanTzolkin,
+                French,
+                OldHinduSolar,
+                OldHinduLunar
+            ],
+            |$c| $body,
+            $default
+        )
+    };
+}
+
+/// Dispatch over the date types supporting month arithmetic (see
+/// [`MonthArithmetic`]).
+macro_rules! dispatch_months {
+    ($calendar:expr, |$c:ident| $body:expr, $default:expr) => {
+        dispatch!(
+            $calendar,
+            [Gregorian, Julian, Islamic, UmmAlQura, Hebrew, French],
+            |$c| $body,
+            $default
+        )
+    };
+}
+
+pub(crate) use {dispatch, dispatch_all};
+
 // Date
+//
+// Boolean flags of a date (such as the leap month flag of Old Hindu lunar
+// dates) are stored as components with value 0 (false) or 1 (true), and are
+// listed in FLAG_COMPONENTS.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Date {
     pub calendar: String,
     pub components: Vec<i64>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub component_names: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
     pub month_names: Vec<String>,
 }
 
-/// Implement fmt::Display trait for [`Date`]
+/// Implement fmt::Display trait for [`Date`]. Dates are displayed in the
+/// canonical form "calendar:component-component-...", e.g. "hebrew:5784-1-15",
+/// which can be parsed back into a [`Date`] with [`str::parse`].
 impl fmt::Display for Date {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}: {:?}", &self.calendar, &self.components)
+        let components: Vec<String> = self.components.iter().map(|c| c.to_string()).collect();
+        write!(f, "{}:{}", &self.calendar, components.join("-"))
     }
 }
 
@@ -64,28 +536,73 @@ impl Date {
         }
     }
 
+    /// Returns the current date (UTC, according to the system clock) with the
+    /// calendar representation specified in `calendar` (see
+    /// [`date_from_absolute`]).
+    #[cfg(feature = "clock")]
+    pub fn today(calendar: &str) -> Date {
+        date_from_absolute(crate::interop::absolute_today(), calendar)
+    }
+
     /// Convert [`Date`] to boxed Date-type (e.g. boxed Gregorian date)
-    pub fn to_calendar_date(&self) -> Box<dyn Calendar> {
+    pub fn to_calendar_date(&self) -> Box<dyn dynamic::DynCalendar> {
         let date = self.clone();
-        match self.calendar.as_str() {
-            "gregorian" => Box::new(gregorian_from_date(date)),
-            "iso" => Box::new(iso_from_date(date)),
-            "julian" => Box::new(julian_from_date(date)),
-            "islamic" => Box::new(islamic_from_date(date)),
-            "hebrew" => Box::new(hebrew_from_date(date)),
-            "mayanLongCount" => Box::new(mayan_long_count_from_date(date)),
-            "mayanHaab" => Box::new(mayan_haab_from_date(date)),
-            "mayanTzolkin" => Box::new(mayan_tzolkin_from_date(date)),
-            "french" => Box::new(french_from_date(date)),
-            "oldHinduSolar" => Box::new(old_hindu_solar_from_date(date)),
-            "oldHinduLunar" => Box::new(old_hindu_lunar_from_date(date)),
-            _ => Box::new(gregorian_from_date(date)),
-        }
+        return dispatch_all!(
+            self.calendar.as_str(),
+            |C| Box::new(C::from_date(date)) as Box<dyn dynamic::DynCalendar>,
+            Box::new(Gregorian::from_date(date))
+        );
     }
 
-    /// Convert [`Date`] to absolute (fixed) date
-    pub fn to_absolute(&self) -> i64 {
-        self.to_calendar_date().to_absolute()
+    /// Convert [`Date`] to boxed Date-type, or return an error if the
+    /// calendar is unknown or the components do not match the calendar.
+    pub fn try_to_calendar_date(&self) -> Result<Box<dyn dynamic::DynCalendar>, CalendarError> {
+        let date = self.clone();
+        return dispatch_all!(
+            self.calendar.as_str(),
+            |C| Ok(Box::new(C::try_from(date)?) as Box<dyn dynamic::DynCalendar>),
+            Err(CalendarError::UnknownCalendar(self.calendar.clone()))
+        );
+    }
+
+    /// Convert [`Date`] to absolute (fixed) date without validating its
+    /// components, reading dates of unknown calendars as Gregorian dates.
+    /// Result contains `None` if the date cannot be related to an absolute
+    /// date (Mayan Haab and Tzolkin dates, and Old Hindu lunar dates that do
+    /// not exist).
+    #[deprecated(since = "0.6.0", note = "use `Date::try_to_absolute` instead")]
+    pub fn to_absolute(&self) -> Option<i64> {
+        return self.absolute_unchecked();
+    }
+
+    /// Convert [`Date`] to absolute (fixed) date without validating it (see
+    /// [`Date::to_absolute`]).
+    pub(crate) fn absolute_unchecked(&self) -> Option<i64> {
+        return self.to_calendar_date().absolute_unchecked();
+    }
+
+    /// Convert [`Date`] to absolute (fixed) date, or return an error if the
+    /// date is malformed, does not exist, lies outside the range supported
+    /// by its calendar (see the `*_YEAR_RANGE` statics of the calendar
+    /// modules), or has no absolute date (Mayan Haab and Tzolkin dates).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::utility::Date;
+    ///
+    /// let date = |components: Vec<i64>| Date::new("gregorian", components, vec![], vec![]);
+    /// assert_eq!(date(vec![1, 1, 1]).try_to_absolute(), Ok(1));
+    /// assert!(date(vec![2023, 2, 29]).try_to_absolute().is_err());
+    /// assert!(date(vec![i64::MAX, 1, 1]).try_to_absolute().is_err());
+    /// ```
+    pub fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        let date = self.clone();
+        return dispatch_all!(
+            self.calendar.as_str(),
+            |C| C::try_from(date)?.try_to_absolute(),
+            Err(CalendarError::UnknownCalendar(self.calendar.clone()))
+        );
     }
 
     /// Convert a given Date into a Date with the calendar representation
@@ -94,8 +611,10 @@ impl Date {
     /// Currently supports the following calendars:
     /// * `"gregorian"`
     /// * `"iso"`
+    /// * `"ordinal"`
     /// * `"julian"`
     /// * `"islamic"`
+    /// * "`ummAlQura`"
     /// * `"hebrew"`
     /// * "`mayanLongCount`"
     /// * "`mayanHaab`"
@@ -103,22 +622,285 @@ impl Date {
     /// * "`french`"
     /// * "`oldHinduSolar`"
     /// * "`oldHinduLunar`"
-    pub fn convert_to(&self, calendar: &str) -> Date {
-        let date = self.to_absolute();
-        match calendar {
-            "gregorian" => gregorian_from_absolute(date).to_date(),
-            "iso" => iso_from_absolute(date).to_date(),
-            "julian" => julian_from_absolute(date).to_date(),
-            "islamic" => islamic_from_absolute(date).to_date(),
-            "hebrew" => hebrew_from_absolute(date).to_date(),
-            "mayanLongCount" => mayan_long_count_from_absolute(date).to_date(),
-            "mayanHaab" => mayan_haab_from_absolute(date).to_date(),
-            "mayanTzolkin" => mayan_tzolkin_from_absolute(date).to_date(),
-            "french" => french_from_absolute(date).to_date(),
-            "oldHinduSolar" => old_hindu_solar_from_absolute(date).to_date(),
-            "oldHinduLunar" => old_hindu_lunar_from_absolute(date).to_date(),
-            _ => gregorian_from_absolute(date).to_date(),
+    ///
+    /// Returns an error if the date cannot be related to an absolute date
+    /// (see [`Date::try_to_absolute`]). Dates outside the range supported by
+    /// the target calendar are clamped to the range, and dates of unknown
+    /// target calendars are returned as Gregorian dates; see
+    /// [`Date::try_convert_to`] for an alternative returning an error
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::utility::Date;
+    ///
+    /// let date: Date = "gregorian:2024-2-29".parse().unwrap();
+    /// assert_eq!(date.convert_to("hebrew").unwrap().to_string(), "hebrew:5784-12-20");
+    ///
+    /// let date: Date = "gregorian:2023-2-29".parse().unwrap();
+    /// assert!(date.convert_to("hebrew").is_err());
+    ///
+    /// let date = Date::new("hebrew", vec![i64::MAX, 1, 1], vec![], vec![]);
+    /// assert!(date.convert_to("gregorian").is_err());
+    ///
+    /// let haab: Date = "mayanHaab:8-13".parse().unwrap();
+    /// assert!(haab.convert_to("gregorian").is_err());
+    /// ```
+    pub fn convert_to(&self, calendar: &str) -> Result<Date, CalendarError> {
+        return Ok(date_from_absolute(self.try_to_absolute()?, calendar));
+    }
+
+    /// Convert a given Date into a Date with the calendar representation
+    /// specified in `calendar`, or return an error if either date cannot be
+    /// represented (see [`Date::try_to_absolute`] and
+    /// [`try_date_from_absolute`]).
+    pub fn try_convert_to(&self, calendar: &str) -> Result<Date, CalendarError> {
+        try_date_from_absolute(self.try_to_absolute()?, calendar)
+    }
+
+    /// Returns the day of the week of a [`Date`], or an error if the date
+    /// cannot be related to an absolute date (see [`Date::try_to_absolute`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{utility::Date, weekday::Weekday};
+    ///
+    /// let date: Date = "islamic:1445-9-1".parse().unwrap();
+    /// assert_eq!(date.weekday(), Ok(Weekday::Monday));
+    ///
+    /// let date = Date::new("islamic", vec![i64::MAX, 1, 1], vec![], vec![]);
+    /// assert!(date.weekday().is_err());
+    /// ```
+    pub fn weekday(&self) -> Result<Weekday, CalendarError> {
+        return Ok(weekday_from_absolute(self.try_to_absolute()?));
+    }
+
+    /// Returns the names of the components of the date. If the date has no
+    /// component names (e.g. because they were omitted when deserializing
+    /// it), the names of the calendar's components are returned.
+    fn names(&self) -> Vec<String> {
+        if !self.component_names.is_empty() {
+            return self.component_names.clone();
+        }
+        return calendar_info(&self.calendar)
+            .map(|info| info.component_names.iter().map(|s| s.to_string()).collect())
+            .unwrap_or_default();
+    }
+
+    /// Returns the value of the component with a given name (e.g. "month"),
+    /// or `None` if the date has no such component.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{hebrew::Hebrew, utility::Calendar};
+    /// use std::collections::HashMap;
+    ///
+    /// let d = Hebrew { year: 5784, month: 1, day: 15 }.to_date();
+    /// assert_eq!(d.component("month"), Some(1));
+    /// assert_eq!(d.component("week"), None);
+    ///
+    /// let map: HashMap<String, i64> = d.components_map().into_iter().collect();
+    /// assert_eq!(map["year"], 5784);
+    /// assert_eq!(
+    ///     "mayanLongCount:9-12-11-16-0".parse::<calendars::utility::Date>().unwrap().components_map(),
+    ///     [("baktun", 9), ("katun", 12), ("tun", 11), ("uinal", 16), ("kin", 0)]
+    ///         .map(|(name, value)| (name.to_string(), value))
+    /// );
+    /// ```
+    pub fn component(&self, name: &str) -> Option<i64> {
+        return self
+            .names()
+            .iter()
+            .position(|n| n == name)
+            .and_then(|i| self.components.get(i).copied());
+    }
+
+    /// Returns the (name, value) pairs of the components of the date, in the
+    /// order of the components.
+    pub fn components_map(&self) -> Vec<(String, i64)> {
+        return self
+            .names()
+            .into_iter()
+            .zip(self.components.iter().copied())
+            .collect();
+    }
+
+    /// Returns the [`Date`] a given number of days after (or, if negative,
+    /// before) the date. Old Hindu lunar dates that do not exist are
+    /// returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{hindu::OldHinduLunar, utility::Calendar};
+    ///
+    /// let date = OldHinduLunar { year: 5125, month: 2, leap_month: false, day: 24 }.to_date();
+    /// assert_eq!(date.add_days(1).to_string(), "oldHinduLunar:5125-2-0-25");
+    /// let nonexistent = OldHinduLunar { year: 5000, month: 3, leap_month: true, day: 5 }.to_date();
+    /// assert_eq!(nonexistent.add_days(1), nonexistent);
+    /// ```
+    pub fn add_days(&self, days: i64) -> Date {
+        let date = self.clone();
+        if self.calendar == OldHinduLunar::INFO.name {
+            return match self.absolute_unchecked() {
+                Some(absolute_date) => {
+                    OldHinduLunar::from_absolute(absolute_date.saturating_add(days)).to_date()
+                }
+                None => date,
+            };
+        }
+        return dispatch!(
+            self.calendar.as_str(),
+            [
+                Gregorian,
+                Iso,
+                Ordinal,
+                Julian,
+                Islamic,
+                UmmAlQura,
+                Hebrew,
+                MayanLongCount,
+                MayanHaab,
+                MayanTzolkin,
+                French,
+                OldHinduSolar
+            ],
+            |C| C::from_date(date).add_days(days).to_date(),
+            date
+        );
+    }
+
+    /// Returns the [`Date`] a given number of days after (or, if negative,
+    /// before) the date, or an error if the date does not exist or the
+    /// resulting date lies outside the range of its calendar (see
+    /// [`FixedConvertible::checked_add_days`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{gregorian::Gregorian, utility::Calendar};
+    ///
+    /// let date = Gregorian::new(2024, 1, 1).to_date();
+    /// assert_eq!(date.checked_add_days(60), Ok(Gregorian::new(2024, 3, 1).to_date()));
+    /// assert!(date.checked_add_days(i64::MAX).is_err());
+    /// assert_eq!(date.add_days(i64::MAX), Gregorian::new(1_000_000_000_000_000, 12, 31).to_date());
+    /// ```
+    pub fn checked_add_days(&self, days: i64) -> Result<Date, CalendarError> {
+        let date = self.clone();
+        return dispatch!(
+            self.calendar.as_str(),
+            [MayanHaab, MayanTzolkin],
+            |C| Ok(C::try_from(date)?.add_days(days).to_date()),
+            try_date_from_absolute(self.try_to_absolute()?.saturating_add(days), &self.calendar)
+        );
+    }
+
+    /// Returns the [`Date`] a given number of months after (or, if negative,
+    /// before) the date, resolving non-existent days according to
+    /// `overflow`.
+    ///
+    /// Only supported for the Gregorian, Julian, Islamic, Hebrew, and French
+    /// Revolutionary calendars; dates of other calendars are returned
+    /// unchanged, as are dates that do not exist. Results outside the range
+    /// supported by the calendar saturate at its first or last date (see
+    /// [`MonthArithmetic::add_months`]).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{arithmetic::Overflow, gregorian::Gregorian, utility::Calendar};
+    ///
+    /// let date = Gregorian::new(2024, 13, 1).to_date();
+    /// assert_eq!(date.add_months(1, Overflow::Clamp), date);
+    ///
+    /// let date = Gregorian::new(2024, 1, 1).to_date();
+    /// assert_eq!(date.add_years(i64::MAX, Overflow::Clamp), Gregorian::new(1_000_000_000_000_000, 12, 31).to_date());
+    /// ```
+    pub fn add_months(&self, months: i64, overflow: Overflow) -> Date {
+        let date = self.clone();
+        return dispatch_months!(
+            self.calendar.as_str(),
+            |C| C::from_date(date).add_months(months, overflow).to_date(),
+            date
+        );
+    }
+
+    /// Returns the [`Date`] a given number of years after (or, if negative,
+    /// before) the date, resolving non-existent months and days according to
+    /// `overflow`.
+    ///
+    /// Only supported for the Gregorian, Julian, Islamic, Hebrew, and French
+    /// Revolutionary calendars; dates of other calendars are returned
+    /// unchanged, as are dates that do not exist. Results outside the range
+    /// supported by the calendar saturate at its first or last date (see
+    /// [`MonthArithmetic::add_years`]).
+    pub fn add_years(&self, years: i64, overflow: Overflow) -> Date {
+        let date = self.clone();
+        return dispatch_months!(
+            self.calendar.as_str(),
+            |C| C::from_date(date).add_years(years, overflow).to_date(),
+            date
+        );
+    }
+
+    /// Returns the anniversary of the [`Date`] in a given year of its
+    /// calendar, resolving dates that do not exist in that year according to
+    /// `policy` (see [`MonthArithmetic::anniversary`]). Result contains `None`
+    /// if the anniversary is skipped, or if the calendar has no years of
+    /// months (ISO, ordinal, Mayan, and Old Hindu dates).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use calendars::{arithmetic::AnniversaryPolicy, gregorian::Gregorian, utility::Calendar};
+    ///
+    /// let born = Gregorian::new(1996, 2, 29).to_date();
+    /// let birthday = born.anniversary(2023, AnniversaryPolicy::After).unwrap();
+    /// assert_eq!(birthday.to_string(), "gregorian:2023-3-1");
+    /// ```
+    pub fn anniversary(&self, target_year: i64, policy: AnniversaryPolicy) -> Option<Date> {
+        let date = self.clone();
+        return dispatch_months!(
+            self.calendar.as_str(),
+            |C| C::from_date(date)
+                .anniversary(target_year, policy)
+                .map(|d| d.to_date()),
+            None
+        );
+    }
+
+    /// Computes the difference from a [`Date`] to another date of the same
+    /// calendar, or returns an error if the calendars differ or either date
+    /// cannot be related to an absolute date (see [`Date::try_to_absolute`]).
+    ///
+    /// Years and months are only broken down for the Gregorian, Julian,
+    /// Islamic, Hebrew, and French Revolutionary calendars; for all other
+    /// calendars, the difference is expressed in days only.
+    pub fn difference(&self, other: &Date) -> Result<Difference, CalendarError> {
+        if self.calendar != other.calendar {
+            return Err(CalendarError::WrongCalendar {
+                expected: self.calendar.clone(),
+                found: other.calendar.clone(),
+            });
         }
+        let date = self.clone();
+        let other = other.clone();
+        return dispatch_months!(
+            self.calendar.as_str(),
+            |C| C::try_from(date)?.difference(&C::try_from(other)?),
+            {
+                let total_days = other.try_to_absolute()? - date.try_to_absolute()?;
+                Ok(Difference {
+                    total_days,
+                    years: 0,
+                    months: 0,
+                    days: total_days,
+                })
+            }
+        );
     }
 
     /// Creates a date string from a [`Date`]
@@ -127,106 +909,274 @@ impl Date {
     }
 }
 
+/// Creates a [`Date`] with the calendar representation specified in
+/// `calendar` from a given absolute (fixed) date. See [`Date::convert_to`] for
+/// the supported calendars.
+pub fn date_from_absolute(absolute_date: i64, calendar: &str) -> Date {
+    return dispatch_all!(
+        calendar,
+        |C| C::from_absolute(absolute_date).to_date(),
+        gregorian_from_absolute(absolute_date).to_date()
+    );
+}
+
+/// Creates a [`Date`] with the calendar representation specified in
+/// `calendar` from a given absolute (fixed) date, or returns an error if the
+/// calendar is unknown or the absolute date lies outside its range (e.g.
+/// Islamic and French Revolutionary dates before the respective epochs, see
+/// the `*_ABSOLUTE_RANGE` statics of the calendar modules).
+pub fn try_date_from_absolute(absolute_date: i64, calendar: &str) -> Result<Date, CalendarError> {
+    return dispatch_all!(
+        calendar,
+        |C| Ok(C::checked_from_absolute(absolute_date)?.to_date()),
+        Err(CalendarError::UnknownCalendar(calendar.to_string()))
+    );
+}
+
+/// Names of [`Date`] components that represent boolean flags.
+static FLAG_COMPONENTS: [&str; 1] = ["leapMonth"];
+
+/// Checks that a given Date belongs to a given calendar, has the number of
+/// components expected for that calendar, and that its flag components are
+/// either 0 or 1.
+fn check_date(d: &Date, calendar: &str, count: usize) -> Result<(), CalendarError> {
+    if d.calendar != calendar {
+        return Err(CalendarError::WrongCalendar {
+            expected: calendar.to_string(),
+            found: d.calendar.clone(),
+        });
+    }
+    if d.components.len() != count {
+        return Err(CalendarError::ComponentCount {
+            calendar: calendar.to_string(),
+            expected: count,
+            found: d.components.len(),
+        });
+    }
+    for (name, value) in d.component_names.iter().zip(d.components.iter()) {
+        if FLAG_COMPONENTS.contains(&name.as_str()) {
+            check_component(calendar, name, *value, 0, 1)?;
+        }
+    }
+    return Ok(());
+}
+
+/// Conversion of a [`Date`] into a date type without validating it (missing
+/// components are 0, see [`nth_component`])
+trait FromDate {
+    fn from_date(d: Date) -> Self;
+}
+
+/// Implement FromDate and TryFrom<Date> for a date type, validating calendar
+/// and number of components (see [`CalendarInfo`](crate::system::CalendarInfo))
+/// before converting.
+macro_rules! impl_try_from_date {
+    ($($t:ty, $from_date:ident);* $(;)?) => {
+        $(
+            impl FromDate for $t {
+                fn from_date(d: Date) -> Self {
+                    return $from_date(d);
+                }
+            }
+
+            impl TryFrom<Date> for $t {
+                type Error = CalendarError;
+
+                fn try_from(d: Date) -> Result<Self, Self::Error> {
+                    let info = <$t>::INFO;
+                    check_date(&d, info.name, info.component_names.len())?;
+                    return Ok($from_date(d));
+                }
+            }
+        )*
+    };
+}
+
+impl_try_from_date!(
+    Gregorian, gregorian_from_date;
+    Iso, iso_from_date;
+    Ordinal, ordinal_from_date;
+    Julian, julian_from_date;
+    Islamic, islamic_from_date;
+    UmmAlQura, umm_al_qura_from_date;
+    Hebrew, hebrew_from_date;
+    MayanLongCount, mayan_long_count_from_date;
+    MayanHaab, mayan_haab_from_date;
+    MayanTzolkin, mayan_tzolkin_from_date;
+    French, french_from_date;
+    OldHinduSolar, old_hindu_solar_from_date;
+    OldHinduLunar, old_hindu_lunar_from_date;
+);
+
+/// Returns the component of a [`Date`] at a given index, or 0 if the Date has
+/// fewer components (see [`check_date`]).
+fn nth_component(d: &Date, index: usize) -> i64 {
+    return d.components.get(index).copied().unwrap_or(0);
+}
+
 /// Convert Date into Gregorian date.
 fn gregorian_from_date(d: Date) -> Gregorian {
     return Gregorian {
-        year: d.components[0],
-        month: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
     };
 }
 
 /// Convert Date into ISO week date.
 fn iso_from_date(d: Date) -> Iso {
     return Iso {
-        year: d.components[0],
-        week: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        week: nth_component(&d, 1),
+        day: nth_component(&d, 2),
+    };
+}
+
+/// Convert Date into ordinal date.
+fn ordinal_from_date(d: Date) -> Ordinal {
+    return Ordinal {
+        year: nth_component(&d, 0),
+        day: nth_component(&d, 1),
     };
 }
 
 /// Convert Date into Julian date.
 fn julian_from_date(d: Date) -> Julian {
     return Julian {
-        year: d.components[0],
-        month: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
     };
 }
 
 /// Convert Date into Islamic date.
 fn islamic_from_date(d: Date) -> Islamic {
     return Islamic {
-        year: d.components[0],
-        month: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
+    };
+}
+
+/// Convert Date into Umm al-Qura date.
+fn umm_al_qura_from_date(d: Date) -> UmmAlQura {
+    return UmmAlQura {
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
     };
 }
 
 /// Convert Date into Hebrew date.
 fn hebrew_from_date(d: Date) -> Hebrew {
     return Hebrew {
-        year: d.components[0],
-        month: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
     };
 }
 
 /// Convert Date into Mayan Long Count.
 fn mayan_long_count_from_date(d: Date) -> MayanLongCount {
     return MayanLongCount {
-        baktun: d.components[0],
-        katun: d.components[1],
-        tun: d.components[2],
-        uinal: d.components[3],
-        kin: d.components[4],
+        baktun: nth_component(&d, 0),
+        katun: nth_component(&d, 1),
+        tun: nth_component(&d, 2),
+        uinal: nth_component(&d, 3),
+        kin: nth_component(&d, 4),
     };
 }
 
 /// Convert Date into Mayan Haab date.
 fn mayan_haab_from_date(d: Date) -> MayanHaab {
     return MayanHaab {
-        day: d.components[0],
-        month: d.components[1],
+        day: nth_component(&d, 0),
+        month: nth_component(&d, 1),
     };
 }
 
 /// Convert Date into Mayan Tzolkin date.
 fn mayan_tzolkin_from_date(d: Date) -> MayanTzolkin {
     return MayanTzolkin {
-        number: d.components[0],
-        name: d.components[1],
+        number: nth_component(&d, 0),
+        name: nth_component(&d, 1),
     };
 }
 
 /// Convert Date into French Revolutionary date.
 fn french_from_date(d: Date) -> French {
     return French {
-        year: d.components[0],
-        month: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
     };
 }
 
 /// Convert Date into Old Hindu Solar date.
 fn old_hindu_solar_from_date(d: Date) -> OldHinduSolar {
     return OldHinduSolar {
-        year: d.components[0],
-        month: d.components[1],
-        day: d.components[2],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        day: nth_component(&d, 2),
     };
 }
 
 /// Convert Date into Old Hindu Lunar date.
 fn old_hindu_lunar_from_date(d: Date) -> OldHinduLunar {
     return OldHinduLunar {
-        year: d.components[0],
-        month: d.components[1],
-        leap_month: d.components[2] == 1,
-        day: d.components[3],
+        year: nth_component(&d, 0),
+        month: nth_component(&d, 1),
+        leap_month: nth_component(&d, 2) == 1,
+        day: nth_component(&d, 3),
+    };
+}
+
+/// Returns the name with a given number (counting from 1), or `None` if no
+/// such name exists.
+fn nth_name(names: &[&'static str], number: i64) -> Option<&'static str> {
+    let index = usize::try_from(number).ok()?.checked_sub(1)?;
+    return names.get(index).copied();
+}
+
+/// Returns the name with a given number (counting from 1), or the number
+/// itself if no such name exists (e.g. for invalid dates).
+fn name_or_number(names: &[&'static str], number: i64) -> String {
+    return match nth_name(names, number) {
+        Some(name) => name.to_string(),
+        None => number.to_string(),
+    };
+}
+
+/// Implement fmt::Display trait for date types, delegating to
+/// [`Calendar::format`].
+macro_rules! impl_display {
+    ($($t:ty),*) => {
+        $(
+            impl fmt::Display for $t {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}", self.format())
+                }
+            }
+        )*
     };
 }
 
+impl_display!(
+    Gregorian,
+    Iso,
+    Ordinal,
+    Julian,
+    Islamic,
+    UmmAlQura,
+    Hebrew,
+    MayanLongCount,
+    MayanHaab,
+    MayanTzolkin,
+    French,
+    OldHinduSolar,
+    OldHinduLunar
+);
+
 impl Calendar for Gregorian {
     fn to_date(&self) -> Date {
         let month_names = GREGORIAN_MONTH_NAMES
@@ -245,17 +1195,39 @@ impl Calendar for Gregorian {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_gregorian(self.clone());
+    fn from_absolute(absolute_date: i64) -> Self {
+        return gregorian_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_gregorian_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_gregorian(*self);
     }
 
     fn format(&self) -> String {
         return self.day.to_string()
             + " "
-            + GREGORIAN_MONTH_NAMES[(&self.month - 1) as usize]
+            + &name_or_number(&GREGORIAN_MONTH_NAMES, self.month)
             + " "
             + &self.year.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&GREGORIAN_MONTH_NAMES, self.month);
+    }
+}
+
+impl FixedConvertible for Gregorian {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_gregorian(*self);
+    }
 }
 
 impl Calendar for Iso {
@@ -272,12 +1244,73 @@ impl Calendar for Iso {
         );
     }
 
+    fn from_absolute(absolute_date: i64) -> Self {
+        return iso_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_iso_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_iso(*self);
+    }
+
+    fn format(&self) -> String {
+        return format!(
+            "{}-W{:02}-{}",
+            format_iso_year(self.year),
+            self.week,
+            self.day
+        );
+    }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+}
+
+impl FixedConvertible for Iso {
     fn to_absolute(&self) -> i64 {
-        return absolute_from_iso(self.clone());
+        return absolute_from_iso(*self);
+    }
+}
+
+impl Calendar for Ordinal {
+    fn to_date(&self) -> Date {
+        let component_names = ["year", "day"].iter().map(|s| s.to_string()).collect();
+        return Date::new(
+            "ordinal",
+            [self.year, self.day].to_vec(),
+            component_names,
+            [].to_vec(),
+        );
+    }
+
+    fn from_absolute(absolute_date: i64) -> Self {
+        return ordinal_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_ordinal_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_ordinal(*self);
     }
 
     fn format(&self) -> String {
-        return self.year.to_string() + "-W" + &self.week.to_string() + "-" + &self.day.to_string();
+        return format!("{}-{:03}", format_iso_year(self.year), self.day);
+    }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+}
+
+impl FixedConvertible for Ordinal {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_ordinal(*self);
     }
 }
 
@@ -299,17 +1332,39 @@ impl Calendar for Julian {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_julian(self.clone());
+    fn from_absolute(absolute_date: i64) -> Self {
+        return julian_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_julian_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_julian(*self);
     }
 
     fn format(&self) -> String {
         return self.day.to_string()
             + " "
-            + GREGORIAN_MONTH_NAMES[(&self.month - 1) as usize]
+            + &name_or_number(&GREGORIAN_MONTH_NAMES, self.month)
             + " "
             + &self.year.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&GREGORIAN_MONTH_NAMES, self.month);
+    }
+}
+
+impl FixedConvertible for Julian {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_julian(*self);
+    }
 }
 
 impl Calendar for Islamic {
@@ -327,17 +1382,89 @@ impl Calendar for Islamic {
         );
     }
 
+    fn from_absolute(absolute_date: i64) -> Self {
+        return islamic_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_islamic_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_islamic(*self);
+    }
+
+    fn format(&self) -> String {
+        return self.day.to_string()
+            + " "
+            + &name_or_number(&ISLAMIC_MONTH_NAMES, self.month)
+            + " "
+            + &self.year.to_string();
+    }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&ISLAMIC_MONTH_NAMES, self.month);
+    }
+}
+
+impl FixedConvertible for Islamic {
     fn to_absolute(&self) -> i64 {
-        return absolute_from_islamic(self.clone());
+        return absolute_from_islamic(*self);
+    }
+}
+
+impl Calendar for UmmAlQura {
+    fn to_date(&self) -> Date {
+        let month_names = ISLAMIC_MONTH_NAMES.iter().map(|s| s.to_string()).collect();
+        let component_names = ["year", "month", "day"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        return Date::new(
+            "ummAlQura",
+            [self.year, self.month, self.day].to_vec(),
+            component_names,
+            month_names,
+        );
+    }
+
+    fn from_absolute(absolute_date: i64) -> Self {
+        return umm_al_qura_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_umm_al_qura_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_umm_al_qura(*self);
     }
 
     fn format(&self) -> String {
         return self.day.to_string()
             + " "
-            + ISLAMIC_MONTH_NAMES[(&self.month - 1) as usize]
+            + &name_or_number(&ISLAMIC_MONTH_NAMES, self.month)
             + " "
             + &self.year.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&ISLAMIC_MONTH_NAMES, self.month);
+    }
+}
+
+impl FixedConvertible for UmmAlQura {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_umm_al_qura(*self);
+    }
 }
 
 impl Calendar for Hebrew {
@@ -355,17 +1482,43 @@ impl Calendar for Hebrew {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_hebrew(self.clone());
+    fn from_absolute(absolute_date: i64) -> Self {
+        return hebrew_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_hebrew_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_hebrew(*self);
     }
 
     fn format(&self) -> String {
-        let month_name = match true {
-            _ if hebrew_leap_year(self.year) && self.month == 12 => "Adar I",
-            _ if hebrew_leap_year(self.year) && self.month == 13 => "Adar II",
-            _ => HEBREW_MONTH_NAMES[(self.month - 1) as usize],
+        let month_name = match self.month_name() {
+            Some(name) => name.to_string(),
+            None => self.month.to_string(),
         };
-        return self.day.to_string() + " " + month_name + " " + &self.year.to_string();
+        return self.day.to_string() + " " + &month_name + " " + &self.year.to_string();
+    }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return match self.month {
+            12 if hebrew_leap_year(self.year) => Some("Adar I"),
+            13 if hebrew_leap_year(self.year) => Some("Adar II"),
+            1..=12 => nth_name(&HEBREW_MONTH_NAMES, self.month).map(str::trim),
+            _ => None,
+        };
+    }
+}
+
+impl FixedConvertible for Hebrew {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_hebrew(*self);
     }
 }
 
@@ -384,8 +1537,16 @@ impl Calendar for MayanLongCount {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_mayan_long_count(self.clone());
+    fn from_absolute(absolute_date: i64) -> Self {
+        return mayan_long_count_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_mayan_long_count_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_mayan_long_count(*self);
     }
 
     fn format(&self) -> String {
@@ -399,11 +1560,21 @@ impl Calendar for MayanLongCount {
             + "."
             + &self.kin.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+}
+
+impl FixedConvertible for MayanLongCount {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_mayan_long_count(*self);
+    }
 }
 
 impl Calendar for MayanHaab {
     fn to_date(&self) -> Date {
-        let month_names: Vec<String> = vec![];
+        let month_names = MAYAN_MONTH_NAMES.iter().map(|s| s.to_string()).collect();
         let component_names = ["day", "month"].iter().map(|s| s.to_string()).collect();
         return Date::new(
             "mayanHaab",
@@ -413,12 +1584,31 @@ impl Calendar for MayanHaab {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        panic!()
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return None;
+    }
+
+    fn from_absolute(absolute_date: i64) -> Self {
+        return mayan_haab_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_mayan_haab_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        MayanHaab::try_new(self.day, self.month)?;
+        return Err(CalendarError::NotConvertible {
+            calendar: Self::INFO.name.to_string(),
+        });
     }
 
     fn format(&self) -> String {
-        return self.day.to_string() + " " + MAYAN_MONTH_NAMES[(&self.month - 1) as usize];
+        return self.day.to_string() + " " + &name_or_number(&MAYAN_MONTH_NAMES, self.month);
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&MAYAN_MONTH_NAMES, self.month);
     }
 }
 
@@ -434,12 +1624,27 @@ impl Calendar for MayanTzolkin {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        panic!()
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return None;
+    }
+
+    fn from_absolute(absolute_date: i64) -> Self {
+        return mayan_tzolkin_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_mayan_tzolkin_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        MayanTzolkin::try_new(self.number, self.name)?;
+        return Err(CalendarError::NotConvertible {
+            calendar: Self::INFO.name.to_string(),
+        });
     }
 
     fn format(&self) -> String {
-        return self.number.to_string() + " " + MAYAN_TZOLKIN_NAMES[(&self.name - 1) as usize];
+        return self.number.to_string() + " " + &name_or_number(&MAYAN_TZOLKIN_NAMES, self.name);
     }
 }
 
@@ -458,17 +1663,49 @@ impl Calendar for French {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_french(self.clone());
+    fn from_absolute(absolute_date: i64) -> Self {
+        return french_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_french_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_french(*self);
     }
 
     fn format(&self) -> String {
         return self.day.to_string()
             + " "
-            + FRENCH_MONTH_NAMES[(&self.month - 1) as usize]
+            + &name_or_number(&FRENCH_MONTH_NAMES, self.month)
             + " "
             + &self.year.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&FRENCH_MONTH_NAMES, self.month);
+    }
+
+    fn weekday_name(&self) -> Option<&'static str> {
+        return if self.month == 13 {
+            nth_name(&SANSCULOTTIDES, self.day)
+        } else if (1..=30).contains(&self.day) {
+            nth_name(&FRENCH_DAY_NAMES, (self.day - 1) % 10 + 1)
+        } else {
+            None
+        };
+    }
+}
+
+impl FixedConvertible for French {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_french(*self);
+    }
 }
 
 impl Calendar for OldHinduSolar {
@@ -489,17 +1726,39 @@ impl Calendar for OldHinduSolar {
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_old_hindu_solar(self.clone());
+    fn from_absolute(absolute_date: i64) -> Self {
+        return old_hindu_solar_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_old_hindu_solar_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_old_hindu_solar(*self);
     }
 
     fn format(&self) -> String {
         return self.day.to_string()
             + " "
-            + HINDU_SOLAR_MONTH_NAMES[(&self.month - 1) as usize]
+            + &name_or_number(&HINDU_SOLAR_MONTH_NAMES, self.month)
             + " "
             + &self.year.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return Some(self.to_absolute());
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&HINDU_SOLAR_MONTH_NAMES, self.month);
+    }
+}
+
+impl FixedConvertible for OldHinduSolar {
+    fn to_absolute(&self) -> i64 {
+        return absolute_from_old_hindu_solar(*self);
+    }
 }
 
 impl Calendar for OldHinduLunar {
@@ -513,22 +1772,185 @@ impl Calendar for OldHinduLunar {
             .map(|s| s.to_string())
             .collect();
         return Date::new(
-            "gregorian",
-            [self.year, self.month, self.day].to_vec(),
+            "oldHinduLunar",
+            [self.year, self.month, self.leap_month as i64, self.day].to_vec(),
             component_names,
             month_names,
         );
     }
 
-    fn to_absolute(&self) -> i64 {
-        return absolute_from_old_hindu_lunar(self.clone()).unwrap();
+    fn from_absolute(absolute_date: i64) -> Self {
+        return old_hindu_lunar_from_absolute(absolute_date);
+    }
+
+    fn checked_from_absolute(absolute_date: i64) -> Result<Self, CalendarError> {
+        return checked_old_hindu_lunar_from_absolute(absolute_date);
+    }
+
+    fn try_to_absolute(&self) -> Result<i64, CalendarError> {
+        return checked_absolute_from_old_hindu_lunar(*self);
     }
 
     fn format(&self) -> String {
         return self.day.to_string()
             + " "
-            + HINDU_LUNAR_MONTH_NAMES[(&self.month - 1) as usize]
+            + &name_or_number(&HINDU_LUNAR_MONTH_NAMES, self.month)
             + " "
             + &self.year.to_string();
     }
+
+    fn absolute_unchecked(&self) -> Option<i64> {
+        return absolute_from_old_hindu_lunar(*self);
+    }
+
+    fn month_name(&self) -> Option<&'static str> {
+        return nth_name(&HINDU_LUNAR_MONTH_NAMES, self.month);
+    }
+}
+
+impl MonthLength for Gregorian {
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self {
+        return Gregorian::new(year, month, day);
+    }
+
+    fn year_month_day(&self) -> (i64, i64, i64) {
+        return (self.year, self.month, self.day);
+    }
+
+    fn last_day_of_month(month: i64, year: i64) -> i64 {
+        return last_day_of_gregorian_month(month, year);
+    }
+
+    fn leap_year(year: i64) -> bool {
+        return gregorian_leap_year(year);
+    }
+}
+
+impl MonthLength for Julian {
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self {
+        return Julian::new(year, month, day);
+    }
+
+    fn year_month_day(&self) -> (i64, i64, i64) {
+        return (self.year, self.month, self.day);
+    }
+
+    fn last_day_of_month(month: i64, year: i64) -> i64 {
+        return last_day_of_julian_month(month, year);
+    }
+
+    fn leap_year(year: i64) -> bool {
+        return julian_leap_year(year);
+    }
+}
+
+impl MonthLength for Islamic {
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self {
+        return Islamic::new(year, month, day);
+    }
+
+    fn year_month_day(&self) -> (i64, i64, i64) {
+        return (self.year, self.month, self.day);
+    }
+
+    fn last_day_of_month(month: i64, year: i64) -> i64 {
+        return last_day_of_islamic_month(month, year);
+    }
+
+    fn leap_year(year: i64) -> bool {
+        return islamic_leap_year(year);
+    }
+}
+
+impl MonthLength for UmmAlQura {
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self {
+        return UmmAlQura::new(year, month, day);
+    }
+
+    fn year_month_day(&self) -> (i64, i64, i64) {
+        return (self.year, self.month, self.day);
+    }
+
+    fn last_day_of_month(month: i64, year: i64) -> i64 {
+        return last_day_of_umm_al_qura_month(month, year);
+    }
+
+    fn leap_year(year: i64) -> bool {
+        // years of 355 days, as in the arithmetical Islamic calendar
+        return (1..=12)
+            .map(|month| last_day_of_umm_al_qura_month(month, year))
+            .sum::<i64>()
+            == 355;
+    }
+}
+
+impl MonthLength for Hebrew {
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self {
+        return Hebrew::new(year, month, day);
+    }
+
+    fn year_month_day(&self) -> (i64, i64, i64) {
+        return (self.year, self.month, self.day);
+    }
+
+    fn last_day_of_month(month: i64, year: i64) -> i64 {
+        return last_day_of_hebrew_month(month, year);
+    }
+
+    fn last_month_of_year(year: i64) -> i64 {
+        return last_month_of_hebrew_year(year);
+    }
+
+    fn first_month_of_year(_year: i64) -> i64 {
+        return 7;
+    }
+
+    /// Every cycle of 19 years has 235 months.
+    fn months_before_year(year: i64) -> Option<i64> {
+        return i64::try_from((235 * i128::from(year) - 234).div_euclid(19)).ok();
+    }
+
+    /// Hebrew years begin with Tishri (month 7), so Elul (month 6) is
+    /// followed by Tishri of the next year.
+    fn next_month(year: i64, month: i64) -> (i64, i64) {
+        return match month {
+            6 => (year + 1, 7),
+            _ if month == last_month_of_hebrew_year(year) => (year, 1),
+            _ => (year, month + 1),
+        };
+    }
+
+    fn previous_month(year: i64, month: i64) -> (i64, i64) {
+        return match month {
+            7 => (year - 1, 6),
+            1 => (year, last_month_of_hebrew_year(year)),
+            _ => (year, month - 1),
+        };
+    }
+
+    fn leap_year(year: i64) -> bool {
+        return hebrew_leap_year(year);
+    }
+}
+
+impl MonthLength for French {
+    fn from_year_month_day(year: i64, month: i64, day: i64) -> Self {
+        return French::new(year, month, day);
+    }
+
+    fn year_month_day(&self) -> (i64, i64, i64) {
+        return (self.year, self.month, self.day);
+    }
+
+    fn last_day_of_month(month: i64, year: i64) -> i64 {
+        return french_last_day_of_month(month, year);
+    }
+
+    fn last_month_of_year(_year: i64) -> i64 {
+        return 13;
+    }
+
+    fn leap_year(year: i64) -> bool {
+        return french_leap_year(year);
+    }
 }
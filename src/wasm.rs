@@ -0,0 +1,100 @@
+//! Provides JavaScript bindings (requires the `wasm` feature)
+//!
+//! Dates are passed as strings of the form `calendar:c1-c2-...` (see
+//! [`Date`]), absolute (fixed) dates as numbers, and locales as language tags
+//! (e.g. "de").
+
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    error::CalendarError,
+    holidays::{Holiday, HOLIDAYS},
+    locale::Locale,
+    utility::try_date_from_absolute,
+    utility::Date,
+};
+
+/// Converts a JavaScript number to an integer, or returns an error if it is
+/// not an integer that JavaScript represents exactly.
+fn integer_from_number(n: f64) -> Result<i64, CalendarError> {
+    // 2^53 - 1
+    let max_safe_integer = 9007199254740991.0;
+    return if n.fract() == 0.0 && n.abs() <= max_safe_integer {
+        Ok(n as i64)
+    } else {
+        Err(CalendarError::ParseError(format!(
+            "{} is not an integer",
+            n
+        )))
+    };
+}
+
+/// Converts a date string to the calendar representation specified in
+/// `calendar` (e.g. `convertDate("gregorian:2024-5-1", "hebrew")`).
+#[wasm_bindgen(js_name = convertDate)]
+pub fn convert_date(date: &str, calendar: &str) -> Result<String, JsError> {
+    return Ok(date.parse::<Date>()?.try_convert_to(calendar)?.to_string());
+}
+
+/// Returns the date string with the calendar representation specified in
+/// `calendar` of a given absolute (fixed) date.
+#[wasm_bindgen(js_name = dateFromAbsolute)]
+pub fn date_from_absolute(absolute_date: f64, calendar: &str) -> Result<String, JsError> {
+    let absolute_date = integer_from_number(absolute_date)?;
+    return Ok(try_date_from_absolute(absolute_date, calendar)?.to_string());
+}
+
+/// Returns the absolute (fixed) date of a date string.
+#[wasm_bindgen(js_name = absoluteFromDate)]
+pub fn absolute_from_date(date: &str) -> Result<f64, JsError> {
+    return Ok(date.parse::<Date>()?.try_to_absolute()? as f64);
+}
+
+/// Returns the components of a date string (e.g. `[2024, 5, 1]` for
+/// `"gregorian:2024-5-1"`).
+#[wasm_bindgen(js_name = dateComponents)]
+pub fn date_components(date: &str) -> Result<Vec<f64>, JsError> {
+    let date = date.parse::<Date>()?;
+    return Ok(date.components.iter().map(|c| *c as f64).collect());
+}
+
+/// Formats a date string according to a pattern (see
+/// [`Date::format_with_locale`]), using the month and weekday names of a
+/// given locale.
+#[wasm_bindgen(js_name = formatDate)]
+pub fn format_date(date: &str, pattern: &str, locale: &str) -> Result<String, JsError> {
+    let locale = locale.parse::<Locale>()?;
+    return Ok(date.parse::<Date>()?.format_with_locale(pattern, locale)?);
+}
+
+/// Formats a date string in the conventional long form of a given locale
+/// (see [`Date::format_localized`]).
+#[wasm_bindgen(js_name = formatDateLocalized)]
+pub fn format_date_localized(date: &str, locale: &str) -> Result<String, JsError> {
+    let locale = locale.parse::<Locale>()?;
+    return Ok(date.parse::<Date>()?.format_localized(locale));
+}
+
+/// Returns the identifiers of the holidays supported by [`holiday`] (see
+/// [`Holiday::id`]).
+#[wasm_bindgen(js_name = holidayNames)]
+pub fn holiday_names() -> Vec<String> {
+    return HOLIDAYS
+        .iter()
+        .map(|holiday| holiday.id().to_string())
+        .collect();
+}
+
+/// Returns the dates of a given holiday (see [`holiday_names`]) in a given
+/// Gregorian year, with the calendar representation specified in `calendar`.
+/// Some holidays occur twice or not at all in a Gregorian year.
+#[wasm_bindgen]
+pub fn holiday(name: &str, year: f64, calendar: &str) -> Result<Vec<String>, JsError> {
+    let year = integer_from_number(year)?;
+    let holiday = name.parse::<Holiday>()?;
+    return holiday
+        .dates_in_gregorian_year(year)
+        .into_iter()
+        .map(|absolute_date| Ok(try_date_from_absolute(absolute_date, calendar)?.to_string()))
+        .collect();
+}
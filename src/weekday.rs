@@ -0,0 +1,148 @@
+//! Provides the days of the week and functions to compute them
+
+use crate::{gregorian::Gregorian, locale::Locale, math::modulus, utility::MonthLength};
+
+/// Weekday names
+pub static WEEKDAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+/// Day of the week
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Sunday,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+}
+
+impl Weekday {
+    /// Create a weekday from its number (0 = Sunday, ..., 6 = Saturday).
+    /// Numbers outside this range are reduced modulo 7.
+    pub fn from_number(k: i64) -> Self {
+        match modulus(k, 7) {
+            0 => Weekday::Sunday,
+            1 => Weekday::Monday,
+            2 => Weekday::Tuesday,
+            3 => Weekday::Wednesday,
+            4 => Weekday::Thursday,
+            5 => Weekday::Friday,
+            _ => Weekday::Saturday,
+        }
+    }
+
+    /// Returns the number of the weekday (0 = Sunday, ..., 6 = Saturday).
+    pub fn number(&self) -> i64 {
+        return *self as i64;
+    }
+
+    /// Returns the (English) name of the weekday.
+    pub fn name(&self) -> &'static str {
+        return WEEKDAY_NAMES[self.number() as usize];
+    }
+
+    /// Returns the name of the weekday in a given locale.
+    pub fn localized_name(&self, locale: Locale) -> &'static str {
+        return locale.weekday_names()[self.number() as usize];
+    }
+}
+
+/// Returns the day of the week of a given absolute (fixed) date.
+pub fn weekday_from_absolute(absolute_date: i64) -> Weekday {
+    return Weekday::from_number(absolute_date);
+}
+
+/// Computes the absolute date of a given week day in the seven-day interval
+/// ending on date.
+pub const fn kday_on_or_before(absolute_date: i64, k: i64) -> i64 {
+    let offset = (absolute_date.rem_euclid(7) - k.rem_euclid(7)).rem_euclid(7);
+    return absolute_date.saturating_sub(offset);
+}
+
+/// Computes the absolute date of a given week day in the seven-day interval
+/// starting on date.
+pub fn kday_on_or_after(absolute_date: i64, k: i64) -> i64 {
+    return kday_on_or_before(absolute_date.saturating_add(6), k);
+}
+
+/// Computes the absolute date of a given week day nearest to date.
+pub fn kday_nearest(absolute_date: i64, k: i64) -> i64 {
+    return kday_on_or_before(absolute_date.saturating_add(3), k);
+}
+
+/// Computes the absolute date of a given week day strictly after date.
+pub fn kday_after(absolute_date: i64, k: i64) -> i64 {
+    return kday_on_or_before(absolute_date.saturating_add(7), k);
+}
+
+/// Computes the absolute date of a given week day strictly before date.
+pub fn kday_before(absolute_date: i64, k: i64) -> i64 {
+    return kday_on_or_before(absolute_date.saturating_sub(1), k);
+}
+
+/// Computes the absolute (fixed) date of the nth given weekday in a given
+/// month in a given Gregorian year. If n is negative, counts backwards from
+/// the end of the month (e.g. `n = -1` denotes the last such weekday of the
+/// month). Returns `None` if n is zero, the month does not exist, or the
+/// month has fewer than n such weekdays.
+///
+/// # Example
+///
+/// ```
+/// use calendars::{
+///     french::French,
+///     gregorian::{absolute_from_gregorian, Gregorian},
+///     weekday::{nth_kday, nth_kday_in_month, Weekday},
+/// };
+///
+/// // Thanksgiving 2024 is the fourth Thursday of November
+/// let thanksgiving = absolute_from_gregorian(Gregorian::new(2024, 11, 28));
+/// assert_eq!(nth_kday(4, Weekday::Thursday, 11, 2024), Some(thanksgiving));
+/// assert_eq!(nth_kday(-1, Weekday::Thursday, 11, 2024), Some(thanksgiving));
+/// assert_eq!(nth_kday(5, Weekday::Thursday, 11, 2024), None);
+/// assert_eq!(nth_kday(0, Weekday::Thursday, 11, 2024), None);
+/// assert_eq!(nth_kday(1, Weekday::Thursday, 13, 2024), None);
+///
+/// // the five or six complementary days (month 13) need not contain a Sunday
+/// assert_eq!(nth_kday_in_month::<French>(1, Weekday::Sunday, 13, 232), None);
+/// ```
+pub fn nth_kday(n: i64, weekday: Weekday, month: i64, year: i64) -> Option<i64> {
+    return nth_kday_in_month::<Gregorian>(n, weekday, month, year);
+}
+
+/// Computes the absolute (fixed) date of the nth given weekday in a given
+/// month in a given year of any calendar implementing [`MonthLength`]. If n
+/// is negative, counts backwards from the end of the month. Returns `None` if
+/// n is zero, the month does not exist in the year, or the month has fewer
+/// than n such weekdays.
+pub fn nth_kday_in_month<C: MonthLength>(
+    n: i64,
+    weekday: Weekday,
+    month: i64,
+    year: i64,
+) -> Option<i64> {
+    if n == 0 || !C::months_of_year(year).any(|m| m == month) {
+        return None;
+    }
+    let first = C::from_year_month_day(year, month, 1).to_absolute();
+    let last = C::from_year_month_day(year, month, C::last_day_of_month(month, year)).to_absolute();
+    let date = if n > 0 {
+        kday_on_or_after(first, weekday.number()).checked_add((n - 1).checked_mul(7)?)?
+    } else {
+        kday_on_or_before(last, weekday.number()).checked_add((n + 1).checked_mul(7)?)?
+    };
+    return if first <= date && date <= last {
+        Some(date)
+    } else {
+        None
+    };
+}